@@ -0,0 +1,139 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+/// The price at a given number of launchpad tokens sold.
+/// Between two points the price changes linearly, and it stays at the last point's price after it.
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct CurvePoint<M: ManagedTypeApi> {
+    pub tokens_sold: BigUint<M>,
+    pub price: BigUint<M>,
+}
+
+#[multiversx_sc::module]
+pub trait CurveModule {
+    /// A single point gives a fixed price, two points a linear curve, and more a piecewise one.
+    /// The first point must be at zero tokens sold, and the prices may not decrease.
+    fn set_curve(&self, points: MultiValueEncoded<MultiValue2<BigUint, BigUint>>) {
+        let mut curve_points = self.curve_points();
+        curve_points.clear();
+
+        let mut opt_previous_point: Option<CurvePoint<Self::Api>> = None;
+        for point in points {
+            let (tokens_sold, price) = point.into_tuple();
+            require!(price > 0, "Invalid curve price");
+
+            match &opt_previous_point {
+                None => require!(tokens_sold == 0, "Curve must start at zero tokens sold"),
+                Some(previous_point) => {
+                    require!(
+                        tokens_sold > previous_point.tokens_sold,
+                        "Curve points must be in increasing order"
+                    );
+                    require!(
+                        price >= previous_point.price,
+                        "Curve price may not decrease"
+                    );
+                }
+            }
+
+            let curve_point = CurvePoint { tokens_sold, price };
+            let _ = curve_points.push(&curve_point);
+            opt_previous_point = Some(curve_point);
+        }
+
+        require!(!curve_points.is_empty(), "Empty curve");
+    }
+
+    /// Price of `price_unit` launchpad tokens, at the given number of tokens sold
+    fn get_price_at(&self, tokens_sold: &BigUint) -> BigUint {
+        let curve_points = self.curve_points();
+        let nr_points = curve_points.len();
+        for index in 1..nr_points {
+            let point = curve_points.get(index);
+            let next_point = curve_points.get(index + 1);
+            if *tokens_sold < next_point.tokens_sold {
+                let segment_length = &next_point.tokens_sold - &point.tokens_sold;
+                let price_increase = &next_point.price - &point.price;
+
+                return point.price
+                    + price_increase * (tokens_sold - &point.tokens_sold) / segment_length;
+            }
+        }
+
+        curve_points.get(nr_points).price
+    }
+
+    /// The area under the curve, between `from` and `from + amount` tokens sold,
+    /// divided by the price unit. Each segment is rounded up.
+    fn compute_buy_cost(&self, from: &BigUint, amount: &BigUint) -> BigUint {
+        let to = from + amount;
+        let price_unit = self.price_unit().get();
+        let curve_points = self.curve_points();
+        let nr_points = curve_points.len();
+
+        let mut cost = BigUint::zero();
+        for index in 1..=nr_points {
+            let point = curve_points.get(index);
+            if point.tokens_sold >= to {
+                break;
+            }
+
+            let lower = if *from > point.tokens_sold {
+                from.clone()
+            } else {
+                point.tokens_sold.clone()
+            };
+
+            if index == nr_points {
+                let flat_cost = (&to - &lower) * &point.price;
+                cost += div_ceil(flat_cost, &price_unit);
+                break;
+            }
+
+            let next_point = curve_points.get(index + 1);
+            if next_point.tokens_sold <= *from {
+                continue;
+            }
+
+            let upper = if to < next_point.tokens_sold {
+                to.clone()
+            } else {
+                next_point.tokens_sold.clone()
+            };
+
+            // trapezoid between the prices at the lower and upper bounds,
+            // with both prices scaled by twice the segment length
+            let segment_length = &next_point.tokens_sold - &point.tokens_sold;
+            let price_increase = &next_point.price - &point.price;
+            let bounds_offset = (&lower - &point.tokens_sold) + (&upper - &point.tokens_sold);
+            let scaled_prices_sum =
+                &point.price * &segment_length * 2u32 + price_increase * bounds_offset;
+            let segment_cost = (&upper - &lower) * scaled_prices_sum;
+            cost += div_ceil(segment_cost, &(segment_length * 2u32 * &price_unit));
+        }
+
+        cost
+    }
+
+    #[view(getCurvePoints)]
+    fn get_curve_points(&self) -> MultiValueEncoded<MultiValue2<BigUint, BigUint>> {
+        let mut result = MultiValueEncoded::new();
+        for point in self.curve_points().iter() {
+            result.push((point.tokens_sold, point.price).into());
+        }
+
+        result
+    }
+
+    #[storage_mapper("curvePoints")]
+    fn curve_points(&self) -> VecMapper<CurvePoint<Self::Api>>;
+
+    /// Amount of launchpad tokens the curve prices refer to, e.g. one whole token
+    #[view(getPriceUnit)]
+    #[storage_mapper("priceUnit")]
+    fn price_unit(&self) -> SingleValueMapper<BigUint>;
+}
+
+fn div_ceil<M: ManagedTypeApi>(numerator: BigUint<M>, denominator: &BigUint<M>) -> BigUint<M> {
+    (numerator + denominator - 1u32) / denominator
+}
@@ -0,0 +1,73 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+#[derive(TypeAbi, TopEncode)]
+pub struct BuyTokensEvent<M: ManagedTypeApi> {
+    user: ManagedAddress<M>,
+    round: u64,
+    epoch: u64,
+    amount: BigUint<M>,
+    cost: BigUint<M>,
+}
+
+#[derive(TypeAbi, TopEncode)]
+pub struct ClaimTokensEvent<M: ManagedTypeApi> {
+    user: ManagedAddress<M>,
+    round: u64,
+    epoch: u64,
+    amount: BigUint<M>,
+}
+
+#[multiversx_sc::module]
+pub trait EventsModule {
+    fn emit_buy_tokens_event(&self, user: ManagedAddress, amount: BigUint, cost: BigUint) {
+        let round = self.blockchain().get_block_round();
+        let epoch = self.blockchain().get_block_epoch();
+        self.buy_tokens_event(
+            user.clone(),
+            round,
+            epoch,
+            BuyTokensEvent {
+                user,
+                round,
+                epoch,
+                amount,
+                cost,
+            },
+        )
+    }
+
+    fn emit_claim_tokens_event(&self, user: ManagedAddress, amount: BigUint) {
+        let round = self.blockchain().get_block_round();
+        let epoch = self.blockchain().get_block_epoch();
+        self.claim_tokens_event(
+            user.clone(),
+            round,
+            epoch,
+            ClaimTokensEvent {
+                user,
+                round,
+                epoch,
+                amount,
+            },
+        )
+    }
+
+    #[event("buyTokens")]
+    fn buy_tokens_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] round: u64,
+        #[indexed] epoch: u64,
+        buy_tokens_event: BuyTokensEvent<Self::Api>,
+    );
+
+    #[event("claimLaunchpadTokens")]
+    fn claim_tokens_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] round: u64,
+        #[indexed] epoch: u64,
+        claim_tokens_event: ClaimTokensEvent<Self::Api>,
+    );
+}
@@ -0,0 +1,132 @@
+#![no_std]
+
+multiversx_sc::imports!();
+
+pub mod curve;
+pub mod events;
+pub mod sale;
+
+use launchpad_common::{
+    config::{TimelineConfig, TokenAmountPair},
+    launch_stage::{Flags, LaunchStage},
+};
+
+/// Launchpad variant without tickets, where the launchpad tokens are sold continuously,
+/// at a price following a curve of the tokens sold.
+/// Reuses the launch stages: the sale runs during the confirmation period,
+/// and the tokens are claimed from the claim start round (TGE).
+#[multiversx_sc::contract]
+pub trait LaunchpadBondingCurve:
+    launchpad_common::launch_stage::LaunchStageModule
+    + launchpad_common::config::ConfigModule
+    + launchpad_common::time_provider::TimeProviderModule
+    + curve::CurveModule
+    + sale::SaleModule
+    + events::EventsModule
+    + multiversx_sc_modules::pause::PauseModule
+{
+    /// The curve is given as `tokens_sold, price` pairs, with prices for `price_unit` tokens
+    #[allow(clippy::too_many_arguments)]
+    #[init]
+    fn init(
+        &self,
+        launchpad_token_id: TokenIdentifier,
+        payment_token: EgldOrEsdtTokenIdentifier,
+        price_unit: BigUint,
+        sale_start_round: u64,
+        sale_end_round: u64,
+        claim_start_round: u64,
+        curve_points: MultiValueEncoded<MultiValue2<BigUint, BigUint>>,
+    ) {
+        require!(
+            launchpad_token_id.is_valid_esdt_identifier(),
+            "Invalid launchpad token ID"
+        );
+        require!(payment_token.is_valid(), "Invalid payment token");
+        require!(price_unit > 0, "Invalid price unit");
+
+        let current_round = self.get_current_time();
+        require!(
+            current_round <= sale_start_round
+                && sale_start_round < sale_end_round
+                && sale_end_round <= claim_start_round,
+            "Invalid sale rounds"
+        );
+
+        self.set_curve(curve_points);
+        self.price_unit().set(price_unit);
+
+        let starting_price = self.get_price_at(&BigUint::zero());
+        self.launchpad_token_id().set(launchpad_token_id);
+        self.ticket_price().set(TokenAmountPair {
+            token_id: payment_token,
+            amount: starting_price,
+        });
+        self.configuration().set(TimelineConfig {
+            confirmation_period_start_round: sale_start_round,
+            winner_selection_start_round: sale_end_round,
+            claim_start_round,
+        });
+
+        // there is no winner selection
+        self.flags().set(Flags {
+            has_winner_selection_process_started: true,
+            were_tickets_filtered: true,
+            were_winners_selected: true,
+            was_additional_step_completed: true,
+        });
+    }
+
+    #[upgrade]
+    fn upgrade(&self) {}
+
+    /// May be called multiple times before the sale starts. All the deposited tokens are for sale.
+    #[only_owner]
+    #[payable("*")]
+    #[endpoint(depositLaunchpadTokens)]
+    fn deposit_launchpad_tokens(&self) {
+        require!(
+            self.get_launch_stage() == LaunchStage::AddTickets,
+            "Sale already started"
+        );
+
+        let (payment_token, payment_amount) = self.call_value().single_fungible_esdt();
+        require!(
+            payment_token == self.launchpad_token_id().get(),
+            "Wrong token"
+        );
+
+        self.launchpad_tokens_deposited().set(true);
+        self.total_launchpad_tokens_deposited()
+            .update(|deposited| *deposited += payment_amount);
+    }
+
+    /// Once the sale ended, sends the payment for the sold tokens to the owner,
+    /// along with the unsold launchpad tokens
+    #[only_owner]
+    #[endpoint(claimTicketPayment)]
+    fn claim_ticket_payment(&self) {
+        require!(
+            self.get_launch_stage() > LaunchStage::Confirm,
+            "Sale not ended yet"
+        );
+
+        let ticket_payment = self.claimable_ticket_payment().take();
+        let tokens_sold = self.total_tokens_sold().get();
+        let deposited_mapper = self.total_launchpad_tokens_deposited();
+        let unsold_tokens = deposited_mapper.get() - &tokens_sold;
+        require!(ticket_payment > 0 || unsold_tokens > 0, "Nothing to claim");
+
+        let owner = self.blockchain().get_caller();
+        if ticket_payment > 0 {
+            let ticket_price: TokenAmountPair<Self::Api> = self.ticket_price().get();
+            self.send()
+                .direct(&owner, &ticket_price.token_id, 0, &ticket_payment);
+        }
+        if unsold_tokens > 0 {
+            deposited_mapper.set(tokens_sold);
+            self.send()
+                .direct_esdt(&owner, &self.launchpad_token_id().get(), 0, &unsold_tokens);
+        }
+    }
+}
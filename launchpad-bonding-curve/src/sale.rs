@@ -0,0 +1,91 @@
+multiversx_sc::imports!();
+
+use launchpad_common::{config::TokenAmountPair, launch_stage::LaunchStage};
+
+#[multiversx_sc::module]
+pub trait SaleModule:
+    launchpad_common::launch_stage::LaunchStageModule
+    + launchpad_common::config::ConfigModule
+    + launchpad_common::time_provider::TimeProviderModule
+    + crate::curve::CurveModule
+    + crate::events::EventsModule
+    + multiversx_sc_modules::pause::PauseModule
+{
+    /// Buys at the curve's price during the sale. Any payment above the cost is sent back.
+    /// The launchpad tokens are claimed once the claim period starts.
+    #[payable("*")]
+    #[endpoint(buyTokens)]
+    fn buy_tokens(&self, amount: BigUint) {
+        self.require_not_paused();
+        require!(
+            self.get_launch_stage() == LaunchStage::Confirm,
+            "Sale not active"
+        );
+        require!(amount > 0, "Invalid amount");
+
+        let tokens_sold = self.total_tokens_sold().get();
+        require!(
+            &tokens_sold + &amount <= self.total_launchpad_tokens_deposited().get(),
+            "Not enough tokens left"
+        );
+
+        let (payment_token, payment_amount) = self.call_value().egld_or_single_fungible_esdt();
+        let ticket_price: TokenAmountPair<Self::Api> = self.ticket_price().get();
+        require!(
+            payment_token == ticket_price.token_id,
+            "Wrong payment token"
+        );
+
+        let cost = self.compute_buy_cost(&tokens_sold, &amount);
+        require!(payment_amount >= cost, "Payment too low");
+
+        let caller = self.blockchain().get_caller();
+        let excess_payment = &payment_amount - &cost;
+        if excess_payment > 0 {
+            self.send()
+                .direct(&caller, &payment_token, 0, &excess_payment);
+        }
+
+        self.total_tokens_sold().set(&tokens_sold + &amount);
+        self.user_tokens_bought(&caller)
+            .update(|bought| *bought += &amount);
+        self.claimable_ticket_payment()
+            .update(|claimable| *claimable += &cost);
+
+        self.emit_buy_tokens_event(caller, amount, cost);
+    }
+
+    #[endpoint(claimLaunchpadTokens)]
+    fn claim_launchpad_tokens(&self) {
+        self.require_not_paused();
+        self.require_claim_period();
+
+        let caller = self.blockchain().get_caller();
+        let amount = self.user_tokens_bought(&caller).take();
+        require!(amount > 0, "Nothing to claim");
+
+        self.send()
+            .direct_esdt(&caller, &self.launchpad_token_id().get(), 0, &amount);
+
+        self.emit_claim_tokens_event(caller, amount);
+    }
+
+    #[view(getCurrentPrice)]
+    fn get_current_price(&self) -> BigUint {
+        self.get_price_at(&self.total_tokens_sold().get())
+    }
+
+    #[view(getBuyCost)]
+    fn get_buy_cost(&self, amount: BigUint) -> BigUint {
+        self.compute_buy_cost(&self.total_tokens_sold().get(), &amount)
+    }
+
+    #[view(getTotalTokensSold)]
+    #[storage_mapper("totalTokensSold")]
+    fn total_tokens_sold(&self) -> SingleValueMapper<BigUint>;
+
+    /// Cleared on claim
+    #[view(getUserTokensBought)]
+    #[storage_mapper("userTokensBought")]
+    fn user_tokens_bought(&self, address: &ManagedAddress) -> SingleValueMapper<BigUint>;
+}
@@ -0,0 +1,136 @@
+use multiversx_sc::types::{Address, EgldOrEsdtTokenIdentifier, MultiValueEncoded};
+
+use launchpad_bonding_curve::{sale::SaleModule, LaunchpadBondingCurve};
+use launchpad_common::config::ConfigModule;
+use multiversx_sc_scenario::{
+    managed_biguint, managed_token_id, rust_biguint,
+    testing_framework::{BlockchainStateWrapper, ContractObjWrapper, TxResult},
+    DebugApi,
+};
+
+pub static LAUNCHPAD_TOKEN_ID: &[u8] = b"LAUNCH-123456";
+pub const TOTAL_LAUNCHPAD_TOKENS: u64 = 200;
+pub const PRICE_UNIT: u64 = 1;
+pub const SALE_START_ROUND: u64 = 10;
+pub const SALE_END_ROUND: u64 = 20;
+pub const CLAIM_START_ROUND: u64 = 25;
+
+pub const NR_PARTICIPANTS: usize = 3;
+pub const USER_BALANCE: u64 = 5_000;
+
+/// Linear from 10 to 30 over the first 100 tokens, then flat
+pub const CURVE_POINTS: [(u64, u64); 2] = [(0, 10), (100, 30)];
+
+pub struct BondingCurveSetup<BondingCurveBuilder>
+where
+    BondingCurveBuilder: 'static + Copy + Fn() -> launchpad_bonding_curve::ContractObj<DebugApi>,
+{
+    pub b_mock: BlockchainStateWrapper,
+    pub owner_address: Address,
+    pub participants: Vec<Address>,
+    pub bc_wrapper:
+        ContractObjWrapper<launchpad_bonding_curve::ContractObj<DebugApi>, BondingCurveBuilder>,
+}
+
+impl<BondingCurveBuilder> BondingCurveSetup<BondingCurveBuilder>
+where
+    BondingCurveBuilder: 'static + Copy + Fn() -> launchpad_bonding_curve::ContractObj<DebugApi>,
+{
+    pub fn new(bc_builder: BondingCurveBuilder) -> Self {
+        let rust_zero = rust_biguint!(0u64);
+        let mut b_mock = BlockchainStateWrapper::new();
+        let owner_address = b_mock.create_user_account(&rust_zero);
+        let mut participants = Vec::new();
+        for _ in 0..NR_PARTICIPANTS {
+            let addr = b_mock.create_user_account(&rust_biguint!(USER_BALANCE));
+            participants.push(addr);
+        }
+
+        b_mock.set_esdt_balance(
+            &owner_address,
+            LAUNCHPAD_TOKEN_ID,
+            &rust_biguint!(TOTAL_LAUNCHPAD_TOKENS),
+        );
+
+        let bc_wrapper = b_mock.create_sc_account(
+            &rust_zero,
+            Some(&owner_address),
+            bc_builder,
+            "bonding curve.wasm",
+        );
+
+        b_mock
+            .execute_tx(&owner_address, &bc_wrapper, &rust_zero, |sc| {
+                let mut curve_points = MultiValueEncoded::new();
+                for (tokens_sold, price) in CURVE_POINTS {
+                    curve_points
+                        .push((managed_biguint!(tokens_sold), managed_biguint!(price)).into());
+                }
+
+                sc.init(
+                    managed_token_id!(LAUNCHPAD_TOKEN_ID),
+                    EgldOrEsdtTokenIdentifier::egld(),
+                    managed_biguint!(PRICE_UNIT),
+                    SALE_START_ROUND,
+                    SALE_END_ROUND,
+                    CLAIM_START_ROUND,
+                    curve_points,
+                );
+            })
+            .assert_ok();
+
+        b_mock
+            .execute_esdt_transfer(
+                &owner_address,
+                &bc_wrapper,
+                LAUNCHPAD_TOKEN_ID,
+                0,
+                &rust_biguint!(TOTAL_LAUNCHPAD_TOKENS),
+                |sc| {
+                    sc.deposit_launchpad_tokens();
+
+                    assert_eq!(
+                        sc.total_launchpad_tokens_deposited().get(),
+                        managed_biguint!(TOTAL_LAUNCHPAD_TOKENS)
+                    );
+                },
+            )
+            .assert_ok();
+
+        BondingCurveSetup {
+            b_mock,
+            owner_address,
+            participants,
+            bc_wrapper,
+        }
+    }
+
+    pub fn buy(&mut self, user: &Address, amount: u64, payment_amount: u64) -> TxResult {
+        self.b_mock.execute_tx(
+            user,
+            &self.bc_wrapper,
+            &rust_biguint!(payment_amount),
+            |sc| {
+                sc.buy_tokens(managed_biguint!(amount));
+            },
+        )
+    }
+
+    pub fn claim(&mut self, user: &Address) -> TxResult {
+        self.b_mock
+            .execute_tx(user, &self.bc_wrapper, &rust_biguint!(0), |sc| {
+                sc.claim_launchpad_tokens();
+            })
+    }
+
+    pub fn claim_ticket_payment(&mut self) -> TxResult {
+        self.b_mock.execute_tx(
+            &self.owner_address,
+            &self.bc_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.claim_ticket_payment();
+            },
+        )
+    }
+}
@@ -0,0 +1,154 @@
+mod bonding_curve_setup;
+
+use bonding_curve_setup::{
+    BondingCurveSetup, CLAIM_START_ROUND, LAUNCHPAD_TOKEN_ID, SALE_END_ROUND, SALE_START_ROUND,
+    TOTAL_LAUNCHPAD_TOKENS, USER_BALANCE,
+};
+use launchpad_bonding_curve::{curve::CurveModule, sale::SaleModule, LaunchpadBondingCurve};
+use multiversx_sc::types::{EgldOrEsdtTokenIdentifier, MultiValueEncoded};
+use multiversx_sc_scenario::{managed_biguint, managed_token_id, rust_biguint};
+
+#[test]
+fn init_test() {
+    let _ = BondingCurveSetup::new(launchpad_bonding_curve::contract_obj);
+}
+
+#[test]
+fn curve_price_and_cost_test() {
+    let mut bc_setup = BondingCurveSetup::new(launchpad_bonding_curve::contract_obj);
+
+    bc_setup
+        .b_mock
+        .execute_query(&bc_setup.bc_wrapper, |sc| {
+            assert_eq!(sc.get_price_at(&managed_biguint!(0)), managed_biguint!(10));
+            assert_eq!(sc.get_price_at(&managed_biguint!(50)), managed_biguint!(20));
+            assert_eq!(
+                sc.get_price_at(&managed_biguint!(150)),
+                managed_biguint!(30)
+            );
+
+            // average price of 20 on the linear part, then 30 on the flat part
+            assert_eq!(
+                sc.compute_buy_cost(&managed_biguint!(0), &managed_biguint!(100)),
+                managed_biguint!(2_000)
+            );
+            assert_eq!(
+                sc.compute_buy_cost(&managed_biguint!(50), &managed_biguint!(50)),
+                managed_biguint!(1_250)
+            );
+            assert_eq!(
+                sc.compute_buy_cost(&managed_biguint!(0), &managed_biguint!(150)),
+                managed_biguint!(3_500)
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn buy_and_claim_test() {
+    let mut bc_setup = BondingCurveSetup::new(launchpad_bonding_curve::contract_obj);
+    let participants = bc_setup.participants.clone();
+
+    bc_setup
+        .buy(&participants[0], 50, 750)
+        .assert_user_error("Sale not active");
+
+    // the first 50 tokens cost 750, the extra 50 is sent back
+    bc_setup.b_mock.set_block_round(SALE_START_ROUND);
+    bc_setup.buy(&participants[0], 50, 800).assert_ok();
+    bc_setup
+        .b_mock
+        .check_egld_balance(&participants[0], &rust_biguint!(USER_BALANCE - 750));
+
+    bc_setup
+        .buy(&participants[1], 50, 1_249)
+        .assert_user_error("Payment too low");
+    bc_setup.buy(&participants[1], 50, 1_250).assert_ok();
+
+    bc_setup
+        .buy(&participants[2], 101, 5_000)
+        .assert_user_error("Not enough tokens left");
+    bc_setup.buy(&participants[2], 50, 1_500).assert_ok();
+
+    bc_setup
+        .b_mock
+        .execute_query(&bc_setup.bc_wrapper, |sc| {
+            assert_eq!(sc.get_current_price(), managed_biguint!(30));
+            assert_eq!(sc.total_tokens_sold().get(), managed_biguint!(150));
+        })
+        .assert_ok();
+
+    bc_setup
+        .claim_ticket_payment()
+        .assert_user_error("Sale not ended yet");
+    bc_setup
+        .claim(&participants[0])
+        .assert_user_error("Not in claim period");
+
+    // the payment and the unsold tokens go to the owner once the sale ended
+    bc_setup.b_mock.set_block_round(SALE_END_ROUND);
+    bc_setup.claim_ticket_payment().assert_ok();
+    bc_setup
+        .b_mock
+        .check_egld_balance(&bc_setup.owner_address, &rust_biguint!(3_500));
+    bc_setup.b_mock.check_esdt_balance(
+        &bc_setup.owner_address,
+        LAUNCHPAD_TOKEN_ID,
+        &rust_biguint!(TOTAL_LAUNCHPAD_TOKENS - 150),
+    );
+    bc_setup
+        .claim_ticket_payment()
+        .assert_user_error("Nothing to claim");
+
+    bc_setup.b_mock.set_block_round(CLAIM_START_ROUND);
+    bc_setup.claim(&participants[0]).assert_ok();
+    bc_setup
+        .b_mock
+        .check_esdt_balance(&participants[0], LAUNCHPAD_TOKEN_ID, &rust_biguint!(50));
+    bc_setup
+        .claim(&participants[0])
+        .assert_user_error("Nothing to claim");
+}
+
+#[test]
+fn invalid_curve_test() {
+    let mut bc_setup = BondingCurveSetup::new(launchpad_bonding_curve::contract_obj);
+    let owner_address = bc_setup.owner_address.clone();
+
+    for (curve_points, expected_err) in [
+        (vec![(10u64, 10u64)], "Curve must start at zero tokens sold"),
+        (
+            vec![(0, 10), (0, 20)],
+            "Curve points must be in increasing order",
+        ),
+        (vec![(0, 20), (100, 10)], "Curve price may not decrease"),
+        (vec![(0, 0)], "Invalid curve price"),
+        (vec![], "Empty curve"),
+    ] {
+        let new_wrapper = bc_setup.b_mock.create_sc_account(
+            &rust_biguint!(0),
+            Some(&owner_address),
+            launchpad_bonding_curve::contract_obj,
+            "bonding curve.wasm",
+        );
+        bc_setup
+            .b_mock
+            .execute_tx(&owner_address, &new_wrapper, &rust_biguint!(0), |sc| {
+                let mut points = MultiValueEncoded::new();
+                for (tokens_sold, price) in curve_points.iter() {
+                    points.push((managed_biguint!(*tokens_sold), managed_biguint!(*price)).into());
+                }
+
+                sc.init(
+                    managed_token_id!(LAUNCHPAD_TOKEN_ID),
+                    EgldOrEsdtTokenIdentifier::egld(),
+                    managed_biguint!(1),
+                    SALE_START_ROUND,
+                    SALE_END_ROUND,
+                    CLAIM_START_ROUND,
+                    points,
+                );
+            })
+            .assert_user_error(expected_err);
+    }
+}
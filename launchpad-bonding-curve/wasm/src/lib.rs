@@ -0,0 +1,65 @@
+// Code generated by the multiversx-sc build system. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+// Init:                                 1
+// Upgrade:                              1
+// Endpoints:                           39
+// Async Callback (empty):               1
+// Total number of exported functions:  42
+
+#![no_std]
+
+multiversx_sc_wasm_adapter::allocator!();
+multiversx_sc_wasm_adapter::panic_handler!();
+
+multiversx_sc_wasm_adapter::endpoints! {
+    launchpad_bonding_curve
+    (
+        init => init
+        upgrade => upgrade
+        depositLaunchpadTokens => deposit_launchpad_tokens
+        claimTicketPayment => claim_ticket_payment
+        getCurrentStage => get_current_stage
+        getLaunchPhases => get_launch_phases
+        getLaunchStageFlags => flags
+        isAllowedSmartContractCaller => is_allowed_smart_contract_caller
+        getLaunchpadTokenId => launchpad_token_id
+        getLaunchpadTokensPerWinningTicket => launchpad_tokens_per_winning_ticket
+        getTicketPrice => ticket_price
+        getNumberOfWinningTickets => nr_winning_tickets
+        getTotalLaunchpadTokensDeposited => total_launchpad_tokens_deposited
+        getClaimDeadlineRound => claim_deadline_round
+        getRefundWindowStartRound => refund_window_start_round
+        getBurnUnsoldLaunchpadTokens => burn_unsold_launchpad_tokens
+        getDynamicWinningTickets => dynamic_winning_tickets
+        getGuaranteedTicketPerConfirmer => guaranteed_ticket_per_confirmer
+        isOrderedSelectionMode => ordered_selection_mode
+        getMaxWinningTicketsPerAddress => max_winning_tickets_per_address
+        getMaxConfirmedTicketsPerAddress => max_confirmed_tickets_per_address
+        getHardCap => hard_cap
+        getFcfsRound => fcfs_round
+        getTreasuryAddress => treasury_address
+        getClaimOnBehalfGraceRounds => claim_on_behalf_grace_rounds
+        getDenySmartContractCallers => deny_smart_contract_callers
+        getClaimableTicketPayment => claimable_ticket_payment
+        getUserTicketPayment => user_ticket_payment
+        getTotalRefundableTicketPayment => total_refundable_ticket_payment
+        getTimeUnit => time_unit
+        getCurvePoints => get_curve_points
+        getPriceUnit => price_unit
+        buyTokens => buy_tokens
+        claimLaunchpadTokens => claim_launchpad_tokens
+        getCurrentPrice => get_current_price
+        getBuyCost => get_buy_cost
+        getTotalTokensSold => total_tokens_sold
+        getUserTokensBought => user_tokens_bought
+        pause => pause_endpoint
+        unpause => unpause_endpoint
+        isPaused => paused_status
+    )
+}
+
+multiversx_sc_wasm_adapter::async_callback_empty! {}
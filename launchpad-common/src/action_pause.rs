@@ -0,0 +1,63 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::permissions::Role;
+
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Copy)]
+pub enum PausableAction {
+    Confirmations,
+    Claims,
+    AdminSetters,
+}
+
+#[multiversx_sc::module]
+pub trait ActionPauseModule:
+    crate::permissions::PermissionsModule + crate::common_events::CommonEventsModule
+{
+    /// Pauses a single kind of action, while `pause` freezes the whole contract.
+    /// Pausing the confirmations also pauses unconfirming tickets.
+    #[endpoint(pauseAction)]
+    fn pause_action(&self, action: PausableAction) {
+        self.require_role(Role::Admin);
+
+        let paused_mapper = self.action_paused(action);
+        require!(!paused_mapper.get(), "Action already paused");
+
+        paused_mapper.set(true);
+
+        self.emit_pause_action_event(action);
+    }
+
+    #[endpoint(unpauseAction)]
+    fn unpause_action(&self, action: PausableAction) {
+        self.require_role(Role::Admin);
+
+        let paused_mapper = self.action_paused(action);
+        require!(paused_mapper.get(), "Action not paused");
+
+        paused_mapper.clear();
+
+        self.emit_unpause_action_event(action);
+    }
+
+    fn require_action_not_paused(&self, action: PausableAction) {
+        if !self.action_paused(action).get() {
+            return;
+        }
+
+        match action {
+            PausableAction::Confirmations => sc_panic!("Confirmations are paused"),
+            PausableAction::Claims => sc_panic!("Claims are paused"),
+            PausableAction::AdminSetters => sc_panic!("Admin setters are paused"),
+        }
+    }
+
+    fn require_config_manager(&self) {
+        self.require_role(Role::ConfigManager);
+        self.require_action_not_paused(PausableAction::AdminSetters);
+    }
+
+    #[view(isActionPaused)]
+    #[storage_mapper("actionPaused")]
+    fn action_paused(&self, action: PausableAction) -> SingleValueMapper<bool>;
+}
@@ -0,0 +1,134 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::{action_pause::PausableAction, permissions::Role, timelock::PendingChangeType};
+
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct AdminProposal<M: ManagedTypeApi> {
+    pub action: PendingChangeType<M>,
+    pub proposer: ManagedAddress<M>,
+    pub approvers: ManagedVec<M, ManagedAddress<M>>,
+}
+
+#[multiversx_sc::module]
+pub trait AdminProposalModule:
+    crate::launch_stage::LaunchStageModule
+    + crate::config::ConfigModule
+    + crate::permissions::PermissionsModule
+    + crate::action_pause::ActionPauseModule
+    + crate::timelock::TimelockModule
+    + crate::setup::SetupModule
+{
+    /// Number of distinct admins which have to approve a proposal before it may be executed
+    #[endpoint(setAdminProposalQuorum)]
+    fn set_admin_proposal_quorum(&self, quorum: usize) {
+        self.require_role(Role::Admin);
+        require!(quorum > 0, "Invalid quorum");
+
+        self.admin_proposal_quorum().set(quorum);
+    }
+
+    /// The proposer's approval is counted. Returns the proposal ID.
+    #[endpoint(proposeAdminAction)]
+    fn propose_admin_action(&self, action: PendingChangeType<Self::Api>) -> usize {
+        self.require_extended_permissions();
+        require!(
+            !self.admin_proposal_quorum().is_empty(),
+            "Admin proposals not enabled"
+        );
+
+        let caller = self.blockchain().get_caller();
+        let proposal_id = self.last_admin_proposal_id().update(|id| {
+            *id += 1;
+            *id
+        });
+        self.admin_proposal(proposal_id).set(AdminProposal {
+            action,
+            proposer: caller.clone(),
+            approvers: ManagedVec::from_single_item(caller),
+        });
+
+        proposal_id
+    }
+
+    #[endpoint(approveAdminProposal)]
+    fn approve_admin_proposal(&self, proposal_id: usize) {
+        self.require_extended_permissions();
+
+        let proposal_mapper = self.admin_proposal(proposal_id);
+        require!(!proposal_mapper.is_empty(), "Proposal not found");
+
+        let caller = self.blockchain().get_caller();
+        proposal_mapper.update(|proposal| {
+            require!(
+                !proposal.approvers.contains(&caller),
+                "Proposal already approved"
+            );
+
+            proposal.approvers.push(caller);
+        });
+    }
+
+    /// Only the proposer or the owner may cancel a proposal
+    #[endpoint(cancelAdminProposal)]
+    fn cancel_admin_proposal(&self, proposal_id: usize) {
+        let proposal_mapper = self.admin_proposal(proposal_id);
+        require!(!proposal_mapper.is_empty(), "Proposal not found");
+
+        let caller = self.blockchain().get_caller();
+        let owner = self.blockchain().get_owner_address();
+        require!(
+            caller == owner || caller == proposal_mapper.get().proposer,
+            "Permission denied"
+        );
+
+        proposal_mapper.clear();
+    }
+
+    /// Anyone may execute a proposal which reached the quorum.
+    /// If the timelock is enabled, the action is queued like any other sensitive change.
+    fn execute_admin_proposal<CustomChangeFn: Fn(&Self, ManagedBuffer)>(
+        &self,
+        proposal_id: usize,
+        custom_change_fn: CustomChangeFn,
+    ) {
+        self.require_action_not_paused(PausableAction::AdminSetters);
+
+        let proposal_mapper = self.admin_proposal(proposal_id);
+        require!(!proposal_mapper.is_empty(), "Proposal not found");
+
+        let proposal = proposal_mapper.take();
+        let nr_approvals = self.get_nr_valid_approvals(&proposal.approvers);
+        require!(
+            nr_approvals >= self.admin_proposal_quorum().get(),
+            "Quorum not reached"
+        );
+
+        if self.is_timelock_enabled() {
+            let _ = self.queue_pending_change(proposal.action);
+
+            return;
+        }
+
+        self.apply_change(proposal.action, custom_change_fn);
+    }
+
+    /// Approvals of addresses which lost their permissions in the meantime are not counted
+    fn get_nr_valid_approvals(&self, approvers: &ManagedVec<ManagedAddress>) -> usize {
+        let mut nr_approvals = 0;
+        for approver in approvers {
+            if self.is_owner_or_admin(&approver) {
+                nr_approvals += 1;
+            }
+        }
+
+        nr_approvals
+    }
+
+    #[storage_mapper("lastAdminProposalId")]
+    fn last_admin_proposal_id(&self) -> SingleValueMapper<usize>;
+
+    #[view(getAdminProposal)]
+    #[storage_mapper("adminProposal")]
+    fn admin_proposal(&self, proposal_id: usize) -> SingleValueMapper<AdminProposal<Self::Api>>;
+}
@@ -1,4 +1,25 @@
 multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::permissions::Role;
+
+#[derive(
+    TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Copy, Debug,
+)]
+pub enum BlacklistReason {
+    Unspecified,
+    Sanctions,
+    KycFailed,
+    Fraud,
+    MultipleAccounts,
+    RefundRequested,
+}
+
+#[derive(TypeAbi, TopEncode, TopDecode)]
+pub struct BlacklistEntry {
+    pub reason: BlacklistReason,
+    pub timestamp: u64,
+}
 
 #[multiversx_sc::module]
 pub trait BlacklistModule:
@@ -8,12 +29,20 @@ pub trait BlacklistModule:
     + crate::token_send::TokenSendModule
     + crate::config::ConfigModule
     + crate::common_events::CommonEventsModule
+    + crate::guardian::GuardianModule
 {
-    fn add_users_to_blacklist(&self, users_list: &ManagedVec<ManagedAddress>) {
-        self.require_extended_permissions();
+    fn add_users_to_blacklist(
+        &self,
+        users_list: &ManagedVec<ManagedAddress>,
+        reason: BlacklistReason,
+    ) {
+        self.require_role(Role::BlacklistManager);
         self.require_before_winner_selection();
+        self.require_blacklist_batch_approval(users_list);
 
+        let timestamp = self.blockchain().get_block_timestamp();
         let blacklist_mapper = self.blacklist();
+        let mut blacklisted_users_mapper = self.blacklisted_users();
         for address in users_list {
             require!(
                 !blacklist_mapper.contains(&address),
@@ -25,28 +54,47 @@ pub trait BlacklistModule:
                 "User has no ticket allowance"
             );
 
-            let confirmed_tickets_mapper = self.nr_confirmed_tickets(&address);
-            let nr_confirmed_tickets = confirmed_tickets_mapper.get();
+            let nr_confirmed_tickets = self.nr_confirmed_tickets(&address).take();
             if nr_confirmed_tickets > 0 {
-                self.refund_ticket_payment(&address, nr_confirmed_tickets);
-                confirmed_tickets_mapper.clear();
+                self.total_participants().update(|total| *total -= 1);
+                let _ = self.confirmed_users().swap_remove(&address);
             }
+            self.total_confirmed_tickets()
+                .update(|total| *total -= nr_confirmed_tickets);
+
+            let ticket_refund = self.release_user_tickets(&address, nr_confirmed_tickets);
+            self.total_payment_raised()
+                .update(|total| *total -= &ticket_refund.payment.amount);
 
             blacklist_mapper.add(&address);
+            let _ = blacklisted_users_mapper.insert(address.clone());
+            self.blacklist_entry(&address)
+                .set(BlacklistEntry { reason, timestamp });
+
+            self.send_ticket_refund(&address, &ticket_refund);
+
+            self.emit_blacklist_user_event(address, reason, timestamp);
         }
     }
 
+    /// The refund at blacklisting is final, so the users are counted again in the totals
+    /// only once they confirm tickets again
     fn remove_users_from_blacklist(&self, users_list: MultiValueEncoded<ManagedAddress>) {
-        self.require_extended_permissions();
+        self.require_role(Role::BlacklistManager);
         self.require_before_winner_selection();
 
         let blacklist_mapper = self.blacklist();
+        let mut blacklisted_users_mapper = self.blacklisted_users();
         for address in users_list {
             require!(
                 blacklist_mapper.contains(&address),
                 "User is not blacklisted"
             );
             blacklist_mapper.remove(&address);
+            let _ = blacklisted_users_mapper.swap_remove(&address);
+            self.blacklist_entry(&address).clear();
+
+            self.emit_remove_user_from_blacklist_event(address);
         }
     }
 
@@ -55,6 +103,41 @@ pub trait BlacklistModule:
         self.blacklist().contains(address)
     }
 
+    /// Page of the blacklisted users, as (address, reason, timestamp) entries.
+    /// `from` is zero-based. The order changes when users are removed from the blacklist.
+    #[view(getBlacklistedUsers)]
+    fn get_blacklisted_users(
+        &self,
+        from: usize,
+        size: usize,
+    ) -> MultiValueEncoded<MultiValue3<ManagedAddress, BlacklistReason, u64>> {
+        let blacklisted_users_mapper = self.blacklisted_users();
+        let nr_users = blacklisted_users_mapper.len();
+        require!(from <= nr_users, "Invalid start index");
+
+        let mut result = MultiValueEncoded::new();
+        let end = core::cmp::min(from.saturating_add(size), nr_users);
+        for index in from..end {
+            let address = blacklisted_users_mapper.get_by_index(index + 1);
+            let entry = self.blacklist_entry(&address).get();
+            result.push((address, entry.reason, entry.timestamp).into());
+        }
+
+        result
+    }
+
+    #[view(getNrBlacklistedUsers)]
+    fn get_nr_blacklisted_users(&self) -> usize {
+        self.blacklisted_users().len()
+    }
+
     #[storage_mapper("blacklisted")]
     fn blacklist(&self) -> WhitelistMapper<Self::Api, ManagedAddress>;
+
+    #[storage_mapper("blacklistedUsers")]
+    fn blacklisted_users(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[view(getBlacklistEntry)]
+    #[storage_mapper("blacklistEntry")]
+    fn blacklist_entry(&self, address: &ManagedAddress) -> SingleValueMapper<BlacklistEntry>;
 }
@@ -0,0 +1,104 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+#[derive(TypeAbi, TopEncode, TopDecode)]
+pub struct StakingContract<M: ManagedTypeApi> {
+    pub address: ManagedAddress<M>,
+    pub endpoint_name: ManagedBuffer<M>,
+}
+
+#[multiversx_sc::module]
+pub trait ClaimAndStakeModule:
+    crate::launch_stage::LaunchStageModule
+    + crate::config::ConfigModule
+    + crate::blacklist::BlacklistModule
+    + crate::tickets::TicketsModule
+    + crate::token_send::TokenSendModule
+    + crate::permissions::PermissionsModule
+    + crate::action_pause::ActionPauseModule
+    + crate::common_events::CommonEventsModule
+    + crate::user_interactions::UserInteractionsModule
+    + multiversx_sc_modules::pause::PauseModule
+{
+    /// The endpoint receives the launchpad tokens and the user's address as its only argument,
+    /// e.g. `stakeFarm` of a farm-staking contract which whitelisted the launchpad
+    #[endpoint(setStakingContract)]
+    fn set_staking_contract(&self, address: ManagedAddress, endpoint_name: ManagedBuffer) {
+        self.require_config_manager();
+        require!(
+            self.blockchain().is_smart_contract(&address),
+            "Invalid SC address"
+        );
+        require!(!endpoint_name.is_empty(), "Invalid endpoint name");
+
+        self.staking_contract().set(StakingContract {
+            address,
+            endpoint_name,
+        });
+    }
+
+    #[endpoint(removeStakingContract)]
+    fn remove_staking_contract(&self) {
+        self.require_config_manager();
+
+        self.staking_contract().clear();
+    }
+
+    /// Same as `claimLaunchpadTokens`, but the launchpad tokens are staked on the caller's behalf.
+    /// The ticket payment refund is still sent to the caller.
+    /// If staking fails, the launchpad tokens are sent to the caller instead.
+    #[endpoint(claimAndStake)]
+    fn claim_and_stake(&self) {
+        let staking_contract_mapper = self.staking_contract();
+        require!(
+            !staking_contract_mapper.is_empty(),
+            "Staking is not enabled"
+        );
+
+        let tokens_claimed_before = self.total_tokens_claimed().get();
+        self.claim_launchpad_tokens(OptionalValue::None, |_, _, _, _| {});
+        let launchpad_tokens_amount = self.total_tokens_claimed().get() - tokens_claimed_before;
+        if launchpad_tokens_amount == 0 {
+            return;
+        }
+
+        let caller = self.blockchain().get_caller();
+        let launchpad_token_id = self.launchpad_token_id().get();
+        let staking_contract = staking_contract_mapper.get();
+        self.tx()
+            .to(&staking_contract.address)
+            .raw_call(staking_contract.endpoint_name)
+            .single_esdt(&launchpad_token_id, 0, &launchpad_tokens_amount)
+            .argument(&caller)
+            .callback(self.callbacks().claim_and_stake_callback(
+                caller.clone(),
+                EsdtTokenPayment::new(
+                    launchpad_token_id.clone(),
+                    0,
+                    launchpad_tokens_amount.clone(),
+                ),
+            ))
+            .async_call_and_exit();
+    }
+
+    #[callback]
+    fn claim_and_stake_callback(
+        &self,
+        user: ManagedAddress,
+        launchpad_tokens: EsdtTokenPayment,
+        #[call_result] result: ManagedAsyncCallResult<IgnoreValue>,
+    ) {
+        if let ManagedAsyncCallResult::Err(_) = result {
+            self.send().direct_esdt(
+                &user,
+                &launchpad_tokens.token_identifier,
+                launchpad_tokens.token_nonce,
+                &launchpad_tokens.amount,
+            );
+        }
+    }
+
+    #[view(getStakingContract)]
+    #[storage_mapper("stakingContract")]
+    fn staking_contract(&self) -> SingleValueMapper<StakingContract<Self::Api>>;
+}
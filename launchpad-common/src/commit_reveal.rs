@@ -0,0 +1,131 @@
+multiversx_sc::imports!();
+
+use crate::{
+    launch_stage::LaunchStage,
+    random::{Hash, Random},
+};
+
+#[multiversx_sc::module]
+pub trait CommitRevealModule:
+    crate::launch_stage::LaunchStageModule
+    + crate::config::ConfigModule
+    + crate::tickets::TicketsModule
+    + crate::common_events::CommonEventsModule
+    + crate::permissions::PermissionsModule
+    + crate::action_pause::ActionPauseModule
+{
+    #[endpoint(setUserEntropyCommitsEnabled)]
+    fn set_user_entropy_commits_enabled(&self, enabled: bool) {
+        self.require_config_manager();
+        self.require_commit_period();
+
+        self.user_entropy_commits_enabled().set(enabled);
+    }
+
+    /// The owner may always commit. Users with tickets may only commit if enabled.
+    /// The owner's entropy has to be revealed before the winner selection may start.
+    /// The users' commitments which are never revealed are ignored. As the block random seed
+    /// is only known when the selection starts, withholding a reveal does not give a user
+    /// a known outcome to choose, so it is not penalised.
+    #[endpoint(commitEntropy)]
+    fn commit_entropy(&self, entropy_hash: Hash<Self::Api>) {
+        self.require_commit_period();
+
+        let caller = self.blockchain().get_caller();
+        let owner = self.blockchain().get_owner_address();
+        if caller != owner {
+            require!(
+                self.user_entropy_commits_enabled().get(),
+                "User entropy commits are not enabled"
+            );
+            require!(
+                !self.ticket_range_for_address(&caller).is_empty(),
+                "You have no tickets"
+            );
+        }
+
+        let commitment_mapper = self.entropy_commitment(&caller);
+        require!(commitment_mapper.is_empty(), "Entropy already committed");
+
+        commitment_mapper.set(&entropy_hash);
+    }
+
+    /// Reveals are accepted during the winner selection period, until the selection starts
+    #[endpoint(revealEntropy)]
+    fn reveal_entropy(&self, entropy: ManagedBuffer) {
+        require!(
+            self.get_launch_stage() == LaunchStage::WinnerSelection,
+            "Not in winner selection period"
+        );
+        require!(
+            self.winner_selection_seed().is_empty(),
+            "Winner selection already started"
+        );
+
+        let caller = self.blockchain().get_caller();
+        let commitment_mapper = self.entropy_commitment(&caller);
+        require!(!commitment_mapper.is_empty(), "No entropy committed");
+
+        let entropy_hash = self.crypto().sha256(&entropy);
+        require!(
+            entropy_hash == commitment_mapper.get(),
+            "Entropy does not match commitment"
+        );
+        commitment_mapper.clear();
+
+        let mut mixed_entropy = self.revealed_entropy().get();
+        mixed_entropy.append(&entropy);
+        let new_revealed_entropy = self.crypto().sha256(&mixed_entropy);
+        self.revealed_entropy()
+            .set(new_revealed_entropy.as_managed_buffer());
+    }
+
+    /// If any entropy was revealed, it is mixed with the block random seed.
+    /// The resulting seed is saved, so it can be checked by anyone.
+    fn create_winner_selection_rng(&self) -> Random<Self::Api> {
+        let owner = self.blockchain().get_owner_address();
+        require!(
+            self.entropy_commitment(&owner).is_empty(),
+            "Owner entropy not revealed"
+        );
+
+        let revealed_entropy = self.revealed_entropy().get();
+        let rng = if revealed_entropy.is_empty() {
+            Random::default()
+        } else {
+            let mut seed = self
+                .blockchain()
+                .get_block_random_seed()
+                .as_managed_buffer()
+                .clone();
+            seed.append(&revealed_entropy);
+
+            Random::from_hash(self.crypto().sha256(&seed), 0)
+        };
+        self.winner_selection_seed().set(&rng.seed);
+
+        rng
+    }
+
+    fn require_commit_period(&self) {
+        require!(
+            self.get_launch_stage() < LaunchStage::WinnerSelection,
+            "Commit period has passed"
+        );
+    }
+
+    #[view(getUserEntropyCommitsEnabled)]
+    #[storage_mapper("userEntropyCommitsEnabled")]
+    fn user_entropy_commits_enabled(&self) -> SingleValueMapper<bool>;
+
+    #[view(getEntropyCommitment)]
+    #[storage_mapper("entropyCommitment")]
+    fn entropy_commitment(&self, address: &ManagedAddress) -> SingleValueMapper<Hash<Self::Api>>;
+
+    #[view(getRevealedEntropy)]
+    #[storage_mapper("revealedEntropy")]
+    fn revealed_entropy(&self) -> SingleValueMapper<ManagedBuffer>;
+
+    #[storage_mapper("winnerSelectionSeed")]
+    fn winner_selection_seed(&self) -> SingleValueMapper<ManagedBuffer>;
+}
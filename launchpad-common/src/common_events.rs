@@ -1,6 +1,8 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
+use crate::{action_pause::PausableAction, blacklist::BlacklistReason, tickets::TicketId};
+
 #[derive(TypeAbi, TopEncode)]
 pub struct RefundTicketPaymentEvent<M: ManagedTypeApi> {
     user: ManagedAddress<M>,
@@ -34,7 +36,7 @@ pub struct FilterTicketsCompletedEvent<M: ManagedTypeApi> {
     user: ManagedAddress<M>,
     round: u64,
     epoch: u64,
-    total_tickets_after_filtering: usize,
+    total_tickets_after_filtering: TicketId,
 }
 
 #[derive(TypeAbi, TopEncode)]
@@ -45,6 +47,96 @@ pub struct SelectWinnersCompletedEvent<M: ManagedTypeApi> {
     total_winning_tickets: usize,
 }
 
+#[derive(TypeAbi, TopEncode)]
+pub struct SweepUnclaimedEvent<M: ManagedTypeApi> {
+    user: ManagedAddress<M>,
+    round: u64,
+    epoch: u64,
+    launchpad_tokens: EsdtTokenPayment<M>,
+    ticket_payment_refunds: EgldOrEsdtTokenPayment<M>,
+}
+
+#[derive(TypeAbi, TopEncode)]
+pub struct BurnUnsoldLaunchpadTokensEvent<M: ManagedTypeApi> {
+    user: ManagedAddress<M>,
+    round: u64,
+    epoch: u64,
+    burned_tokens: EsdtTokenPayment<M>,
+}
+
+#[derive(TypeAbi, TopEncode)]
+pub struct ClaimToReceiverEvent<M: ManagedTypeApi> {
+    user: ManagedAddress<M>,
+    round: u64,
+    epoch: u64,
+    receiver: ManagedAddress<M>,
+}
+
+#[derive(TypeAbi, TopEncode)]
+pub struct ConfirmTicketsForUserEvent<M: ManagedTypeApi> {
+    user: ManagedAddress<M>,
+    round: u64,
+    epoch: u64,
+    beneficiary: ManagedAddress<M>,
+    nr_tickets: usize,
+}
+
+#[derive(TypeAbi, TopEncode)]
+pub struct WalletMigrationEvent<M: ManagedTypeApi> {
+    user: ManagedAddress<M>,
+    round: u64,
+    epoch: u64,
+    old_address: ManagedAddress<M>,
+    new_address: ManagedAddress<M>,
+}
+
+#[derive(TypeAbi, TopEncode)]
+pub struct BlacklistUserEvent<M: ManagedTypeApi> {
+    user: ManagedAddress<M>,
+    round: u64,
+    epoch: u64,
+    blacklisted_address: ManagedAddress<M>,
+    reason: BlacklistReason,
+    timestamp: u64,
+}
+
+#[derive(TypeAbi, TopEncode)]
+pub struct RemoveUserFromBlacklistEvent<M: ManagedTypeApi> {
+    user: ManagedAddress<M>,
+    round: u64,
+    epoch: u64,
+    removed_address: ManagedAddress<M>,
+    timestamp: u64,
+}
+
+#[derive(TypeAbi, TopEncode)]
+pub struct WinningTicketsAdjustedEvent<M: ManagedTypeApi> {
+    user: ManagedAddress<M>,
+    round: u64,
+    epoch: u64,
+    configured_winning_tickets: usize,
+    nr_winning_tickets: usize,
+    released_launchpad_tokens: EsdtTokenPayment<M>,
+}
+
+#[derive(TypeAbi, TopEncode)]
+pub struct BuyFcfsTicketsEvent<M: ManagedTypeApi> {
+    user: ManagedAddress<M>,
+    round: u64,
+    epoch: u64,
+    tickets_bought: usize,
+    total_bought: usize,
+    token_payment: EgldOrEsdtTokenPayment<M>,
+}
+
+#[derive(TypeAbi, TopEncode)]
+pub struct PauseActionEvent<M: ManagedTypeApi> {
+    user: ManagedAddress<M>,
+    round: u64,
+    epoch: u64,
+    action: PausableAction,
+}
+
 #[multiversx_sc::module]
 pub trait CommonEventsModule {
     fn emit_refund_ticket_payment_event(
@@ -88,12 +180,12 @@ pub trait CommonEventsModule {
 
     fn emit_confirm_tickets_event(
         &self,
+        user: ManagedAddress,
         tickets_confirmed: usize,
         total_confirmed: usize,
         total_tickets: usize,
         token_payment: EgldOrEsdtTokenPayment<Self::Api>,
     ) {
-        let user = self.blockchain().get_caller();
         let round = self.blockchain().get_block_round();
         let epoch = self.blockchain().get_block_epoch();
         self.confirm_tickets_event(
@@ -112,7 +204,7 @@ pub trait CommonEventsModule {
         )
     }
 
-    fn emit_filter_tickets_completed_event(&self, total_tickets_after_filtering: usize) {
+    fn emit_filter_tickets_completed_event(&self, total_tickets_after_filtering: TicketId) {
         let user = self.blockchain().get_caller();
         let round = self.blockchain().get_block_round();
         let epoch = self.blockchain().get_block_epoch();
@@ -146,6 +238,227 @@ pub trait CommonEventsModule {
         )
     }
 
+    fn emit_sweep_unclaimed_event(
+        &self,
+        launchpad_tokens: EsdtTokenPayment<Self::Api>,
+        ticket_payment_refunds: EgldOrEsdtTokenPayment<Self::Api>,
+    ) {
+        let user = self.blockchain().get_caller();
+        let round = self.blockchain().get_block_round();
+        let epoch = self.blockchain().get_block_epoch();
+        self.sweep_unclaimed_event(
+            user.clone(),
+            round,
+            epoch,
+            SweepUnclaimedEvent {
+                user,
+                round,
+                epoch,
+                launchpad_tokens,
+                ticket_payment_refunds,
+            },
+        )
+    }
+
+    fn emit_burn_unsold_launchpad_tokens_event(&self, burned_tokens: EsdtTokenPayment<Self::Api>) {
+        let user = self.blockchain().get_caller();
+        let round = self.blockchain().get_block_round();
+        let epoch = self.blockchain().get_block_epoch();
+        self.burn_unsold_launchpad_tokens_event(
+            user.clone(),
+            round,
+            epoch,
+            BurnUnsoldLaunchpadTokensEvent {
+                user,
+                round,
+                epoch,
+                burned_tokens,
+            },
+        )
+    }
+
+    fn emit_claim_to_receiver_event(&self, receiver: ManagedAddress) {
+        let user = self.blockchain().get_caller();
+        let round = self.blockchain().get_block_round();
+        let epoch = self.blockchain().get_block_epoch();
+        self.claim_to_receiver_event(
+            user.clone(),
+            round,
+            epoch,
+            ClaimToReceiverEvent {
+                user,
+                round,
+                epoch,
+                receiver,
+            },
+        )
+    }
+
+    fn emit_confirm_tickets_for_user_event(&self, beneficiary: ManagedAddress, nr_tickets: usize) {
+        let user = self.blockchain().get_caller();
+        let round = self.blockchain().get_block_round();
+        let epoch = self.blockchain().get_block_epoch();
+        self.confirm_tickets_for_user_event(
+            user.clone(),
+            round,
+            epoch,
+            ConfirmTicketsForUserEvent {
+                user,
+                round,
+                epoch,
+                beneficiary,
+                nr_tickets,
+            },
+        )
+    }
+
+    fn emit_wallet_migration_event(
+        &self,
+        old_address: ManagedAddress,
+        new_address: ManagedAddress,
+    ) {
+        let user = self.blockchain().get_caller();
+        let round = self.blockchain().get_block_round();
+        let epoch = self.blockchain().get_block_epoch();
+        self.wallet_migration_event(
+            user.clone(),
+            round,
+            epoch,
+            WalletMigrationEvent {
+                user,
+                round,
+                epoch,
+                old_address,
+                new_address,
+            },
+        )
+    }
+
+    fn emit_blacklist_user_event(
+        &self,
+        blacklisted_address: ManagedAddress,
+        reason: BlacklistReason,
+        timestamp: u64,
+    ) {
+        let user = self.blockchain().get_caller();
+        let round = self.blockchain().get_block_round();
+        let epoch = self.blockchain().get_block_epoch();
+        self.blacklist_user_event(
+            user.clone(),
+            round,
+            epoch,
+            BlacklistUserEvent {
+                user,
+                round,
+                epoch,
+                blacklisted_address,
+                reason,
+                timestamp,
+            },
+        )
+    }
+
+    fn emit_remove_user_from_blacklist_event(&self, removed_address: ManagedAddress) {
+        let user = self.blockchain().get_caller();
+        let round = self.blockchain().get_block_round();
+        let epoch = self.blockchain().get_block_epoch();
+        let timestamp = self.blockchain().get_block_timestamp();
+        self.remove_user_from_blacklist_event(
+            user.clone(),
+            round,
+            epoch,
+            RemoveUserFromBlacklistEvent {
+                user,
+                round,
+                epoch,
+                removed_address,
+                timestamp,
+            },
+        )
+    }
+
+    fn emit_winning_tickets_adjusted_event(
+        &self,
+        configured_winning_tickets: usize,
+        nr_winning_tickets: usize,
+        released_launchpad_tokens: EsdtTokenPayment,
+    ) {
+        let user = self.blockchain().get_caller();
+        let round = self.blockchain().get_block_round();
+        let epoch = self.blockchain().get_block_epoch();
+        self.winning_tickets_adjusted_event(
+            user.clone(),
+            round,
+            epoch,
+            WinningTicketsAdjustedEvent {
+                user,
+                round,
+                epoch,
+                configured_winning_tickets,
+                nr_winning_tickets,
+                released_launchpad_tokens,
+            },
+        )
+    }
+
+    fn emit_buy_fcfs_tickets_event(
+        &self,
+        user: ManagedAddress,
+        tickets_bought: usize,
+        total_bought: usize,
+        token_payment: EgldOrEsdtTokenPayment<Self::Api>,
+    ) {
+        let round = self.blockchain().get_block_round();
+        let epoch = self.blockchain().get_block_epoch();
+        self.buy_fcfs_tickets_event(
+            user.clone(),
+            round,
+            epoch,
+            BuyFcfsTicketsEvent {
+                user,
+                round,
+                epoch,
+                tickets_bought,
+                total_bought,
+                token_payment,
+            },
+        )
+    }
+
+    fn emit_pause_action_event(&self, action: PausableAction) {
+        let user = self.blockchain().get_caller();
+        let round = self.blockchain().get_block_round();
+        let epoch = self.blockchain().get_block_epoch();
+        self.pause_action_event(
+            user.clone(),
+            round,
+            epoch,
+            PauseActionEvent {
+                user,
+                round,
+                epoch,
+                action,
+            },
+        )
+    }
+
+    fn emit_unpause_action_event(&self, action: PausableAction) {
+        let user = self.blockchain().get_caller();
+        let round = self.blockchain().get_block_round();
+        let epoch = self.blockchain().get_block_epoch();
+        self.unpause_action_event(
+            user.clone(),
+            round,
+            epoch,
+            PauseActionEvent {
+                user,
+                round,
+                epoch,
+                action,
+            },
+        )
+    }
+
     #[event("refundTicketPayment")]
     fn refund_ticket_payment_event(
         &self,
@@ -190,4 +503,103 @@ pub trait CommonEventsModule {
         #[indexed] epoch: u64,
         select_winners_completed_event: SelectWinnersCompletedEvent<Self::Api>,
     );
+
+    #[event("sweepUnclaimed")]
+    fn sweep_unclaimed_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] round: u64,
+        #[indexed] epoch: u64,
+        sweep_unclaimed_event: SweepUnclaimedEvent<Self::Api>,
+    );
+
+    #[event("burnUnsoldLaunchpadTokens")]
+    fn burn_unsold_launchpad_tokens_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] round: u64,
+        #[indexed] epoch: u64,
+        burn_unsold_launchpad_tokens_event: BurnUnsoldLaunchpadTokensEvent<Self::Api>,
+    );
+
+    #[event("claimToReceiver")]
+    fn claim_to_receiver_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] round: u64,
+        #[indexed] epoch: u64,
+        claim_to_receiver_event: ClaimToReceiverEvent<Self::Api>,
+    );
+
+    #[event("confirmTicketsForUser")]
+    fn confirm_tickets_for_user_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] round: u64,
+        #[indexed] epoch: u64,
+        confirm_tickets_for_user_event: ConfirmTicketsForUserEvent<Self::Api>,
+    );
+
+    #[event("walletMigration")]
+    fn wallet_migration_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] round: u64,
+        #[indexed] epoch: u64,
+        wallet_migration_event: WalletMigrationEvent<Self::Api>,
+    );
+
+    #[event("blacklistUser")]
+    fn blacklist_user_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] round: u64,
+        #[indexed] epoch: u64,
+        blacklist_user_event: BlacklistUserEvent<Self::Api>,
+    );
+
+    #[event("removeUserFromBlacklist")]
+    fn remove_user_from_blacklist_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] round: u64,
+        #[indexed] epoch: u64,
+        remove_user_from_blacklist_event: RemoveUserFromBlacklistEvent<Self::Api>,
+    );
+
+    #[event("winningTicketsAdjusted")]
+    fn winning_tickets_adjusted_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] round: u64,
+        #[indexed] epoch: u64,
+        winning_tickets_adjusted_event: WinningTicketsAdjustedEvent<Self::Api>,
+    );
+
+    #[event("buyFcfsTickets")]
+    fn buy_fcfs_tickets_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] round: u64,
+        #[indexed] epoch: u64,
+        buy_fcfs_tickets_event: BuyFcfsTicketsEvent<Self::Api>,
+    );
+
+    #[event("pauseAction")]
+    fn pause_action_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] round: u64,
+        #[indexed] epoch: u64,
+        pause_action_event: PauseActionEvent<Self::Api>,
+    );
+
+    #[event("unpauseAction")]
+    fn unpause_action_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] round: u64,
+        #[indexed] epoch: u64,
+        unpause_action_event: PauseActionEvent<Self::Api>,
+    );
 }
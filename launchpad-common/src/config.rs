@@ -14,14 +14,47 @@ pub struct TimelineConfig {
     pub claim_start_round: u64,
 }
 
+#[derive(TypeAbi, TopEncode, TopDecode)]
+pub struct FcfsRoundConfig {
+    pub start_round: u64,
+    pub end_round: u64,
+    pub max_tickets_per_address: usize,
+}
+
 #[multiversx_sc::module]
-pub trait ConfigModule {
+pub trait ConfigModule: crate::time_provider::TimeProviderModule {
     #[inline]
     fn were_launchpad_tokens_deposited(&self) -> bool {
         self.launchpad_tokens_deposited().get()
     }
 
-    #[view(getConfiguration)]
+    /// The unsold launchpad tokens are kept for the FCFS round until it ends
+    fn is_fcfs_round_pending(&self) -> bool {
+        let fcfs_round_mapper = self.fcfs_round();
+        if fcfs_round_mapper.is_empty() {
+            return false;
+        }
+
+        self.get_current_time() < fcfs_round_mapper.get().end_round
+    }
+
+    fn require_caller_not_denied_contract(&self, caller: &ManagedAddress) {
+        if !self.deny_smart_contract_callers().get() {
+            return;
+        }
+
+        require!(
+            !self.blockchain().is_smart_contract(caller)
+                || self.allowed_smart_contract_callers().contains(caller),
+            "Smart contract callers are not allowed"
+        );
+    }
+
+    #[view(isAllowedSmartContractCaller)]
+    fn is_allowed_smart_contract_caller(&self, address: ManagedAddress) -> bool {
+        self.allowed_smart_contract_callers().contains(&address)
+    }
+
     #[storage_mapper("configuration")]
     fn configuration(&self) -> SingleValueMapper<TimelineConfig>;
 
@@ -48,6 +81,88 @@ pub trait ConfigModule {
     #[storage_mapper("launchpadTokensDeposited")]
     fn launchpad_tokens_deposited(&self) -> SingleValueMapper<bool>;
 
+    /// Zero means there is no claim deadline
+    #[view(getClaimDeadlineRound)]
+    #[storage_mapper("claimDeadlineRound")]
+    fn claim_deadline_round(&self) -> SingleValueMapper<u64>;
+
+    /// Zero means there is no refund window before the claim period
+    #[view(getRefundWindowStartRound)]
+    #[storage_mapper("refundWindowStartRound")]
+    fn refund_window_start_round(&self) -> SingleValueMapper<u64>;
+
+    #[view(getBurnUnsoldLaunchpadTokens)]
+    #[storage_mapper("burnUnsoldLaunchpadTokens")]
+    fn burn_unsold_launchpad_tokens(&self) -> SingleValueMapper<bool>;
+
+    /// When set, the number of winning tickets is capped to the confirmed tickets at selection time
+    #[view(getDynamicWinningTickets)]
+    #[storage_mapper("dynamicWinningTickets")]
+    fn dynamic_winning_tickets(&self) -> SingleValueMapper<bool>;
+
+    #[view(getGuaranteedTicketPerConfirmer)]
+    #[storage_mapper("guaranteedTicketPerConfirmer")]
+    fn guaranteed_ticket_per_confirmer(&self) -> SingleValueMapper<bool>;
+
+    /// When set, there is no random draw: the confirmed tickets win in ticket ID order
+    #[view(isOrderedSelectionMode)]
+    #[storage_mapper("orderedSelectionMode")]
+    fn ordered_selection_mode(&self) -> SingleValueMapper<bool>;
+
+    /// Zero means there is no cap
+    #[view(getMaxWinningTicketsPerAddress)]
+    #[storage_mapper("maxWinningTicketsPerAddress")]
+    fn max_winning_tickets_per_address(&self) -> SingleValueMapper<usize>;
+
+    /// Zero means there is no cap
+    #[view(getMaxConfirmedTicketsPerAddress)]
+    #[storage_mapper("maxConfirmedTicketsPerAddress")]
+    fn max_confirmed_tickets_per_address(&self) -> SingleValueMapper<usize>;
+
+    /// Maximum total payment raised through confirmations. Zero means there is no cap
+    #[view(getHardCap)]
+    #[storage_mapper("hardCap")]
+    fn hard_cap(&self) -> SingleValueMapper<BigUint>;
+
+    #[view(getFcfsRound)]
+    #[storage_mapper("fcfsRound")]
+    fn fcfs_round(&self) -> SingleValueMapper<FcfsRoundConfig>;
+
+    #[view(getTreasuryAddress)]
+    #[storage_mapper("treasuryAddress")]
+    fn treasury_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    /// Rounds after the claim start round before the admins may claim on behalf of users
+    #[view(getClaimOnBehalfGraceRounds)]
+    #[storage_mapper("claimOnBehalfGraceRounds")]
+    fn claim_on_behalf_grace_rounds(&self) -> SingleValueMapper<u64>;
+
+    /// When set, only allowlisted smart contracts may confirm tickets and claim
+    #[view(getDenySmartContractCallers)]
+    #[storage_mapper("denySmartContractCallers")]
+    fn deny_smart_contract_callers(&self) -> SingleValueMapper<bool>;
+
+    #[storage_mapper("allowedSmartContractCallers")]
+    fn allowed_smart_contract_callers(&self) -> WhitelistMapper<Self::Api, ManagedAddress>;
+
+    /// Ticket payment of the winning tickets, which the owner may claim
+    #[view(getClaimableTicketPayment)]
     #[storage_mapper("claimableTicketPayment")]
     fn claimable_ticket_payment(&self) -> SingleValueMapper<BigUint>;
+
+    /// Ticket payment held for the user's confirmed tickets, until it is refunded
+    /// or settled for the winning tickets at claim. Locked token payments are not included.
+    #[view(getUserTicketPayment)]
+    #[storage_mapper("userTicketPayment")]
+    fn user_ticket_payment(&self, address: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    /// Sum of the ticket payments held for users
+    #[view(getTotalRefundableTicketPayment)]
+    #[storage_mapper("totalRefundableTicketPayment")]
+    fn total_refundable_ticket_payment(&self) -> SingleValueMapper<BigUint>;
+
+    /// Locked token payments are not included
+    #[view(getTotalPaymentRaised)]
+    #[storage_mapper("totalPaymentRaised")]
+    fn total_payment_raised(&self) -> SingleValueMapper<BigUint>;
 }
@@ -0,0 +1,92 @@
+multiversx_sc::imports!();
+
+use crate::{
+    launch_stage::{Flags, LaunchStage},
+    tickets::TicketId,
+};
+
+#[multiversx_sc::module]
+pub trait ConsolationAirdropModule:
+    crate::launch_stage::LaunchStageModule
+    + crate::config::ConfigModule
+    + crate::tickets::TicketsModule
+    + crate::ongoing_operation::OngoingOperationModule
+    + crate::common_events::CommonEventsModule
+{
+    /// Split evenly between all the confirmed tickets which did not win,
+    /// and sent along with the ticket payment refund at claim.
+    /// May be called multiple times, but always with the same token.
+    #[only_owner]
+    #[payable("*")]
+    #[endpoint(depositConsolationTokens)]
+    fn deposit_consolation_tokens(&self) {
+        let flags: Flags = self.flags().get();
+        require!(
+            flags.were_winners_selected && flags.was_additional_step_completed,
+            "Winners not selected yet"
+        );
+        require!(
+            self.get_launch_stage() < LaunchStage::Claim,
+            "Claim period has started"
+        );
+
+        let payment = self.call_value().single_esdt();
+        require!(
+            payment.token_nonce == 0 && payment.amount > 0,
+            "Invalid consolation tokens payment"
+        );
+        require!(
+            payment.token_identifier != self.launchpad_token_id().get(),
+            "Consolation token must be different from launchpad token"
+        );
+
+        let nr_losing_tickets =
+            self.get_total_tickets() - self.nr_winning_tickets().get() as TicketId;
+        require!(nr_losing_tickets > 0, "No losing tickets");
+
+        let pool_mapper = self.consolation_pool();
+        let mut pool = if pool_mapper.is_empty() {
+            EsdtTokenPayment::new(payment.token_identifier.clone(), 0, BigUint::zero())
+        } else {
+            pool_mapper.get()
+        };
+        require!(
+            pool.token_identifier == payment.token_identifier,
+            "Wrong consolation token"
+        );
+
+        pool.amount += payment.amount;
+        self.consolation_tokens_per_ticket()
+            .set(&pool.amount / nr_losing_tickets as u32);
+        pool_mapper.set(pool);
+    }
+
+    fn send_consolation_tokens(&self, address: &ManagedAddress, nr_losing_tickets: usize) {
+        let pool_mapper = self.consolation_pool();
+        if nr_losing_tickets == 0 || pool_mapper.is_empty() {
+            return;
+        }
+
+        let amount = self.consolation_tokens_per_ticket().get() * nr_losing_tickets as u32;
+        if amount == 0 {
+            return;
+        }
+
+        let pool = pool_mapper.get();
+        self.send()
+            .direct_esdt(address, &pool.token_identifier, 0, &amount);
+    }
+
+    #[view(getConsolationPool)]
+    #[storage_mapper("consolationPool")]
+    fn consolation_pool(&self) -> SingleValueMapper<EsdtTokenPayment>;
+
+    #[view(getConsolationTokensPerTicket)]
+    #[storage_mapper("consolationTokensPerTicket")]
+    fn consolation_tokens_per_ticket(&self) -> SingleValueMapper<BigUint>;
+
+    /// Losing tickets already refunded during the refund window, still owed consolation tokens
+    #[view(getUserConsolationTickets)]
+    #[storage_mapper("userConsolationTickets")]
+    fn user_consolation_tickets(&self, address: &ManagedAddress) -> SingleValueMapper<usize>;
+}
@@ -0,0 +1,163 @@
+multiversx_sc::imports!();
+
+use crate::{
+    config::{FcfsRoundConfig, TokenAmountPair},
+    launch_stage::LaunchStage,
+    tickets::TicketId,
+};
+
+#[multiversx_sc::module]
+pub trait FcfsRoundModule:
+    crate::launch_stage::LaunchStageModule
+    + crate::config::ConfigModule
+    + crate::permissions::PermissionsModule
+    + crate::action_pause::ActionPauseModule
+    + crate::tickets::TicketsModule
+    + crate::token_send::TokenSendModule
+    + crate::user_interactions::UserInteractionsModule
+    + multiversx_sc_modules::pause::PauseModule
+{
+    /// Optional second round, during the claim period, in which the launchpad tokens of the
+    /// unsold winning tickets are sold first come, first served, at the ticket price.
+    /// Only the addresses which confirmed tickets in the lottery round may buy.
+    #[endpoint(setFcfsRound)]
+    fn set_fcfs_round(&self, start_round: u64, end_round: u64, max_tickets_per_address: usize) {
+        self.require_config_manager();
+        require!(
+            self.get_launch_stage() < LaunchStage::Claim,
+            "Cannot change after claim period started"
+        );
+
+        let config = self.configuration().get();
+        require!(
+            start_round >= config.claim_start_round,
+            "FCFS round must start in the claim period"
+        );
+        require!(start_round < end_round, "Invalid FCFS round");
+        require!(
+            max_tickets_per_address > 0,
+            "Invalid max tickets per address"
+        );
+
+        self.fcfs_round().set(FcfsRoundConfig {
+            start_round,
+            end_round,
+            max_tickets_per_address,
+        });
+    }
+
+    #[endpoint(removeFcfsRound)]
+    fn remove_fcfs_round(&self) {
+        self.require_config_manager();
+        require!(
+            self.get_launch_stage() < LaunchStage::Claim,
+            "Cannot change after claim period started"
+        );
+
+        self.fcfs_round().clear();
+    }
+
+    fn buy_fcfs_tickets<
+        SendLaunchpadTokensFn: Fn(&Self, &ManagedAddress, &EsdtTokenPayment<Self::Api>, TicketId),
+    >(
+        &self,
+        nr_tickets: usize,
+        send_fn: SendLaunchpadTokensFn,
+    ) {
+        self.require_not_paused();
+        self.require_claim_period();
+
+        let caller = self.blockchain().get_caller();
+        self.require_caller_not_denied_contract(&caller);
+
+        let fcfs_round_mapper = self.fcfs_round();
+        require!(!fcfs_round_mapper.is_empty(), "No FCFS round");
+
+        let fcfs_round = fcfs_round_mapper.get();
+        let current_round = self.get_current_time();
+        require!(
+            current_round >= fcfs_round.start_round && current_round < fcfs_round.end_round,
+            "Not in FCFS round"
+        );
+        require!(
+            self.has_confirmed_in_lottery_round(&caller),
+            "Only lottery round participants may buy"
+        );
+        require!(nr_tickets > 0, "Invalid number of tickets");
+
+        let tickets_bought_mapper = self.fcfs_tickets_bought(&caller);
+        let total_bought = tickets_bought_mapper.get() + nr_tickets;
+        require!(
+            total_bought <= fcfs_round.max_tickets_per_address,
+            "Max FCFS tickets per address exceeded"
+        );
+        require!(
+            nr_tickets <= self.get_fcfs_tickets_available(),
+            "Not enough FCFS tickets left"
+        );
+
+        let (payment_token, payment_amount) = self.call_value().egld_or_single_fungible_esdt();
+        let ticket_price: TokenAmountPair<Self::Api> = self.ticket_price().get();
+        require!(
+            payment_token == ticket_price.token_id
+                && payment_amount == ticket_price.amount * nr_tickets as u32,
+            "Wrong payment"
+        );
+
+        tickets_bought_mapper.set(total_bought);
+        self.total_fcfs_tickets_sold()
+            .update(|total| *total += nr_tickets);
+        self.claimable_ticket_payment()
+            .update(|claimable| *claimable += &payment_amount);
+
+        self.send_launchpad_tokens(&caller, nr_tickets, 0, send_fn);
+
+        self.emit_buy_fcfs_tickets_event(
+            caller,
+            nr_tickets,
+            total_bought,
+            EgldOrEsdtTokenPayment::new(payment_token, 0, payment_amount),
+        );
+    }
+
+    /// Confirmed tickets are only kept by filterTickets, so the addresses left with a ticket range
+    /// confirmed in the lottery round, as well as the ones which already claimed
+    fn has_confirmed_in_lottery_round(&self, address: &ManagedAddress) -> bool {
+        self.claim_list().contains(address) || !self.ticket_range_for_address(address).is_empty()
+    }
+
+    /// The launchpad tokens which are not needed for the unclaimed winning tickets
+    #[view(getFcfsTicketsAvailable)]
+    fn get_fcfs_tickets_available(&self) -> usize {
+        if self.get_launch_stage() != LaunchStage::Claim {
+            return 0;
+        }
+
+        let launchpad_token_id = self.launchpad_token_id().get();
+        let launchpad_tokens_balance = self.blockchain().get_esdt_balance(
+            &self.blockchain().get_sc_address(),
+            &launchpad_token_id,
+            0,
+        );
+
+        let nr_winning_tickets = self.nr_winning_tickets().get();
+        let amount_per_ticket = self.launchpad_tokens_per_winning_ticket().get();
+        let launchpad_tokens_needed = &amount_per_ticket * (nr_winning_tickets as u32);
+        if launchpad_tokens_balance <= launchpad_tokens_needed {
+            return 0;
+        }
+
+        let nr_tickets_available =
+            (launchpad_tokens_balance - launchpad_tokens_needed) / amount_per_ticket;
+
+        nr_tickets_available.to_u64().unwrap_or_default() as usize
+    }
+
+    #[view(getFcfsTicketsBought)]
+    #[storage_mapper("fcfsTicketsBought")]
+    fn fcfs_tickets_bought(&self, address: &ManagedAddress) -> SingleValueMapper<usize>;
+
+    #[view(getTotalFcfsTicketsSold)]
+    #[storage_mapper("totalFcfsTicketsSold")]
+    fn total_fcfs_tickets_sold(&self) -> SingleValueMapper<usize>;
+}
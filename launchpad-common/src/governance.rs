@@ -0,0 +1,73 @@
+multiversx_sc::imports!();
+
+use crate::launch_stage::LaunchStage;
+
+pub mod governance_proxy {
+    multiversx_sc::imports!();
+
+    #[multiversx_sc::proxy]
+    pub trait GovernanceProxy {
+        #[view(isWinnerSelectionApproved)]
+        fn is_winner_selection_approved(&self, launchpad_address: ManagedAddress) -> bool;
+    }
+}
+
+#[multiversx_sc::module]
+pub trait GovernanceModule:
+    crate::launch_stage::LaunchStageModule
+    + crate::config::ConfigModule
+    + crate::permissions::PermissionsModule
+    + crate::action_pause::ActionPauseModule
+{
+    /// Once set, `selectWinners` may only run after the governance contract approved it.
+    /// The governance contract has to be in the same shard, as it is queried synchronously.
+    #[endpoint(setGovernanceContract)]
+    fn set_governance_contract(&self, governance_address: ManagedAddress) {
+        self.require_config_manager();
+        require!(
+            self.get_launch_stage() < LaunchStage::WinnerSelection,
+            "Cannot change after winner selection started"
+        );
+        require!(
+            self.blockchain().is_smart_contract(&governance_address),
+            "Invalid SC address"
+        );
+
+        self.governance_contract().set(governance_address);
+    }
+
+    #[endpoint(removeGovernanceContract)]
+    fn remove_governance_contract(&self) {
+        self.require_config_manager();
+        require!(
+            self.get_launch_stage() < LaunchStage::WinnerSelection,
+            "Cannot change after winner selection started"
+        );
+
+        self.governance_contract().clear();
+    }
+
+    fn require_governance_approval(&self) {
+        let governance_mapper = self.governance_contract();
+        if governance_mapper.is_empty() {
+            return;
+        }
+
+        let sc_address = self.blockchain().get_sc_address();
+        let is_approved: bool = self
+            .governance_proxy_builder(governance_mapper.get())
+            .is_winner_selection_approved(sc_address)
+            .execute_on_dest_context();
+        require!(is_approved, "Winner selection not approved by governance");
+    }
+
+    #[view(getGovernanceContract)]
+    #[storage_mapper("governanceContract")]
+    fn governance_contract(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[proxy]
+    fn governance_proxy_builder(
+        &self,
+        sc_address: ManagedAddress,
+    ) -> governance_proxy::Proxy<Self::Api>;
+}
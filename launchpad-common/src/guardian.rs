@@ -0,0 +1,158 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::permissions::Role;
+
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Copy)]
+pub enum GuardedOperationType {
+    ChangeGuardian,
+    BlacklistBatch,
+    UnlockSchedule,
+}
+
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct GuardianConfig<M: ManagedTypeApi> {
+    pub guardian_address: ManagedAddress<M>,
+    pub max_unapproved_blacklist_batch: usize,
+}
+
+#[multiversx_sc::module]
+pub trait GuardianModule: crate::permissions::PermissionsModule {
+    /// Once a guardian is set, destructive operations have to be pre-approved by it,
+    /// including changing or removing the guardian itself.
+    /// Single address blacklisting never needs approval, so users may still request refunds.
+    /// The approval is for the top-encoded `GuardianConfig`.
+    #[endpoint(setGuardian)]
+    fn set_guardian(
+        &self,
+        guardian_address: ManagedAddress,
+        max_unapproved_blacklist_batch: usize,
+    ) {
+        self.require_role(Role::Admin);
+        require!(!guardian_address.is_zero(), "Invalid guardian address");
+        require!(
+            max_unapproved_blacklist_batch > 0,
+            "Invalid max unapproved blacklist batch"
+        );
+
+        let guardian_config = GuardianConfig {
+            guardian_address,
+            max_unapproved_blacklist_batch,
+        };
+        let mut encoded_data = ManagedBuffer::new();
+        let _ = guardian_config.top_encode(&mut encoded_data);
+        self.require_guardian_approval(GuardedOperationType::ChangeGuardian, &encoded_data);
+
+        self.guardian_config().set(guardian_config);
+    }
+
+    /// The approval is for an empty buffer
+    #[endpoint(removeGuardian)]
+    fn remove_guardian(&self) {
+        self.require_role(Role::Admin);
+        self.require_guardian_approval(GuardedOperationType::ChangeGuardian, &ManagedBuffer::new());
+
+        self.guardian_config().clear();
+        self.approved_operations().clear();
+    }
+
+    /// `encoded_data` is the top-encoded argument of the guarded operation,
+    /// e.g. the blacklisted addresses or the unlock schedule.
+    /// The approval is consumed when the operation is executed.
+    #[endpoint(approveOperation)]
+    fn approve_operation(&self, operation_type: GuardedOperationType, encoded_data: ManagedBuffer) {
+        self.require_caller_guardian();
+
+        let operation_hash = self.get_operation_hash(operation_type, &encoded_data);
+        require!(
+            self.approved_operations().insert(operation_hash),
+            "Operation already approved"
+        );
+    }
+
+    #[endpoint(revokeOperationApproval)]
+    fn revoke_operation_approval(
+        &self,
+        operation_type: GuardedOperationType,
+        encoded_data: ManagedBuffer,
+    ) {
+        self.require_caller_guardian();
+
+        let operation_hash = self.get_operation_hash(operation_type, &encoded_data);
+        require!(
+            self.approved_operations().swap_remove(&operation_hash),
+            "Operation not approved"
+        );
+    }
+
+    #[view(isOperationApproved)]
+    fn is_operation_approved(
+        &self,
+        operation_type: GuardedOperationType,
+        encoded_data: ManagedBuffer,
+    ) -> bool {
+        let operation_hash = self.get_operation_hash(operation_type, &encoded_data);
+
+        self.approved_operations().contains(&operation_hash)
+    }
+
+    /// Does nothing if no guardian is set
+    fn require_guardian_approval(
+        &self,
+        operation_type: GuardedOperationType,
+        encoded_data: &ManagedBuffer,
+    ) {
+        if self.guardian_config().is_empty() {
+            return;
+        }
+
+        let operation_hash = self.get_operation_hash(operation_type, encoded_data);
+        require!(
+            self.approved_operations().swap_remove(&operation_hash),
+            "Guardian approval required"
+        );
+    }
+
+    fn require_blacklist_batch_approval(&self, users_list: &ManagedVec<ManagedAddress>) {
+        let guardian_config_mapper = self.guardian_config();
+        if guardian_config_mapper.is_empty()
+            || users_list.len() <= guardian_config_mapper.get().max_unapproved_blacklist_batch
+        {
+            return;
+        }
+
+        let mut encoded_data = ManagedBuffer::new();
+        let _ = users_list.top_encode(&mut encoded_data);
+        self.require_guardian_approval(GuardedOperationType::BlacklistBatch, &encoded_data);
+    }
+
+    fn require_caller_guardian(&self) {
+        let guardian_config_mapper = self.guardian_config();
+        require!(!guardian_config_mapper.is_empty(), "No guardian set");
+
+        let caller = self.blockchain().get_caller();
+        require!(
+            caller == guardian_config_mapper.get().guardian_address,
+            "Only the guardian may approve operations"
+        );
+    }
+
+    fn get_operation_hash(
+        &self,
+        operation_type: GuardedOperationType,
+        encoded_data: &ManagedBuffer,
+    ) -> ManagedByteArray<Self::Api, 32> {
+        let mut operation = ManagedBuffer::new();
+        let _ = operation_type.dep_encode(&mut operation);
+        operation.append(encoded_data);
+
+        self.crypto().keccak256(operation)
+    }
+
+    #[view(getGuardianConfig)]
+    #[storage_mapper("guardianConfig")]
+    fn guardian_config(&self) -> SingleValueMapper<GuardianConfig<Self::Api>>;
+
+    #[storage_mapper("approvedOperations")]
+    fn approved_operations(&self) -> UnorderedSetMapper<ManagedByteArray<Self::Api, 32>>;
+}
@@ -0,0 +1,91 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::{
+    config::TokenAmountPair,
+    tickets::{FIRST_TICKET_ID, WINNING_TICKET},
+};
+
+#[derive(TypeAbi, TopEncode, TopDecode)]
+pub struct InvariantsReport<M: ManagedTypeApi> {
+    pub nr_winning_tickets: usize,
+    pub nr_unclaimed_winning_tickets: usize,
+    pub winning_tickets_consistent: bool,
+    pub launchpad_tokens_needed: BigUint<M>,
+    pub launchpad_tokens_balance: BigUint<M>,
+    pub launchpad_tokens_covered: bool,
+    pub claimable_ticket_payment: BigUint<M>,
+    pub refundable_ticket_payment: BigUint<M>,
+    pub ticket_payment_balance: BigUint<M>,
+    pub ticket_payment_covered: bool,
+}
+
+impl<M: ManagedTypeApi> InvariantsReport<M> {
+    pub fn all_hold(&self) -> bool {
+        self.winning_tickets_consistent
+            && self.launchpad_tokens_covered
+            && self.ticket_payment_covered
+    }
+}
+
+#[multiversx_sc::module]
+pub trait InvariantsModule:
+    crate::launch_stage::LaunchStageModule
+    + crate::config::ConfigModule
+    + crate::tickets::TicketsModule
+    + crate::permissions::PermissionsModule
+    + crate::action_pause::ActionPauseModule
+    + crate::common_events::CommonEventsModule
+    + crate::locked_token_payment::LockedTokenPaymentModule
+{
+    /// Checks the accounting of the launch against the contract's balances.
+    /// Iterates over all the tickets, so it is only meant to be queried off-chain.
+    /// The winning tickets are only checked once all the winners were selected,
+    /// and the launchpad tokens once they were deposited.
+    #[view(verifyInvariants)]
+    fn verify_invariants(&self) -> InvariantsReport<Self::Api> {
+        let nr_winning_tickets = self.nr_winning_tickets().get();
+        let mut nr_unclaimed_winning_tickets = 0;
+        for ticket_id in FIRST_TICKET_ID..=self.last_ticket_id().get() {
+            if self.get_ticket_status(ticket_id) == WINNING_TICKET {
+                nr_unclaimed_winning_tickets += 1;
+            }
+        }
+
+        let flags = self.flags().get();
+        let were_all_winners_selected =
+            flags.were_winners_selected && flags.was_additional_step_completed;
+        let winning_tickets_consistent =
+            !were_all_winners_selected || nr_unclaimed_winning_tickets == nr_winning_tickets;
+
+        let launchpad_tokens_needed =
+            self.launchpad_tokens_per_winning_ticket().get() * nr_winning_tickets as u32;
+        let launchpad_tokens_balance = self.blockchain().get_esdt_balance(
+            &self.blockchain().get_sc_address(),
+            &self.launchpad_token_id().get(),
+            0,
+        );
+        let launchpad_tokens_covered = !self.were_launchpad_tokens_deposited()
+            || launchpad_tokens_balance >= launchpad_tokens_needed;
+
+        let claimable_ticket_payment = self.get_owner_claimable_ticket_payment();
+        let refundable_ticket_payment = self.total_refundable_ticket_payment().get();
+        let ticket_price: TokenAmountPair<Self::Api> = self.ticket_price().get();
+        let ticket_payment_balance = self.blockchain().get_sc_balance(&ticket_price.token_id, 0);
+        let ticket_payment_covered = ticket_payment_balance >= claimable_ticket_payment
+            && ticket_payment_balance >= refundable_ticket_payment;
+
+        InvariantsReport {
+            nr_winning_tickets,
+            nr_unclaimed_winning_tickets,
+            winning_tickets_consistent,
+            launchpad_tokens_needed,
+            launchpad_tokens_balance,
+            launchpad_tokens_covered,
+            claimable_ticket_payment,
+            refundable_ticket_payment,
+            ticket_payment_balance,
+            ticket_payment_covered,
+        }
+    }
+}
@@ -0,0 +1,124 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::permissions::Role;
+
+#[derive(TypeAbi, TopEncode, TopDecode)]
+pub struct KeeperBounty<M: ManagedTypeApi> {
+    pub token_id: EgldOrEsdtTokenIdentifier<M>,
+    pub amount_per_batch: BigUint<M>,
+    pub min_gas_per_batch: u64,
+}
+
+#[multiversx_sc::module]
+pub trait KeeperIncentivesModule:
+    crate::launch_stage::LaunchStageModule + crate::permissions::PermissionsModule
+{
+    /// Anyone running `filterTickets` or `selectWinners` is paid the bounty for each batch,
+    /// as long as the call was given at least `min_gas_per_batch`, so batches can't be split
+    /// into tiny ones just to collect more bounties. The owner is never paid.
+    #[endpoint(setKeeperBounty)]
+    fn set_keeper_bounty(
+        &self,
+        token_id: EgldOrEsdtTokenIdentifier,
+        amount_per_batch: BigUint,
+        min_gas_per_batch: u64,
+    ) {
+        self.require_role(Role::Finance);
+
+        require!(token_id.is_valid(), "Invalid token ID");
+        require!(amount_per_batch > 0, "Invalid bounty amount");
+
+        let bounty_mapper = self.keeper_bounty();
+        if !bounty_mapper.is_empty() {
+            let current_bounty = bounty_mapper.get();
+            require!(
+                current_bounty.token_id == token_id || self.keeper_bounty_pool().get() == 0,
+                "Must withdraw the bounty pool before changing the token"
+            );
+        }
+
+        bounty_mapper.set(KeeperBounty {
+            token_id,
+            amount_per_batch,
+            min_gas_per_batch,
+        });
+    }
+
+    #[only_owner]
+    #[payable("*")]
+    #[endpoint(depositKeeperBountyPool)]
+    fn deposit_keeper_bounty_pool(&self) {
+        let bounty_mapper = self.keeper_bounty();
+        require!(!bounty_mapper.is_empty(), "Keeper bounty not set");
+
+        let (payment_token, payment_amount) = self.call_value().egld_or_single_fungible_esdt();
+        require!(
+            payment_token == bounty_mapper.get().token_id && payment_amount > 0,
+            "Invalid keeper bounty payment"
+        );
+
+        self.keeper_bounty_pool()
+            .update(|pool| *pool += payment_amount);
+    }
+
+    /// Bounties are only paid during winner selection, so the rest of the pool may be withdrawn
+    /// once the claim period starts
+    #[endpoint(withdrawKeeperBountyPool)]
+    fn withdraw_keeper_bounty_pool(&self) {
+        self.require_role(Role::Finance);
+        self.require_claim_period();
+
+        let pool = self.keeper_bounty_pool().take();
+        if pool == 0 {
+            return;
+        }
+
+        let owner = self.blockchain().get_owner_address();
+        let bounty = self.keeper_bounty().get();
+        self.send().direct(&owner, &bounty.token_id, 0, &pool);
+    }
+
+    /// The bounty is skipped, without failing the batch, if the pool was depleted
+    fn pay_keeper_bounty(&self, gas_at_batch_start: u64) {
+        let bounty_mapper = self.keeper_bounty();
+        if bounty_mapper.is_empty() {
+            return;
+        }
+
+        let caller = self.blockchain().get_caller();
+        if caller == self.blockchain().get_owner_address() {
+            return;
+        }
+
+        let bounty = bounty_mapper.get();
+        if gas_at_batch_start < bounty.min_gas_per_batch {
+            return;
+        }
+
+        let pool_mapper = self.keeper_bounty_pool();
+        let pool = pool_mapper.get();
+        if pool < bounty.amount_per_batch {
+            return;
+        }
+
+        pool_mapper.set(pool - &bounty.amount_per_batch);
+        self.keeper_bounties_paid(&caller)
+            .update(|paid| *paid += &bounty.amount_per_batch);
+
+        self.send()
+            .direct(&caller, &bounty.token_id, 0, &bounty.amount_per_batch);
+    }
+
+    #[view(getKeeperBounty)]
+    #[storage_mapper("keeperBounty")]
+    fn keeper_bounty(&self) -> SingleValueMapper<KeeperBounty<Self::Api>>;
+
+    #[view(getKeeperBountyPool)]
+    #[storage_mapper("keeperBountyPool")]
+    fn keeper_bounty_pool(&self) -> SingleValueMapper<BigUint>;
+
+    #[view(getKeeperBountiesPaid)]
+    #[storage_mapper("keeperBountiesPaid")]
+    fn keeper_bounties_paid(&self, keeper: &ManagedAddress) -> SingleValueMapper<BigUint>;
+}
@@ -0,0 +1,97 @@
+multiversx_sc::imports!();
+
+use crate::{launch_stage::LaunchStage, permissions::Role};
+
+const ED25519_PUBLIC_KEY_LEN: usize = 32;
+
+#[multiversx_sc::module]
+pub trait KycModule:
+    crate::launch_stage::LaunchStageModule
+    + crate::config::ConfigModule
+    + crate::permissions::PermissionsModule
+    + crate::action_pause::ActionPauseModule
+{
+    /// When required, only addresses which passed KYC may confirm tickets
+    #[endpoint(setKycRequired)]
+    fn set_kyc_required(&self, kyc_required: bool) {
+        self.require_config_manager();
+        require!(
+            self.get_launch_stage() < LaunchStage::WinnerSelection,
+            "Cannot change after confirmation period"
+        );
+
+        self.kyc_required().set(kyc_required);
+    }
+
+    /// Ed25519 public key of the off-chain KYC provider, used by `submitKycSignature`
+    #[endpoint(setKycPublicKey)]
+    fn set_kyc_public_key(&self, public_key: ManagedBuffer) {
+        self.require_config_manager();
+        require!(
+            public_key.len() == ED25519_PUBLIC_KEY_LEN,
+            "Invalid public key"
+        );
+
+        self.kyc_public_key().set(public_key);
+    }
+
+    #[endpoint(markKycPassed)]
+    fn mark_kyc_passed(&self, addresses: MultiValueEncoded<ManagedAddress>) {
+        self.require_role(Role::KycAttestor);
+
+        let kyc_passed_mapper = self.kyc_passed();
+        for address in addresses {
+            kyc_passed_mapper.add(&address);
+        }
+    }
+
+    #[endpoint(revokeKyc)]
+    fn revoke_kyc(&self, addresses: MultiValueEncoded<ManagedAddress>) {
+        self.require_role(Role::KycAttestor);
+
+        let kyc_passed_mapper = self.kyc_passed();
+        for address in addresses {
+            kyc_passed_mapper.remove(&address);
+        }
+    }
+
+    /// The signature is over the launchpad's address followed by the caller's address,
+    /// so it may not be reused for other launches
+    #[endpoint(submitKycSignature)]
+    fn submit_kyc_signature(&self, signature: ManagedBuffer) {
+        let public_key_mapper = self.kyc_public_key();
+        require!(!public_key_mapper.is_empty(), "KYC public key not set");
+
+        let caller = self.blockchain().get_caller();
+        let mut message = self
+            .blockchain()
+            .get_sc_address()
+            .as_managed_buffer()
+            .clone();
+        message.append(caller.as_managed_buffer());
+        self.crypto()
+            .verify_ed25519(&public_key_mapper.get(), &message, &signature);
+
+        self.kyc_passed().add(&caller);
+    }
+
+    fn has_required_kyc(&self, address: &ManagedAddress) -> bool {
+        !self.kyc_required().get() || self.is_kyc_passed(address)
+    }
+
+    #[view(isKycPassed)]
+    fn is_kyc_passed(&self, address: &ManagedAddress) -> bool {
+        self.kyc_passed().contains(address)
+    }
+
+    #[view(isKycRequired)]
+    #[storage_mapper("kycRequired")]
+    fn kyc_required(&self) -> SingleValueMapper<bool>;
+
+    #[view(getKycPublicKey)]
+    #[storage_mapper("kycPublicKey")]
+    fn kyc_public_key(&self) -> SingleValueMapper<ManagedBuffer>;
+
+    #[storage_mapper("kycPassed")]
+    fn kyc_passed(&self) -> WhitelistMapper<Self::Api, ManagedAddress>;
+}
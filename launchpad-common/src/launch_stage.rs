@@ -8,9 +8,71 @@ pub enum LaunchStage {
     AddTickets,
     Confirm,
     WinnerSelection,
+    Refund,
     Claim,
 }
 
+#[derive(TypeAbi, TopEncode, TopDecode, PartialEq, Debug)]
+pub enum CurrentStage {
+    AddTickets,
+    Confirm,
+    WinnerSelection,
+    Claim,
+    Ended,
+    Refund,
+    GuaranteedRound,
+}
+
+#[derive(
+    TypeAbi,
+    TopEncode,
+    TopDecode,
+    NestedEncode,
+    NestedDecode,
+    ManagedVecItem,
+    Clone,
+    Copy,
+    PartialEq,
+    PartialOrd,
+    Debug,
+)]
+pub enum PhaseType {
+    Registration,
+    GuaranteedRound,
+    PublicRound,
+    WinnerSelection,
+    Claim,
+}
+
+/// The confirmation period is made of an optional guaranteed round, followed by the public round.
+/// Each of them has its own cap of confirmed tickets per address, where zero means there is no cap.
+#[derive(
+    TypeAbi,
+    TopEncode,
+    TopDecode,
+    NestedEncode,
+    NestedDecode,
+    ManagedVecItem,
+    Clone,
+    PartialEq,
+    Debug,
+)]
+pub struct LaunchPhase {
+    pub phase_type: PhaseType,
+    pub start_round: u64,
+    pub max_confirmed_tickets_per_address: usize,
+}
+
+impl LaunchPhase {
+    pub fn new(phase_type: PhaseType, start_round: u64) -> Self {
+        LaunchPhase {
+            phase_type,
+            start_round,
+            max_confirmed_tickets_per_address: 0,
+        }
+    }
+}
+
 #[derive(TypeAbi, TopEncode, TopDecode, Default)]
 pub struct Flags {
     pub has_winner_selection_process_started: bool,
@@ -22,7 +84,7 @@ pub struct Flags {
 #[multiversx_sc::module]
 pub trait LaunchStageModule: crate::config::ConfigModule {
     fn get_launch_stage(&self) -> LaunchStage {
-        let current_round = self.blockchain().get_block_round();
+        let current_round = self.get_current_time();
         let config: TimelineConfig = self.configuration().get();
         let flags: Flags = self.flags().get();
 
@@ -41,12 +103,102 @@ pub trait LaunchStageModule: crate::config::ConfigModule {
         if current_round >= config.winner_selection_start_round
             && current_round < config.claim_start_round
         {
+            let refund_window_start_round = self.refund_window_start_round().get();
+            if refund_window_start_round > 0 && current_round >= refund_window_start_round {
+                return LaunchStage::Refund;
+            }
+
             return LaunchStage::WinnerSelection;
         }
 
         LaunchStage::Claim
     }
 
+    /// Also returns the number of rounds until the next stage,
+    /// or 0 if the next stage does not start at a fixed round
+    #[view(getCurrentStage)]
+    fn get_current_stage(&self) -> MultiValue2<CurrentStage, u64> {
+        let current_round = self.get_current_time();
+        let config: TimelineConfig = self.configuration().get();
+        let (current_stage, next_stage_round) = match self.get_launch_stage() {
+            LaunchStage::AddTickets => (
+                CurrentStage::AddTickets,
+                config.confirmation_period_start_round,
+            ),
+            LaunchStage::Confirm => {
+                let public_round_start_round = self.public_round_start_round().get();
+                if current_round < public_round_start_round {
+                    (CurrentStage::GuaranteedRound, public_round_start_round)
+                } else {
+                    (CurrentStage::Confirm, config.winner_selection_start_round)
+                }
+            }
+            LaunchStage::WinnerSelection => {
+                (CurrentStage::WinnerSelection, config.claim_start_round)
+            }
+            LaunchStage::Refund => (CurrentStage::Refund, config.claim_start_round),
+            LaunchStage::Claim => {
+                if self.is_claim_deadline_passed() {
+                    (CurrentStage::Ended, 0)
+                } else {
+                    (CurrentStage::Claim, self.claim_deadline_round().get())
+                }
+            }
+        };
+        let rounds_remaining = next_stage_round.saturating_sub(current_round);
+
+        (current_stage, rounds_remaining).into()
+    }
+
+    /// The schedule of the launch, in order, as stored in the timeline configuration
+    #[view(getLaunchPhases)]
+    fn get_launch_phases(&self) -> MultiValueEncoded<LaunchPhase> {
+        let config: TimelineConfig = self.configuration().get();
+        let mut launch_phases = MultiValueEncoded::new();
+        launch_phases.push(LaunchPhase::new(PhaseType::Registration, 0));
+
+        let max_confirmed_tickets = self.max_confirmed_tickets_per_address().get();
+        let public_round_start_round = self.public_round_start_round().get();
+        if public_round_start_round > 0 {
+            launch_phases.push(LaunchPhase {
+                phase_type: PhaseType::GuaranteedRound,
+                start_round: config.confirmation_period_start_round,
+                max_confirmed_tickets_per_address: self
+                    .guaranteed_round_max_confirmed_tickets()
+                    .get(),
+            });
+            launch_phases.push(LaunchPhase {
+                phase_type: PhaseType::PublicRound,
+                start_round: public_round_start_round,
+                max_confirmed_tickets_per_address: max_confirmed_tickets,
+            });
+        } else {
+            launch_phases.push(LaunchPhase {
+                phase_type: PhaseType::PublicRound,
+                start_round: config.confirmation_period_start_round,
+                max_confirmed_tickets_per_address: max_confirmed_tickets,
+            });
+        }
+
+        launch_phases.push(LaunchPhase::new(
+            PhaseType::WinnerSelection,
+            config.winner_selection_start_round,
+        ));
+        launch_phases.push(LaunchPhase::new(PhaseType::Claim, config.claim_start_round));
+
+        launch_phases
+    }
+
+    /// Zero means there is no cap
+    fn get_current_max_confirmed_tickets_per_address(&self) -> usize {
+        let current_round = self.get_current_time();
+        if current_round < self.public_round_start_round().get() {
+            return self.guaranteed_round_max_confirmed_tickets().get();
+        }
+
+        self.max_confirmed_tickets_per_address().get()
+    }
+
     #[inline]
     fn require_add_tickets_period(&self) {
         require!(
@@ -79,6 +231,14 @@ pub trait LaunchStageModule: crate::config::ConfigModule {
         );
     }
 
+    #[inline]
+    fn require_refund_period(&self) {
+        require!(
+            self.get_launch_stage() == LaunchStage::Refund,
+            "Not in refund period"
+        );
+    }
+
     #[inline]
     fn require_claim_period(&self) {
         require!(
@@ -87,6 +247,28 @@ pub trait LaunchStageModule: crate::config::ConfigModule {
         );
     }
 
+    fn is_claim_deadline_passed(&self) -> bool {
+        let claim_deadline_round = self.claim_deadline_round().get();
+        let current_round = self.get_current_time();
+
+        claim_deadline_round > 0 && current_round >= claim_deadline_round
+    }
+
+    #[inline]
+    fn require_before_claim_deadline(&self) {
+        require!(
+            !self.is_claim_deadline_passed(),
+            "Claim deadline has passed"
+        );
+    }
+
+    /// Zero means there is no guaranteed round
+    #[storage_mapper("publicRoundStartRound")]
+    fn public_round_start_round(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("guaranteedRoundMaxConfirmedTickets")]
+    fn guaranteed_round_max_confirmed_tickets(&self) -> SingleValueMapper<usize>;
+
     #[view(getLaunchStageFlags)]
     #[storage_mapper("flags")]
     fn flags(&self) -> SingleValueMapper<Flags>;
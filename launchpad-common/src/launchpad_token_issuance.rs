@@ -0,0 +1,63 @@
+multiversx_sc::imports!();
+
+#[multiversx_sc::module]
+pub trait LaunchpadTokenIssuanceModule:
+    crate::config::ConfigModule
+    + crate::reward_bundle::RewardBundleModule
+    + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
+{
+    /// Only possible if the contract was deployed with an empty launchpad token ID.
+    /// The contract receives all the roles for the issued token, including mint and burn.
+    #[only_owner]
+    #[payable("EGLD")]
+    #[endpoint(issueLaunchpadToken)]
+    fn issue_launchpad_token(
+        &self,
+        token_display_name: ManagedBuffer,
+        token_ticker: ManagedBuffer,
+        num_decimals: usize,
+    ) {
+        require!(
+            self.launchpad_token_id().is_empty(),
+            "Launchpad token already set"
+        );
+
+        let issue_cost = self.call_value().egld_value().clone_value();
+        self.issued_launchpad_token().issue_and_set_all_roles(
+            issue_cost,
+            token_display_name,
+            token_ticker,
+            num_decimals,
+            None,
+        );
+    }
+
+    /// Mints the launchpad tokens for all the winning tickets, instead of depositing them.
+    /// Not possible with extra rewards, as those still have to be deposited.
+    fn mint_launchpad_tokens(&self, total_winning_tickets: usize) {
+        require!(
+            !self.were_launchpad_tokens_deposited(),
+            "Tokens already deposited"
+        );
+        require!(
+            self.extra_rewards_per_winning_ticket().is_empty(),
+            "Extra rewards must be deposited"
+        );
+
+        let token_mapper = self.issued_launchpad_token();
+        require!(!token_mapper.is_empty(), "Launchpad token not issued");
+
+        let launchpad_token_id = token_mapper.get_token_id();
+        self.launchpad_token_id().set(&launchpad_token_id);
+
+        let amount_per_ticket = self.launchpad_tokens_per_winning_ticket().get();
+        let amount_needed = amount_per_ticket * (total_winning_tickets as u32);
+        let _ = token_mapper.mint(amount_needed.clone());
+
+        self.launchpad_tokens_deposited().set(true);
+        self.total_launchpad_tokens_deposited().set(amount_needed);
+    }
+
+    #[storage_mapper("issuedLaunchpadToken")]
+    fn issued_launchpad_token(&self) -> FungibleTokenMapper;
+}
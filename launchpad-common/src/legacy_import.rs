@@ -0,0 +1,73 @@
+multiversx_sc::imports!();
+
+use crate::config::TokenAmountPair;
+
+#[multiversx_sc::module]
+pub trait LegacyImportModule:
+    crate::launch_stage::LaunchStageModule
+    + crate::config::ConfigModule
+    + crate::tickets::TicketsModule
+    + crate::user_interactions::UserInteractionsModule
+    + crate::permissions::PermissionsModule
+    + crate::action_pause::ActionPauseModule
+{
+    /// Imports the ticket allocations and confirmations exported from a legacy deployment,
+    /// so an in-progress launch may be moved to this contract.
+    /// Entries are (address, number of tickets, number of confirmed tickets).
+    /// The payment for the confirmed tickets of the chunk, withdrawn from the legacy contract,
+    /// has to be sent along. Chunks have to be sent in order, starting from 0.
+    #[payable("*")]
+    #[endpoint(importLegacyState)]
+    fn import_legacy_state(
+        &self,
+        chunk_index: usize,
+        entries: MultiValueEncoded<MultiValue3<ManagedAddress, usize, usize>>,
+    ) {
+        self.require_config_manager();
+        self.require_add_tickets_period();
+        self.require_add_tickets_not_finalized();
+
+        let nr_imported_chunks_mapper = self.nr_imported_legacy_chunks();
+        require!(
+            chunk_index == nr_imported_chunks_mapper.get(),
+            "Invalid chunk index"
+        );
+
+        let ticket_price: TokenAmountPair<Self::Api> = self.ticket_price().get();
+        let mut total_confirmed = 0;
+        for entry in entries {
+            let (address, nr_tickets, nr_confirmed) = entry.into_tuple();
+            require!(nr_tickets > 0, "Invalid number of tickets");
+            require!(
+                nr_confirmed <= nr_tickets,
+                "Confirmed more tickets than allocated"
+            );
+
+            self.try_create_tickets(address.clone(), nr_tickets);
+            if nr_confirmed > 0 {
+                self.record_tickets_confirmation(
+                    &address,
+                    nr_confirmed,
+                    ticket_price.token_id.clone(),
+                    &ticket_price.amount * nr_confirmed as u32,
+                );
+            }
+
+            total_confirmed += nr_confirmed;
+        }
+
+        let (payment_token, payment_amount) = self.call_value().egld_or_single_fungible_esdt();
+        let expected_amount = ticket_price.amount * total_confirmed as u32;
+        require!(
+            payment_amount == expected_amount
+                && (expected_amount == 0 || payment_token == ticket_price.token_id),
+            "Wrong payment"
+        );
+
+        nr_imported_chunks_mapper.set(chunk_index + 1);
+    }
+
+    #[view(getNrImportedLegacyChunks)]
+    #[storage_mapper("nrImportedLegacyChunks")]
+    fn nr_imported_legacy_chunks(&self) -> SingleValueMapper<usize>;
+}
@@ -3,36 +3,131 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
+pub mod action_pause;
+pub mod admin_proposal;
 pub mod blacklist;
+pub mod claim_and_stake;
+pub mod commit_reveal;
 pub mod common_events;
 pub mod config;
+pub mod consolation_airdrop;
+pub mod fcfs_round;
+pub mod governance;
+pub mod guardian;
+pub mod invariants;
+pub mod keeper_incentives;
+pub mod kyc;
 pub mod launch_stage;
+pub mod launchpad_token_issuance;
+pub mod legacy_import;
+pub mod liquidity_bootstrap;
+pub mod locked_token_payment;
+pub mod loyalty;
 pub mod ongoing_operation;
+pub mod participation_badge;
 pub mod permissions;
+pub mod price_oracle;
 pub mod random;
+pub mod referral;
+pub mod relayer;
+pub mod results_distribution;
+pub mod reward_bundle;
 pub mod setup;
+pub mod signature_eligibility;
+pub mod storage_cleanup;
+pub mod swap_and_confirm;
 pub mod tickets;
+pub mod tickets_layout_migration;
+pub mod tiers;
+pub mod time_provider;
+pub mod timelock;
+pub mod token_rescue;
 pub mod token_send;
+pub mod unclaimed_sweep;
 pub mod user_interactions;
+pub mod wallet_migration;
 pub mod winner_selection;
+pub mod winners_hash;
 
-use config::TimelineConfig;
-use launch_stage::Flags;
+use config::TokenAmountPair;
+use launch_stage::{Flags, LaunchPhase, PhaseType};
 use tickets::FIRST_TICKET_ID;
 
+/// Bumped whenever the storage layout changes in a way that needs a migration on upgrade
+pub const CURRENT_STORAGE_VERSION: u32 = 3;
+
+/// From this version on, ticket IDs and positions are encoded as u64,
+/// and the ticket statuses are packed in chunks.
+/// The tickets of older contracts are migrated on upgrade.
+pub const TICKETS_LAYOUT_STORAGE_VERSION: u32 = 3;
+
+#[derive(TypeAbi, TopEncode, TopDecode)]
+pub struct UserFullStatus<M: ManagedTypeApi> {
+    pub total_tickets: usize,
+    pub confirmed_tickets: usize,
+    pub winning_tickets: usize,
+    pub guaranteed_tickets: usize,
+    pub has_claimed: bool,
+    pub refundable_amount: BigUint<M>,
+    pub is_blacklisted: bool,
+}
+
+/// Everything needed to display a launch, returned by `getConfiguration`.
+/// `release_config` is the variant specific lock or vesting configuration, top-encoded,
+/// and is empty for the variants which send the launchpad tokens directly.
+#[derive(TypeAbi, TopEncode, TopDecode)]
+pub struct LaunchConfiguration<M: ManagedTypeApi> {
+    pub launchpad_token_id: TokenIdentifier<M>,
+    pub launchpad_tokens_per_winning_ticket: BigUint<M>,
+    pub ticket_payment_token: EgldOrEsdtTokenIdentifier<M>,
+    pub ticket_price: BigUint<M>,
+    pub nr_winning_tickets: usize,
+    pub confirmation_period_start_round: u64,
+    pub winner_selection_start_round: u64,
+    pub claim_start_round: u64,
+    pub release_config: ManagedBuffer<M>,
+    pub launchpad_tokens_deposited: bool,
+}
+
 #[multiversx_sc::module]
 pub trait LaunchpadMain:
     launch_stage::LaunchStageModule
     + config::ConfigModule
+    + time_provider::TimeProviderModule
     + setup::SetupModule
+    + admin_proposal::AdminProposalModule
     + tickets::TicketsModule
+    + tickets_layout_migration::TicketsLayoutMigrationModule
     + winner_selection::WinnerSelectionModule
+    + commit_reveal::CommitRevealModule
     + ongoing_operation::OngoingOperationModule
     + permissions::PermissionsModule
+    + timelock::TimelockModule
+    + guardian::GuardianModule
     + blacklist::BlacklistModule
     + token_send::TokenSendModule
     + common_events::CommonEventsModule
     + user_interactions::UserInteractionsModule
+    + consolation_airdrop::ConsolationAirdropModule
+    + participation_badge::ParticipationBadgeModule
+    + loyalty::LoyaltyModule
+    + referral::ReferralModule
+    + kyc::KycModule
+    + signature_eligibility::SignatureEligibilityModule
+    + relayer::RelayerModule
+    + keeper_incentives::KeeperIncentivesModule
+    + governance::GovernanceModule
+    + price_oracle::PriceOracleModule
+    + swap_and_confirm::SwapAndConfirmModule
+    + locked_token_payment::LockedTokenPaymentModule
+    + reward_bundle::RewardBundleModule
+    + token_rescue::TokenRescueModule
+    + unclaimed_sweep::UnclaimedSweepModule
+    + storage_cleanup::StorageCleanupModule
+    + invariants::InvariantsModule
+    + winners_hash::WinnersHashModule
+    + tiers::TiersModule
+    + action_pause::ActionPauseModule
     + multiversx_sc_modules::pause::PauseModule
 {
     #[allow(clippy::too_many_arguments)]
@@ -55,22 +150,104 @@ pub trait LaunchpadMain:
             );
         }
 
+        // left empty if the contract is to issue the launchpad token itself
         self.launchpad_token_id().set(&launchpad_token_id);
 
         self.try_set_launchpad_tokens_per_winning_ticket(&launchpad_tokens_per_winning_ticket);
         self.try_set_ticket_price(ticket_payment_token, ticket_price);
         self.try_set_nr_winning_tickets(nr_winning_tickets);
 
-        let config = TimelineConfig {
+        // a guaranteed round may be added afterwards, through setLaunchPhases
+        let mut launch_phases = ManagedVec::new();
+        launch_phases.push(LaunchPhase::new(
+            PhaseType::PublicRound,
             confirmation_period_start_round,
+        ));
+        launch_phases.push(LaunchPhase::new(
+            PhaseType::WinnerSelection,
             winner_selection_start_round,
-            claim_start_round,
-        };
-        self.require_valid_time_periods(&config);
-        self.configuration().set(&config);
+        ));
+        launch_phases.push(LaunchPhase::new(PhaseType::Claim, claim_start_round));
+        self.try_set_launch_phases(&launch_phases);
         self.flags().set_if_empty(flags);
 
-        let caller = self.blockchain().get_caller();
-        self.support_address().set_if_empty(&caller);
+        let support_addresses_mapper = self.support_addresses();
+        if support_addresses_mapper.is_empty() {
+            let _ = support_addresses_mapper.insert(self.blockchain().get_caller());
+        }
+        self.storage_version().set(CURRENT_STORAGE_VERSION);
+    }
+
+    /// Contracts deployed before storage versioning are at version 0.
+    /// `migrate_storage_fn` receives the previous version, and runs before the version is bumped.
+    fn upgrade_base<MigrateStorageFn: Fn(&Self, u32)>(&self, migrate_storage_fn: MigrateStorageFn) {
+        let storage_version_mapper = self.storage_version();
+        let previous_version = storage_version_mapper.get();
+        require!(
+            previous_version <= CURRENT_STORAGE_VERSION,
+            "Cannot downgrade storage version"
+        );
+        if previous_version == CURRENT_STORAGE_VERSION {
+            return;
+        }
+
+        if previous_version < 2 {
+            self.migrate_legacy_support_address();
+        }
+        if previous_version < TICKETS_LAYOUT_STORAGE_VERSION {
+            self.migrate_legacy_tickets_layout();
+        }
+
+        migrate_storage_fn(self, previous_version);
+        storage_version_mapper.set(CURRENT_STORAGE_VERSION);
+    }
+
+    fn default_migrate_storage_fn(&self, _previous_version: u32) {}
+
+    fn get_user_full_status(
+        &self,
+        address: ManagedAddress,
+        guaranteed_tickets: usize,
+    ) -> UserFullStatus<Self::Api> {
+        let total_tickets = self.get_total_number_of_tickets_for_address(&address);
+        let confirmed_tickets = self.nr_confirmed_tickets(&address).get();
+        let winning_tickets = self.get_number_of_winning_tickets_for_address(address.clone());
+        let has_claimed = self.has_user_claimed(&address);
+        let refundable_amount = self.get_user_refundable_amount(address.clone());
+
+        UserFullStatus {
+            total_tickets,
+            confirmed_tickets,
+            winning_tickets,
+            guaranteed_tickets,
+            has_claimed,
+            refundable_amount,
+            is_blacklisted: self.is_user_blacklisted(&address),
+        }
     }
+
+    fn get_launch_configuration(
+        &self,
+        release_config: ManagedBuffer,
+    ) -> LaunchConfiguration<Self::Api> {
+        let ticket_price: TokenAmountPair<Self::Api> = self.ticket_price().get();
+        let timeline = self.configuration().get();
+
+        LaunchConfiguration {
+            launchpad_token_id: self.launchpad_token_id().get(),
+            launchpad_tokens_per_winning_ticket: self.launchpad_tokens_per_winning_ticket().get(),
+            ticket_payment_token: ticket_price.token_id,
+            ticket_price: ticket_price.amount,
+            nr_winning_tickets: self.nr_winning_tickets().get(),
+            confirmation_period_start_round: timeline.confirmation_period_start_round,
+            winner_selection_start_round: timeline.winner_selection_start_round,
+            claim_start_round: timeline.claim_start_round,
+            release_config,
+            launchpad_tokens_deposited: self.were_launchpad_tokens_deposited(),
+        }
+    }
+
+    #[view(getStorageVersion)]
+    #[storage_mapper("storageVersion")]
+    fn storage_version(&self) -> SingleValueMapper<u32>;
 }
@@ -0,0 +1,221 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::{config::TokenAmountPair, launch_stage::LaunchStage, permissions::Role};
+
+pub const MAX_LIQUIDITY_PERCENTAGE: u64 = 10_000; // 100%
+const MIN_ADDED_AMOUNT: u32 = 1;
+
+pub mod pair_proxy {
+    multiversx_sc::imports!();
+
+    pub type AddLiquidityResultType<M> =
+        MultiValue3<EsdtTokenPayment<M>, EsdtTokenPayment<M>, EsdtTokenPayment<M>>;
+
+    #[multiversx_sc::proxy]
+    pub trait PairProxy {
+        #[payable("*")]
+        #[endpoint(addLiquidity)]
+        fn add_liquidity(
+            &self,
+            first_token_amount_min: BigUint,
+            second_token_amount_min: BigUint,
+        ) -> AddLiquidityResultType<Self::Api>;
+
+        #[view(getFirstTokenId)]
+        fn get_first_token_id(&self) -> TokenIdentifier;
+    }
+}
+
+#[derive(TypeAbi, TopEncode, TopDecode)]
+pub struct LiquidityBootstrapConfig<M: ManagedTypeApi> {
+    pub pair_address: ManagedAddress<M>,
+    pub payment_percentage: u64,
+    pub lp_lock_epochs: u64,
+}
+
+#[multiversx_sc::module]
+pub trait LiquidityBootstrapModule:
+    crate::launch_stage::LaunchStageModule
+    + crate::config::ConfigModule
+    + crate::locked_token_payment::LockedTokenPaymentModule
+    + crate::permissions::PermissionsModule
+    + crate::action_pause::ActionPauseModule
+{
+    /// The pair has to be created through the xExchange router beforehand,
+    /// and be in the same shard as the launchpad
+    #[endpoint(setLiquidityBootstrapConfig)]
+    fn set_liquidity_bootstrap_config(
+        &self,
+        pair_address: ManagedAddress,
+        payment_percentage: u64,
+        lp_lock_epochs: u64,
+    ) {
+        self.require_config_manager();
+        require!(
+            self.get_launch_stage() < LaunchStage::Claim,
+            "Cannot change after claim period started"
+        );
+        require!(
+            self.blockchain().is_smart_contract(&pair_address),
+            "Invalid SC address"
+        );
+        require!(
+            payment_percentage > 0 && payment_percentage <= MAX_LIQUIDITY_PERCENTAGE,
+            "Invalid payment percentage"
+        );
+
+        let ticket_price: TokenAmountPair<Self::Api> = self.ticket_price().get();
+        require!(
+            ticket_price.token_id.is_esdt(),
+            "Ticket payment token must be an ESDT"
+        );
+
+        self.liquidity_bootstrap_config()
+            .set(LiquidityBootstrapConfig {
+                pair_address,
+                payment_percentage,
+                lp_lock_epochs,
+            });
+    }
+
+    /// Adds the configured percentage of the raised payment tokens as liquidity, together with
+    /// the launchpad tokens sent by the caller, which have to match the ticket price.
+    /// The LP tokens are kept by the contract for the configured number of epochs,
+    /// then sent to the owner.
+    #[payable("*")]
+    #[endpoint(bootstrapLiquidity)]
+    fn bootstrap_liquidity(&self) {
+        self.require_role(Role::Finance);
+        self.require_claim_period();
+
+        let config_mapper = self.liquidity_bootstrap_config();
+        require!(
+            !config_mapper.is_empty(),
+            "Liquidity bootstrap not configured"
+        );
+        require!(
+            self.locked_lp_tokens().is_empty(),
+            "Liquidity already added"
+        );
+
+        let config = config_mapper.get();
+        let ticket_price: TokenAmountPair<Self::Api> = self.ticket_price().get();
+        let payment_token_id = ticket_price.token_id.unwrap_esdt();
+        let owner_ticket_payment = self.get_owner_claimable_ticket_payment();
+        require!(owner_ticket_payment > 0, "No raised funds");
+
+        let payment_amount =
+            owner_ticket_payment * config.payment_percentage / MAX_LIQUIDITY_PERCENTAGE;
+        require!(payment_amount > 0, "No raised funds");
+
+        let launchpad_tokens = self.call_value().single_esdt();
+        let launchpad_token_id = self.launchpad_token_id().get();
+        let launchpad_tokens_needed = &payment_amount
+            * &self.launchpad_tokens_per_winning_ticket().get()
+            / &ticket_price.amount;
+        require!(
+            launchpad_tokens.token_identifier == launchpad_token_id
+                && launchpad_tokens.amount == launchpad_tokens_needed,
+            "Wrong launchpad tokens payment"
+        );
+
+        self.claimable_ticket_payment()
+            .update(|claimable| *claimable -= &payment_amount);
+
+        let first_token_id: TokenIdentifier = self
+            .pair_proxy_builder(config.pair_address.clone())
+            .get_first_token_id()
+            .execute_on_dest_context();
+        let payment_tokens = EsdtTokenPayment::new(payment_token_id, 0, payment_amount);
+        let mut liquidity_payments = ManagedVec::new();
+        if first_token_id == launchpad_token_id {
+            liquidity_payments.push(launchpad_tokens);
+            liquidity_payments.push(payment_tokens);
+        } else {
+            liquidity_payments.push(payment_tokens);
+            liquidity_payments.push(launchpad_tokens);
+        }
+
+        let add_liquidity_result: pair_proxy::AddLiquidityResultType<Self::Api> = self
+            .pair_proxy_builder(config.pair_address)
+            .add_liquidity(
+                BigUint::from(MIN_ADDED_AMOUNT),
+                BigUint::from(MIN_ADDED_AMOUNT),
+            )
+            .with_multi_token_transfer(liquidity_payments)
+            .execute_on_dest_context();
+        let (lp_tokens, first_token_refund, second_token_refund) =
+            add_liquidity_result.into_tuple();
+
+        self.handle_liquidity_refund(first_token_refund, &launchpad_token_id);
+        self.handle_liquidity_refund(second_token_refund, &launchpad_token_id);
+
+        let current_epoch = self.blockchain().get_block_epoch();
+        self.lp_tokens_unlock_epoch()
+            .set(current_epoch + config.lp_lock_epochs);
+        self.locked_lp_tokens().set(lp_tokens);
+    }
+
+    /// Unused payment tokens go back to the raised funds, unused launchpad tokens to the owner
+    fn handle_liquidity_refund(
+        &self,
+        refund: EsdtTokenPayment,
+        launchpad_token_id: &TokenIdentifier,
+    ) {
+        if refund.amount == 0 {
+            return;
+        }
+
+        if &refund.token_identifier == launchpad_token_id {
+            let owner = self.blockchain().get_owner_address();
+            self.send().direct_esdt(
+                &owner,
+                &refund.token_identifier,
+                refund.token_nonce,
+                &refund.amount,
+            );
+        } else {
+            self.claimable_ticket_payment()
+                .update(|payment| *payment += &refund.amount);
+        }
+    }
+
+    #[endpoint(claimLpTokens)]
+    fn claim_lp_tokens(&self) {
+        self.require_role(Role::Finance);
+
+        let locked_lp_tokens_mapper = self.locked_lp_tokens();
+        require!(!locked_lp_tokens_mapper.is_empty(), "No LP tokens");
+
+        let current_epoch = self.blockchain().get_block_epoch();
+        require!(
+            current_epoch >= self.lp_tokens_unlock_epoch().get(),
+            "LP tokens are still locked"
+        );
+
+        let lp_tokens = locked_lp_tokens_mapper.take();
+        let owner = self.blockchain().get_owner_address();
+        self.send().direct_esdt(
+            &owner,
+            &lp_tokens.token_identifier,
+            lp_tokens.token_nonce,
+            &lp_tokens.amount,
+        );
+    }
+
+    #[view(getLiquidityBootstrapConfig)]
+    #[storage_mapper("liquidityBootstrapConfig")]
+    fn liquidity_bootstrap_config(&self) -> SingleValueMapper<LiquidityBootstrapConfig<Self::Api>>;
+
+    #[view(getLockedLpTokens)]
+    #[storage_mapper("lockedLpTokens")]
+    fn locked_lp_tokens(&self) -> SingleValueMapper<EsdtTokenPayment>;
+
+    #[view(getLpTokensUnlockEpoch)]
+    #[storage_mapper("lpTokensUnlockEpoch")]
+    fn lp_tokens_unlock_epoch(&self) -> SingleValueMapper<u64>;
+
+    #[proxy]
+    fn pair_proxy_builder(&self, sc_address: ManagedAddress) -> pair_proxy::Proxy<Self::Api>;
+}
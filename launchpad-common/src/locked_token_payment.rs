@@ -0,0 +1,337 @@
+multiversx_sc::imports!();
+
+use crate::config::TokenAmountPair;
+
+/// Locked tokens released from a user's payment, not sent yet
+pub struct LockedTicketsRefund<M: ManagedTypeApi> {
+    pub nr_tickets: usize,
+    pub amount: BigUint<M>,
+    pub payments: ManagedVec<M, EsdtTokenPayment<M>>,
+}
+
+#[multiversx_sc::module]
+pub trait LockedTokenPaymentModule:
+    crate::launch_stage::LaunchStageModule
+    + crate::config::ConfigModule
+    + crate::permissions::PermissionsModule
+    + crate::action_pause::ActionPauseModule
+    + crate::common_events::CommonEventsModule
+{
+    /// Locked MEX meta-ESDTs (e.g. XMEX) accepted as ticket payment, at `amount_per_ticket`
+    /// per ticket, which may be set below the ticket price's equivalent to reward lockers.
+    /// The locked tokens of winning tickets are forwarded to the treasury at claim,
+    /// the ones of losing tickets are refunded with their original nonces.
+    #[endpoint(setLockedTokenPayment)]
+    fn set_locked_token_payment(&self, token_id: TokenIdentifier, amount_per_ticket: BigUint) {
+        self.require_config_manager();
+        self.require_add_tickets_period();
+        require!(token_id.is_valid_esdt_identifier(), "Invalid token ID");
+        require!(amount_per_ticket > 0, "Invalid amount per ticket");
+        require!(
+            !self.treasury_address().is_empty(),
+            "Treasury address not set"
+        );
+
+        self.locked_payment_token_id().set(token_id);
+        self.locked_tokens_per_ticket().set(amount_per_ticket);
+    }
+
+    #[endpoint(removeLockedTokenPayment)]
+    fn remove_locked_token_payment(&self) {
+        self.require_config_manager();
+        self.require_add_tickets_period();
+
+        self.locked_payment_token_id().clear();
+        self.locked_tokens_per_ticket().clear();
+    }
+
+    /// Keeps the price of the tickets out of the payments, in order, and returns the rest.
+    /// A partially used payment is split, so both parts keep its nonce.
+    fn store_locked_tokens_payment(
+        &self,
+        address: &ManagedAddress,
+        payments: &ManagedVec<EsdtTokenPayment>,
+        nr_tickets: usize,
+    ) -> ManagedVec<EsdtTokenPayment> {
+        let locked_token_id_mapper = self.locked_payment_token_id();
+        require!(
+            !locked_token_id_mapper.is_empty(),
+            "Locked token payment not enabled"
+        );
+
+        let locked_token_id = locked_token_id_mapper.get();
+        let mut remaining_price = self.locked_tokens_per_ticket().get() * nr_tickets as u32;
+        let mut stored_payments = self.user_locked_payments(address).get();
+        let mut excess_payments = ManagedVec::new();
+        for payment in payments.iter() {
+            require!(
+                payment.token_identifier == locked_token_id,
+                "Wrong payment token used"
+            );
+
+            if remaining_price == 0 {
+                excess_payments.push(payment.clone());
+            } else if payment.amount > remaining_price {
+                stored_payments.push(EsdtTokenPayment::new(
+                    payment.token_identifier.clone(),
+                    payment.token_nonce,
+                    remaining_price.clone(),
+                ));
+                excess_payments.push(EsdtTokenPayment::new(
+                    payment.token_identifier.clone(),
+                    payment.token_nonce,
+                    &payment.amount - &remaining_price,
+                ));
+                remaining_price = BigUint::zero();
+            } else {
+                remaining_price -= &payment.amount;
+                stored_payments.push(payment.clone());
+            }
+        }
+        require!(remaining_price == 0, "Wrong amount sent");
+
+        self.user_locked_payments(address).set(stored_payments);
+        self.user_locked_tickets(address)
+            .update(|nr_locked| *nr_locked += nr_tickets);
+        self.total_locked_tickets()
+            .update(|total| *total += nr_tickets);
+
+        excess_payments
+    }
+
+    /// Returns the number of refunded tickets
+    fn refund_locked_tickets(
+        &self,
+        address: &ManagedAddress,
+        receiver: &ManagedAddress,
+        max_tickets_to_refund: usize,
+    ) -> usize {
+        let refund = self.release_locked_tickets(address, max_tickets_to_refund);
+        self.send_locked_tickets_refund(receiver, &refund);
+
+        refund.nr_tickets
+    }
+
+    /// Only updates the storage, the locked tokens are sent by `send_locked_tickets_refund`
+    fn release_locked_tickets(
+        &self,
+        address: &ManagedAddress,
+        max_tickets_to_refund: usize,
+    ) -> LockedTicketsRefund<Self::Api> {
+        let nr_locked_tickets = self.user_locked_tickets(address).get();
+        let nr_tickets_to_refund = core::cmp::min(nr_locked_tickets, max_tickets_to_refund);
+        if nr_tickets_to_refund == 0 {
+            return LockedTicketsRefund {
+                nr_tickets: 0,
+                amount: BigUint::zero(),
+                payments: ManagedVec::new(),
+            };
+        }
+
+        let refund_amount = self.locked_tokens_per_ticket().get() * nr_tickets_to_refund as u32;
+        let refund_payments = self.take_user_locked_payments(address, refund_amount.clone());
+        self.user_locked_tickets(address)
+            .set(nr_locked_tickets - nr_tickets_to_refund);
+        self.total_locked_tickets()
+            .update(|total| *total -= nr_tickets_to_refund);
+
+        LockedTicketsRefund {
+            nr_tickets: nr_tickets_to_refund,
+            amount: refund_amount,
+            payments: refund_payments,
+        }
+    }
+
+    fn send_locked_tickets_refund(
+        &self,
+        receiver: &ManagedAddress,
+        refund: &LockedTicketsRefund<Self::Api>,
+    ) {
+        if refund.nr_tickets == 0 {
+            return;
+        }
+
+        self.send().direct_multi(receiver, &refund.payments);
+
+        let locked_token_id = self.locked_payment_token_id().get();
+        self.emit_refund_ticket_payment_event(
+            refund.nr_tickets,
+            EgldOrEsdtTokenPayment::new(
+                EgldOrEsdtTokenIdentifier::esdt(locked_token_id),
+                0,
+                refund.amount.clone(),
+            ),
+        );
+    }
+
+    /// Called once the losing tickets were refunded, so all the remaining locked tickets won.
+    /// Their locked tokens go to the treasury.
+    fn settle_locked_winning_tickets(&self, address: &ManagedAddress) {
+        let locked_payments = self.release_locked_winning_tickets(address);
+        self.send_to_treasury(&locked_payments);
+    }
+
+    /// Only updates the storage, and returns the locked tokens owed to the treasury
+    fn release_locked_winning_tickets(
+        &self,
+        address: &ManagedAddress,
+    ) -> ManagedVec<EsdtTokenPayment> {
+        let nr_locked_winning_tickets = self.user_locked_tickets(address).take();
+        if nr_locked_winning_tickets == 0 {
+            return ManagedVec::new();
+        }
+
+        self.total_locked_tickets()
+            .update(|total| *total -= nr_locked_winning_tickets);
+        self.settled_locked_winning_tickets()
+            .update(|total| *total += nr_locked_winning_tickets);
+
+        self.user_locked_payments(address).take()
+    }
+
+    fn send_to_treasury(&self, payments: &ManagedVec<EsdtTokenPayment>) {
+        if !payments.is_empty() {
+            self.send()
+                .direct_multi(&self.treasury_address().get(), payments);
+        }
+    }
+
+    /// The only ticket payment sent to the owner. It is computed from the tracked amounts only,
+    /// so unrelated funds in the same token are never paid out as ticket revenue.
+    /// The users' payments held for refunds are tracked separately in
+    /// `total_refundable_ticket_payment`, so the outstanding refunds are never part of it.
+    fn take_claimable_ticket_payment(&self) -> BigUint {
+        let owner_ticket_payment = self.get_owner_claimable_ticket_payment();
+        if owner_ticket_payment == 0 {
+            return owner_ticket_payment;
+        }
+
+        self.total_ticket_payment_claimed()
+            .update(|total| *total += &owner_ticket_payment);
+
+        owner_ticket_payment
+    }
+
+    /// The claimable ticket payment is computed from all the winning tickets, including the ones
+    /// paid with locked tokens. Their ticket price equivalent was never paid, so it is kept
+    /// for the locked tickets which may still win, as well as for the settled ones.
+    /// What the owner already claimed is subtracted.
+    #[view(getOwnerClaimableTicketPayment)]
+    fn get_owner_claimable_ticket_payment(&self) -> BigUint {
+        let unavailable_payment =
+            self.get_locked_tickets_reserved_payment() + self.total_ticket_payment_claimed().get();
+        let claimable_ticket_payment = self.claimable_ticket_payment().get();
+        if claimable_ticket_payment <= unavailable_payment {
+            return BigUint::zero();
+        }
+
+        claimable_ticket_payment - unavailable_payment
+    }
+
+    /// The held payments also include the ones of the winning tickets not claimed yet,
+    /// which are not refunded. All the tickets paid with locked tokens are counted as losing,
+    /// so the outstanding refunds are never underestimated.
+    #[view(getOutstandingRefunds)]
+    fn get_outstanding_refunds(&self) -> BigUint {
+        let held_ticket_payment = self.total_refundable_ticket_payment().get();
+        let nr_paid_winning_tickets = self
+            .nr_winning_tickets()
+            .get()
+            .saturating_sub(self.total_locked_tickets().get());
+        let ticket_price: TokenAmountPair<Self::Api> = self.ticket_price().get();
+        let winning_tickets_payment = ticket_price.amount * nr_paid_winning_tickets as u32;
+        if held_ticket_payment <= winning_tickets_payment {
+            return BigUint::zero();
+        }
+
+        held_ticket_payment - winning_tickets_payment
+    }
+
+    fn get_locked_tickets_reserved_payment(&self) -> BigUint {
+        let nr_locked_tickets =
+            self.total_locked_tickets().get() + self.settled_locked_winning_tickets().get();
+        if nr_locked_tickets == 0 {
+            return BigUint::zero();
+        }
+
+        let ticket_price: TokenAmountPair<Self::Api> = self.ticket_price().get();
+
+        ticket_price.amount * nr_locked_tickets as u32
+    }
+
+    /// Latest payments are taken first, splitting the last one if needed
+    fn take_user_locked_payments(
+        &self,
+        address: &ManagedAddress,
+        mut amount: BigUint,
+    ) -> ManagedVec<EsdtTokenPayment> {
+        let mut stored_payments = self.user_locked_payments(address).get();
+        let mut taken_payments = ManagedVec::new();
+        while amount > 0 && !stored_payments.is_empty() {
+            let last_index = stored_payments.len() - 1;
+            let mut payment = stored_payments.get(last_index).clone();
+            if payment.amount > amount {
+                taken_payments.push(EsdtTokenPayment::new(
+                    payment.token_identifier.clone(),
+                    payment.token_nonce,
+                    amount.clone(),
+                ));
+                payment.amount -= &amount;
+                let _ = stored_payments.set(last_index, payment);
+                amount = BigUint::zero();
+            } else {
+                amount -= &payment.amount;
+                stored_payments.remove(last_index);
+                taken_payments.push(payment);
+            }
+        }
+
+        self.user_locked_payments(address).set(stored_payments);
+
+        taken_payments
+    }
+
+    fn migrate_locked_payments(&self, old_address: &ManagedAddress, new_address: &ManagedAddress) {
+        let nr_locked_tickets = self.user_locked_tickets(old_address).take();
+        if nr_locked_tickets == 0 {
+            return;
+        }
+
+        self.user_locked_tickets(new_address).set(nr_locked_tickets);
+        self.user_locked_payments(new_address)
+            .set(self.user_locked_payments(old_address).take());
+    }
+
+    #[view(getLockedPaymentTokenId)]
+    #[storage_mapper("lockedPaymentTokenId")]
+    fn locked_payment_token_id(&self) -> SingleValueMapper<TokenIdentifier>;
+
+    #[view(getLockedTokensPerTicket)]
+    #[storage_mapper("lockedTokensPerTicket")]
+    fn locked_tokens_per_ticket(&self) -> SingleValueMapper<BigUint>;
+
+    #[view(getUserLockedTickets)]
+    #[storage_mapper("userLockedTickets")]
+    fn user_locked_tickets(&self, address: &ManagedAddress) -> SingleValueMapper<usize>;
+
+    #[view(getUserLockedPayments)]
+    #[storage_mapper("userLockedPayments")]
+    fn user_locked_payments(
+        &self,
+        address: &ManagedAddress,
+    ) -> SingleValueMapper<ManagedVec<EsdtTokenPayment>>;
+
+    /// Confirmed tickets paid with locked tokens, which were neither refunded nor claimed
+    #[view(getTotalLockedTickets)]
+    #[storage_mapper("totalLockedTickets")]
+    fn total_locked_tickets(&self) -> SingleValueMapper<usize>;
+
+    #[view(getSettledLockedWinningTickets)]
+    #[storage_mapper("settledLockedWinningTickets")]
+    fn settled_locked_winning_tickets(&self) -> SingleValueMapper<usize>;
+
+    /// Ticket payment sent to the owner so far
+    #[view(getTotalTicketPaymentClaimed)]
+    #[storage_mapper("totalTicketPaymentClaimed")]
+    fn total_ticket_payment_claimed(&self) -> SingleValueMapper<BigUint>;
+}
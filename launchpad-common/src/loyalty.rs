@@ -0,0 +1,206 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::launch_stage::LaunchStage;
+
+pub const MAX_LOYALTY_BONUS_TIERS: usize = 10;
+
+#[derive(TypeAbi, TopEncode, TopDecode)]
+pub struct LoyaltyPointsConfig {
+    pub points_per_confirmed_ticket: u64,
+    pub points_per_winning_ticket: u64,
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, ManagedVecItem)]
+pub struct LoyaltyBonusTier {
+    pub min_points: u64,
+    pub bonus_tickets: usize,
+}
+
+#[multiversx_sc::module]
+pub trait LoyaltyModule:
+    crate::launch_stage::LaunchStageModule
+    + crate::config::ConfigModule
+    + crate::tickets::TicketsModule
+    + crate::ongoing_operation::OngoingOperationModule
+    + crate::common_events::CommonEventsModule
+    + crate::permissions::PermissionsModule
+    + crate::action_pause::ActionPauseModule
+{
+    /// Points are accrued at claim, for each confirmed and each winning ticket
+    #[endpoint(setLoyaltyPointsConfig)]
+    fn set_loyalty_points_config(
+        &self,
+        points_per_confirmed_ticket: u64,
+        points_per_winning_ticket: u64,
+    ) {
+        self.require_config_manager();
+        require!(
+            self.get_launch_stage() < LaunchStage::Claim,
+            "Cannot change after claim period started"
+        );
+
+        self.loyalty_points_config().set(LoyaltyPointsConfig {
+            points_per_confirmed_ticket,
+            points_per_winning_ticket,
+        });
+    }
+
+    /// Previous launchpads, whose loyalty points are added to the ones accrued in this launch.
+    /// They have to be in the same shard, as their storage is read directly.
+    #[endpoint(addLoyaltySourceLaunchpads)]
+    fn add_loyalty_source_launchpads(&self, sc_addresses: MultiValueEncoded<ManagedAddress>) {
+        self.require_config_manager();
+
+        let own_shard = self
+            .blockchain()
+            .get_shard_of_address(&self.blockchain().get_sc_address());
+        let mut source_launchpads_mapper = self.loyalty_source_launchpads();
+        for sc_address in sc_addresses {
+            require!(
+                self.blockchain().is_smart_contract(&sc_address)
+                    && self.blockchain().get_shard_of_address(&sc_address) == own_shard,
+                "Invalid SC address"
+            );
+
+            let _ = source_launchpads_mapper.insert(sc_address);
+        }
+    }
+
+    #[endpoint(removeLoyaltySourceLaunchpads)]
+    fn remove_loyalty_source_launchpads(&self, sc_addresses: MultiValueEncoded<ManagedAddress>) {
+        self.require_config_manager();
+
+        let mut source_launchpads_mapper = self.loyalty_source_launchpads();
+        for sc_address in sc_addresses {
+            let _ = source_launchpads_mapper.swap_remove(&sc_address);
+        }
+    }
+
+    /// Tiers are given as (min_points, bonus_tickets) pairs, in ascending order of points.
+    /// A user gets the bonus of the highest tier they qualify for.
+    #[endpoint(setLoyaltyBonusTiers)]
+    fn set_loyalty_bonus_tiers(&self, tiers: MultiValueEncoded<MultiValue2<u64, usize>>) {
+        self.require_config_manager();
+        self.require_add_tickets_period();
+        require!(
+            tiers.len() <= MAX_LOYALTY_BONUS_TIERS,
+            "Maximum loyalty bonus tiers exceeded"
+        );
+
+        let mut loyalty_bonus_tiers = ManagedVec::new();
+        let mut last_min_points = 0;
+        for tier in tiers {
+            let (min_points, bonus_tickets) = tier.into_tuple();
+            require!(
+                min_points > last_min_points && bonus_tickets > 0,
+                "Invalid loyalty bonus tier"
+            );
+
+            last_min_points = min_points;
+            loyalty_bonus_tiers.push(LoyaltyBonusTier {
+                min_points,
+                bonus_tickets,
+            });
+        }
+
+        self.loyalty_bonus_tiers().set(loyalty_bonus_tiers);
+    }
+
+    /// Adds bonus tickets to users which already have tickets, based on their accumulated points.
+    /// Users which were already granted a bonus are skipped.
+    #[endpoint(grantLoyaltyBonusTickets)]
+    fn grant_loyalty_bonus_tickets(&self, users: MultiValueEncoded<ManagedAddress>) {
+        self.require_config_manager();
+        self.require_add_tickets_period();
+
+        for user in users {
+            let bonus_tickets_mapper = self.loyalty_bonus_tickets(&user);
+            if !bonus_tickets_mapper.is_empty() || self.ticket_range_for_address(&user).is_empty() {
+                continue;
+            }
+
+            let points = self.get_accumulated_loyalty_points(user.clone());
+            let bonus_tickets = self.get_loyalty_bonus_tickets(points);
+            if bonus_tickets == 0 {
+                continue;
+            }
+
+            bonus_tickets_mapper.set(bonus_tickets);
+            self.add_tickets_to_existing_user(user, bonus_tickets);
+        }
+    }
+
+    fn accrue_loyalty_points(
+        &self,
+        address: &ManagedAddress,
+        nr_confirmed_tickets: usize,
+        nr_winning_tickets: usize,
+    ) {
+        let config_mapper = self.loyalty_points_config();
+        if config_mapper.is_empty() {
+            return;
+        }
+
+        let config = config_mapper.get();
+        let points = config.points_per_confirmed_ticket * nr_confirmed_tickets as u64
+            + config.points_per_winning_ticket * nr_winning_tickets as u64;
+        if points == 0 {
+            return;
+        }
+
+        self.loyalty_points(address)
+            .update(|total_points| *total_points += points);
+    }
+
+    fn get_loyalty_bonus_tickets(&self, points: u64) -> usize {
+        let mut bonus_tickets = 0;
+        for tier in self.loyalty_bonus_tiers().get().iter() {
+            if points < tier.min_points {
+                break;
+            }
+
+            bonus_tickets = tier.bonus_tickets;
+        }
+
+        bonus_tickets
+    }
+
+    /// Points accrued in this launch, plus the ones from all the source launchpads
+    #[view(getAccumulatedLoyaltyPoints)]
+    fn get_accumulated_loyalty_points(&self, address: ManagedAddress) -> u64 {
+        let mut points = self.loyalty_points(&address).get();
+        for sc_address in self.loyalty_source_launchpads().iter() {
+            points += self.source_loyalty_points(sc_address, &address).get();
+        }
+
+        points
+    }
+
+    #[view(getLoyaltyPoints)]
+    #[storage_mapper("loyaltyPoints")]
+    fn loyalty_points(&self, address: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    #[storage_mapper_from_address("loyaltyPoints")]
+    fn source_loyalty_points(
+        &self,
+        sc_address: ManagedAddress,
+        address: &ManagedAddress,
+    ) -> SingleValueMapper<u64, ManagedAddress>;
+
+    #[view(getLoyaltyPointsConfig)]
+    #[storage_mapper("loyaltyPointsConfig")]
+    fn loyalty_points_config(&self) -> SingleValueMapper<LoyaltyPointsConfig>;
+
+    #[view(getLoyaltySourceLaunchpads)]
+    #[storage_mapper("loyaltySourceLaunchpads")]
+    fn loyalty_source_launchpads(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[view(getLoyaltyBonusTiers)]
+    #[storage_mapper("loyaltyBonusTiers")]
+    fn loyalty_bonus_tiers(&self) -> SingleValueMapper<ManagedVec<LoyaltyBonusTier>>;
+
+    #[view(getLoyaltyBonusTickets)]
+    #[storage_mapper("loyaltyBonusTickets")]
+    fn loyalty_bonus_tickets(&self, address: &ManagedAddress) -> SingleValueMapper<usize>;
+}
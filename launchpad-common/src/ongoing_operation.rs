@@ -3,25 +3,49 @@ multiversx_sc::derive_imports!();
 
 use multiversx_sc::api::CryptoApi;
 
-use crate::{random::Random, FIRST_TICKET_ID};
-
-const MIN_GAS_TO_SAVE_PROGRESS: u64 = 10_000_000;
+use crate::{
+    random::{Hash, Random, HASH_LEN},
+    tickets::{TicketId, TICKET_STATUS_CHUNK_BITS},
+    FIRST_TICKET_ID,
+};
+
+const DEFAULT_MIN_GAS_TO_SAVE_PROGRESS: u64 = 10_000_000;
+pub const MIN_GAS_TO_SAVE_PROGRESS_LOWER_BOUND: u64 = 1_000_000;
+pub const MIN_GAS_TO_SAVE_PROGRESS_UPPER_BOUND: u64 = 100_000_000;
 static ANOTHER_OP_ERR_MSG: &[u8] = b"Another ongoing operation is in progress";
 
 #[derive(TypeAbi, TopEncode, TopDecode)]
 pub enum OngoingOperationType<M: ManagedTypeApi + CryptoApi> {
     None,
     FilterTickets {
-        first_ticket_id_in_batch: usize,
-        nr_removed: usize,
+        first_ticket_id_in_batch: TicketId,
+        nr_removed: TicketId,
     },
     SelectWinners {
         rng: Random<M>,
-        ticket_position: usize,
+        ticket_position: TicketId,
+        nr_selected_tickets: usize,
     },
     AdditionalSelection {
         encoded_data: ManagedBuffer<M>,
     },
+    SweepUnclaimed {
+        ticket_id: TicketId,
+    },
+    AddTickets {
+        nr_processed_chunks: usize,
+        nr_added_users: usize,
+    },
+    DistributeResults {
+        ticket_id: TicketId,
+    },
+    CleanupStorage {
+        ticket_id: TicketId,
+    },
+    ComputeWinnersHash {
+        chunk_index: TicketId,
+        hash: Hash<M>,
+    },
 }
 
 pub type LoopOp = bool;
@@ -30,6 +54,20 @@ pub const STOP_OP: bool = false;
 
 #[multiversx_sc::module]
 pub trait OngoingOperationModule {
+    /// Gas kept in reserve on each iteration, so the progress can still be saved.
+    /// Should be adjusted if the protocol's gas schedule changes.
+    #[only_owner]
+    #[endpoint(setMinGasToSaveProgress)]
+    fn set_min_gas_to_save_progress(&self, min_gas: u64) {
+        require!(
+            (MIN_GAS_TO_SAVE_PROGRESS_LOWER_BOUND..=MIN_GAS_TO_SAVE_PROGRESS_UPPER_BOUND)
+                .contains(&min_gas),
+            "Invalid min gas value"
+        );
+
+        self.min_gas_to_save_progress().set(min_gas);
+    }
+
     fn run_while_it_has_gas<Process>(&self, mut process: Process) -> OperationCompletionStatus
     where
         Process: FnMut() -> LoopOp,
@@ -63,12 +101,13 @@ pub trait OngoingOperationModule {
     fn can_continue_operation(&self, operation_cost: u64) -> bool {
         let gas_left = self.blockchain().get_gas_left();
 
-        gas_left > MIN_GAS_TO_SAVE_PROGRESS + operation_cost
+        gas_left > self.get_min_gas_to_save_progress() + operation_cost
     }
 
     #[inline]
     fn save_progress(&self, op: &OngoingOperationType<Self::Api>) {
         self.current_ongoing_operation().set(op);
+        self.operation_nr_calls().update(|nr_calls| *nr_calls += 1);
     }
 
     fn save_additional_selection_progress<T: TopEncode>(&self, data: &T) {
@@ -80,9 +119,11 @@ pub trait OngoingOperationModule {
     #[inline]
     fn clear_operation(&self) {
         self.current_ongoing_operation().clear();
+        self.operation_nr_calls().clear();
     }
 
-    fn load_filter_tickets_operation(&self) -> (usize, usize) {
+    /// An unfinished ticket addition has to be ended through `finalizeAddTickets` first
+    fn load_filter_tickets_operation(&self) -> (TicketId, TicketId) {
         let ongoing_operation = self.current_ongoing_operation().get();
         match ongoing_operation {
             OngoingOperationType::None => (FIRST_TICKET_ID, 0),
@@ -94,14 +135,67 @@ pub trait OngoingOperationModule {
         }
     }
 
-    fn load_select_winners_operation(&self) -> (Random<Self::Api>, usize) {
+    fn load_select_winners_operation(&self) -> (Random<Self::Api>, TicketId, usize) {
         let ongoing_operation = self.current_ongoing_operation().get();
         match ongoing_operation {
-            OngoingOperationType::None => (Random::default(), FIRST_TICKET_ID),
+            OngoingOperationType::None => (Random::default(), FIRST_TICKET_ID, 0),
             OngoingOperationType::SelectWinners {
                 rng,
                 ticket_position,
-            } => (rng, ticket_position),
+                nr_selected_tickets,
+            } => (rng, ticket_position, nr_selected_tickets),
+            _ => sc_panic!(ANOTHER_OP_ERR_MSG),
+        }
+    }
+
+    fn load_sweep_unclaimed_operation(&self) -> TicketId {
+        let ongoing_operation = self.current_ongoing_operation().get();
+        match ongoing_operation {
+            OngoingOperationType::None => FIRST_TICKET_ID,
+            OngoingOperationType::SweepUnclaimed { ticket_id } => ticket_id,
+            _ => sc_panic!(ANOTHER_OP_ERR_MSG),
+        }
+    }
+
+    fn load_distribute_results_operation(&self) -> TicketId {
+        let ongoing_operation = self.current_ongoing_operation().get();
+        match ongoing_operation {
+            OngoingOperationType::None => FIRST_TICKET_ID,
+            OngoingOperationType::DistributeResults { ticket_id } => ticket_id,
+            _ => sc_panic!(ANOTHER_OP_ERR_MSG),
+        }
+    }
+
+    fn load_cleanup_storage_operation(&self) -> TicketId {
+        let ongoing_operation = self.current_ongoing_operation().get();
+        match ongoing_operation {
+            OngoingOperationType::None => FIRST_TICKET_ID,
+            OngoingOperationType::CleanupStorage { ticket_id } => ticket_id,
+            _ => sc_panic!(ANOTHER_OP_ERR_MSG),
+        }
+    }
+
+    /// The hash starts as all zeroes
+    fn load_compute_winners_hash_operation(&self) -> (TicketId, Hash<Self::Api>) {
+        let ongoing_operation = self.current_ongoing_operation().get();
+        match ongoing_operation {
+            OngoingOperationType::None => (
+                FIRST_TICKET_ID / TICKET_STATUS_CHUNK_BITS,
+                Hash::new_from_bytes(&[0u8; HASH_LEN]),
+            ),
+            OngoingOperationType::ComputeWinnersHash { chunk_index, hash } => (chunk_index, hash),
+            _ => sc_panic!(ANOTHER_OP_ERR_MSG),
+        }
+    }
+
+    fn load_add_tickets_operation(&self) -> (usize, usize) {
+        let ongoing_operation = self.current_ongoing_operation().get();
+        match ongoing_operation {
+            OngoingOperationType::None => (0, 0),
+            OngoingOperationType::AddTickets {
+                nr_processed_chunks,
+                nr_added_users,
+            } => (nr_processed_chunks, nr_added_users),
             _ => sc_panic!(ANOTHER_OP_ERR_MSG),
         }
     }
@@ -118,6 +212,23 @@ pub trait OngoingOperationModule {
         }
     }
 
+    #[view(getMinGasToSaveProgress)]
+    fn get_min_gas_to_save_progress(&self) -> u64 {
+        let min_gas_mapper = self.min_gas_to_save_progress();
+        if min_gas_mapper.is_empty() {
+            DEFAULT_MIN_GAS_TO_SAVE_PROGRESS
+        } else {
+            min_gas_mapper.get()
+        }
+    }
+
     #[storage_mapper("operation")]
     fn current_ongoing_operation(&self) -> SingleValueMapper<OngoingOperationType<Self::Api>>;
+
+    /// Number of calls which saved progress for the current operation
+    #[storage_mapper("operationNrCalls")]
+    fn operation_nr_calls(&self) -> SingleValueMapper<usize>;
+
+    #[storage_mapper("minGasToSaveProgress")]
+    fn min_gas_to_save_progress(&self) -> SingleValueMapper<u64>;
 }
@@ -0,0 +1,107 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::config::{TimelineConfig, TokenAmountPair};
+
+pub const BADGE_AMOUNT: u32 = 1;
+
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct ParticipationBadgeAttributes<M: ManagedTypeApi> {
+    pub launchpad_token_id: TokenIdentifier<M>,
+    pub launchpad_tokens_per_winning_ticket: BigUint<M>,
+    pub ticket_price: EgldOrEsdtTokenPayment<M>,
+    pub claim_start_round: u64,
+}
+
+#[multiversx_sc::module]
+pub trait ParticipationBadgeModule:
+    crate::config::ConfigModule
+    + crate::permissions::PermissionsModule
+    + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
+{
+    #[payable("EGLD")]
+    #[endpoint(issueParticipationBadge)]
+    fn issue_participation_badge(
+        &self,
+        token_display_name: ManagedBuffer,
+        token_ticker: ManagedBuffer,
+    ) {
+        self.require_extended_permissions();
+
+        let issue_cost = self.call_value().egld_value().clone_value();
+        self.participation_badge().issue_and_set_all_roles(
+            EsdtTokenType::SemiFungible,
+            issue_cost,
+            token_display_name,
+            token_ticker,
+            0,
+            None,
+        );
+    }
+
+    /// Only the contract gets the transfer role, so users can't transfer their badges.
+    /// Badges are only minted after this step.
+    #[endpoint(setParticipationBadgeTransferRole)]
+    fn set_participation_badge_transfer_role(&self) {
+        self.require_extended_permissions();
+
+        let sc_address = self.blockchain().get_sc_address();
+        self.participation_badge().set_local_roles_for_address(
+            &sc_address,
+            &[EsdtLocalRole::Transfer],
+            None,
+        );
+        self.participation_badge_transfer_role_set().set(true);
+    }
+
+    /// All the badges share the same nonce, which is created on the first claim
+    fn send_participation_badge(&self, address: &ManagedAddress) {
+        if !self.participation_badge_transfer_role_set().get() {
+            return;
+        }
+
+        let badge_mapper = self.participation_badge();
+        let nonce_mapper = self.participation_badge_nonce();
+        if !nonce_mapper.is_empty() {
+            let _ = badge_mapper.nft_add_quantity_and_send(
+                address,
+                nonce_mapper.get(),
+                BigUint::from(BADGE_AMOUNT),
+            );
+
+            return;
+        }
+
+        let config: TimelineConfig = self.configuration().get();
+        let ticket_price: TokenAmountPair<Self::Api> = self.ticket_price().get();
+        let attributes = ParticipationBadgeAttributes {
+            launchpad_token_id: self.launchpad_token_id().get(),
+            launchpad_tokens_per_winning_ticket: self.launchpad_tokens_per_winning_ticket().get(),
+            ticket_price: EgldOrEsdtTokenPayment::new(
+                ticket_price.token_id,
+                0,
+                ticket_price.amount,
+            ),
+            claim_start_round: config.claim_start_round,
+        };
+        let badge = badge_mapper.nft_create(BigUint::from(BADGE_AMOUNT), &attributes);
+        nonce_mapper.set(badge.token_nonce);
+        self.send().direct_esdt(
+            address,
+            &badge.token_identifier,
+            badge.token_nonce,
+            &badge.amount,
+        );
+    }
+
+    #[view(getParticipationBadgeTokenId)]
+    #[storage_mapper("participationBadge")]
+    fn participation_badge(&self) -> NonFungibleTokenMapper<Self::Api>;
+
+    #[view(getParticipationBadgeNonce)]
+    #[storage_mapper("participationBadgeNonce")]
+    fn participation_badge_nonce(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("participationBadgeTransferRoleSet")]
+    fn participation_badge_transfer_role_set(&self) -> SingleValueMapper<bool>;
+}
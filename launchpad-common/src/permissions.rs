@@ -1,25 +1,184 @@
 multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Copy)]
+pub enum Role {
+    Admin,
+    BlacklistManager,
+    ConfigManager,
+    Finance,
+    KycAttestor,
+    Relayer,
+}
 
 #[multiversx_sc::module]
 pub trait PermissionsModule {
+    /// The new support address only gets its permissions after calling `acceptSupportAddress`.
+    /// There may be multiple support addresses, each revoked on its own.
     #[only_owner]
-    #[endpoint(setSupportAddress)]
-    fn add_support_address(&self, address: ManagedAddress) {
-        self.support_address().set(&address);
+    #[endpoint(proposeSupportAddress)]
+    fn propose_support_address(&self, address: ManagedAddress) {
+        self.pending_support_address().set(&address);
     }
 
-    fn require_extended_permissions(&self) {
+    #[endpoint(acceptSupportAddress)]
+    fn accept_support_address(&self) {
+        let caller = self.blockchain().get_caller();
+        let pending_support_address_mapper = self.pending_support_address();
+        require!(
+            !pending_support_address_mapper.is_empty()
+                && pending_support_address_mapper.get() == caller,
+            "Not the proposed support address"
+        );
+
+        pending_support_address_mapper.clear();
+        let _ = self.support_addresses().insert(caller);
+    }
+
+    #[only_owner]
+    #[endpoint(removeSupportAddress)]
+    fn remove_support_address(&self, address: ManagedAddress) {
+        require!(
+            self.support_addresses().swap_remove(&address),
+            "Not a support address"
+        );
+    }
+
+    /// `from` is zero-based. The order changes when a support address is removed.
+    #[view(getSupportAddresses)]
+    fn get_support_addresses(&self, from: usize, size: usize) -> MultiValueEncoded<ManagedAddress> {
+        let mut support_addresses = MultiValueEncoded::new();
+        let support_addresses_mapper = self.support_addresses();
+        let end = core::cmp::min(from.saturating_add(size), support_addresses_mapper.len());
+        for index in from..end {
+            support_addresses.push(support_addresses_mapper.get_by_index(index + 1));
+        }
+
+        support_addresses
+    }
+
+    /// Contracts upgraded from before the support address list keep their support address
+    fn migrate_legacy_support_address(&self) {
+        let legacy_support_address_mapper = self.legacy_support_address();
+        if legacy_support_address_mapper.is_empty() {
+            return;
+        }
+
+        let _ = self
+            .support_addresses()
+            .insert(legacy_support_address_mapper.take());
+    }
+
+    /// The owner may grant any role. Admins (including the support address)
+    /// may grant every role except Admin.
+    /// Admin grants are only proposals, which have to be accepted through `acceptAdminRole`.
+    #[endpoint(addRole)]
+    fn add_role(&self, role: Role, addresses: MultiValueEncoded<ManagedAddress>) {
+        self.require_can_manage_role(role);
+
+        let mut role_members_mapper = self.role_members(role);
+        let mut pending_admins_mapper = self.pending_admins();
+        for address in addresses {
+            require!(
+                !role_members_mapper.contains(&address),
+                "Address already has role"
+            );
+
+            if role == Role::Admin {
+                let _ = pending_admins_mapper.insert(address);
+            } else {
+                let _ = role_members_mapper.insert(address);
+            }
+        }
+    }
+
+    #[endpoint(acceptAdminRole)]
+    fn accept_admin_role(&self) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.pending_admins().swap_remove(&caller),
+            "Admin role was not proposed"
+        );
+
+        let _ = self.role_members(Role::Admin).insert(caller);
+    }
+
+    #[endpoint(removeRole)]
+    fn remove_role(&self, role: Role, addresses: MultiValueEncoded<ManagedAddress>) {
+        self.require_can_manage_role(role);
+
+        let mut role_members_mapper = self.role_members(role);
+        let mut pending_admins_mapper = self.pending_admins();
+        for address in addresses {
+            let was_pending = role == Role::Admin && pending_admins_mapper.swap_remove(&address);
+            require!(
+                role_members_mapper.swap_remove(&address) || was_pending,
+                "Address does not have role"
+            );
+        }
+    }
+
+    #[view(hasRole)]
+    fn has_role(&self, address: &ManagedAddress, role: Role) -> bool {
+        if self.is_owner_or_admin(address) {
+            return true;
+        }
+
+        self.role_members(role).contains(address)
+    }
+
+    fn is_owner_or_admin(&self, address: &ManagedAddress) -> bool {
+        let owner = self.blockchain().get_owner_address();
+        if address == &owner || self.support_addresses().contains(address) {
+            return true;
+        }
+
+        self.role_members(Role::Admin).contains(address)
+    }
+
+    fn require_role(&self, role: Role) {
+        let caller = self.blockchain().get_caller();
+        require!(self.has_role(&caller, role), "Permission denied");
+    }
+
+    fn require_can_manage_role(&self, role: Role) {
         let caller = self.blockchain().get_caller();
         let owner = self.blockchain().get_owner_address();
-        let support_address = self.support_address().get();
+        if caller == owner {
+            return;
+        }
 
         require!(
-            caller == owner || caller == support_address,
+            role != Role::Admin && self.is_owner_or_admin(&caller),
             "Permission denied"
         );
     }
 
-    #[view(getSupportAddress)]
+    #[inline]
+    fn require_extended_permissions(&self) {
+        self.require_role(Role::Admin);
+    }
+
+    #[view(isSupportAddress)]
+    fn is_support_address(&self, address: ManagedAddress) -> bool {
+        self.support_addresses().contains(&address)
+    }
+
+    #[storage_mapper("supportAddresses")]
+    fn support_addresses(&self) -> UnorderedSetMapper<ManagedAddress>;
+
     #[storage_mapper("supportAddress")]
-    fn support_address(&self) -> SingleValueMapper<ManagedAddress>;
+    fn legacy_support_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[view(getPendingSupportAddress)]
+    #[storage_mapper("pendingSupportAddress")]
+    fn pending_support_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[view(getRoleMembers)]
+    #[storage_mapper("roleMembers")]
+    fn role_members(&self, role: Role) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[view(getPendingAdmins)]
+    #[storage_mapper("pendingAdmins")]
+    fn pending_admins(&self) -> UnorderedSetMapper<ManagedAddress>;
 }
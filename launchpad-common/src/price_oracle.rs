@@ -0,0 +1,170 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::config::TokenAmountPair;
+
+pub static USD_TICKER: &[u8] = b"USD";
+
+pub mod price_aggregator_proxy {
+    multiversx_sc::imports!();
+    multiversx_sc::derive_imports!();
+
+    #[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode)]
+    pub struct PriceFeed<M: ManagedTypeApi> {
+        pub round_id: u32,
+        pub from: ManagedBuffer<M>,
+        pub to: ManagedBuffer<M>,
+        pub timestamp: u64,
+        pub price: BigUint<M>,
+        pub decimals: u8,
+    }
+
+    #[multiversx_sc::proxy]
+    pub trait PriceAggregatorProxy {
+        #[view(latestPriceFeedOptional)]
+        fn latest_price_feed_optional(
+            &self,
+            from: ManagedBuffer,
+            to: ManagedBuffer,
+        ) -> OptionalValue<PriceFeed<Self::Api>>;
+    }
+}
+
+#[derive(TypeAbi, TopEncode, TopDecode)]
+pub struct UsdPricingConfig<M: ManagedTypeApi> {
+    pub oracle_address: ManagedAddress<M>,
+    pub payment_token_ticker: ManagedBuffer<M>,
+    pub payment_token_decimals: u32,
+    pub usd_ticket_price: BigUint<M>,
+    pub max_price_age_seconds: u64,
+}
+
+#[multiversx_sc::module]
+pub trait PriceOracleModule:
+    crate::launch_stage::LaunchStageModule
+    + crate::config::ConfigModule
+    + crate::permissions::PermissionsModule
+    + crate::action_pause::ActionPauseModule
+    + crate::common_events::CommonEventsModule
+{
+    /// The USD ticket price uses the same number of decimals as the price feed.
+    /// The price aggregator has to be in the same shard, as it is queried synchronously.
+    /// Confirmations are rejected while the price feed is missing or stale.
+    #[endpoint(setUsdTicketPricing)]
+    fn set_usd_ticket_pricing(
+        &self,
+        oracle_address: ManagedAddress,
+        payment_token_ticker: ManagedBuffer,
+        payment_token_decimals: u32,
+        usd_ticket_price: BigUint,
+        max_price_age_seconds: u64,
+    ) {
+        self.require_config_manager();
+        self.require_add_tickets_period();
+        require!(
+            self.blockchain().is_smart_contract(&oracle_address),
+            "Invalid SC address"
+        );
+        require!(!payment_token_ticker.is_empty(), "Invalid token ticker");
+        require!(usd_ticket_price > 0, "Ticket price must be higher than 0");
+        require!(max_price_age_seconds > 0, "Invalid max price age");
+
+        self.usd_pricing_config().set(UsdPricingConfig {
+            oracle_address,
+            payment_token_ticker,
+            payment_token_decimals,
+            usd_ticket_price,
+            max_price_age_seconds,
+        });
+    }
+
+    #[endpoint(removeUsdTicketPricing)]
+    fn remove_usd_ticket_pricing(&self) {
+        self.require_config_manager();
+        self.require_add_tickets_period();
+
+        self.usd_pricing_config().clear();
+    }
+
+    /// The oracle is queried on each confirmation, which is rejected if the price feed is missing
+    /// or stale, instead of silently keeping the previous price.
+    /// Refunds and raised funds are computed with a single ticket price,
+    /// so the quote only sets the ticket price until the first tickets are confirmed.
+    fn update_usd_ticket_price(&self) {
+        if self.usd_pricing_config().is_empty() {
+            return;
+        }
+
+        let amount = match self.get_usd_ticket_price_quote() {
+            OptionalValue::Some(amount) => amount,
+            OptionalValue::None => sc_panic!("USD price feed missing or stale"),
+        };
+        if self.usd_ticket_price_fixed().get() {
+            return;
+        }
+
+        self.usd_ticket_price_fixed().set(true);
+
+        let mut ticket_price: TokenAmountPair<Self::Api> = self.ticket_price().get();
+        ticket_price.amount = amount;
+        self.ticket_price().set(&ticket_price);
+
+        self.emit_set_ticket_price_event(EgldOrEsdtTokenPayment::new(
+            ticket_price.token_id,
+            0,
+            ticket_price.amount,
+        ));
+    }
+
+    /// The ticket price in payment tokens, at the current oracle price.
+    /// Returns nothing if the price feed is missing or stale.
+    #[view(getUsdTicketPriceQuote)]
+    fn get_usd_ticket_price_quote(&self) -> OptionalValue<BigUint> {
+        let config_mapper = self.usd_pricing_config();
+        if config_mapper.is_empty() {
+            return OptionalValue::None;
+        }
+
+        let config = config_mapper.get();
+        let opt_price_feed: OptionalValue<price_aggregator_proxy::PriceFeed<Self::Api>> = self
+            .price_aggregator_proxy_builder(config.oracle_address)
+            .latest_price_feed_optional(
+                config.payment_token_ticker,
+                ManagedBuffer::from(USD_TICKER),
+            )
+            .execute_on_dest_context();
+        let price_feed = match opt_price_feed {
+            OptionalValue::Some(price_feed) => price_feed,
+            OptionalValue::None => return OptionalValue::None,
+        };
+
+        let current_timestamp = self.blockchain().get_block_timestamp();
+        if price_feed.price == 0
+            || price_feed.timestamp + config.max_price_age_seconds < current_timestamp
+        {
+            return OptionalValue::None;
+        }
+
+        let token_unit = BigUint::from(10u32).pow(config.payment_token_decimals);
+        let amount = config.usd_ticket_price * token_unit / price_feed.price;
+        if amount == 0 {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(amount)
+    }
+
+    #[view(getUsdPricingConfig)]
+    #[storage_mapper("usdPricingConfig")]
+    fn usd_pricing_config(&self) -> SingleValueMapper<UsdPricingConfig<Self::Api>>;
+
+    #[view(isUsdTicketPriceFixed)]
+    #[storage_mapper("usdTicketPriceFixed")]
+    fn usd_ticket_price_fixed(&self) -> SingleValueMapper<bool>;
+
+    #[proxy]
+    fn price_aggregator_proxy_builder(
+        &self,
+        sc_address: ManagedAddress,
+    ) -> price_aggregator_proxy::Proxy<Self::Api>;
+}
@@ -1,14 +1,26 @@
 use multiversx_sc::api::{CryptoApi, CryptoApiImpl};
 
+use crate::tickets::TicketId;
+
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
 const USIZE_BYTES: usize = 4;
+const U64_BYTES: usize = 8;
 pub const HASH_LEN: usize = 32;
 static FAILED_COPY_ERR_MSG: &[u8] = b"Failed copy to/from managed buffer";
 
 pub type Hash<M> = ManagedByteArray<M, HASH_LEN>;
 
+/// Used instead of the block random seed when built with the `mock-random` feature,
+/// so the winner selection can be reproduced in tests and on devnet.
+/// May be overridden at build time through the `MOCK_RANDOM_SEED` environment variable.
+#[cfg(feature = "mock-random")]
+pub const MOCK_RANDOM_SEED: &str = match option_env!("MOCK_RANDOM_SEED") {
+    Some(seed) => seed,
+    None => "launchpad-mock-random-seed",
+};
+
 #[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode)]
 pub struct Random<M: ManagedTypeApi + CryptoApi> {
     pub seed: ManagedBuffer<M>,
@@ -16,12 +28,24 @@ pub struct Random<M: ManagedTypeApi + CryptoApi> {
 }
 
 impl<M: ManagedTypeApi + CryptoApi> Default for Random<M> {
+    #[cfg(not(feature = "mock-random"))]
     fn default() -> Self {
         Self {
             seed: ManagedBuffer::new_random(HASH_LEN),
             index: 0,
         }
     }
+
+    #[cfg(feature = "mock-random")]
+    fn default() -> Self {
+        let mut rng = Self {
+            seed: ManagedBuffer::new_from_bytes(MOCK_RANDOM_SEED.as_bytes()),
+            index: 0,
+        };
+        rng.hash_seed();
+
+        rng
+    }
 }
 
 impl<M: ManagedTypeApi + CryptoApi> Random<M> {
@@ -59,6 +83,40 @@ impl<M: ManagedTypeApi + CryptoApi> Random<M> {
         }
     }
 
+    pub fn next_u64(&mut self) -> u64 {
+        if self.index + U64_BYTES > HASH_LEN {
+            self.hash_seed();
+        }
+
+        let raw_buffer = match self.seed.copy_slice(self.index, U64_BYTES) {
+            Some(buffer) => buffer,
+            None => M::error_api_impl().signal_error(FAILED_COPY_ERR_MSG),
+        };
+        let rand = u64::top_decode(raw_buffer).unwrap_or_default();
+
+        self.index += U64_BYTES;
+
+        rand
+    }
+
+    /// Range is [min, max).
+    /// Ranges which fit in 32 bits consume the same random bytes as `next_usize_in_range`,
+    /// so the selection of smaller launches can still be verified the same way.
+    pub fn next_ticket_id_in_range(&mut self, min: TicketId, max: TicketId) -> TicketId {
+        if min >= max {
+            return min;
+        }
+
+        let range_len = max - min;
+        let rand = if range_len <= u32::MAX as u64 {
+            self.next_usize() as u64
+        } else {
+            self.next_u64()
+        };
+
+        min + rand % range_len
+    }
+
     fn hash_seed(&mut self) {
         let handle = self.seed.get_raw_handle();
         M::crypto_api_impl().sha256_managed(handle.into(), handle.into());
@@ -0,0 +1,166 @@
+multiversx_sc::imports!();
+
+use crate::{config::TokenAmountPair, launch_stage::LaunchStage, permissions::Role};
+
+pub const MAX_REFERRAL_REWARD_PERCENTAGE: u64 = 10_000; // 100%
+
+#[multiversx_sc::module]
+pub trait ReferralModule:
+    crate::launch_stage::LaunchStageModule
+    + crate::config::ConfigModule
+    + crate::permissions::PermissionsModule
+    + crate::action_pause::ActionPauseModule
+{
+    /// Percentage of the referred users' winning tickets payment, credited to their referrer at claim
+    #[endpoint(setReferralRewardPercentage)]
+    fn set_referral_reward_percentage(&self, reward_percentage: u64) {
+        self.require_config_manager();
+        require!(
+            self.get_launch_stage() < LaunchStage::Claim,
+            "Cannot change after claim period started"
+        );
+        require!(
+            reward_percentage <= MAX_REFERRAL_REWARD_PERCENTAGE,
+            "Invalid reward percentage"
+        );
+
+        self.referral_reward_percentage().set(reward_percentage);
+    }
+
+    /// The referral rewards are paid from this pool, in the ticket payment token
+    #[payable("*")]
+    #[endpoint(depositReferralRewards)]
+    fn deposit_referral_rewards(&self) {
+        self.require_role(Role::Finance);
+
+        let (payment_token, payment_amount) = self.call_value().egld_or_single_fungible_esdt();
+        let ticket_price: TokenAmountPair<Self::Api> = self.ticket_price().get();
+        require!(
+            payment_token == ticket_price.token_id && payment_amount > 0,
+            "Invalid referral rewards payment"
+        );
+
+        self.referral_reward_pool()
+            .update(|pool| *pool += payment_amount);
+    }
+
+    /// The part of the pool which was not claimed by referrers is returned once the claim deadline passed
+    #[endpoint(withdrawReferralRewardPool)]
+    fn withdraw_referral_reward_pool(&self) {
+        self.require_role(Role::Finance);
+        self.require_claim_period();
+        require!(
+            self.is_claim_deadline_passed(),
+            "Claim deadline has not passed yet"
+        );
+
+        let pool = self.referral_reward_pool().take();
+        if pool == 0 {
+            return;
+        }
+
+        let owner = self.blockchain().get_owner_address();
+        let ticket_price: TokenAmountPair<Self::Api> = self.ticket_price().get();
+        self.send().direct(&owner, &ticket_price.token_id, 0, &pool);
+    }
+
+    #[endpoint(claimReferralRewards)]
+    fn claim_referral_rewards(&self) {
+        self.require_claim_period();
+        self.require_before_claim_deadline();
+
+        let caller = self.blockchain().get_caller();
+        let rewards = self.referral_rewards(&caller).take();
+        require!(rewards > 0, "No referral rewards");
+
+        let pool_mapper = self.referral_reward_pool();
+        let pool = pool_mapper.get();
+        require!(pool >= rewards, "Not enough referral rewards deposited");
+
+        pool_mapper.set(pool - &rewards);
+
+        let ticket_price: TokenAmountPair<Self::Api> = self.ticket_price().get();
+        self.send()
+            .direct(&caller, &ticket_price.token_id, 0, &rewards);
+    }
+
+    /// The referrer is set on the first referred confirmation, and may not be changed afterwards
+    fn set_user_referrer(
+        &self,
+        user: &ManagedAddress,
+        opt_referrer: OptionalValue<ManagedAddress>,
+    ) {
+        let referrer = match opt_referrer {
+            OptionalValue::Some(referrer) => referrer,
+            OptionalValue::None => return,
+        };
+        require!(!referrer.is_zero(), "Invalid referrer address");
+        require!(&referrer != user, "Cannot refer yourself");
+
+        let user_referrer_mapper = self.user_referrer(user);
+        if !user_referrer_mapper.is_empty() {
+            require!(
+                user_referrer_mapper.get() == referrer,
+                "Referrer already set"
+            );
+
+            return;
+        }
+
+        let referrer_referrer_mapper = self.user_referrer(&referrer);
+        require!(
+            referrer_referrer_mapper.is_empty() || &referrer_referrer_mapper.get() != user,
+            "Circular referral"
+        );
+
+        user_referrer_mapper.set(&referrer);
+        self.nr_referred_users(&referrer)
+            .update(|nr_users| *nr_users += 1);
+    }
+
+    fn credit_referral_rewards(&self, user: &ManagedAddress, nr_winning_tickets: usize) {
+        let user_referrer_mapper = self.user_referrer(user);
+        if nr_winning_tickets == 0 || user_referrer_mapper.is_empty() {
+            return;
+        }
+
+        let reward_percentage = self.referral_reward_percentage().get();
+        let ticket_price: TokenAmountPair<Self::Api> = self.ticket_price().get();
+        let rewards = ticket_price.amount * nr_winning_tickets as u32 * reward_percentage
+            / MAX_REFERRAL_REWARD_PERCENTAGE;
+        if rewards == 0 {
+            return;
+        }
+
+        let referrer = user_referrer_mapper.get();
+        self.total_referral_rewards(&referrer)
+            .update(|total| *total += &rewards);
+        self.referral_rewards(&referrer)
+            .update(|pending| *pending += rewards);
+    }
+
+    #[view(getReferralRewardPercentage)]
+    #[storage_mapper("referralRewardPercentage")]
+    fn referral_reward_percentage(&self) -> SingleValueMapper<u64>;
+
+    #[view(getReferralRewardPool)]
+    #[storage_mapper("referralRewardPool")]
+    fn referral_reward_pool(&self) -> SingleValueMapper<BigUint>;
+
+    #[view(getUserReferrer)]
+    #[storage_mapper("userReferrer")]
+    fn user_referrer(&self, user: &ManagedAddress) -> SingleValueMapper<ManagedAddress>;
+
+    #[view(getNrReferredUsers)]
+    #[storage_mapper("nrReferredUsers")]
+    fn nr_referred_users(&self, referrer: &ManagedAddress) -> SingleValueMapper<usize>;
+
+    /// Rewards credited so far, including the ones already claimed
+    #[view(getTotalReferralRewards)]
+    #[storage_mapper("totalReferralRewards")]
+    fn total_referral_rewards(&self, referrer: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    #[view(getReferralRewards)]
+    #[storage_mapper("referralRewards")]
+    fn referral_rewards(&self, referrer: &ManagedAddress) -> SingleValueMapper<BigUint>;
+}
@@ -0,0 +1,37 @@
+multiversx_sc::imports!();
+
+use crate::permissions::Role;
+
+#[multiversx_sc::module]
+pub trait RelayerModule: crate::permissions::PermissionsModule {
+    /// The user signs, with their own key, the launchpad's address, followed by
+    /// the number of tickets and their relayed confirmation nonce, both as big endian u64.
+    /// The nonce is incremented on each relayed confirmation, so signatures may not be replayed.
+    fn verify_relayed_confirmation(
+        &self,
+        user: &ManagedAddress,
+        nr_tickets_to_confirm: usize,
+        signature: &ManagedBuffer,
+    ) {
+        self.require_role(Role::Relayer);
+
+        let nonce_mapper = self.relayed_confirmation_nonce(user);
+        let nonce = nonce_mapper.get();
+
+        let mut message = self
+            .blockchain()
+            .get_sc_address()
+            .as_managed_buffer()
+            .clone();
+        message.append_bytes(&(nr_tickets_to_confirm as u64).to_be_bytes());
+        message.append_bytes(&nonce.to_be_bytes());
+        self.crypto()
+            .verify_ed25519(user.as_managed_buffer(), &message, signature);
+
+        nonce_mapper.set(nonce + 1);
+    }
+
+    #[view(getRelayedConfirmationNonce)]
+    #[storage_mapper("relayedConfirmationNonce")]
+    fn relayed_confirmation_nonce(&self, user: &ManagedAddress) -> SingleValueMapper<u64>;
+}
@@ -0,0 +1,67 @@
+multiversx_sc::imports!();
+
+use crate::{
+    ongoing_operation::{OngoingOperationType, CONTINUE_OP, STOP_OP},
+    tickets::{TicketBatch, TicketId, TicketRange},
+};
+
+#[multiversx_sc::module]
+pub trait ResultsDistributionModule:
+    crate::launch_stage::LaunchStageModule
+    + crate::config::ConfigModule
+    + crate::blacklist::BlacklistModule
+    + crate::tickets::TicketsModule
+    + crate::ongoing_operation::OngoingOperationModule
+    + crate::token_send::TokenSendModule
+    + crate::permissions::PermissionsModule
+    + crate::common_events::CommonEventsModule
+    + crate::user_interactions::UserInteractionsModule
+    + multiversx_sc_modules::pause::PauseModule
+{
+    /// Sends the launchpad tokens and refunds to every user who did not claim yet.
+    /// Users may still claim by themselves while the distribution is in progress.
+    fn distribute_results<
+        SendLaunchpadTokensFn: Fn(&Self, &ManagedAddress, &EsdtTokenPayment<Self::Api>, TicketId),
+    >(
+        &self,
+        send_fn: SendLaunchpadTokensFn,
+    ) -> OperationCompletionStatus {
+        self.require_claim_period();
+        self.require_before_claim_deadline();
+
+        let last_ticket_id = self.last_ticket_id().get();
+        let mut ticket_id = self.load_distribute_results_operation();
+
+        let run_result = self.run_while_it_has_gas(|| {
+            if ticket_id > last_ticket_id {
+                return STOP_OP;
+            }
+
+            // batches of users who already claimed are cleared
+            let ticket_batch_mapper = self.ticket_batch(ticket_id);
+            if ticket_batch_mapper.is_empty() {
+                ticket_id += 1;
+
+                return CONTINUE_OP;
+            }
+
+            let ticket_batch: TicketBatch<Self::Api> = ticket_batch_mapper.get();
+            let ticket_range = TicketRange {
+                first_id: ticket_id,
+                last_id: ticket_id + ticket_batch.nr_tickets as TicketId - 1,
+            };
+            ticket_id = ticket_range.last_id + 1;
+
+            let address = &ticket_batch.address;
+            self.claim_launchpad_tokens_for_address(address, address, ticket_range, &send_fn);
+
+            CONTINUE_OP
+        });
+
+        if run_result == OperationCompletionStatus::InterruptedBeforeOutOfGas {
+            self.save_progress(&OngoingOperationType::DistributeResults { ticket_id });
+        }
+
+        run_result
+    }
+}
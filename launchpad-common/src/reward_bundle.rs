@@ -0,0 +1,112 @@
+multiversx_sc::imports!();
+
+#[multiversx_sc::module]
+pub trait RewardBundleModule:
+    crate::launch_stage::LaunchStageModule
+    + crate::config::ConfigModule
+    + crate::permissions::PermissionsModule
+    + crate::action_pause::ActionPauseModule
+{
+    /// Rewards sent for each winning ticket along with the launchpad tokens, which may also be SFTs
+    /// or meta-ESDTs. They are deposited in the same transfer as the launchpad tokens,
+    /// after them and in the configured order.
+    #[endpoint(setExtraRewardsPerWinningTicket)]
+    fn set_extra_rewards_per_winning_ticket(
+        &self,
+        rewards: MultiValueEncoded<MultiValue3<TokenIdentifier, u64, BigUint>>,
+    ) {
+        self.require_config_manager();
+        self.require_add_tickets_period();
+        require!(
+            !self.were_launchpad_tokens_deposited(),
+            "Tokens already deposited"
+        );
+
+        let launchpad_token_id = self.launchpad_token_id().get();
+        let mut extra_rewards = ManagedVec::<Self::Api, EsdtTokenPayment>::new();
+        for reward in rewards {
+            let (token_id, token_nonce, amount) = reward.into_tuple();
+            require!(token_id.is_valid_esdt_identifier(), "Invalid token ID");
+            require!(
+                token_id != launchpad_token_id || token_nonce != 0,
+                "Launchpad tokens may not be extra rewards"
+            );
+            require!(amount > 0, "Invalid reward amount");
+
+            for existing_reward in extra_rewards.iter() {
+                require!(
+                    existing_reward.token_identifier != token_id
+                        || existing_reward.token_nonce != token_nonce,
+                    "Duplicate reward token"
+                );
+            }
+
+            extra_rewards.push(EsdtTokenPayment::new(token_id, token_nonce, amount));
+        }
+
+        self.extra_rewards_per_winning_ticket().set(extra_rewards);
+    }
+
+    /// `payments` is the whole deposit, the launchpad tokens being the first transfer
+    fn require_extra_rewards_deposit(
+        &self,
+        payments: &ManagedVec<EsdtTokenPayment>,
+        total_winning_tickets: usize,
+    ) {
+        let expected_payments = self.get_extra_rewards(total_winning_tickets);
+        require!(
+            payments.len() == expected_payments.len() + 1,
+            "Wrong number of payments"
+        );
+
+        for (i, expected_payment) in expected_payments.iter().enumerate() {
+            let payment = payments.get(i + 1);
+            require!(
+                payment.token_identifier == expected_payment.token_identifier
+                    && payment.token_nonce == expected_payment.token_nonce,
+                "Wrong token"
+            );
+            require!(payment.amount == expected_payment.amount, "Wrong amount");
+        }
+    }
+
+    fn send_extra_rewards(&self, address: &ManagedAddress, nr_winning_tickets: usize) {
+        if nr_winning_tickets == 0 {
+            return;
+        }
+
+        let extra_rewards = self.get_extra_rewards(nr_winning_tickets);
+        if !extra_rewards.is_empty() {
+            self.send().direct_multi(address, &extra_rewards);
+        }
+    }
+
+    fn get_extra_rewards(&self, nr_winning_tickets: usize) -> ManagedVec<EsdtTokenPayment> {
+        let mut extra_rewards = self.extra_rewards_per_winning_ticket().get();
+        for i in 0..extra_rewards.len() {
+            let mut reward = extra_rewards.get(i).clone();
+            reward.amount *= nr_winning_tickets as u32;
+            let _ = extra_rewards.set(i, reward);
+        }
+
+        extra_rewards
+    }
+
+    /// Everything sent for a winning ticket, starting with the launchpad tokens
+    #[view(getRewardBundlePerWinningTicket)]
+    fn get_reward_bundle_per_winning_ticket(&self) -> ManagedVec<EsdtTokenPayment> {
+        let mut reward_bundle = ManagedVec::new();
+        reward_bundle.push(EsdtTokenPayment::new(
+            self.launchpad_token_id().get(),
+            0,
+            self.launchpad_tokens_per_winning_ticket().get(),
+        ));
+        reward_bundle.append_vec(self.extra_rewards_per_winning_ticket().get());
+
+        reward_bundle
+    }
+
+    #[view(getExtraRewardsPerWinningTicket)]
+    #[storage_mapper("extraRewardsPerWinningTicket")]
+    fn extra_rewards_per_winning_ticket(&self) -> SingleValueMapper<ManagedVec<EsdtTokenPayment>>;
+}
@@ -1,44 +1,70 @@
 multiversx_sc::imports!();
 
-use crate::config::{TimelineConfig, TokenAmountPair};
+use crate::{
+    config::{TimelineConfig, TokenAmountPair},
+    launch_stage::{LaunchPhase, LaunchStage, PhaseType},
+    time_provider::TimeUnit,
+    timelock::PendingChangeType,
+};
 
 #[multiversx_sc::module]
 pub trait SetupModule:
     crate::launch_stage::LaunchStageModule
     + crate::config::ConfigModule
+    + crate::permissions::PermissionsModule
+    + crate::action_pause::ActionPauseModule
+    + crate::timelock::TimelockModule
     + crate::common_events::CommonEventsModule
+    + crate::reward_bundle::RewardBundleModule
 {
+    /// The extra rewards per winning ticket, if any, are deposited in the same transfer
     fn deposit_launchpad_tokens(&self, total_winning_tickets: usize) {
         require!(
             !self.were_launchpad_tokens_deposited(),
             "Tokens already deposited"
         );
 
-        let (payment_token, payment_amount) = self.call_value().single_fungible_esdt();
+        let payments = self.call_value().all_esdt_transfers().clone_value();
+        require!(!payments.is_empty(), "No payment");
+
+        let launchpad_tokens = payments.get(0).clone();
         let launchpad_token_id = self.launchpad_token_id().get();
-        require!(payment_token == launchpad_token_id, "Wrong token");
+        require!(
+            launchpad_tokens.token_identifier == launchpad_token_id
+                && launchpad_tokens.token_nonce == 0,
+            "Wrong token"
+        );
 
         let amount_per_ticket = self.launchpad_tokens_per_winning_ticket().get();
         let amount_needed = amount_per_ticket * (total_winning_tickets as u32);
-        require!(payment_amount == amount_needed, "Wrong amount");
+        require!(launchpad_tokens.amount == amount_needed, "Wrong amount");
+
+        self.require_extra_rewards_deposit(&payments, total_winning_tickets);
 
         self.launchpad_tokens_deposited().set(true);
-        self.total_launchpad_tokens_deposited().set(payment_amount);
+        self.total_launchpad_tokens_deposited()
+            .set(launchpad_tokens.amount);
     }
 
-    #[only_owner]
     #[endpoint(setTicketPrice)]
     fn set_ticket_price(&self, token_id: EgldOrEsdtTokenIdentifier, amount: BigUint) {
+        self.require_config_manager();
+        self.require_admin_proposals_disabled();
         self.require_add_tickets_period();
-        self.try_set_ticket_price(token_id.clone(), amount.clone());
 
-        let ticket_price = EgldOrEsdtTokenPayment::new(token_id, 0, amount);
-        self.emit_set_ticket_price_event(ticket_price);
+        if self.is_timelock_enabled() {
+            self.require_valid_ticket_price(&token_id, &amount);
+            let _ = self.queue_pending_change(PendingChangeType::TicketPrice { token_id, amount });
+
+            return;
+        }
+
+        self.apply_ticket_price_change(token_id, amount);
     }
 
-    #[only_owner]
     #[endpoint(setLaunchpadTokensPerWinningTicket)]
     fn set_launchpad_tokens_per_winning_ticket(&self, amount: BigUint) {
+        self.require_config_manager();
         self.require_add_tickets_period();
         require!(
             !self.were_launchpad_tokens_deposited(),
@@ -47,9 +73,436 @@ pub trait SetupModule:
         self.try_set_launchpad_tokens_per_winning_ticket(&amount);
     }
 
-    #[only_owner]
+    /// Replaces the whole schedule of the launch. See `getLaunchPhases` for the expected format.
+    #[endpoint(setLaunchPhases)]
+    fn set_launch_phases(&self, launch_phases: MultiValueEncoded<LaunchPhase>) {
+        self.require_config_manager();
+        self.require_admin_proposals_disabled();
+        self.require_add_tickets_period();
+
+        let launch_phases = launch_phases.to_vec();
+        if self.is_timelock_enabled() {
+            let _ = self.build_timeline_config(&launch_phases);
+            let _ = self.queue_pending_change(PendingChangeType::LaunchPhases { launch_phases });
+
+            return;
+        }
+
+        self.try_set_launch_phases(&launch_phases);
+    }
+
+    /// All the configured rounds are reinterpreted in the new unit,
+    /// so the launch phases should be set in the new unit first
+    #[endpoint(setTimeUnit)]
+    fn set_time_unit(&self, time_unit: TimeUnit) {
+        self.require_config_manager();
+        self.require_add_tickets_period();
+
+        self.time_unit().set(time_unit);
+        require!(
+            self.get_launch_stage() == LaunchStage::AddTickets,
+            "Launch already started in the new time unit"
+        );
+    }
+
     #[endpoint(setConfirmationPeriodStartRound)]
     fn set_confirmation_period_start_round(&self, new_start_round: u64) {
+        self.require_config_manager();
+        self.require_admin_proposals_disabled();
+
+        if self.is_timelock_enabled() {
+            let config = self.configuration().get();
+            self.require_valid_config_timeline_change(
+                config.confirmation_period_start_round,
+                new_start_round,
+            );
+            let _ = self.queue_pending_change(PendingChangeType::ConfirmationPeriodStartRound {
+                new_start_round,
+            });
+
+            return;
+        }
+
+        self.try_set_confirmation_period_start_round(new_start_round);
+    }
+
+    #[endpoint(setWinnerSelectionStartRound)]
+    fn set_winner_selection_start_round(&self, new_start_round: u64) {
+        self.require_config_manager();
+        self.require_admin_proposals_disabled();
+
+        if self.is_timelock_enabled() {
+            let config = self.configuration().get();
+            self.require_valid_config_timeline_change(
+                config.winner_selection_start_round,
+                new_start_round,
+            );
+            let _ = self.queue_pending_change(PendingChangeType::WinnerSelectionStartRound {
+                new_start_round,
+            });
+
+            return;
+        }
+
+        self.try_set_winner_selection_start_round(new_start_round);
+    }
+
+    #[endpoint(setClaimStartRound)]
+    fn set_claim_start_round(&self, new_start_round: u64) {
+        self.require_config_manager();
+        self.require_admin_proposals_disabled();
+
+        if self.is_timelock_enabled() {
+            let config = self.configuration().get();
+            self.require_valid_config_timeline_change(config.claim_start_round, new_start_round);
+            let _ =
+                self.queue_pending_change(PendingChangeType::ClaimStartRound { new_start_round });
+
+            return;
+        }
+
+        self.try_set_claim_start_round(new_start_round);
+    }
+
+    #[endpoint(setClaimDeadlineRound)]
+    fn set_claim_deadline_round(&self, claim_deadline_round: u64) {
+        self.require_config_manager();
+        self.require_before_claim_deadline();
+
+        let current_round = self.get_current_time();
+        require!(
+            claim_deadline_round > current_round,
+            "Claim deadline cannot be in the past"
+        );
+
+        self.claim_deadline_round().set(claim_deadline_round);
+        self.require_valid_time_periods(&self.configuration().get());
+    }
+
+    /// Losing tickets may be refunded from this round until the claim start round,
+    /// once all the winners were selected. Zero disables the refund window.
+    #[endpoint(setRefundWindowStartRound)]
+    fn set_refund_window_start_round(&self, refund_window_start_round: u64) {
+        self.require_config_manager();
+        require!(
+            self.get_launch_stage() < LaunchStage::Refund,
+            "Cannot change after refund period started"
+        );
+
+        let current_round = self.get_current_time();
+        require!(
+            refund_window_start_round == 0 || refund_window_start_round > current_round,
+            "Start round cannot be in the past"
+        );
+
+        self.refund_window_start_round()
+            .set(refund_window_start_round);
+        self.require_valid_time_periods(&self.configuration().get());
+    }
+
+    /// When enabled, the launchpad tokens left over after the claim of the ticket payment
+    /// are burned instead of being sent back to the owner. Requires the local burn role.
+    #[endpoint(setBurnUnsoldLaunchpadTokens)]
+    fn set_burn_unsold_launchpad_tokens(&self, burn_unsold: bool) {
+        self.require_config_manager();
+        require!(
+            self.get_launch_stage() < LaunchStage::Claim,
+            "Cannot change after claim period started"
+        );
+
+        self.burn_unsold_launchpad_tokens().set(burn_unsold);
+    }
+
+    /// When enabled, the configured number of winning tickets is only a maximum.
+    /// If fewer tickets are confirmed, the launchpad tokens deposited for the missing
+    /// winning tickets are sent back to the owner once the tickets are filtered.
+    #[endpoint(setDynamicWinningTickets)]
+    fn set_dynamic_winning_tickets(&self, enabled: bool) {
+        self.require_config_manager();
+        require!(
+            self.get_launch_stage() < LaunchStage::WinnerSelection,
+            "Cannot change after winner selection started"
+        );
+
+        self.dynamic_winning_tickets().set(enabled);
+    }
+
+    /// When enabled, every address that confirmed at least one ticket gets one winning ticket,
+    /// before the remaining winning tickets are drawn randomly
+    #[endpoint(setGuaranteedTicketPerConfirmer)]
+    fn set_guaranteed_ticket_per_confirmer(&self, enabled: bool) {
+        self.require_config_manager();
+        require!(
+            self.get_launch_stage() < LaunchStage::WinnerSelection,
+            "Cannot change after winner selection started"
+        );
+
+        self.guaranteed_ticket_per_confirmer().set(enabled);
+    }
+
+    /// Each address gets exactly the tickets it confirms, up to its allowance,
+    /// as long as the number of winning tickets covers all the allowances.
+    /// Confirmations may no longer be withdrawn. The winner selection stage still runs,
+    /// but `selectWinners` marks the confirmed tickets as winning in ID order, without drawing.
+    #[endpoint(setOrderedSelectionMode)]
+    fn set_ordered_selection_mode(&self, enabled: bool) {
+        self.require_config_manager();
+        require!(
+            self.get_launch_stage() == LaunchStage::AddTickets,
+            "Cannot change after confirmation period started"
+        );
+
+        self.ordered_selection_mode().set(enabled);
+    }
+
+    #[endpoint(setMaxWinningTicketsPerAddress)]
+    fn set_max_winning_tickets_per_address(&self, max_winning_tickets: usize) {
+        self.require_config_manager();
+        require!(
+            self.get_launch_stage() < LaunchStage::WinnerSelection,
+            "Cannot change after winner selection started"
+        );
+
+        self.max_winning_tickets_per_address()
+            .set(max_winning_tickets);
+    }
+
+    /// Confirmations which would push the total payment raised above the cap are rejected.
+    /// Zero removes the cap.
+    #[endpoint(setHardCap)]
+    fn set_hard_cap(&self, hard_cap: BigUint) {
+        self.require_config_manager();
+        require!(
+            self.get_launch_stage() < LaunchStage::WinnerSelection,
+            "Cannot change after winner selection started"
+        );
+
+        self.hard_cap().set(hard_cap);
+    }
+
+    /// Caps the confirmed tickets of every address in the public round,
+    /// regardless of its ticket allocation. Already confirmed tickets above a lowered cap are kept.
+    #[endpoint(setMaxConfirmedTicketsPerAddress)]
+    fn set_max_confirmed_tickets_per_address(&self, max_confirmed_tickets: usize) {
+        self.require_config_manager();
+        require!(
+            self.get_launch_stage() < LaunchStage::WinnerSelection,
+            "Cannot change after winner selection started"
+        );
+
+        self.max_confirmed_tickets_per_address()
+            .set(max_confirmed_tickets);
+    }
+
+    #[endpoint(setClaimOnBehalfGraceRounds)]
+    fn set_claim_on_behalf_grace_rounds(&self, grace_rounds: u64) {
+        self.require_config_manager();
+        require!(
+            self.get_launch_stage() < LaunchStage::Claim,
+            "Cannot change after claim period started"
+        );
+
+        self.claim_on_behalf_grace_rounds().set(grace_rounds);
+    }
+
+    #[endpoint(setDenySmartContractCallers)]
+    fn set_deny_smart_contract_callers(&self, deny: bool) {
+        self.require_config_manager();
+
+        self.deny_smart_contract_callers().set(deny);
+    }
+
+    /// Integrator contracts which may still confirm and claim while smart contract callers are denied
+    #[endpoint(addAllowedSmartContractCallers)]
+    fn add_allowed_smart_contract_callers(&self, addresses: MultiValueEncoded<ManagedAddress>) {
+        self.require_config_manager();
+
+        let allowlist_mapper = self.allowed_smart_contract_callers();
+        for address in addresses {
+            require!(
+                self.blockchain().is_smart_contract(&address),
+                "Address is not a smart contract"
+            );
+
+            allowlist_mapper.add(&address);
+        }
+    }
+
+    #[endpoint(removeAllowedSmartContractCallers)]
+    fn remove_allowed_smart_contract_callers(&self, addresses: MultiValueEncoded<ManagedAddress>) {
+        self.require_config_manager();
+
+        let allowlist_mapper = self.allowed_smart_contract_callers();
+        for address in addresses {
+            allowlist_mapper.remove(&address);
+        }
+    }
+
+    fn execute_pending_change<CustomChangeFn: Fn(&Self, ManagedBuffer)>(
+        &self,
+        change_id: usize,
+        custom_change_fn: CustomChangeFn,
+    ) {
+        self.require_config_manager();
+
+        let change_type = self.take_executable_change(change_id);
+        self.apply_change(change_type, custom_change_fn);
+    }
+
+    /// Changes are validated again when applied,
+    /// as the launch may have progressed since they were requested
+    fn apply_change<CustomChangeFn: Fn(&Self, ManagedBuffer)>(
+        &self,
+        change_type: PendingChangeType<Self::Api>,
+        custom_change_fn: CustomChangeFn,
+    ) {
+        match change_type {
+            PendingChangeType::TicketPrice { token_id, amount } => {
+                self.require_add_tickets_period();
+                self.apply_ticket_price_change(token_id, amount);
+            }
+            PendingChangeType::ConfirmationPeriodStartRound { new_start_round } => {
+                self.try_set_confirmation_period_start_round(new_start_round);
+            }
+            PendingChangeType::WinnerSelectionStartRound { new_start_round } => {
+                self.try_set_winner_selection_start_round(new_start_round);
+            }
+            PendingChangeType::ClaimStartRound { new_start_round } => {
+                self.try_set_claim_start_round(new_start_round);
+            }
+            PendingChangeType::LaunchPhases { launch_phases } => {
+                self.require_add_tickets_period();
+                self.try_set_launch_phases(&launch_phases);
+            }
+            PendingChangeType::TimelockDelay { new_delay } => {
+                self.timelock_delay().set(new_delay);
+            }
+            PendingChangeType::Custom { encoded_data } => {
+                custom_change_fn(self, encoded_data);
+            }
+        }
+    }
+
+    fn default_custom_change_fn(&self, _encoded_data: ManagedBuffer) {
+        sc_panic!("Unsupported pending change");
+    }
+
+    fn apply_ticket_price_change(&self, token_id: EgldOrEsdtTokenIdentifier, amount: BigUint) {
+        self.try_set_ticket_price(token_id.clone(), amount.clone());
+
+        let ticket_price = EgldOrEsdtTokenPayment::new(token_id, 0, amount);
+        self.emit_set_ticket_price_event(ticket_price);
+    }
+
+    fn try_set_launch_phases(&self, launch_phases: &ManagedVec<LaunchPhase>) {
+        let config = self.build_timeline_config(launch_phases);
+
+        let mut public_round_start_round = 0;
+        let mut guaranteed_round_max_confirmed_tickets = 0;
+        let mut max_confirmed_tickets = 0;
+        for launch_phase in launch_phases.iter() {
+            match launch_phase.phase_type {
+                PhaseType::GuaranteedRound => {
+                    guaranteed_round_max_confirmed_tickets =
+                        launch_phase.max_confirmed_tickets_per_address;
+                }
+                PhaseType::PublicRound => {
+                    if launch_phase.start_round != config.confirmation_period_start_round {
+                        public_round_start_round = launch_phase.start_round;
+                    }
+                    max_confirmed_tickets = launch_phase.max_confirmed_tickets_per_address;
+                }
+                _ => {}
+            }
+        }
+
+        self.public_round_start_round()
+            .set(public_round_start_round);
+        self.guaranteed_round_max_confirmed_tickets()
+            .set(guaranteed_round_max_confirmed_tickets);
+        self.max_confirmed_tickets_per_address()
+            .set(max_confirmed_tickets);
+
+        self.require_valid_time_periods(&config);
+        self.configuration().set(config);
+    }
+
+    /// Phases must be given in order, each at most once. The registration phase always starts at
+    /// deploy, so it may be omitted. The guaranteed round is optional, all the other ones are required.
+    fn build_timeline_config(&self, launch_phases: &ManagedVec<LaunchPhase>) -> TimelineConfig {
+        let mut config = TimelineConfig {
+            confirmation_period_start_round: 0,
+            winner_selection_start_round: 0,
+            claim_start_round: 0,
+        };
+        let mut opt_last_phase: Option<LaunchPhase> = None;
+        for launch_phase in launch_phases.iter() {
+            if let Some(last_phase) = &opt_last_phase {
+                require!(
+                    last_phase.phase_type < launch_phase.phase_type,
+                    "Launch phases must be in order"
+                );
+                require!(
+                    last_phase.start_round < launch_phase.start_round
+                        || launch_phase.phase_type == PhaseType::Claim,
+                    "Launch phases must start in order"
+                );
+            }
+
+            match launch_phase.phase_type {
+                PhaseType::Registration => {
+                    require!(
+                        launch_phase.start_round == 0,
+                        "Registration starts at deploy"
+                    );
+                }
+                PhaseType::GuaranteedRound => {
+                    config.confirmation_period_start_round = launch_phase.start_round;
+                }
+                PhaseType::PublicRound => {
+                    let has_guaranteed_round = matches!(
+                        &opt_last_phase,
+                        Some(last_phase) if last_phase.phase_type == PhaseType::GuaranteedRound
+                    );
+                    if !has_guaranteed_round {
+                        config.confirmation_period_start_round = launch_phase.start_round;
+                    }
+                }
+                PhaseType::WinnerSelection => {
+                    config.winner_selection_start_round = launch_phase.start_round;
+                }
+                PhaseType::Claim => {
+                    config.claim_start_round = launch_phase.start_round;
+                }
+            }
+
+            let is_confirmation_round = launch_phase.phase_type == PhaseType::GuaranteedRound
+                || launch_phase.phase_type == PhaseType::PublicRound;
+            require!(
+                is_confirmation_round || launch_phase.max_confirmed_tickets_per_address == 0,
+                "Ticket caps only apply to confirmation rounds"
+            );
+
+            opt_last_phase = Some(launch_phase.clone());
+        }
+
+        // the phases are in order, so a single pass finds all the required ones
+        let mut phase_types = launch_phases
+            .iter()
+            .map(|launch_phase| launch_phase.phase_type);
+        let has_public_round = phase_types.any(|phase_type| phase_type == PhaseType::PublicRound);
+        let has_winner_selection =
+            phase_types.any(|phase_type| phase_type == PhaseType::WinnerSelection);
+        let has_claim = phase_types.any(|phase_type| phase_type == PhaseType::Claim);
+        require!(
+            has_public_round && has_winner_selection && has_claim,
+            "Missing launch phases"
+        );
+
+        config
+    }
+
+    fn try_set_confirmation_period_start_round(&self, new_start_round: u64) {
         self.configuration().update(|config| {
             self.require_valid_config_timeline_change(
                 config.confirmation_period_start_round,
@@ -61,9 +514,7 @@ pub trait SetupModule:
         });
     }
 
-    #[only_owner]
-    #[endpoint(setWinnerSelectionStartRound)]
-    fn set_winner_selection_start_round(&self, new_start_round: u64) {
+    fn try_set_winner_selection_start_round(&self, new_start_round: u64) {
         self.configuration().update(|config| {
             self.require_valid_config_timeline_change(
                 config.winner_selection_start_round,
@@ -75,9 +526,7 @@ pub trait SetupModule:
         });
     }
 
-    #[only_owner]
-    #[endpoint(setClaimStartRound)]
-    fn set_claim_start_round(&self, new_start_round: u64) {
+    fn try_set_claim_start_round(&self, new_start_round: u64) {
         self.configuration().update(|config| {
             self.require_valid_config_timeline_change(config.claim_start_round, new_start_round);
 
@@ -87,13 +536,17 @@ pub trait SetupModule:
     }
 
     fn try_set_ticket_price(&self, token_id: EgldOrEsdtTokenIdentifier, amount: BigUint) {
-        require!(token_id.is_valid(), "Invalid token ID");
-        require!(amount > 0, "Ticket price must be higher than 0");
+        self.require_valid_ticket_price(&token_id, &amount);
 
         self.ticket_price()
             .set(&TokenAmountPair { token_id, amount });
     }
 
+    fn require_valid_ticket_price(&self, token_id: &EgldOrEsdtTokenIdentifier, amount: &BigUint) {
+        require!(token_id.is_valid(), "Invalid token ID");
+        require!(amount > &0, "Ticket price must be higher than 0");
+    }
+
     fn try_set_launchpad_tokens_per_winning_ticket(&self, amount: &BigUint) {
         require!(
             amount > &0,
@@ -113,7 +566,7 @@ pub trait SetupModule:
     }
 
     fn require_valid_config_timeline_change(&self, old_start_round: u64, new_start_round: u64) {
-        let current_round = self.blockchain().get_block_round();
+        let current_round = self.get_current_time();
         require!(
             old_start_round > current_round,
             "Cannot change start round, it's either in progress or passed already"
@@ -133,5 +586,27 @@ pub trait SetupModule:
             config.winner_selection_start_round <= config.claim_start_round,
             "Claim period must be after winner selection"
         );
+
+        let public_round_start_round = self.public_round_start_round().get();
+        require!(
+            public_round_start_round == 0
+                || (config.confirmation_period_start_round < public_round_start_round
+                    && public_round_start_round < config.winner_selection_start_round),
+            "Public round must start during the confirmation period"
+        );
+
+        let claim_deadline_round = self.claim_deadline_round().get();
+        require!(
+            claim_deadline_round == 0 || config.claim_start_round < claim_deadline_round,
+            "Claim deadline must be after claim start round"
+        );
+
+        let refund_window_start_round = self.refund_window_start_round().get();
+        require!(
+            refund_window_start_round == 0
+                || (config.winner_selection_start_round <= refund_window_start_round
+                    && refund_window_start_round < config.claim_start_round),
+            "Refund window must be between winner selection and claim start round"
+        );
     }
 }
@@ -0,0 +1,54 @@
+multiversx_sc::imports!();
+
+const ED25519_PUBLIC_KEY_LEN: usize = 32;
+
+#[multiversx_sc::module]
+pub trait SignatureEligibilityModule:
+    crate::launch_stage::LaunchStageModule
+    + crate::config::ConfigModule
+    + crate::tickets::TicketsModule
+    + crate::ongoing_operation::OngoingOperationModule
+    + crate::common_events::CommonEventsModule
+{
+    /// Ed25519 public key of the operator signing ticket allowances off-chain
+    #[only_owner]
+    #[endpoint(setEligibilitySigner)]
+    fn set_eligibility_signer(&self, public_key: ManagedBuffer) {
+        require!(
+            public_key.len() == ED25519_PUBLIC_KEY_LEN,
+            "Invalid public key"
+        );
+
+        self.eligibility_signer().set(public_key);
+    }
+
+    /// The signature is over the user's address, followed by `max_tickets` as a big endian u64
+    /// and the launchpad's address, which acts as the launch ID.
+    /// Users which already have tickets are not registered again.
+    fn register_signed_eligibility(
+        &self,
+        address: &ManagedAddress,
+        max_tickets: usize,
+        signature: &ManagedBuffer,
+    ) {
+        if !self.ticket_range_for_address(address).is_empty() {
+            return;
+        }
+
+        let signer_mapper = self.eligibility_signer();
+        require!(!signer_mapper.is_empty(), "Eligibility signer not set");
+        require!(max_tickets > 0, "Invalid number of tickets");
+
+        let mut message = address.as_managed_buffer().clone();
+        message.append_bytes(&(max_tickets as u64).to_be_bytes());
+        message.append(self.blockchain().get_sc_address().as_managed_buffer());
+        self.crypto()
+            .verify_ed25519(&signer_mapper.get(), &message, signature);
+
+        self.try_create_tickets(address.clone(), max_tickets);
+    }
+
+    #[view(getEligibilitySigner)]
+    #[storage_mapper("eligibilitySigner")]
+    fn eligibility_signer(&self) -> SingleValueMapper<ManagedBuffer>;
+}
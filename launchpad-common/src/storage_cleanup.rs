@@ -0,0 +1,85 @@
+multiversx_sc::imports!();
+
+use crate::{
+    ongoing_operation::{OngoingOperationType, CONTINUE_OP, STOP_OP},
+    tickets::{TicketBatch, TicketId, TicketRange, TICKET_STATUS_CHUNK_BITS},
+};
+
+#[multiversx_sc::module]
+pub trait StorageCleanupModule:
+    crate::launch_stage::LaunchStageModule
+    + crate::config::ConfigModule
+    + crate::tickets::TicketsModule
+    + crate::ongoing_operation::OngoingOperationModule
+{
+    /// Once the claim deadline has passed and the unclaimed tickets were swept,
+    /// clears the per-ticket entries and the remaining ticket batches.
+    /// The per-user entries were already cleared on claim or by the sweep.
+    /// May have to be called multiple times, until it returns `completed`.
+    #[endpoint(cleanupStorage)]
+    fn cleanup_storage(&self) -> OperationCompletionStatus {
+        self.require_claim_period();
+        require!(
+            self.is_claim_deadline_passed(),
+            "Claim deadline has not passed yet"
+        );
+        require!(
+            !self.storage_cleaned_up().get(),
+            "Storage already cleaned up"
+        );
+
+        let last_ticket_id = self.last_ticket_id().get();
+        let mut ticket_id = self.load_cleanup_storage_operation();
+        let run_result = self.run_while_it_has_gas(|| {
+            if ticket_id > last_ticket_id {
+                return STOP_OP;
+            }
+
+            if (ticket_id + 1) % TICKET_STATUS_CHUNK_BITS == 0 || ticket_id == last_ticket_id {
+                self.ticket_status_chunk(ticket_id / TICKET_STATUS_CHUNK_BITS)
+                    .clear();
+            }
+            self.ticket_pos_to_id(ticket_id).clear();
+
+            let ticket_batch_mapper = self.ticket_batch(ticket_id);
+            if !ticket_batch_mapper.is_empty() {
+                let ticket_batch: TicketBatch<Self::Api> = ticket_batch_mapper.take();
+                self.require_voided_ticket_batch(&ticket_batch.address, ticket_id);
+            }
+
+            ticket_id += 1;
+
+            CONTINUE_OP
+        });
+
+        match run_result {
+            OperationCompletionStatus::InterruptedBeforeOutOfGas => {
+                self.save_progress(&OngoingOperationType::CleanupStorage { ticket_id });
+            }
+            OperationCompletionStatus::Completed => {
+                self.storage_cleaned_up().set(true);
+            }
+        }
+
+        run_result
+    }
+
+    /// Only voided batches are left after the sweep.
+    /// A user whose current ticket range starts at the batch did not claim, nor was swept.
+    fn require_voided_ticket_batch(&self, address: &ManagedAddress, first_ticket_id: TicketId) {
+        let ticket_range_mapper = self.ticket_range_for_address(address);
+        if ticket_range_mapper.is_empty() {
+            return;
+        }
+
+        let ticket_range: TicketRange = ticket_range_mapper.get();
+        require!(
+            ticket_range.first_id != first_ticket_id,
+            "Unclaimed tickets must be swept first"
+        );
+    }
+
+    #[view(wasStorageCleanedUp)]
+    #[storage_mapper("storageCleanedUp")]
+    fn storage_cleaned_up(&self) -> SingleValueMapper<bool>;
+}
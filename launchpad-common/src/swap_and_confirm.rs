@@ -0,0 +1,190 @@
+multiversx_sc::imports!();
+
+use crate::{action_pause::PausableAction, config::TokenAmountPair};
+
+static MULTI_PAIR_SWAP_ENDPOINT: &[u8] = b"multiPairSwap";
+static SWAP_FIXED_OUTPUT_FUNCTION: &[u8] = b"swapTokensFixedOutput";
+
+#[multiversx_sc::module]
+pub trait SwapAndConfirmModule:
+    crate::launch_stage::LaunchStageModule
+    + crate::config::ConfigModule
+    + crate::price_oracle::PriceOracleModule
+    + crate::user_interactions::UserInteractionsModule
+    + crate::permissions::PermissionsModule
+    + crate::action_pause::ActionPauseModule
+    + multiversx_sc_modules::pause::PauseModule
+{
+    /// The xExchange router, which has to be in the same shard as the launchpad
+    #[endpoint(setSwapRouterAddress)]
+    fn set_swap_router_address(&self, router_address: ManagedAddress) {
+        self.require_config_manager();
+        require!(
+            self.blockchain().is_smart_contract(&router_address),
+            "Invalid SC address"
+        );
+
+        self.swap_router_address().set(router_address);
+    }
+
+    /// Each accepted token is swapped through its pair with the ticket payment token
+    #[endpoint(addSwapTokens)]
+    fn add_swap_tokens(
+        &self,
+        tokens: MultiValueEncoded<MultiValue2<TokenIdentifier, ManagedAddress>>,
+    ) {
+        self.require_config_manager();
+
+        for token in tokens {
+            let (token_id, pair_address) = token.into_tuple();
+            require!(token_id.is_valid_esdt_identifier(), "Invalid token ID");
+            require!(
+                self.blockchain().is_smart_contract(&pair_address),
+                "Invalid SC address"
+            );
+
+            self.swap_token_pair(&token_id).set(pair_address);
+        }
+    }
+
+    #[endpoint(removeSwapTokens)]
+    fn remove_swap_tokens(&self, tokens: MultiValueEncoded<TokenIdentifier>) {
+        self.require_config_manager();
+
+        for token_id in tokens {
+            self.swap_token_pair(&token_id).clear();
+        }
+    }
+
+    /// Swaps the payment for the exact price of the tickets, then confirms them in the callback.
+    /// The unused part of the payment is refunded. If the swap fails, or the tickets may no longer
+    /// be confirmed when the swap completes, everything is refunded instead.
+    #[payable("*")]
+    #[endpoint(swapAndConfirmTickets)]
+    fn swap_and_confirm_tickets(&self, nr_tickets_to_confirm: usize) {
+        self.require_not_paused();
+        self.require_action_not_paused(PausableAction::Confirmations);
+        self.require_confirmation_period();
+
+        let caller = self.blockchain().get_caller();
+        self.require_caller_not_denied_contract(&caller);
+
+        let router_mapper = self.swap_router_address();
+        require!(!router_mapper.is_empty(), "Swap router not set");
+
+        let payment = self.call_value().single_esdt();
+        let pair_mapper = self.swap_token_pair(&payment.token_identifier);
+        require!(!pair_mapper.is_empty(), "Token not accepted for swap");
+
+        self.update_usd_ticket_price();
+
+        let ticket_price: TokenAmountPair<Self::Api> = self.ticket_price().get();
+        require!(
+            ticket_price.token_id.is_esdt(),
+            "Ticket payment token must be an ESDT"
+        );
+
+        let total_ticket_price = ticket_price.amount * nr_tickets_to_confirm as u32;
+        let check_result = self.check_tickets_confirmation(
+            &caller,
+            nr_tickets_to_confirm,
+            &ticket_price.token_id,
+            &total_ticket_price,
+        );
+        if let Err(err_msg) = check_result {
+            sc_panic!(err_msg);
+        }
+
+        let mut swap_operations = MultiValueEncoded::new();
+        swap_operations.push(MultiValue4::from((
+            pair_mapper.get(),
+            ManagedBuffer::from(SWAP_FIXED_OUTPUT_FUNCTION),
+            ticket_price.token_id.unwrap_esdt(),
+            total_ticket_price,
+        )));
+
+        self.tx()
+            .to(&router_mapper.get())
+            .raw_call(MULTI_PAIR_SWAP_ENDPOINT)
+            .single_esdt(
+                &payment.token_identifier,
+                payment.token_nonce,
+                &payment.amount,
+            )
+            .argument(&swap_operations)
+            .callback(self.callbacks().swap_and_confirm_callback(
+                caller,
+                nr_tickets_to_confirm,
+                payment,
+            ))
+            .async_call_and_exit();
+    }
+
+    #[callback]
+    fn swap_and_confirm_callback(
+        &self,
+        user: ManagedAddress,
+        nr_tickets_to_confirm: usize,
+        original_payment: EsdtTokenPayment,
+        #[call_result] result: ManagedAsyncCallResult<IgnoreValue>,
+    ) {
+        if let ManagedAsyncCallResult::Err(_) = result {
+            self.send().direct_esdt(
+                &user,
+                &original_payment.token_identifier,
+                original_payment.token_nonce,
+                &original_payment.amount,
+            );
+
+            return;
+        }
+
+        let ticket_price: TokenAmountPair<Self::Api> = self.ticket_price().get();
+        let mut received_amount = BigUint::zero();
+        for payment in self.call_value().all_esdt_transfers().iter() {
+            if ticket_price.token_id == payment.token_identifier {
+                received_amount += &payment.amount;
+            } else {
+                self.send().direct_esdt(
+                    &user,
+                    &payment.token_identifier,
+                    payment.token_nonce,
+                    &payment.amount,
+                );
+            }
+        }
+
+        let total_ticket_price = ticket_price.amount * nr_tickets_to_confirm as u32;
+        let can_confirm = received_amount >= total_ticket_price
+            && self
+                .check_tickets_confirmation(
+                    &user,
+                    nr_tickets_to_confirm,
+                    &ticket_price.token_id,
+                    &total_ticket_price,
+                )
+                .is_ok();
+        if can_confirm {
+            received_amount -= &total_ticket_price;
+            self.record_tickets_confirmation(
+                &user,
+                nr_tickets_to_confirm,
+                ticket_price.token_id.clone(),
+                total_ticket_price,
+            );
+        }
+
+        if received_amount > 0 {
+            self.send()
+                .direct(&user, &ticket_price.token_id, 0, &received_amount);
+        }
+    }
+
+    #[view(getSwapRouterAddress)]
+    #[storage_mapper("swapRouterAddress")]
+    fn swap_router_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[view(getSwapTokenPair)]
+    #[storage_mapper("swapTokenPair")]
+    fn swap_token_pair(&self, token_id: &TokenIdentifier) -> SingleValueMapper<ManagedAddress>;
+}
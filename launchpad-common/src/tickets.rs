@@ -1,17 +1,38 @@
-use crate::config::TokenAmountPair;
+use crate::{config::TokenAmountPair, ongoing_operation::OngoingOperationType};
 
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
-pub const FIRST_TICKET_ID: usize = 1;
+/// Ticket IDs and positions are explicitly 64 bits wide, as `usize` is 32 bits wide on wasm
+pub type TicketId = u64;
+
+pub const FIRST_TICKET_ID: TicketId = 1;
+pub const PACKED_ADDRESS_LEN: usize = 32;
+pub const PACKED_TICKETS_ENTRY_LEN: usize = PACKED_ADDRESS_LEN + 2;
 
 pub type TicketStatus = bool;
 pub const WINNING_TICKET: TicketStatus = true;
+pub const TICKET_STATUS_CHUNK_BITS: TicketId = 64;
+pub const TICKET_POS_SWAPS_FLUSH_SIZE: usize = 32;
+pub const MAX_TICKET_STATUS_BATCH_SIZE: TicketId = 100_000;
 
 #[derive(TopEncode, TopDecode)]
 pub struct TicketRange {
-    pub first_id: usize,
-    pub last_id: usize,
+    pub first_id: TicketId,
+    pub last_id: TicketId,
+}
+
+impl TicketRange {
+    #[inline]
+    pub fn nr_tickets(&self) -> usize {
+        (self.last_id - self.first_id + 1) as usize
+    }
+}
+
+#[derive(ManagedVecItem, Clone)]
+pub struct TicketPosSwap {
+    pub ticket_pos: TicketId,
+    pub ticket_id: TicketId,
 }
 
 #[derive(TopEncode, TopDecode)]
@@ -22,7 +43,11 @@ pub struct TicketBatch<M: ManagedTypeApi> {
 
 #[multiversx_sc::module]
 pub trait TicketsModule:
-    crate::launch_stage::LaunchStageModule + crate::config::ConfigModule
+    crate::launch_stage::LaunchStageModule
+    + crate::config::ConfigModule
+    + crate::ongoing_operation::OngoingOperationModule
+    + crate::common_events::CommonEventsModule
+    + crate::locked_token_payment::LockedTokenPaymentModule
 {
     fn add_tickets(
         &self,
@@ -37,21 +62,263 @@ pub trait TicketsModule:
         }
     }
 
+    /// Each entry is a 32 bytes address, followed by the number of tickets as a big endian u16
+    fn add_tickets_packed(&self, packed_entries: ManagedBuffer) {
+        self.require_add_tickets_period();
+
+        let packed_len = packed_entries.len();
+        require!(
+            packed_len % PACKED_TICKETS_ENTRY_LEN == 0,
+            "Invalid packed tickets length"
+        );
+
+        let mut entry = [0u8; PACKED_TICKETS_ENTRY_LEN];
+        let mut address_bytes = [0u8; PACKED_ADDRESS_LEN];
+        let mut offset = 0;
+        while offset < packed_len {
+            let _ = packed_entries.load_slice(offset, &mut entry);
+            address_bytes.copy_from_slice(&entry[..PACKED_ADDRESS_LEN]);
+            let nr_tickets =
+                u16::from_be_bytes([entry[PACKED_ADDRESS_LEN], entry[PACKED_ADDRESS_LEN + 1]]);
+
+            self.try_create_tickets(ManagedAddress::from(&address_bytes), nr_tickets as usize);
+
+            offset += PACKED_TICKETS_ENTRY_LEN;
+        }
+    }
+
+    /// Chunks have to be sent in order, starting from 0,
+    /// so a chunk that was already processed can't be added a second time
+    fn add_tickets_chunk(&self, chunk_index: usize, packed_entries: ManagedBuffer) {
+        let (nr_processed_chunks, nr_added_users) = self.load_add_tickets_operation();
+        require!(chunk_index == nr_processed_chunks, "Invalid chunk index");
+
+        let nr_chunk_users = packed_entries.len() / PACKED_TICKETS_ENTRY_LEN;
+        self.add_tickets_packed(packed_entries);
+
+        self.save_progress(&OngoingOperationType::AddTickets {
+            nr_processed_chunks: nr_processed_chunks + 1,
+            nr_added_users: nr_added_users + nr_chunk_users,
+        });
+    }
+
+    /// If the user's batch is not the last one, it's voided and moved to the end,
+    /// so each address still has a single ticket range. Voided batches are dropped by filterTickets.
+    #[only_owner]
+    #[endpoint(addTicketsToExistingUser)]
+    fn add_tickets_to_existing_user(&self, address: ManagedAddress, nr_tickets: usize) {
+        self.require_add_tickets_period();
+        self.require_add_tickets_not_finalized();
+        require!(nr_tickets > 0, "Invalid number of tickets");
+
+        let ticket_range_mapper = self.ticket_range_for_address(&address);
+        require!(!ticket_range_mapper.is_empty(), "User has no tickets");
+
+        let ticket_range: TicketRange = ticket_range_mapper.get();
+        let last_ticket_id_mapper = self.last_ticket_id();
+        let last_ticket_id = last_ticket_id_mapper.get();
+        require!(
+            last_ticket_id < TicketId::MAX - nr_tickets as TicketId,
+            "Maximum number of tickets was reached"
+        );
+
+        if ticket_range.last_id == last_ticket_id {
+            self.ticket_batch(ticket_range.first_id)
+                .update(|ticket_batch| ticket_batch.nr_tickets += nr_tickets);
+            ticket_range_mapper.set(TicketRange {
+                first_id: ticket_range.first_id,
+                last_id: ticket_range.last_id + nr_tickets as TicketId,
+            });
+            last_ticket_id_mapper.set(last_ticket_id + nr_tickets as TicketId);
+
+            return;
+        }
+
+        self.void_ticket_batch(&address, &ticket_range);
+
+        let total_tickets = ticket_range.nr_tickets() + nr_tickets;
+        self.try_create_tickets(address, total_tickets);
+    }
+
+    /// If the user's batch is the last one, it is shrunk in place.
+    /// Otherwise, it's voided and the remaining tickets, if any, are moved to the end.
+    #[only_owner]
+    #[endpoint(removeTicketsFromUser)]
+    fn remove_tickets_from_user(&self, address: ManagedAddress, nr_tickets_to_remove: usize) {
+        self.require_add_tickets_period();
+        self.require_add_tickets_not_finalized();
+        require!(nr_tickets_to_remove > 0, "Invalid number of tickets");
+
+        let ticket_range_mapper = self.ticket_range_for_address(&address);
+        require!(!ticket_range_mapper.is_empty(), "User has no tickets");
+
+        let ticket_range: TicketRange = ticket_range_mapper.get();
+        let nr_user_tickets = ticket_range.nr_tickets();
+        require!(
+            nr_tickets_to_remove <= nr_user_tickets,
+            "Trying to remove too many tickets"
+        );
+
+        let remaining_tickets = nr_user_tickets - nr_tickets_to_remove;
+        let last_ticket_id_mapper = self.last_ticket_id();
+        if ticket_range.last_id != last_ticket_id_mapper.get() {
+            self.void_ticket_batch(&address, &ticket_range);
+            if remaining_tickets > 0 {
+                self.try_create_tickets(address, remaining_tickets);
+            }
+
+            return;
+        }
+
+        last_ticket_id_mapper
+            .update(|last_ticket_id| *last_ticket_id -= nr_tickets_to_remove as TicketId);
+        if remaining_tickets > 0 {
+            self.ticket_batch(ticket_range.first_id)
+                .update(|ticket_batch| ticket_batch.nr_tickets = remaining_tickets);
+            ticket_range_mapper.set(TicketRange {
+                first_id: ticket_range.first_id,
+                last_id: ticket_range.last_id - nr_tickets_to_remove as TicketId,
+            });
+
+            return;
+        }
+
+        self.ticket_batch(ticket_range.first_id).clear();
+        ticket_range_mapper.clear();
+
+        let mut batch_first_ticket_ids = self.batch_first_ticket_ids();
+        let nr_batches = batch_first_ticket_ids.len();
+        if nr_batches > 0 && batch_first_ticket_ids.get(nr_batches) == ticket_range.first_id {
+            batch_first_ticket_ids.swap_remove(nr_batches);
+        }
+    }
+
+    /// Locks the ticket allocations. Any chunked addition still in progress is ended.
+    #[only_owner]
+    #[endpoint(finalizeAddTickets)]
+    fn finalize_add_tickets(&self) {
+        self.require_add_tickets_period();
+        self.require_add_tickets_not_finalized();
+
+        let _ = self.load_add_tickets_operation();
+        self.clear_operation();
+        self.add_tickets_finalized().set(true);
+    }
+
+    /// Returns the number of processed chunks and the number of users added through them
+    #[view(getAddTicketsProgress)]
+    fn get_add_tickets_progress(&self) -> MultiValue2<usize, usize> {
+        let ongoing_operation = self.current_ongoing_operation().get();
+        match ongoing_operation {
+            OngoingOperationType::AddTickets {
+                nr_processed_chunks,
+                nr_added_users,
+            } => (nr_processed_chunks, nr_added_users).into(),
+            _ => (0, 0).into(),
+        }
+    }
+
+    /// Returns the operation name, the number of processed and total items,
+    /// and the estimated number of calls needed to complete it,
+    /// based on the average number of items processed per call so far.
+    /// Ticket additions report the processed chunks as total, as the number of chunks is not known,
+    /// and contract specific selections are reported as a single remaining step.
+    #[view(getOngoingOperationStatus)]
+    fn get_ongoing_operation_status(&self) -> MultiValue4<ManagedBuffer, u64, u64, u64> {
+        let last_ticket_id = self.last_ticket_id().get();
+        let (name, processed, total): (&[u8], u64, u64) =
+            match self.current_ongoing_operation().get() {
+                OngoingOperationType::None => (b"None", 0, 0),
+                OngoingOperationType::FilterTickets {
+                    first_ticket_id_in_batch,
+                    ..
+                } => (
+                    b"FilterTickets",
+                    first_ticket_id_in_batch - FIRST_TICKET_ID,
+                    last_ticket_id,
+                ),
+                OngoingOperationType::SelectWinners {
+                    nr_selected_tickets,
+                    ..
+                } => (
+                    b"SelectWinners",
+                    nr_selected_tickets as u64,
+                    self.nr_winning_tickets().get() as u64,
+                ),
+                OngoingOperationType::AdditionalSelection { .. } => (b"AdditionalSelection", 0, 1),
+                OngoingOperationType::SweepUnclaimed { ticket_id } => (
+                    b"SweepUnclaimed",
+                    ticket_id - FIRST_TICKET_ID,
+                    last_ticket_id,
+                ),
+                OngoingOperationType::AddTickets {
+                    nr_processed_chunks,
+                    ..
+                } => (
+                    b"AddTickets",
+                    nr_processed_chunks as u64,
+                    nr_processed_chunks as u64,
+                ),
+                OngoingOperationType::DistributeResults { ticket_id } => (
+                    b"DistributeResults",
+                    ticket_id - FIRST_TICKET_ID,
+                    last_ticket_id,
+                ),
+                OngoingOperationType::CleanupStorage { ticket_id } => (
+                    b"CleanupStorage",
+                    ticket_id - FIRST_TICKET_ID,
+                    last_ticket_id,
+                ),
+                OngoingOperationType::ComputeWinnersHash { chunk_index, .. } => (
+                    b"ComputeWinnersHash",
+                    chunk_index,
+                    last_ticket_id / TICKET_STATUS_CHUNK_BITS + 1,
+                ),
+            };
+
+        let nr_calls = self.operation_nr_calls().get() as u64;
+        let remaining_calls = if total <= processed {
+            0
+        } else if processed == 0 || nr_calls == 0 {
+            1
+        } else {
+            ((total - processed) * nr_calls).div_ceil(processed)
+        };
+
+        (ManagedBuffer::from(name), processed, total, remaining_calls).into()
+    }
+
+    #[inline]
+    fn require_add_tickets_not_finalized(&self) {
+        require!(
+            !self.add_tickets_finalized().get(),
+            "Adding tickets was finalized"
+        );
+    }
+
+    /// Voided batches keep their tickets until filterTickets, as no one can confirm them
+    fn void_ticket_batch(&self, address: &ManagedAddress, ticket_range: &TicketRange) {
+        self.ticket_batch(ticket_range.first_id)
+            .update(|ticket_batch| ticket_batch.address = ManagedAddress::zero());
+        self.ticket_range_for_address(address).clear();
+    }
+
     fn claim_ticket_payment(&self) {
         self.require_claim_period();
 
-        let owner = self.blockchain().get_caller();
+        let owner = self.blockchain().get_owner_address();
 
-        let ticket_payment_mapper = self.claimable_ticket_payment();
-        let claimable_ticket_payment = ticket_payment_mapper.get();
+        let claimable_ticket_payment = self.take_claimable_ticket_payment();
         if claimable_ticket_payment > 0 {
-            ticket_payment_mapper.clear();
-
             let ticket_price: TokenAmountPair<Self::Api> = self.ticket_price().get();
             self.send()
                 .direct(&owner, &ticket_price.token_id, 0, &claimable_ticket_payment);
         }
 
+        if self.is_fcfs_round_pending() {
+            return;
+        }
+
         let launchpad_token_id = self.launchpad_token_id().get();
         let launchpad_tokens_balance = self.blockchain().get_esdt_balance(
             &self.blockchain().get_sc_address(),
@@ -65,9 +332,31 @@ pub trait TicketsModule:
 
         let extra_launchpad_tokens = launchpad_tokens_balance - launchpad_tokens_needed;
         if extra_launchpad_tokens > 0 {
+            self.send_extra_launchpad_tokens(&owner, launchpad_token_id, extra_launchpad_tokens);
+        }
+    }
+
+    fn send_extra_launchpad_tokens(
+        &self,
+        owner: &ManagedAddress,
+        launchpad_token_id: TokenIdentifier,
+        extra_launchpad_tokens: BigUint,
+    ) {
+        if !self.burn_unsold_launchpad_tokens().get() {
             self.send()
-                .direct_esdt(&owner, &launchpad_token_id, 0, &extra_launchpad_tokens);
+                .direct_esdt(owner, &launchpad_token_id, 0, &extra_launchpad_tokens);
+
+            return;
         }
+
+        self.send()
+            .esdt_local_burn(&launchpad_token_id, 0, &extra_launchpad_tokens);
+
+        self.emit_burn_unsold_launchpad_tokens_event(EsdtTokenPayment::new(
+            launchpad_token_id,
+            0,
+            extra_launchpad_tokens,
+        ));
     }
 
     // range is [min, max], both inclusive
@@ -75,7 +364,7 @@ pub trait TicketsModule:
     fn get_ticket_range_for_address(
         &self,
         address: &ManagedAddress,
-    ) -> OptionalValue<MultiValue2<usize, usize>> {
+    ) -> OptionalValue<MultiValue2<TicketId, TicketId>> {
         let ticket_range_mapper = self.ticket_range_for_address(address);
         if ticket_range_mapper.is_empty() {
             return OptionalValue::None;
@@ -93,10 +382,49 @@ pub trait TicketsModule:
         }
 
         let ticket_range: TicketRange = ticket_range_mapper.get();
-        ticket_range.last_id - ticket_range.first_id + 1
+        ticket_range.nr_tickets()
+    }
+
+    /// Statuses of the tickets in `[from_id, to_id]`, packed one bit per ticket:
+    /// bit `i % 8` of byte `i / 8` is set if ticket `from_id + i` is winning
+    #[view(getTicketStatusBatch)]
+    fn get_ticket_status_batch(&self, from_id: TicketId, to_id: TicketId) -> ManagedBuffer {
+        require!(
+            from_id >= FIRST_TICKET_ID && from_id <= to_id,
+            "Invalid ticket range"
+        );
+        require!(
+            to_id - from_id < MAX_TICKET_STATUS_BATCH_SIZE,
+            "Too many tickets requested"
+        );
+
+        let mut packed_statuses = ManagedBuffer::new();
+        let mut current_byte = 0u8;
+        let mut chunk_index = from_id / TICKET_STATUS_CHUNK_BITS;
+        let mut chunk = self.ticket_status_chunk(chunk_index).get();
+        for ticket_id in from_id..=to_id {
+            if ticket_id / TICKET_STATUS_CHUNK_BITS != chunk_index {
+                chunk_index = ticket_id / TICKET_STATUS_CHUNK_BITS;
+                chunk = self.ticket_status_chunk(chunk_index).get();
+            }
+
+            let bit_index = (ticket_id - from_id) % 8;
+            if chunk & (1u64 << (ticket_id % TICKET_STATUS_CHUNK_BITS)) != 0 {
+                current_byte |= 1u8 << bit_index;
+            }
+
+            if bit_index == 7 || ticket_id == to_id {
+                packed_statuses.append_bytes(&[current_byte]);
+                current_byte = 0;
+            }
+        }
+
+        packed_statuses
     }
 
     fn try_create_tickets(&self, buyer: ManagedAddress, nr_tickets: usize) {
+        self.require_add_tickets_not_finalized();
+
         let ticket_range_mapper = self.ticket_range_for_address(&buyer);
         require!(ticket_range_mapper.is_empty(), "Duplicate entry for user");
 
@@ -104,11 +432,11 @@ pub trait TicketsModule:
         let first_ticket_id = last_ticket_id_mapper.get() + 1;
 
         require!(
-            first_ticket_id < usize::MAX - nr_tickets,
+            first_ticket_id < TicketId::MAX - nr_tickets as TicketId,
             "Maximum number of tickets was reached"
         );
 
-        let last_ticket_id = first_ticket_id + nr_tickets - 1;
+        let last_ticket_id = first_ticket_id + nr_tickets as TicketId - 1;
 
         ticket_range_mapper.set(TicketRange {
             first_id: first_ticket_id,
@@ -118,6 +446,7 @@ pub trait TicketsModule:
             address: buyer,
             nr_tickets,
         });
+        let _ = self.batch_first_ticket_ids().push(&first_ticket_id);
         last_ticket_id_mapper.set(last_ticket_id);
     }
 
@@ -128,7 +457,7 @@ pub trait TicketsModule:
         ticket_range_mapper.get()
     }
 
-    fn get_ticket_id_from_pos(&self, ticket_pos: usize) -> usize {
+    fn get_ticket_id_from_pos(&self, ticket_pos: TicketId) -> TicketId {
         let ticket_id = self.ticket_pos_to_id(ticket_pos).get();
         if ticket_id == 0 {
             ticket_pos
@@ -137,20 +466,100 @@ pub trait TicketsModule:
         }
     }
 
+    /// Like `get_ticket_id_from_pos`, also looking through the swaps which were not written yet
+    fn get_buffered_ticket_id_from_pos(
+        &self,
+        ticket_pos: TicketId,
+        pending_swaps: &ManagedVec<TicketPosSwap>,
+    ) -> TicketId {
+        for swap in pending_swaps.iter() {
+            if swap.ticket_pos == ticket_pos {
+                return swap.ticket_id;
+            }
+        }
+
+        self.get_ticket_id_from_pos(ticket_pos)
+    }
+
+    /// Keeps the swap in memory, overwriting any previous swap for the same position.
+    /// The swaps are written to storage once `TICKET_POS_SWAPS_FLUSH_SIZE` positions are pending,
+    /// so each position is written at most once per chunk.
+    fn buffer_ticket_pos_swap(
+        &self,
+        ticket_pos: TicketId,
+        ticket_id: TicketId,
+        pending_swaps: &mut ManagedVec<TicketPosSwap>,
+    ) {
+        let swap = TicketPosSwap {
+            ticket_pos,
+            ticket_id,
+        };
+        for i in 0..pending_swaps.len() {
+            if pending_swaps.get(i).ticket_pos == ticket_pos {
+                let _ = pending_swaps.set(i, swap);
+                return;
+            }
+        }
+
+        pending_swaps.push(swap);
+        if pending_swaps.len() >= TICKET_POS_SWAPS_FLUSH_SIZE {
+            self.flush_ticket_pos_swaps(pending_swaps);
+        }
+    }
+
+    fn flush_ticket_pos_swaps(&self, pending_swaps: &mut ManagedVec<TicketPosSwap>) {
+        for swap in pending_swaps.iter() {
+            self.ticket_pos_to_id(swap.ticket_pos).set(swap.ticket_id);
+        }
+
+        pending_swaps.clear();
+    }
+
     #[inline]
-    fn get_total_tickets(&self) -> usize {
+    fn get_total_tickets(&self) -> TicketId {
         self.last_ticket_id().get()
     }
 
-    #[storage_mapper("ticketStatus")]
-    fn ticket_status(&self, ticket_id: usize) -> SingleValueMapper<TicketStatus>;
+    fn get_ticket_status(&self, ticket_id: TicketId) -> TicketStatus {
+        let chunk = self
+            .ticket_status_chunk(ticket_id / TICKET_STATUS_CHUNK_BITS)
+            .get();
+        let ticket_bit = 1u64 << (ticket_id % TICKET_STATUS_CHUNK_BITS);
+
+        chunk & ticket_bit != 0
+    }
+
+    fn set_ticket_status(&self, ticket_id: TicketId, status: TicketStatus) {
+        let ticket_bit = 1u64 << (ticket_id % TICKET_STATUS_CHUNK_BITS);
+        self.ticket_status_chunk(ticket_id / TICKET_STATUS_CHUNK_BITS)
+            .update(|chunk| {
+                if status == WINNING_TICKET {
+                    *chunk |= ticket_bit;
+                } else {
+                    *chunk &= !ticket_bit;
+                }
+            });
+    }
+
+    #[inline]
+    fn clear_ticket_status(&self, ticket_id: TicketId) {
+        self.set_ticket_status(ticket_id, !WINNING_TICKET);
+    }
+
+    /// The status of each ticket is a single bit, packed in chunks of `TICKET_STATUS_CHUNK_BITS`,
+    /// the chunk at index `i` holding the tickets with IDs in `[i * 64, (i + 1) * 64)`
+    #[storage_mapper("ticketStatusChunk")]
+    fn ticket_status_chunk(&self, chunk_index: TicketId) -> SingleValueMapper<u64>;
 
     #[view(getTotalNumberOfTickets)]
     #[storage_mapper("lastTicketId")]
-    fn last_ticket_id(&self) -> SingleValueMapper<usize>;
+    fn last_ticket_id(&self) -> SingleValueMapper<TicketId>;
 
     #[storage_mapper("ticketBatch")]
-    fn ticket_batch(&self, start_index: usize) -> SingleValueMapper<TicketBatch<Self::Api>>;
+    fn ticket_batch(&self, start_index: TicketId) -> SingleValueMapper<TicketBatch<Self::Api>>;
+
+    #[storage_mapper("batchFirstTicketIds")]
+    fn batch_first_ticket_ids(&self) -> VecMapper<TicketId>;
 
     #[storage_mapper("ticketRangeForAddress")]
     fn ticket_range_for_address(&self, address: &ManagedAddress) -> SingleValueMapper<TicketRange>;
@@ -159,7 +568,23 @@ pub trait TicketsModule:
     #[storage_mapper("nrConfirmedTickets")]
     fn nr_confirmed_tickets(&self, address: &ManagedAddress) -> SingleValueMapper<usize>;
 
+    /// Users who confirmed at least one ticket. They are kept after claiming.
+    #[storage_mapper("confirmedUsers")]
+    fn confirmed_users(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[view(getTotalConfirmedTickets)]
+    #[storage_mapper("totalConfirmedTickets")]
+    fn total_confirmed_tickets(&self) -> SingleValueMapper<usize>;
+
+    #[view(getTotalParticipants)]
+    #[storage_mapper("totalParticipants")]
+    fn total_participants(&self) -> SingleValueMapper<usize>;
+
+    #[view(isAddTicketsFinalized)]
+    #[storage_mapper("addTicketsFinalized")]
+    fn add_tickets_finalized(&self) -> SingleValueMapper<bool>;
+
     // only used during shuffling. Default (0) means ticket pos = ticket ID.
     #[storage_mapper("ticketPosToId")]
-    fn ticket_pos_to_id(&self, ticket_pos: usize) -> SingleValueMapper<usize>;
+    fn ticket_pos_to_id(&self, ticket_pos: TicketId) -> SingleValueMapper<TicketId>;
 }
@@ -0,0 +1,147 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::{
+    launch_stage::Flags,
+    ongoing_operation::OngoingOperationType,
+    tickets::{TicketBatch, TicketId, TicketRange, TicketStatus, FIRST_TICKET_ID, WINNING_TICKET},
+    winner_selection::WinnerSelectionProof,
+};
+
+/// Ticket IDs and positions were encoded as `usize`, which is 32 bits wide on wasm
+pub type LegacyTicketId = u32;
+
+#[derive(TopEncode, TopDecode)]
+pub struct LegacyTicketRange {
+    pub first_id: LegacyTicketId,
+    pub last_id: LegacyTicketId,
+}
+
+#[derive(TopEncode, TopDecode)]
+pub struct LegacyWinnerSelectionProof<M: ManagedTypeApi> {
+    pub seed: ManagedBuffer<M>,
+    pub seed_index: usize,
+    pub last_ticket_position: LegacyTicketId,
+    pub nr_winning_tickets: usize,
+    pub nr_guaranteed_tickets: usize,
+    pub max_winning_tickets_per_address: usize,
+}
+
+#[multiversx_sc::module]
+pub trait TicketsLayoutMigrationModule:
+    crate::launch_stage::LaunchStageModule
+    + crate::tickets::TicketsModule
+    + crate::winner_selection::WinnerSelectionModule
+    + crate::ongoing_operation::OngoingOperationModule
+{
+    /// Moves the tickets of contracts older than `TICKETS_LAYOUT_STORAGE_VERSION` to the current
+    /// layout: the ticket batches, ranges and positions are re-encoded with u64 ticket IDs,
+    /// and the per-ticket statuses are packed in chunks.
+    /// Every ticket is migrated during the upgrade, so its gas limit has to cover all of them.
+    fn migrate_legacy_tickets_layout(&self) {
+        // only the chunked ticket additions kept their encoding
+        let ongoing_operation_mapper = self.current_ongoing_operation();
+        require!(
+            ongoing_operation_mapper.is_empty()
+                || matches!(
+                    ongoing_operation_mapper.get(),
+                    OngoingOperationType::AddTickets { .. }
+                ),
+            "Cannot upgrade during an ongoing operation"
+        );
+
+        // contracts deployed before the batch lists were added have to build them
+        let flags: Flags = self.flags().get();
+        let mut batch_first_ticket_ids = if flags.were_tickets_filtered {
+            self.confirmers_first_ticket_ids()
+        } else {
+            self.batch_first_ticket_ids()
+        };
+        let should_list_batches = batch_first_ticket_ids.is_empty();
+
+        let last_ticket_id = self.last_ticket_id().get();
+        for ticket_id in FIRST_TICKET_ID..=last_ticket_id {
+            let legacy_ticket_id = ticket_id as LegacyTicketId;
+            if self.legacy_ticket_status(legacy_ticket_id).take() == WINNING_TICKET {
+                self.set_ticket_status(ticket_id, WINNING_TICKET);
+            }
+
+            let legacy_ticket_pos_to_id_mapper = self.legacy_ticket_pos_to_id(legacy_ticket_id);
+            if !legacy_ticket_pos_to_id_mapper.is_empty() {
+                let pos_ticket_id = legacy_ticket_pos_to_id_mapper.take();
+                self.ticket_pos_to_id(ticket_id)
+                    .set(pos_ticket_id as TicketId);
+            }
+
+            let legacy_ticket_batch_mapper = self.legacy_ticket_batch(legacy_ticket_id);
+            if legacy_ticket_batch_mapper.is_empty() {
+                continue;
+            }
+
+            // voided batches have no ticket range
+            let ticket_batch = legacy_ticket_batch_mapper.take();
+            if !ticket_batch.address.is_zero() {
+                self.migrate_legacy_ticket_range(&ticket_batch.address);
+            }
+            if should_list_batches {
+                let _ = batch_first_ticket_ids.push(&ticket_id);
+            }
+
+            self.ticket_batch(ticket_id).set(&ticket_batch);
+        }
+
+        self.migrate_legacy_winner_selection_proof();
+    }
+
+    fn migrate_legacy_ticket_range(&self, address: &ManagedAddress) {
+        let legacy_ticket_range: LegacyTicketRange =
+            self.legacy_ticket_range_for_address(address).get();
+        self.ticket_range_for_address(address).set(TicketRange {
+            first_id: legacy_ticket_range.first_id as TicketId,
+            last_id: legacy_ticket_range.last_id as TicketId,
+        });
+    }
+
+    fn migrate_legacy_winner_selection_proof(&self) {
+        let legacy_proof_mapper = self.legacy_winner_selection_proof();
+        if legacy_proof_mapper.is_empty() {
+            return;
+        }
+
+        let legacy_proof = legacy_proof_mapper.get();
+        self.winner_selection_proof().set(WinnerSelectionProof {
+            seed: legacy_proof.seed,
+            seed_index: legacy_proof.seed_index,
+            last_ticket_position: legacy_proof.last_ticket_position as TicketId,
+            nr_winning_tickets: legacy_proof.nr_winning_tickets,
+            nr_guaranteed_tickets: legacy_proof.nr_guaranteed_tickets,
+            max_winning_tickets_per_address: legacy_proof.max_winning_tickets_per_address,
+        });
+    }
+
+    #[storage_mapper("ticketStatus")]
+    fn legacy_ticket_status(&self, ticket_id: LegacyTicketId) -> SingleValueMapper<TicketStatus>;
+
+    #[storage_mapper("ticketBatch")]
+    fn legacy_ticket_batch(
+        &self,
+        start_index: LegacyTicketId,
+    ) -> SingleValueMapper<TicketBatch<Self::Api>>;
+
+    #[storage_mapper("ticketRangeForAddress")]
+    fn legacy_ticket_range_for_address(
+        &self,
+        address: &ManagedAddress,
+    ) -> SingleValueMapper<LegacyTicketRange>;
+
+    #[storage_mapper("ticketPosToId")]
+    fn legacy_ticket_pos_to_id(
+        &self,
+        ticket_pos: LegacyTicketId,
+    ) -> SingleValueMapper<LegacyTicketId>;
+
+    #[storage_mapper("winnerSelectionProof")]
+    fn legacy_winner_selection_proof(
+        &self,
+    ) -> SingleValueMapper<LegacyWinnerSelectionProof<Self::Api>>;
+}
@@ -0,0 +1,155 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::launch_stage::LaunchStage;
+
+pub const MAX_TIER_PERCENTAGE: u64 = 10_000; // 100%
+
+pub type TierId = u32;
+
+/// The price multiplier and lock percentage are in basis points, i.e. 10_000 is 1x and 100%
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct Tier<M: ManagedTypeApi> {
+    pub name: ManagedBuffer<M>,
+    pub max_tickets: usize,
+    pub price_multiplier: u64,
+    pub guaranteed_tickets: usize,
+    pub lock_percentage: u64,
+}
+
+#[multiversx_sc::module]
+pub trait TiersModule:
+    crate::launch_stage::LaunchStageModule
+    + crate::config::ConfigModule
+    + crate::tickets::TicketsModule
+    + crate::permissions::PermissionsModule
+    + crate::action_pause::ActionPauseModule
+{
+    /// Users are given the tier's max tickets when added.
+    /// The max tickets of a tier with assigned users may only be lowered,
+    /// which caps the tickets its users may confirm.
+    #[endpoint(setTier)]
+    fn set_tier(
+        &self,
+        tier_id: TierId,
+        name: ManagedBuffer,
+        max_tickets: usize,
+        price_multiplier: u64,
+        guaranteed_tickets: usize,
+        lock_percentage: u64,
+    ) {
+        self.require_config_manager();
+        require!(
+            self.get_launch_stage() < LaunchStage::WinnerSelection,
+            "Cannot change after confirmation period"
+        );
+        require!(
+            max_tickets > 0 && guaranteed_tickets <= max_tickets,
+            "Invalid tier tickets"
+        );
+        require!(price_multiplier > 0, "Invalid price multiplier");
+        require!(
+            lock_percentage <= MAX_TIER_PERCENTAGE,
+            "Invalid lock percentage"
+        );
+
+        let tier_mapper = self.tier(tier_id);
+        if !tier_mapper.is_empty() && self.tier_nr_users(tier_id).get() > 0 {
+            require!(
+                max_tickets <= tier_mapper.get().max_tickets,
+                "Cannot raise max tickets of an assigned tier"
+            );
+        }
+
+        let _ = self.tier_ids().insert(tier_id);
+        tier_mapper.set(Tier {
+            name,
+            max_tickets,
+            price_multiplier,
+            guaranteed_tickets,
+            lock_percentage,
+        });
+    }
+
+    #[endpoint(removeTier)]
+    fn remove_tier(&self, tier_id: TierId) {
+        self.require_config_manager();
+        require!(self.tier_ids().contains(&tier_id), "Unknown tier");
+        require!(
+            self.tier_nr_users(tier_id).get() == 0,
+            "Tier has assigned users"
+        );
+
+        let _ = self.tier_ids().swap_remove(&tier_id);
+        self.tier(tier_id).clear();
+    }
+
+    /// Each user gets the max tickets of their tier
+    fn add_tickets_with_tiers(
+        &self,
+        address_tier_pairs: MultiValueEncoded<MultiValue2<ManagedAddress, TierId>>,
+    ) {
+        self.require_add_tickets_period();
+
+        for multi_arg in address_tier_pairs {
+            let (buyer, tier_id) = multi_arg.into_tuple();
+            let tier = self.get_tier(tier_id);
+
+            self.try_create_tickets(buyer.clone(), tier.max_tickets);
+            self.user_tier(&buyer).set(tier_id);
+            self.tier_nr_users(tier_id)
+                .update(|nr_users| *nr_users += 1);
+        }
+    }
+
+    /// Users added without a tier are not capped
+    fn get_tier_max_tickets(&self, address: &ManagedAddress) -> usize {
+        let user_tier_mapper = self.user_tier(address);
+        if user_tier_mapper.is_empty() {
+            return usize::MAX;
+        }
+
+        self.tier(user_tier_mapper.get()).get().max_tickets
+    }
+
+    fn get_tier(&self, tier_id: TierId) -> Tier<Self::Api> {
+        let tier_mapper = self.tier(tier_id);
+        require!(!tier_mapper.is_empty(), "Unknown tier");
+
+        tier_mapper.get()
+    }
+
+    #[view(getAllTiers)]
+    fn get_all_tiers(&self) -> MultiValueEncoded<MultiValue2<TierId, Tier<Self::Api>>> {
+        let mut result = MultiValueEncoded::new();
+        for tier_id in self.tier_ids().iter() {
+            result.push((tier_id, self.tier(tier_id).get()).into());
+        }
+
+        result
+    }
+
+    #[view(getUserTierInfo)]
+    fn get_user_tier_info(&self, address: ManagedAddress) -> OptionalValue<Tier<Self::Api>> {
+        let user_tier_mapper = self.user_tier(&address);
+        if user_tier_mapper.is_empty() {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(self.tier(user_tier_mapper.get()).get())
+    }
+
+    #[view(getTier)]
+    #[storage_mapper("tier")]
+    fn tier(&self, tier_id: TierId) -> SingleValueMapper<Tier<Self::Api>>;
+
+    #[storage_mapper("tierIds")]
+    fn tier_ids(&self) -> UnorderedSetMapper<TierId>;
+
+    #[view(getUserTier)]
+    #[storage_mapper("userTier")]
+    fn user_tier(&self, address: &ManagedAddress) -> SingleValueMapper<TierId>;
+
+    #[storage_mapper("tierNrUsers")]
+    fn tier_nr_users(&self, tier_id: TierId) -> SingleValueMapper<usize>;
+}
@@ -0,0 +1,28 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+#[derive(TypeAbi, TopEncode, TopDecode, PartialEq, Clone, Copy, Debug)]
+pub enum TimeUnit {
+    Round,
+    BlockNonce,
+    Epoch,
+    Timestamp,
+}
+
+#[multiversx_sc::module]
+pub trait TimeProviderModule {
+    /// The launch schedule is kept in "rounds", which are compared against the current time
+    /// in the configured unit. Rounds are used by default.
+    fn get_current_time(&self) -> u64 {
+        match self.time_unit().get() {
+            TimeUnit::Round => self.blockchain().get_block_round(),
+            TimeUnit::BlockNonce => self.blockchain().get_block_nonce(),
+            TimeUnit::Epoch => self.blockchain().get_block_epoch(),
+            TimeUnit::Timestamp => self.blockchain().get_block_timestamp(),
+        }
+    }
+
+    #[view(getTimeUnit)]
+    #[storage_mapper("timeUnit")]
+    fn time_unit(&self) -> SingleValueMapper<TimeUnit>;
+}
@@ -0,0 +1,148 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::{launch_stage::LaunchPhase, permissions::Role};
+
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub enum PendingChangeType<M: ManagedTypeApi> {
+    TicketPrice {
+        token_id: EgldOrEsdtTokenIdentifier<M>,
+        amount: BigUint<M>,
+    },
+    ConfirmationPeriodStartRound {
+        new_start_round: u64,
+    },
+    WinnerSelectionStartRound {
+        new_start_round: u64,
+    },
+    ClaimStartRound {
+        new_start_round: u64,
+    },
+    TimelockDelay {
+        new_delay: u64,
+    },
+    Custom {
+        encoded_data: ManagedBuffer<M>,
+    },
+    LaunchPhases {
+        launch_phases: ManagedVec<M, LaunchPhase>,
+    },
+}
+
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct PendingChange<M: ManagedTypeApi> {
+    pub change_type: PendingChangeType<M>,
+    pub queued_round: u64,
+    pub executable_round: u64,
+}
+
+#[multiversx_sc::module]
+pub trait TimelockModule:
+    crate::permissions::PermissionsModule
+    + crate::action_pause::ActionPauseModule
+    + crate::time_provider::TimeProviderModule
+{
+    /// The delay is in the configured time unit. Raising it takes effect immediately.
+    /// Lowering it is queued like any other sensitive change.
+    #[endpoint(setTimelockDelay)]
+    fn set_timelock_delay(&self, new_delay: u64) {
+        self.require_role(Role::Admin);
+
+        let current_delay = self.timelock_delay().get();
+        if new_delay >= current_delay {
+            self.timelock_delay().set(new_delay);
+            return;
+        }
+
+        let _ = self.queue_pending_change(PendingChangeType::TimelockDelay { new_delay });
+    }
+
+    #[endpoint(cancelPendingChange)]
+    fn cancel_pending_change(&self, change_id: usize) {
+        self.require_config_manager();
+        require!(
+            self.pending_change_ids().swap_remove(&change_id),
+            "Pending change not found"
+        );
+
+        self.pending_change(change_id).clear();
+    }
+
+    #[view(getPendingChanges)]
+    fn get_pending_changes(
+        &self,
+    ) -> MultiValueEncoded<MultiValue2<usize, PendingChange<Self::Api>>> {
+        let mut result = MultiValueEncoded::new();
+        for change_id in self.pending_change_ids().iter() {
+            let pending_change = self.pending_change(change_id).get();
+            result.push((change_id, pending_change).into());
+        }
+
+        result
+    }
+
+    #[inline]
+    fn is_timelock_enabled(&self) -> bool {
+        self.timelock_delay().get() > 0
+    }
+
+    /// Once admin proposals are enabled, the changes they cover may not be applied directly
+    fn require_admin_proposals_disabled(&self) {
+        require!(
+            self.admin_proposal_quorum().is_empty(),
+            "Admin proposals enabled, use proposeAdminAction"
+        );
+    }
+
+    fn queue_pending_change(&self, change_type: PendingChangeType<Self::Api>) -> usize {
+        let current_round = self.get_current_time();
+        let executable_round = current_round + self.timelock_delay().get();
+        let change_id = self.last_pending_change_id().update(|id| {
+            *id += 1;
+            *id
+        });
+
+        self.pending_change(change_id).set(PendingChange {
+            change_type,
+            queued_round: current_round,
+            executable_round,
+        });
+        let _ = self.pending_change_ids().insert(change_id);
+
+        change_id
+    }
+
+    fn take_executable_change(&self, change_id: usize) -> PendingChangeType<Self::Api> {
+        require!(
+            self.pending_change_ids().swap_remove(&change_id),
+            "Pending change not found"
+        );
+
+        let pending_change = self.pending_change(change_id).take();
+        let current_round = self.get_current_time();
+        require!(
+            current_round >= pending_change.executable_round,
+            "Timelock has not expired yet"
+        );
+
+        pending_change.change_type
+    }
+
+    #[view(getTimelockDelay)]
+    #[storage_mapper("timelockDelay")]
+    fn timelock_delay(&self) -> SingleValueMapper<u64>;
+
+    #[view(getAdminProposalQuorum)]
+    #[storage_mapper("adminProposalQuorum")]
+    fn admin_proposal_quorum(&self) -> SingleValueMapper<usize>;
+
+    #[storage_mapper("lastPendingChangeId")]
+    fn last_pending_change_id(&self) -> SingleValueMapper<usize>;
+
+    #[storage_mapper("pendingChangeIds")]
+    fn pending_change_ids(&self) -> UnorderedSetMapper<usize>;
+
+    #[view(getPendingChange)]
+    #[storage_mapper("pendingChange")]
+    fn pending_change(&self, change_id: usize) -> SingleValueMapper<PendingChange<Self::Api>>;
+}
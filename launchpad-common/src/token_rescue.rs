@@ -0,0 +1,67 @@
+multiversx_sc::imports!();
+
+use crate::config::TokenAmountPair;
+
+#[multiversx_sc::module]
+pub trait TokenRescueModule:
+    crate::config::ConfigModule
+    + crate::consolation_airdrop::ConsolationAirdropModule
+    + crate::locked_token_payment::LockedTokenPaymentModule
+    + crate::reward_bundle::RewardBundleModule
+    + crate::keeper_incentives::KeeperIncentivesModule
+{
+    /// Sends the whole balance of a token sent to the contract by mistake to the owner.
+    /// The tokens the contract holds for the launch may not be rescued,
+    /// and each variant checks its own ones before calling this.
+    fn rescue_tokens(&self, token_id: EgldOrEsdtTokenIdentifier, token_nonce: u64) {
+        require!(
+            !self.is_launch_token(&token_id),
+            "Cannot rescue the launch tokens"
+        );
+
+        let balance = self.blockchain().get_sc_balance(&token_id, token_nonce);
+        require!(balance > 0, "No tokens to rescue");
+
+        let owner = self.blockchain().get_owner_address();
+        self.send().direct(&owner, &token_id, token_nonce, &balance);
+    }
+
+    fn is_launch_token(&self, token_id: &EgldOrEsdtTokenIdentifier) -> bool {
+        let ticket_price: TokenAmountPair<Self::Api> = self.ticket_price().get();
+        if token_id == &ticket_price.token_id {
+            return true;
+        }
+
+        let keeper_bounty_mapper = self.keeper_bounty();
+        if !keeper_bounty_mapper.is_empty() && token_id == &keeper_bounty_mapper.get().token_id {
+            return true;
+        }
+
+        let esdt_token_id = match token_id.as_esdt_option() {
+            Some(esdt_token_id) => esdt_token_id,
+            None => return false,
+        };
+        if *esdt_token_id == self.launchpad_token_id().get() {
+            return true;
+        }
+
+        let locked_payment_token_mapper = self.locked_payment_token_id();
+        if !locked_payment_token_mapper.is_empty()
+            && *esdt_token_id == locked_payment_token_mapper.get()
+        {
+            return true;
+        }
+
+        let consolation_pool_mapper = self.consolation_pool();
+        if !consolation_pool_mapper.is_empty()
+            && *esdt_token_id == consolation_pool_mapper.get().token_identifier
+        {
+            return true;
+        }
+
+        self.extra_rewards_per_winning_ticket()
+            .get()
+            .iter()
+            .any(|reward| *esdt_token_id == reward.token_identifier)
+    }
+}
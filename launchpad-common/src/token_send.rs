@@ -1,35 +1,105 @@
 multiversx_sc::imports!();
 
-use crate::{common_events, config::TokenAmountPair};
+use crate::{
+    common_events, config::TokenAmountPair, locked_token_payment::LockedTicketsRefund,
+    tickets::TicketId,
+};
+
+/// Both parts of a user's ticket refund, already released from the storage
+pub struct TicketRefund<M: ManagedTypeApi> {
+    pub locked_tickets: LockedTicketsRefund<M>,
+    pub nr_paid_tickets: usize,
+    pub payment: EgldOrEsdtTokenPayment<M>,
+}
 
 #[multiversx_sc::module]
-pub trait TokenSendModule: crate::config::ConfigModule + common_events::CommonEventsModule {
-    fn refund_ticket_payment(&self, address: &ManagedAddress, nr_tickets_to_refund: usize) {
-        if nr_tickets_to_refund == 0 {
+pub trait TokenSendModule:
+    crate::config::ConfigModule
+    + common_events::CommonEventsModule
+    + crate::locked_token_payment::LockedTokenPaymentModule
+    + crate::reward_bundle::RewardBundleModule
+{
+    /// Tickets paid with locked tokens are refunded first, with the locked tokens.
+    /// Only updates the storage. The refund is sent by `send_ticket_refund`,
+    /// once all the other effects of the caller are done.
+    fn release_user_tickets(
+        &self,
+        user: &ManagedAddress,
+        nr_tickets_to_refund: usize,
+    ) -> TicketRefund<Self::Api> {
+        let locked_tickets = self.release_locked_tickets(user, nr_tickets_to_refund);
+        let nr_paid_tickets = nr_tickets_to_refund - locked_tickets.nr_tickets;
+        let ticket_price: TokenAmountPair<Self::Api> = self.ticket_price().get();
+        let payment_amount = ticket_price.amount * nr_paid_tickets as u32;
+        if nr_paid_tickets > 0 {
+            self.release_user_ticket_payment(user, &payment_amount);
+        }
+
+        TicketRefund {
+            locked_tickets,
+            nr_paid_tickets,
+            payment: EgldOrEsdtTokenPayment::new(ticket_price.token_id, 0, payment_amount),
+        }
+    }
+
+    fn send_ticket_refund(&self, receiver: &ManagedAddress, refund: &TicketRefund<Self::Api>) {
+        self.send_locked_tickets_refund(receiver, &refund.locked_tickets);
+        if refund.nr_paid_tickets == 0 {
             return;
         }
 
-        let ticket_price: TokenAmountPair<Self::Api> = self.ticket_price().get();
-        let ticket_payment_refund_amount = ticket_price.amount * nr_tickets_to_refund as u32;
         self.send().direct(
-            address,
-            &ticket_price.token_id,
+            receiver,
+            &refund.payment.token_identifier,
             0,
-            &ticket_payment_refund_amount,
+            &refund.payment.amount,
         );
 
-        self.emit_refund_ticket_payment_event(
-            nr_tickets_to_refund,
-            EgldOrEsdtTokenPayment::new(ticket_price.token_id, 0, ticket_payment_refund_amount),
+        self.emit_refund_ticket_payment_event(refund.nr_paid_tickets, refund.payment.clone());
+    }
+
+    fn hold_user_ticket_payment(&self, address: &ManagedAddress, amount: &BigUint) {
+        self.user_ticket_payment(address)
+            .update(|payment| *payment += amount);
+        self.total_refundable_ticket_payment()
+            .update(|total| *total += amount);
+    }
+
+    /// Refunds may only draw from the user's own payment,
+    /// so they never reach the other users' payments or the owner's share
+    fn release_user_ticket_payment(&self, address: &ManagedAddress, amount: &BigUint) {
+        let user_payment_mapper = self.user_ticket_payment(address);
+        let user_payment = user_payment_mapper.get();
+        require!(
+            amount <= &user_payment,
+            "Refund exceeds the ticket payment held"
         );
+
+        user_payment_mapper.set(user_payment - amount);
+        self.total_refundable_ticket_payment()
+            .update(|total| *total -= amount);
     }
 
+    /// Called once the losing tickets were refunded, so the payment left is the one
+    /// of the winning tickets, which is already part of the claimable ticket payment
+    fn settle_user_ticket_payment(&self, address: &ManagedAddress) {
+        let winning_tickets_payment = self.user_ticket_payment(address).take();
+        if winning_tickets_payment > 0 {
+            self.total_refundable_ticket_payment()
+                .update(|total| *total -= winning_tickets_payment);
+        }
+    }
+
+    /// `send_fn` also receives the ID of the claimed ticket batch,
+    /// for variants which keep per-batch settings.
+    /// The extra rewards are always sent directly.
     fn send_launchpad_tokens<
-        SendLaunchpadTokensFn: Fn(&Self, &ManagedAddress, &EsdtTokenPayment<Self::Api>),
+        SendLaunchpadTokensFn: Fn(&Self, &ManagedAddress, &EsdtTokenPayment<Self::Api>, TicketId),
     >(
         &self,
         address: &ManagedAddress,
         nr_claimed_tickets: usize,
+        ticket_batch_id: TicketId,
         send_fn: SendLaunchpadTokensFn,
     ) {
         if nr_claimed_tickets == 0 {
@@ -42,13 +112,16 @@ pub trait TokenSendModule: crate::config::ConfigModule + common_events::CommonEv
             BigUint::from(nr_claimed_tickets as u32) * tokens_per_winning_ticket;
 
         let payment = EsdtTokenPayment::new(launchpad_token_id, 0, launchpad_tokens_amount_to_send);
-        send_fn(self, address, &payment);
+        send_fn(self, address, &payment, ticket_batch_id);
+
+        self.send_extra_rewards(address, nr_claimed_tickets);
     }
 
     fn default_send_launchpad_tokens_fn(
         &self,
         address: &ManagedAddress,
         payment: &EsdtTokenPayment<Self::Api>,
+        _ticket_batch_id: TicketId,
     ) {
         self.send().direct_esdt(
             address,
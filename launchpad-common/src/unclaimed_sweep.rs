@@ -0,0 +1,144 @@
+multiversx_sc::imports!();
+
+use crate::{
+    config::TokenAmountPair,
+    ongoing_operation::{OngoingOperationType, CONTINUE_OP, STOP_OP},
+    permissions::Role,
+    tickets::{TicketBatch, TicketId, WINNING_TICKET},
+};
+
+#[multiversx_sc::module]
+pub trait UnclaimedSweepModule:
+    crate::launch_stage::LaunchStageModule
+    + crate::config::ConfigModule
+    + crate::blacklist::BlacklistModule
+    + crate::tickets::TicketsModule
+    + crate::ongoing_operation::OngoingOperationModule
+    + crate::token_send::TokenSendModule
+    + crate::permissions::PermissionsModule
+    + crate::common_events::CommonEventsModule
+    + crate::user_interactions::UserInteractionsModule
+    + multiversx_sc_modules::pause::PauseModule
+{
+    #[endpoint(setTreasuryAddress)]
+    fn set_treasury_address(&self, treasury_address: ManagedAddress) {
+        self.require_role(Role::Finance);
+
+        require!(!treasury_address.is_zero(), "Invalid treasury address");
+
+        self.treasury_address().set(&treasury_address);
+    }
+
+    /// Once the claim deadline has passed, the launchpad tokens of users who did not claim
+    /// are returned to the owner, and their ticket payment refunds are sent to the treasury.
+    /// Each call sends the amounts swept so far, and may have to be called multiple times.
+    #[endpoint(sweepUnclaimed)]
+    fn sweep_unclaimed(&self) -> OperationCompletionStatus {
+        self.require_role(Role::Finance);
+        self.require_claim_period();
+        require!(
+            self.is_claim_deadline_passed(),
+            "Claim deadline has not passed yet"
+        );
+
+        let treasury_mapper = self.treasury_address();
+        require!(!treasury_mapper.is_empty(), "Treasury address not set");
+
+        let ticket_price: TokenAmountPair<Self::Api> = self.ticket_price().get();
+        let last_ticket_id = self.last_ticket_id().get();
+        let mut ticket_id = self.load_sweep_unclaimed_operation();
+        let mut nr_swept_winning_tickets = 0;
+        let mut nr_swept_refunded_tickets = 0;
+
+        let run_result = self.run_while_it_has_gas(|| {
+            if ticket_id > last_ticket_id {
+                return STOP_OP;
+            }
+
+            // batches of users who already claimed are cleared
+            let ticket_batch_mapper = self.ticket_batch(ticket_id);
+            if ticket_batch_mapper.is_empty() {
+                ticket_id += 1;
+
+                return CONTINUE_OP;
+            }
+
+            let ticket_batch: TicketBatch<Self::Api> = ticket_batch_mapper.take();
+            let last_id_in_batch = ticket_id + ticket_batch.nr_tickets as TicketId - 1;
+            let mut nr_user_winning_tickets = 0;
+            for user_ticket_id in ticket_id..=last_id_in_batch {
+                if self.get_ticket_status(user_ticket_id) == WINNING_TICKET {
+                    self.clear_ticket_status(user_ticket_id);
+
+                    nr_user_winning_tickets += 1;
+                }
+
+                self.ticket_pos_to_id(user_ticket_id).clear();
+            }
+
+            let address = &ticket_batch.address;
+            let nr_confirmed_tickets = self.nr_confirmed_tickets(address).take();
+            self.ticket_range_for_address(address).clear();
+            self.mark_user_swept(address);
+
+            // locked tokens are sent to the treasury as they are, both for losing and winning tickets
+            let nr_user_refunded_tickets = nr_confirmed_tickets - nr_user_winning_tickets;
+            let nr_locked_tickets_refunded = self.refund_locked_tickets(
+                address,
+                &treasury_mapper.get(),
+                nr_user_refunded_tickets,
+            );
+            self.settle_locked_winning_tickets(address);
+
+            let nr_user_paid_refunded_tickets =
+                nr_user_refunded_tickets - nr_locked_tickets_refunded;
+            self.release_user_ticket_payment(
+                address,
+                &(&ticket_price.amount * nr_user_paid_refunded_tickets as u32),
+            );
+            self.settle_user_ticket_payment(address);
+
+            nr_swept_winning_tickets += nr_user_winning_tickets;
+            nr_swept_refunded_tickets += nr_user_paid_refunded_tickets;
+            ticket_id = last_id_in_batch + 1;
+
+            CONTINUE_OP
+        });
+
+        if run_result == OperationCompletionStatus::InterruptedBeforeOutOfGas {
+            self.save_progress(&OngoingOperationType::SweepUnclaimed { ticket_id });
+        }
+
+        if nr_swept_winning_tickets > 0 {
+            self.nr_winning_tickets()
+                .update(|nr_winning_tickets| *nr_winning_tickets -= nr_swept_winning_tickets);
+        }
+
+        let launchpad_token_id = self.launchpad_token_id().get();
+        let launchpad_tokens_amount =
+            self.launchpad_tokens_per_winning_ticket().get() * nr_swept_winning_tickets as u32;
+        if launchpad_tokens_amount > 0 {
+            let owner = self.blockchain().get_owner_address();
+            self.send()
+                .direct_esdt(&owner, &launchpad_token_id, 0, &launchpad_tokens_amount);
+            self.send_extra_rewards(&owner, nr_swept_winning_tickets);
+        }
+
+        let refunds_amount = ticket_price.amount * nr_swept_refunded_tickets as u32;
+        if refunds_amount > 0 {
+            self.send().direct(
+                &treasury_mapper.get(),
+                &ticket_price.token_id,
+                0,
+                &refunds_amount,
+            );
+        }
+
+        self.emit_sweep_unclaimed_event(
+            EsdtTokenPayment::new(launchpad_token_id, 0, launchpad_tokens_amount),
+            EgldOrEsdtTokenPayment::new(ticket_price.token_id, 0, refunds_amount),
+        );
+
+        run_result
+    }
+}
@@ -1,6 +1,24 @@
 multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
 
-use crate::{config::TokenAmountPair, tickets::WINNING_TICKET};
+use crate::{
+    action_pause::PausableAction,
+    config::TokenAmountPair,
+    launch_stage::LaunchStage,
+    tickets::{TicketId, TicketRange, WINNING_TICKET},
+};
+
+/// Outcome of the user's claim. `Vesting` is only used by the variants
+/// which keep part of the launchpad tokens locked after the claim.
+/// `Swept` users did not claim before the deadline, so they received nothing.
+#[derive(TypeAbi, TopEncode, TopDecode, PartialEq, Clone, Copy, Debug)]
+pub enum ClaimStatus {
+    NotClaimed,
+    ClaimedTokens,
+    RefundedOnly,
+    Vesting,
+    Swept,
+}
 
 #[multiversx_sc::module]
 pub trait UserInteractionsModule:
@@ -10,47 +28,248 @@ pub trait UserInteractionsModule:
     + crate::tickets::TicketsModule
     + crate::token_send::TokenSendModule
     + crate::permissions::PermissionsModule
+    + crate::action_pause::ActionPauseModule
     + crate::common_events::CommonEventsModule
+    + crate::consolation_airdrop::ConsolationAirdropModule
+    + crate::participation_badge::ParticipationBadgeModule
+    + crate::loyalty::LoyaltyModule
+    + crate::referral::ReferralModule
+    + crate::kyc::KycModule
+    + crate::signature_eligibility::SignatureEligibilityModule
+    + crate::relayer::RelayerModule
+    + crate::price_oracle::PriceOracleModule
+    + crate::tiers::TiersModule
     + multiversx_sc_modules::pause::PauseModule
 {
+    /// Without the number of tickets, it is derived from the payment,
+    /// which then must be a multiple of the ticket price.
+    /// The optional referrer is credited a part of the caller's winning tickets payment at claim.
     #[payable("*")]
     #[endpoint(confirmTickets)]
-    fn confirm_tickets(&self, nr_tickets_to_confirm: usize) {
-        self.require_not_paused();
-        let (payment_token, payment_amount) = self.call_value().egld_or_single_fungible_esdt();
+    fn confirm_tickets(
+        &self,
+        opt_nr_tickets_to_confirm: OptionalValue<usize>,
+        opt_referrer: OptionalValue<ManagedAddress>,
+    ) {
+        let caller = self.blockchain().get_caller();
+        self.require_caller_not_denied_contract(&caller);
 
+        let nr_tickets_to_confirm = match opt_nr_tickets_to_confirm {
+            OptionalValue::Some(nr_tickets_to_confirm) => nr_tickets_to_confirm,
+            OptionalValue::None => self.get_nr_tickets_paid(),
+        };
+        self.confirm_tickets_for_address(&caller, nr_tickets_to_confirm, opt_referrer);
+    }
+
+    fn get_nr_tickets_paid(&self) -> usize {
         self.require_confirmation_period();
+        self.update_usd_ticket_price();
+
+        let (_, payment_amount) = self.get_ticket_payment();
+        let ticket_price: TokenAmountPair<Self::Api> = self.ticket_price().get();
+        let nr_tickets = &payment_amount / &ticket_price.amount;
         require!(
-            self.were_launchpad_tokens_deposited(),
-            "Launchpad tokens not deposited yet"
+            nr_tickets > 0 && &nr_tickets * &ticket_price.amount == payment_amount,
+            "Payment is not a multiple of the ticket price"
         );
 
+        match nr_tickets.to_u64() {
+            Some(nr_tickets) => nr_tickets as usize,
+            None => sc_panic!("Trying to confirm too many tickets"),
+        }
+    }
+
+    /// Either EGLD or a single fungible ESDT. Extra transfers are rejected instead of ignored.
+    fn get_ticket_payment(&self) -> (EgldOrEsdtTokenIdentifier, BigUint) {
+        let esdt_payments = self.call_value().all_esdt_transfers();
+        match esdt_payments.len() {
+            0 => (
+                EgldOrEsdtTokenIdentifier::egld(),
+                self.call_value().egld_value().clone_value(),
+            ),
+            1 => {
+                let payment = esdt_payments.get(0);
+                require!(
+                    payment.token_nonce == 0,
+                    "Ticket payment must be a fungible token"
+                );
+
+                (
+                    EgldOrEsdtTokenIdentifier::esdt(payment.token_identifier.clone()),
+                    payment.amount.clone(),
+                )
+            }
+            _ => sc_panic!("Only one payment may be sent"),
+        }
+    }
+
+    /// Confirms all the caller's tickets not confirmed yet.
+    /// The payment must be exactly the price of those tickets.
+    #[payable("*")]
+    #[endpoint(confirmAllTickets)]
+    fn confirm_all_tickets(&self) {
         let caller = self.blockchain().get_caller();
-        require!(
-            !self.is_user_blacklisted(&caller),
-            "You have been put into the blacklist and may not confirm tickets"
-        );
+        self.require_caller_not_denied_contract(&caller);
 
         let total_tickets = self.get_total_number_of_tickets_for_address(&caller);
-        let nr_confirmed = self.nr_confirmed_tickets(&caller).get();
-        let total_confirmed = nr_confirmed + nr_tickets_to_confirm;
+        let nr_confirmed_tickets = self.nr_confirmed_tickets(&caller).get();
         require!(
-            total_confirmed <= total_tickets,
-            "Trying to confirm too many tickets"
+            nr_confirmed_tickets < total_tickets,
+            "No tickets left to confirm"
         );
 
-        let ticket_price: TokenAmountPair<Self::Api> = self.ticket_price().get();
-        let total_ticket_price = ticket_price.amount * nr_tickets_to_confirm as u32;
-        require!(
-            payment_token == ticket_price.token_id,
-            "Wrong payment token used"
+        self.confirm_tickets_for_address(
+            &caller,
+            total_tickets - nr_confirmed_tickets,
+            OptionalValue::None,
+        );
+    }
+
+    /// Pays for the tickets of another address, which remains the one to claim.
+    /// Refunds are sent to the beneficiary, not to the payer.
+    #[payable("*")]
+    #[endpoint(confirmTicketsForUser)]
+    fn confirm_tickets_for_user(&self, beneficiary: ManagedAddress, nr_tickets_to_confirm: usize) {
+        let caller = self.blockchain().get_caller();
+        self.require_caller_not_denied_contract(&caller);
+        require!(caller != beneficiary, "Use confirmTickets instead");
+
+        self.confirm_tickets_for_address(&beneficiary, nr_tickets_to_confirm, OptionalValue::None);
+        self.emit_confirm_tickets_for_user_event(beneficiary, nr_tickets_to_confirm);
+    }
+
+    /// Called by an approved relayer, which pays for the user's tickets.
+    /// The signature is the user's, over the relayed confirmation message.
+    /// Refunds are sent to the user, not to the relayer.
+    #[payable("*")]
+    #[endpoint(confirmTicketsFor)]
+    fn confirm_tickets_for(
+        &self,
+        user: ManagedAddress,
+        nr_tickets_to_confirm: usize,
+        signature: ManagedBuffer,
+        opt_referrer: OptionalValue<ManagedAddress>,
+    ) {
+        self.verify_relayed_confirmation(&user, nr_tickets_to_confirm, &signature);
+        self.confirm_tickets_for_address(&user, nr_tickets_to_confirm, opt_referrer);
+    }
+
+    fn confirm_tickets_for_address(
+        &self,
+        address: &ManagedAddress,
+        nr_tickets_to_confirm: usize,
+        opt_referrer: OptionalValue<ManagedAddress>,
+    ) {
+        self.require_not_paused();
+        self.require_action_not_paused(PausableAction::Confirmations);
+        let (payment_token, payment_amount) = self.get_ticket_payment();
+
+        self.require_confirmation_period();
+        self.update_usd_ticket_price();
+
+        let check_result = self.check_tickets_confirmation(
+            address,
+            nr_tickets_to_confirm,
+            &payment_token,
+            &payment_amount,
+        );
+        if let Err(err_msg) = check_result {
+            sc_panic!(err_msg);
+        }
+
+        self.set_user_referrer(address, opt_referrer);
+        self.record_tickets_confirmation(
+            address,
+            nr_tickets_to_confirm,
+            payment_token,
+            payment_amount,
         );
-        require!(payment_amount == total_ticket_price, "Wrong amount sent");
+    }
 
-        self.nr_confirmed_tickets(&caller).set(total_confirmed);
+    /// Does not fail, so it can also be used where the payment has to be refunded instead,
+    /// e.g. in async call callbacks
+    fn check_tickets_confirmation(
+        &self,
+        address: &ManagedAddress,
+        nr_tickets_to_confirm: usize,
+        payment_token: &EgldOrEsdtTokenIdentifier,
+        payment_amount: &BigUint,
+    ) -> Result<(), &'static str> {
+        if self.is_paused() {
+            return Err("Contract is paused");
+        }
+        if self.get_launch_stage() != LaunchStage::Confirm {
+            return Err("Not in confirmation period");
+        }
+        if !self.were_launchpad_tokens_deposited() {
+            return Err("Launchpad tokens not deposited yet");
+        }
+        if self.is_user_blacklisted(address) {
+            return Err("You have been put into the blacklist and may not confirm tickets");
+        }
+        if !self.has_required_kyc(address) {
+            return Err("KYC not passed");
+        }
+
+        let total_tickets = self.get_total_number_of_tickets_for_address(address);
+        let total_confirmed = self.nr_confirmed_tickets(address).get() + nr_tickets_to_confirm;
+        if total_confirmed > total_tickets {
+            return Err("Trying to confirm too many tickets");
+        }
+
+        let max_confirmed_tickets = self.get_current_max_confirmed_tickets_per_address();
+        if max_confirmed_tickets > 0 && total_confirmed > max_confirmed_tickets {
+            return Err("Confirmed tickets cap per address exceeded");
+        }
+        if total_confirmed > self.get_tier_max_tickets(address) {
+            return Err("Tier max tickets exceeded");
+        }
+
+        let ticket_price: TokenAmountPair<Self::Api> = self.ticket_price().get();
+        if payment_token != &ticket_price.token_id {
+            return Err("Wrong payment token used");
+        }
+        if payment_amount != &(ticket_price.amount * nr_tickets_to_confirm as u32) {
+            return Err("Wrong amount sent");
+        }
+
+        let hard_cap = self.hard_cap().get();
+        if hard_cap > 0 && self.total_payment_raised().get() + payment_amount > hard_cap {
+            return Err("Hard cap exceeded");
+        }
+
+        Ok(())
+    }
+
+    fn record_tickets_confirmation(
+        &self,
+        address: &ManagedAddress,
+        nr_tickets_to_confirm: usize,
+        payment_token: EgldOrEsdtTokenIdentifier,
+        payment_amount: BigUint,
+    ) {
+        let total_tickets = self.get_total_number_of_tickets_for_address(address);
+        let nr_confirmed = self.nr_confirmed_tickets(address).get();
+        let total_confirmed = nr_confirmed + nr_tickets_to_confirm;
+        self.nr_confirmed_tickets(address).set(total_confirmed);
+        if nr_confirmed == 0 && nr_tickets_to_confirm > 0 {
+            self.total_participants().update(|total| *total += 1);
+            let _ = self.confirmed_users().insert(address.clone());
+        }
+        self.total_confirmed_tickets()
+            .update(|total| *total += nr_tickets_to_confirm);
+
+        // locked token payments are not part of the raised amount
+        let ticket_price: TokenAmountPair<Self::Api> = self.ticket_price().get();
+        if payment_token == ticket_price.token_id {
+            self.total_payment_raised()
+                .update(|total| *total += &payment_amount);
+            self.hold_user_ticket_payment(address, &payment_amount);
+        }
 
         let token_payment = EgldOrEsdtTokenPayment::new(payment_token, 0, payment_amount);
         self.emit_confirm_tickets_event(
+            address.clone(),
             nr_tickets_to_confirm,
             total_confirmed,
             total_tickets,
@@ -58,25 +277,229 @@ pub trait UserInteractionsModule:
         );
     }
 
+    /// Same as `confirmTickets`, for users which are not whitelisted on-chain.
+    /// Their ticket allowance is registered from the operator's signature on the first call.
+    #[payable("*")]
+    #[endpoint(confirmTicketsWithSignature)]
+    fn confirm_tickets_with_signature(
+        &self,
+        nr_tickets_to_confirm: usize,
+        max_tickets: usize,
+        signature: ManagedBuffer,
+        opt_referrer: OptionalValue<ManagedAddress>,
+    ) {
+        self.require_not_paused();
+        self.require_confirmation_period();
+
+        let caller = self.blockchain().get_caller();
+        self.register_signed_eligibility(&caller, max_tickets, &signature);
+        self.confirm_tickets(OptionalValue::Some(nr_tickets_to_confirm), opt_referrer);
+    }
+
+    /// Same as `confirmTickets`, paid with locked tokens, possibly with different nonces.
+    /// The payments exceeding the tickets' price are returned.
+    #[payable("*")]
+    #[endpoint(confirmTicketsWithLockedTokens)]
+    fn confirm_tickets_with_locked_tokens(&self, nr_tickets_to_confirm: usize) {
+        self.require_not_paused();
+        self.require_action_not_paused(PausableAction::Confirmations);
+        self.require_confirmation_period();
+
+        let caller = self.blockchain().get_caller();
+        self.require_caller_not_denied_contract(&caller);
+        self.update_usd_ticket_price();
+
+        // the payment itself is checked when stored, the rest as if paid with the ticket price
+        let ticket_price: TokenAmountPair<Self::Api> = self.ticket_price().get();
+        let total_ticket_price = ticket_price.amount * nr_tickets_to_confirm as u32;
+        let check_result = self.check_tickets_confirmation(
+            &caller,
+            nr_tickets_to_confirm,
+            &ticket_price.token_id,
+            &total_ticket_price,
+        );
+        if let Err(err_msg) = check_result {
+            sc_panic!(err_msg);
+        }
+
+        let payments = self.call_value().all_esdt_transfers();
+        let excess_payments =
+            self.store_locked_tokens_payment(&caller, &payments, nr_tickets_to_confirm);
+        if !excess_payments.is_empty() {
+            self.send().direct_multi(&caller, &excess_payments);
+        }
+
+        let locked_token_id = self.locked_payment_token_id().get();
+        let locked_tokens_amount =
+            self.locked_tokens_per_ticket().get() * nr_tickets_to_confirm as u32;
+        self.record_tickets_confirmation(
+            &caller,
+            nr_tickets_to_confirm,
+            EgldOrEsdtTokenIdentifier::esdt(locked_token_id),
+            locked_tokens_amount,
+        );
+    }
+
+    /// Only possible during the confirmation period. The payment for the tickets is refunded.
+    #[endpoint(unconfirmTickets)]
+    fn unconfirm_tickets(&self, nr_tickets_to_unconfirm: usize) {
+        let caller = self.blockchain().get_caller();
+        self.require_caller_not_denied_contract(&caller);
+        self.require_not_paused();
+        self.require_action_not_paused(PausableAction::Confirmations);
+        self.require_confirmation_period();
+        require!(
+            !self.ordered_selection_mode().get(),
+            "Confirmed tickets are final in ordered selection mode"
+        );
+        require!(nr_tickets_to_unconfirm > 0, "Invalid number of tickets");
+
+        let nr_confirmed = self.nr_confirmed_tickets(&caller).get();
+        require!(
+            nr_tickets_to_unconfirm <= nr_confirmed,
+            "Trying to unconfirm too many tickets"
+        );
+
+        let remaining_confirmed = nr_confirmed - nr_tickets_to_unconfirm;
+        self.nr_confirmed_tickets(&caller).set(remaining_confirmed);
+        if remaining_confirmed == 0 {
+            self.total_participants().update(|total| *total -= 1);
+            let _ = self.confirmed_users().swap_remove(&caller);
+        }
+        self.total_confirmed_tickets()
+            .update(|total| *total -= nr_tickets_to_unconfirm);
+
+        let ticket_refund = self.release_user_tickets(&caller, nr_tickets_to_unconfirm);
+        self.total_payment_raised()
+            .update(|total| *total -= &ticket_refund.payment.amount);
+
+        self.send_ticket_refund(&caller, &ticket_refund);
+    }
+
+    /// Only possible during the refund window. The payment for the losing tickets is refunded,
+    /// while the winning tickets are kept for the claim period.
+    #[endpoint(claimRefund)]
+    fn claim_refund(&self) {
+        self.require_not_paused();
+        self.require_action_not_paused(PausableAction::Claims);
+        self.require_refund_period();
+
+        let caller = self.blockchain().get_caller();
+        self.require_caller_not_denied_contract(&caller);
+
+        let ticket_range = self.try_get_ticket_range(&caller);
+        let mut nr_winning_tickets = 0;
+        for ticket_id in ticket_range.first_id..=ticket_range.last_id {
+            if self.get_ticket_status(ticket_id) == WINNING_TICKET {
+                nr_winning_tickets += 1;
+            }
+        }
+
+        let nr_confirmed = self.nr_confirmed_tickets(&caller).get();
+        require!(nr_confirmed > nr_winning_tickets, "No tickets to refund");
+
+        let nr_losing_tickets = nr_confirmed - nr_winning_tickets;
+        self.nr_confirmed_tickets(&caller).set(nr_winning_tickets);
+        self.total_confirmed_tickets()
+            .update(|total| *total -= nr_losing_tickets);
+        self.user_consolation_tickets(&caller)
+            .update(|nr_tickets| *nr_tickets += nr_losing_tickets);
+
+        let ticket_refund = self.release_user_tickets(&caller, nr_losing_tickets);
+        self.total_payment_raised()
+            .update(|total| *total -= &ticket_refund.payment.amount);
+
+        self.send_ticket_refund(&caller, &ticket_refund);
+    }
+
     fn claim_launchpad_tokens<
-        SendLaunchpadTokensFn: Fn(&Self, &ManagedAddress, &EsdtTokenPayment<Self::Api>),
+        SendLaunchpadTokensFn: Fn(&Self, &ManagedAddress, &EsdtTokenPayment<Self::Api>, TicketId),
     >(
         &self,
+        opt_receiver: OptionalValue<ManagedAddress>,
         send_fn: SendLaunchpadTokensFn,
     ) {
+        self.require_action_not_paused(PausableAction::Claims);
         self.require_claim_period();
+        self.require_before_claim_deadline();
 
         let caller = self.blockchain().get_caller();
+        self.require_caller_not_denied_contract(&caller);
         require!(!self.has_user_claimed(&caller), "Already claimed");
 
         let ticket_range = self.try_get_ticket_range(&caller);
-        let nr_confirmed_tickets = self.nr_confirmed_tickets(&caller).get();
+        let receiver = self.get_claim_receiver(&caller, opt_receiver);
+        self.claim_launchpad_tokens_for_address(&caller, &receiver, ticket_range, &send_fn);
+    }
+
+    /// Defaults to the caller. A different receiver is recorded through an event.
+    fn get_claim_receiver(
+        &self,
+        caller: &ManagedAddress,
+        opt_receiver: OptionalValue<ManagedAddress>,
+    ) -> ManagedAddress {
+        match opt_receiver {
+            OptionalValue::Some(receiver) if &receiver != caller => {
+                require!(!receiver.is_zero(), "Invalid receiver address");
+
+                self.emit_claim_to_receiver_event(receiver.clone());
+
+                receiver
+            }
+            _ => caller.clone(),
+        }
+    }
+
+    /// Pushes the launchpad tokens and refunds to users who did not claim yet,
+    /// once the grace period after the claim start round has passed.
+    /// Addresses which already claimed or have no tickets are skipped.
+    fn claim_launchpad_tokens_for<
+        SendLaunchpadTokensFn: Fn(&Self, &ManagedAddress, &EsdtTokenPayment<Self::Api>, TicketId),
+    >(
+        &self,
+        addresses: MultiValueEncoded<ManagedAddress>,
+        send_fn: SendLaunchpadTokensFn,
+    ) {
+        self.require_extended_permissions();
+        self.require_action_not_paused(PausableAction::Claims);
+        self.require_claim_period();
+        self.require_before_claim_deadline();
+
+        let config = self.configuration().get();
+        let grace_rounds = self.claim_on_behalf_grace_rounds().get();
+        let current_round = self.get_current_time();
+        require!(
+            current_round >= config.claim_start_round + grace_rounds,
+            "Claim grace period has not passed yet"
+        );
+
+        for address in addresses {
+            let ticket_range_mapper = self.ticket_range_for_address(&address);
+            if self.has_user_claimed(&address) || ticket_range_mapper.is_empty() {
+                continue;
+            }
+
+            let ticket_range = ticket_range_mapper.get();
+            self.claim_launchpad_tokens_for_address(&address, &address, ticket_range, &send_fn);
+        }
+    }
+
+    fn claim_launchpad_tokens_for_address<
+        SendLaunchpadTokensFn: Fn(&Self, &ManagedAddress, &EsdtTokenPayment<Self::Api>, TicketId),
+    >(
+        &self,
+        address: &ManagedAddress,
+        receiver: &ManagedAddress,
+        ticket_range: TicketRange,
+        send_fn: &SendLaunchpadTokensFn,
+    ) {
+        let nr_confirmed_tickets = self.nr_confirmed_tickets(address).get();
         let mut nr_redeemable_tickets = 0;
 
         for ticket_id in ticket_range.first_id..=ticket_range.last_id {
-            let ticket_status = self.ticket_status(ticket_id).get();
+            let ticket_status = self.get_ticket_status(ticket_id);
             if ticket_status == WINNING_TICKET {
-                self.ticket_status(ticket_id).clear();
+                self.clear_ticket_status(ticket_id);
 
                 nr_redeemable_tickets += 1;
             }
@@ -84,8 +507,8 @@ pub trait UserInteractionsModule:
             self.ticket_pos_to_id(ticket_id).clear();
         }
 
-        self.nr_confirmed_tickets(&caller).clear();
-        self.ticket_range_for_address(&caller).clear();
+        self.nr_confirmed_tickets(address).clear();
+        self.ticket_range_for_address(address).clear();
         self.ticket_batch(ticket_range.first_id).clear();
 
         if nr_redeemable_tickets > 0 {
@@ -93,11 +516,137 @@ pub trait UserInteractionsModule:
                 .update(|nr_winning_tickets| *nr_winning_tickets -= nr_redeemable_tickets);
         }
 
-        self.claim_list().add(&caller);
+        self.mark_user_claimed(address, nr_redeemable_tickets);
 
         let nr_tickets_to_refund = nr_confirmed_tickets - nr_redeemable_tickets;
-        self.refund_ticket_payment(&caller, nr_tickets_to_refund);
-        self.send_launchpad_tokens(&caller, nr_redeemable_tickets, send_fn);
+        let ticket_refund = self.release_user_tickets(address, nr_tickets_to_refund);
+        let locked_winning_payments = self.release_locked_winning_tickets(address);
+        self.settle_user_ticket_payment(address);
+        let nr_consolation_tickets =
+            nr_tickets_to_refund + self.user_consolation_tickets(address).take();
+        self.accrue_loyalty_points(address, nr_confirmed_tickets, nr_redeemable_tickets);
+        self.credit_referral_rewards(address, nr_redeemable_tickets);
+        if nr_redeemable_tickets > 0 {
+            let tokens_per_winning_ticket = self.launchpad_tokens_per_winning_ticket().get();
+            self.total_tokens_claimed()
+                .update(|total| *total += tokens_per_winning_ticket * nr_redeemable_tickets as u32);
+        }
+
+        // the claim is fully recorded before any transfer, so a receiver may not claim again
+        self.send_ticket_refund(receiver, &ticket_refund);
+        self.send_to_treasury(&locked_winning_payments);
+        self.send_consolation_tokens(receiver, nr_consolation_tickets);
+        if nr_confirmed_tickets > 0 {
+            self.send_participation_badge(receiver);
+        }
+        self.send_launchpad_tokens(
+            receiver,
+            nr_redeemable_tickets,
+            ticket_range.first_id,
+            send_fn,
+        );
+    }
+
+    /// Ticket payment refunded at claim for the losing tickets.
+    /// Tickets paid with locked tokens are refunded with the locked tokens, so they are not included.
+    /// Only known once all the winners have been selected, and zero after the user claimed.
+    #[view(getUserRefundableAmount)]
+    fn get_user_refundable_amount(&self, address: ManagedAddress) -> BigUint {
+        let flags = self.flags().get();
+        let all_winners_selected =
+            flags.were_winners_selected && flags.was_additional_step_completed;
+        if !all_winners_selected || self.has_user_claimed(&address) {
+            return BigUint::zero();
+        }
+
+        let ticket_range_mapper = self.ticket_range_for_address(&address);
+        let mut nr_winning_tickets = 0;
+        if !ticket_range_mapper.is_empty() {
+            let ticket_range: TicketRange = ticket_range_mapper.get();
+            for ticket_id in ticket_range.first_id..=ticket_range.last_id {
+                if self.get_ticket_status(ticket_id) == WINNING_TICKET {
+                    nr_winning_tickets += 1;
+                }
+            }
+        }
+
+        let nr_confirmed_tickets = self.nr_confirmed_tickets(&address).get();
+        let nr_losing_tickets = nr_confirmed_tickets.saturating_sub(nr_winning_tickets);
+        let nr_locked_losing_tickets =
+            core::cmp::min(self.user_locked_tickets(&address).get(), nr_losing_tickets);
+        let ticket_price: TokenAmountPair<Self::Api> = self.ticket_price().get();
+
+        ticket_price.amount * (nr_losing_tickets - nr_locked_losing_tickets) as u32
+    }
+
+    /// Page of the users who confirmed tickets, with their number of confirmed tickets,
+    /// which is zero for the users who already claimed.
+    /// `from` is zero-based. The order changes when a user unconfirms all their tickets.
+    #[view(getConfirmedUsers)]
+    fn get_confirmed_users(
+        &self,
+        from: usize,
+        size: usize,
+    ) -> MultiValueEncoded<MultiValue2<ManagedAddress, usize>> {
+        let mut confirmed_users = MultiValueEncoded::new();
+        let confirmed_users_mapper = self.confirmed_users();
+        let end = core::cmp::min(from.saturating_add(size), confirmed_users_mapper.len());
+        for index in from..end {
+            let address = confirmed_users_mapper.get_by_index(index + 1);
+            let nr_confirmed_tickets = self.nr_confirmed_tickets(&address).get();
+            confirmed_users.push((address, nr_confirmed_tickets).into());
+        }
+
+        confirmed_users
+    }
+
+    /// Payment which may still be raised before reaching the hard cap, if there is one
+    #[view(getRemainingRaiseCapacity)]
+    fn get_remaining_raise_capacity(&self) -> OptionalValue<BigUint> {
+        let hard_cap = self.hard_cap().get();
+        if hard_cap == 0 {
+            return OptionalValue::None;
+        }
+
+        let total_payment_raised = self.total_payment_raised().get();
+        if total_payment_raised >= hard_cap {
+            return OptionalValue::Some(BigUint::zero());
+        }
+
+        OptionalValue::Some(hard_cap - total_payment_raised)
+    }
+
+    fn mark_user_claimed(&self, address: &ManagedAddress, nr_redeemable_tickets: usize) {
+        let claim_status = if nr_redeemable_tickets > 0 {
+            ClaimStatus::ClaimedTokens
+        } else {
+            ClaimStatus::RefundedOnly
+        };
+        self.record_claim_status(address, claim_status);
+    }
+
+    /// Swept users are added to the claim list as well, so they may no longer claim
+    fn mark_user_swept(&self, address: &ManagedAddress) {
+        self.record_claim_status(address, ClaimStatus::Swept);
+    }
+
+    fn record_claim_status(&self, address: &ManagedAddress, claim_status: ClaimStatus) {
+        self.claim_list().add(address);
+        self.user_claim_status(address).set(claim_status);
+    }
+
+    /// Only changes the status of the users who claimed
+    fn update_vesting_claim_status(&self, address: &ManagedAddress, has_locked_tokens: bool) {
+        if !self.has_user_claimed(address) {
+            return;
+        }
+
+        let claim_status = if has_locked_tokens {
+            ClaimStatus::Vesting
+        } else {
+            ClaimStatus::ClaimedTokens
+        };
+        self.user_claim_status(address).set(claim_status);
     }
 
     #[view(hasUserClaimedTokens)]
@@ -105,8 +654,32 @@ pub trait UserInteractionsModule:
         self.claim_list().contains(address)
     }
 
+    /// Users who claimed before the status was recorded are reported as `ClaimedTokens`
+    #[view(getUserClaimStatus)]
+    fn get_user_claim_status(&self, address: ManagedAddress) -> ClaimStatus {
+        if !self.has_user_claimed(&address) {
+            return ClaimStatus::NotClaimed;
+        }
+
+        let claim_status_mapper = self.user_claim_status(&address);
+        if claim_status_mapper.is_empty() {
+            return ClaimStatus::ClaimedTokens;
+        }
+
+        claim_status_mapper.get()
+    }
+
     // flags
 
     #[storage_mapper("claimedTokens")]
     fn claim_list(&self) -> WhitelistMapper<Self::Api, ManagedAddress>;
+
+    #[storage_mapper("userClaimStatus")]
+    fn user_claim_status(&self, address: &ManagedAddress) -> SingleValueMapper<ClaimStatus>;
+
+    // statistics
+
+    #[view(getTotalTokensClaimed)]
+    #[storage_mapper("totalTokensClaimed")]
+    fn total_tokens_claimed(&self) -> SingleValueMapper<BigUint>;
 }
@@ -0,0 +1,132 @@
+multiversx_sc::imports!();
+
+use crate::launch_stage::LaunchStage;
+
+#[multiversx_sc::module]
+pub trait WalletMigrationModule:
+    crate::launch_stage::LaunchStageModule
+    + crate::config::ConfigModule
+    + crate::tickets::TicketsModule
+    + crate::ongoing_operation::OngoingOperationModule
+    + crate::blacklist::BlacklistModule
+    + crate::token_send::TokenSendModule
+    + crate::permissions::PermissionsModule
+    + crate::common_events::CommonEventsModule
+{
+    /// Overwrites any previous request of the caller.
+    /// The tickets are only moved once an admin approves the request.
+    #[endpoint(requestWalletMigration)]
+    fn request_wallet_migration(&self, new_address: ManagedAddress) {
+        self.require_before_winner_selection_for_migration();
+
+        let caller = self.blockchain().get_caller();
+        require!(
+            !self.ticket_range_for_address(&caller).is_empty(),
+            "You have no tickets"
+        );
+        require!(
+            !self.is_user_blacklisted(&caller),
+            "You have been put into the blacklist"
+        );
+        self.require_valid_migration_address(&caller, &new_address);
+
+        self.wallet_migration_request(&caller).set(new_address);
+    }
+
+    #[endpoint(cancelWalletMigration)]
+    fn cancel_wallet_migration(&self) {
+        let caller = self.blockchain().get_caller();
+        let request_mapper = self.wallet_migration_request(&caller);
+        require!(!request_mapper.is_empty(), "No wallet migration requested");
+
+        request_mapper.clear();
+    }
+
+    /// Moves the tickets and confirmations. Variant-specific user data is moved by `migrate_fn`.
+    fn approve_wallet_migration<MigrateFn: Fn(&Self, &ManagedAddress, &ManagedAddress)>(
+        &self,
+        old_address: ManagedAddress,
+        migrate_fn: MigrateFn,
+    ) {
+        self.require_extended_permissions();
+        self.require_before_winner_selection_for_migration();
+
+        let request_mapper = self.wallet_migration_request(&old_address);
+        require!(!request_mapper.is_empty(), "No wallet migration requested");
+        require!(
+            !self.is_user_blacklisted(&old_address),
+            "User is blacklisted"
+        );
+
+        let new_address = request_mapper.take();
+        self.require_valid_migration_address(&old_address, &new_address);
+
+        let ticket_range = self.try_get_ticket_range(&old_address);
+        self.ticket_batch(ticket_range.first_id)
+            .update(|ticket_batch| ticket_batch.address = new_address.clone());
+        self.ticket_range_for_address(&old_address).clear();
+        self.ticket_range_for_address(&new_address)
+            .set(ticket_range);
+
+        let nr_confirmed_tickets = self.nr_confirmed_tickets(&old_address).take();
+        if nr_confirmed_tickets > 0 {
+            self.nr_confirmed_tickets(&new_address)
+                .set(nr_confirmed_tickets);
+
+            let mut confirmed_users_mapper = self.confirmed_users();
+            let _ = confirmed_users_mapper.swap_remove(&old_address);
+            let _ = confirmed_users_mapper.insert(new_address.clone());
+        }
+
+        let user_ticket_payment = self.user_ticket_payment(&old_address).take();
+        if user_ticket_payment > 0 {
+            self.user_ticket_payment(&new_address)
+                .set(user_ticket_payment);
+        }
+
+        self.migrate_locked_payments(&old_address, &new_address);
+        migrate_fn(self, &old_address, &new_address);
+
+        self.emit_wallet_migration_event(old_address, new_address);
+    }
+
+    fn default_migrate_wallet_fn(
+        &self,
+        _old_address: &ManagedAddress,
+        _new_address: &ManagedAddress,
+    ) {
+    }
+
+    fn require_valid_migration_address(
+        &self,
+        old_address: &ManagedAddress,
+        new_address: &ManagedAddress,
+    ) {
+        require!(
+            !new_address.is_zero() && new_address != old_address,
+            "Invalid new address"
+        );
+        require!(
+            self.ticket_range_for_address(new_address).is_empty(),
+            "New address already has tickets"
+        );
+        require!(
+            !self.is_user_blacklisted(new_address),
+            "New address is blacklisted"
+        );
+    }
+
+    fn require_before_winner_selection_for_migration(&self) {
+        require!(
+            self.get_launch_stage() < LaunchStage::WinnerSelection,
+            "May only migrate wallets before winner selection"
+        );
+    }
+
+    #[view(getWalletMigrationRequest)]
+    #[storage_mapper("walletMigrationRequest")]
+    fn wallet_migration_request(
+        &self,
+        address: &ManagedAddress,
+    ) -> SingleValueMapper<ManagedAddress>;
+}
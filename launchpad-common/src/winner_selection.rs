@@ -1,12 +1,23 @@
 multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
 
 use crate::{
     launch_stage::Flags,
     ongoing_operation::{OngoingOperationType, CONTINUE_OP, STOP_OP},
-    random::Random,
-    tickets::{TicketBatch, TicketRange, FIRST_TICKET_ID, WINNING_TICKET},
+    tickets::{TicketBatch, TicketId, TicketPosSwap, TicketRange, FIRST_TICKET_ID, WINNING_TICKET},
 };
 
+/// Everything needed to re-run the winner selection off-chain
+#[derive(TypeAbi, TopEncode, TopDecode)]
+pub struct WinnerSelectionProof<M: ManagedTypeApi> {
+    pub seed: ManagedBuffer<M>,
+    pub seed_index: usize,
+    pub last_ticket_position: TicketId,
+    pub nr_winning_tickets: usize,
+    pub nr_guaranteed_tickets: usize,
+    pub max_winning_tickets_per_address: usize,
+}
+
 #[multiversx_sc::module]
 pub trait WinnerSelectionModule:
     crate::launch_stage::LaunchStageModule
@@ -17,6 +28,9 @@ pub trait WinnerSelectionModule:
     + crate::token_send::TokenSendModule
     + crate::permissions::PermissionsModule
     + crate::common_events::CommonEventsModule
+    + crate::commit_reveal::CommitRevealModule
+    + crate::keeper_incentives::KeeperIncentivesModule
+    + crate::governance::GovernanceModule
     + multiversx_sc_modules::pause::PauseModule
 {
     #[endpoint(filterTickets)]
@@ -24,12 +38,15 @@ pub trait WinnerSelectionModule:
         self.require_not_paused();
         self.require_winner_selection_period();
 
+        let gas_at_batch_start = self.blockchain().get_gas_left();
+
         let flags_mapper = self.flags();
         let mut flags: Flags = flags_mapper.get();
         require!(!flags.were_tickets_filtered, "Tickets already filtered");
 
         let last_ticket_id = self.last_ticket_id().get();
         let (mut first_ticket_id_in_batch, mut nr_removed) = self.load_filter_tickets_operation();
+        let mut confirmers_first_ticket_ids = self.confirmers_first_ticket_ids();
 
         if first_ticket_id_in_batch == FIRST_TICKET_ID {
             flags.has_winner_selection_process_started = true;
@@ -46,12 +63,16 @@ pub trait WinnerSelectionModule:
             let nr_tickets_in_batch = ticket_batch.nr_tickets;
 
             let nr_confirmed_tickets = self.nr_confirmed_tickets(address).get();
+            let new_first_id = first_ticket_id_in_batch - nr_removed;
+            if nr_confirmed_tickets > 0 {
+                let _ = confirmers_first_ticket_ids.push(&new_first_id);
+            }
+
             if nr_confirmed_tickets == 0 {
                 self.ticket_range_for_address(address).clear();
                 current_ticket_batch_mapper.clear();
             } else if nr_removed > 0 || nr_confirmed_tickets < nr_tickets_in_batch {
-                let new_first_id = first_ticket_id_in_batch - nr_removed;
-                let new_last_id = new_first_id + nr_confirmed_tickets - 1;
+                let new_last_id = new_first_id + nr_confirmed_tickets as TicketId - 1;
 
                 current_ticket_batch_mapper.clear();
 
@@ -65,8 +86,8 @@ pub trait WinnerSelectionModule:
                 });
             }
 
-            nr_removed += nr_tickets_in_batch - nr_confirmed_tickets;
-            first_ticket_id_in_batch += nr_tickets_in_batch;
+            nr_removed += (nr_tickets_in_batch - nr_confirmed_tickets) as TicketId;
+            first_ticket_id_in_batch += nr_tickets_in_batch as TicketId;
 
             CONTINUE_OP
         });
@@ -83,8 +104,16 @@ pub trait WinnerSelectionModule:
                 // and we end up with less total tickets than winning
                 let new_last_ticket_id = last_ticket_id - nr_removed;
                 let nr_winning_tickets = self.nr_winning_tickets().get();
-                if nr_winning_tickets > new_last_ticket_id {
-                    self.nr_winning_tickets().set(new_last_ticket_id);
+                if nr_winning_tickets as TicketId > new_last_ticket_id {
+                    let nr_remaining_tickets = new_last_ticket_id as usize;
+                    self.nr_winning_tickets().set(nr_remaining_tickets);
+
+                    if self.dynamic_winning_tickets().get() {
+                        self.release_unneeded_launchpad_tokens(
+                            nr_winning_tickets,
+                            nr_remaining_tickets,
+                        );
+                    }
                 }
 
                 self.last_ticket_id().set(new_last_ticket_id);
@@ -95,6 +124,7 @@ pub trait WinnerSelectionModule:
         };
 
         flags_mapper.set(&flags);
+        self.pay_keeper_bounty(gas_at_batch_start);
 
         run_result
     }
@@ -103,9 +133,12 @@ pub trait WinnerSelectionModule:
     fn select_winners(&self) -> OperationCompletionStatus {
         self.require_not_paused();
         self.require_winner_selection_period();
+        self.require_governance_approval();
 
         self.check_caller_owner_or_user();
 
+        let gas_at_batch_start = self.blockchain().get_gas_left();
+
         let flags_mapper = self.flags();
         let mut flags: Flags = flags_mapper.get();
         require!(flags.were_tickets_filtered, "Must filter tickets first");
@@ -114,15 +147,58 @@ pub trait WinnerSelectionModule:
         let nr_winning_tickets = self.nr_winning_tickets().get();
         let last_ticket_position = self.get_total_tickets();
 
-        let (mut rng, mut ticket_position) = self.load_select_winners_operation();
+        // the guarantee only applies if there are enough winning tickets for all confirmers
+        let confirmers_first_ticket_ids = self.confirmers_first_ticket_ids();
+        let nr_guaranteed_tickets = if self.guaranteed_ticket_per_confirmer().get()
+            && confirmers_first_ticket_ids.len() <= nr_winning_tickets
+        {
+            confirmers_first_ticket_ids.len()
+        } else {
+            0
+        };
+
+        let max_winning_tickets_per_address = self.max_winning_tickets_per_address().get();
+        let is_ordered_selection = self.ordered_selection_mode().get();
+        let is_new_selection = self.current_ongoing_operation().is_empty();
+        let (mut rng, mut ticket_position, mut nr_selected_tickets) =
+            self.load_select_winners_operation();
+        if is_new_selection && !is_ordered_selection {
+            rng = self.create_winner_selection_rng();
+            self.winner_selection_proof().set(WinnerSelectionProof {
+                seed: rng.seed.clone(),
+                seed_index: rng.index,
+                last_ticket_position,
+                nr_winning_tickets,
+                nr_guaranteed_tickets,
+                max_winning_tickets_per_address,
+            });
+        }
+        let mut pending_swaps = ManagedVec::new();
         let run_result = self.run_while_it_has_gas(|| {
-            if nr_winning_tickets == 0 {
+            if nr_winning_tickets == 0 || ticket_position > last_ticket_position {
                 return STOP_OP;
             }
 
-            self.shuffle_single_ticket(&mut rng, ticket_position, last_ticket_position);
+            let selected_pos = if is_ordered_selection {
+                ticket_position
+            } else if ticket_position <= nr_guaranteed_tickets as TicketId {
+                // the first ticket of each confirmer was not moved by the previous swaps
+                confirmers_first_ticket_ids.get(ticket_position as usize)
+            } else {
+                rng.next_ticket_id_in_range(ticket_position, last_ticket_position + 1)
+            };
+
+            let is_winning = self.select_ticket_at_position(
+                ticket_position,
+                selected_pos,
+                max_winning_tickets_per_address,
+                &mut pending_swaps,
+            );
+            if is_winning {
+                nr_selected_tickets += 1;
+            }
 
-            if ticket_position == nr_winning_tickets {
+            if nr_selected_tickets == nr_winning_tickets {
                 return STOP_OP;
             }
 
@@ -130,46 +206,181 @@ pub trait WinnerSelectionModule:
 
             CONTINUE_OP
         });
+        self.flush_ticket_pos_swaps(&mut pending_swaps);
 
         match run_result {
             OperationCompletionStatus::InterruptedBeforeOutOfGas => {
                 self.save_progress(&OngoingOperationType::SelectWinners {
                     rng,
                     ticket_position,
+                    nr_selected_tickets,
                 });
             }
             OperationCompletionStatus::Completed => {
                 flags.were_winners_selected = true;
 
+                // tickets left unassigned because of the per address cap are treated as unsold
+                if nr_selected_tickets < nr_winning_tickets {
+                    self.nr_winning_tickets().set(nr_selected_tickets);
+                }
+
                 let ticket_price = self.ticket_price().get();
-                let claimable_ticket_payment = ticket_price.amount * (nr_winning_tickets as u32);
+                let claimable_ticket_payment = ticket_price.amount * (nr_selected_tickets as u32);
                 self.claimable_ticket_payment()
                     .set(&claimable_ticket_payment);
 
-                self.emit_select_winners_completed_event(nr_winning_tickets);
+                self.emit_select_winners_completed_event(nr_selected_tickets);
             }
         };
 
         flags_mapper.set(&flags);
+        self.pay_keeper_bounty(gas_at_batch_start);
 
         run_result
     }
 
-    /// Fisher-Yates algorithm,
-    /// each position i is swapped with a random one in range [i, n]
-    fn shuffle_single_ticket(
+    /// Sends back to the owner the launchpad tokens and extra rewards deposited for the winning
+    /// tickets which can no longer be won, as there were not enough confirmed tickets
+    fn release_unneeded_launchpad_tokens(
         &self,
-        rng: &mut Random<Self::Api>,
-        current_ticket_position: usize,
-        last_ticket_position: usize,
+        configured_winning_tickets: usize,
+        nr_winning_tickets: usize,
     ) {
-        let rand_pos = rng.next_usize_in_range(current_ticket_position, last_ticket_position + 1);
+        let launchpad_token_id = self.launchpad_token_id().get();
+        let amount_per_ticket = self.launchpad_tokens_per_winning_ticket().get();
+        let released_amount =
+            amount_per_ticket * (configured_winning_tickets - nr_winning_tickets) as u32;
+        if released_amount > 0 {
+            self.total_launchpad_tokens_deposited()
+                .update(|deposited| *deposited -= &released_amount);
+
+            let owner = self.blockchain().get_owner_address();
+            self.send()
+                .direct_esdt(&owner, &launchpad_token_id, 0, &released_amount);
+            self.send_extra_rewards(&owner, configured_winning_tickets - nr_winning_tickets);
+        }
+
+        self.emit_winning_tickets_adjusted_event(
+            configured_winning_tickets,
+            nr_winning_tickets,
+            EsdtTokenPayment::new(launchpad_token_id, 0, released_amount),
+        );
+    }
 
-        let winning_ticket_id = self.get_ticket_id_from_pos(rand_pos);
-        self.ticket_status(winning_ticket_id).set(WINNING_TICKET);
+    /// Runs filterTickets, selectWinners and the additional step in order, starting from the
+    /// first one which was not completed. Each step saves its own ongoing operation when
+    /// interrupted, so the current phase is resumed on the next call.
+    fn complete_winner_selection<AdditionalStepFn: Fn(&Self) -> OperationCompletionStatus>(
+        &self,
+        additional_step_fn: AdditionalStepFn,
+    ) -> OperationCompletionStatus {
+        let flags: Flags = self.flags().get();
+        require!(
+            !flags.was_additional_step_completed,
+            "Winner selection already completed"
+        );
+
+        if !flags.were_tickets_filtered {
+            let run_result = self.filter_tickets();
+            if run_result == OperationCompletionStatus::InterruptedBeforeOutOfGas {
+                return run_result;
+            }
+        }
 
-        let current_ticket_id = self.get_ticket_id_from_pos(current_ticket_position);
-        self.ticket_pos_to_id(rand_pos).set(current_ticket_id);
+        if !flags.were_winners_selected {
+            let run_result = self.select_winners();
+            if run_result == OperationCompletionStatus::InterruptedBeforeOutOfGas {
+                return run_result;
+            }
+        }
+
+        additional_step_fn(self)
+    }
+
+    /// Fisher-Yates algorithm,
+    /// each position i is swapped with a random one in range [i, n].
+    /// Tickets of addresses that already reached the cap are moved out of the draw without winning.
+    /// The position swaps are buffered, and have to be flushed before the end of the transaction.
+    fn select_ticket_at_position(
+        &self,
+        current_ticket_position: TicketId,
+        selected_pos: TicketId,
+        max_winning_tickets_per_address: usize,
+        pending_swaps: &mut ManagedVec<TicketPosSwap>,
+    ) -> bool {
+        let selected_ticket_id = self.get_buffered_ticket_id_from_pos(selected_pos, pending_swaps);
+        let is_winning = max_winning_tickets_per_address == 0
+            || self.try_count_winning_ticket(selected_ticket_id, max_winning_tickets_per_address);
+        if is_winning {
+            self.set_ticket_status(selected_ticket_id, WINNING_TICKET);
+        }
+
+        let current_ticket_id =
+            self.get_buffered_ticket_id_from_pos(current_ticket_position, pending_swaps);
+        self.buffer_ticket_pos_swap(selected_pos, current_ticket_id, pending_swaps);
+
+        is_winning
+    }
+
+    fn try_count_winning_ticket(
+        &self,
+        ticket_id: TicketId,
+        max_winning_tickets_per_address: usize,
+    ) -> bool {
+        let owner = self.get_ticket_owner(ticket_id);
+        let selected_winning_tickets_mapper = self.selected_winning_tickets(&owner);
+        let selected_winning_tickets = selected_winning_tickets_mapper.get();
+        if selected_winning_tickets >= max_winning_tickets_per_address {
+            return false;
+        }
+
+        selected_winning_tickets_mapper.set(selected_winning_tickets + 1);
+
+        true
+    }
+
+    #[view(getTicketOwner)]
+    fn get_ticket_owner_view(&self, ticket_id: TicketId) -> ManagedAddress {
+        let flags: Flags = self.flags().get();
+        require!(
+            !flags.has_winner_selection_process_started || flags.were_tickets_filtered,
+            "Tickets are being filtered"
+        );
+        require!(
+            (FIRST_TICKET_ID..=self.last_ticket_id().get()).contains(&ticket_id),
+            "Invalid ticket ID"
+        );
+
+        self.get_ticket_owner(ticket_id)
+    }
+
+    /// Binary search for the batch containing the ticket, by the first ticket ID of each batch.
+    /// Ticket IDs change when filtering, so the batches of confirmers are used afterwards.
+    fn get_ticket_owner(&self, ticket_id: TicketId) -> ManagedAddress {
+        let flags: Flags = self.flags().get();
+        let batch_first_ticket_ids = if flags.were_tickets_filtered {
+            self.confirmers_first_ticket_ids()
+        } else {
+            self.batch_first_ticket_ids()
+        };
+        let mut low = 1;
+        let mut high = batch_first_ticket_ids.len();
+        while low < high {
+            let mid = (low + high + 1) / 2;
+            if batch_first_ticket_ids.get(mid) <= ticket_id {
+                low = mid;
+            } else {
+                high = mid - 1;
+            }
+        }
+
+        let first_ticket_id = batch_first_ticket_ids.get(low);
+        let ticket_batch_mapper = self.ticket_batch(first_ticket_id);
+        require!(!ticket_batch_mapper.is_empty(), "Ticket owner not found");
+
+        let ticket_batch: TicketBatch<Self::Api> = ticket_batch_mapper.get();
+
+        ticket_batch.address
     }
 
     #[view(getNumberOfWinningTicketsForAddress)]
@@ -181,7 +392,7 @@ pub trait WinnerSelectionModule:
     fn get_winning_ticket_ids_for_address(
         &self,
         address: ManagedAddress,
-    ) -> MultiValueEncoded<usize> {
+    ) -> MultiValueEncoded<TicketId> {
         let flags: Flags = self.flags().get();
         let ticket_range_mapper = self.ticket_range_for_address(&address);
         let mut ticket_ids = MultiValueEncoded::new();
@@ -191,7 +402,7 @@ pub trait WinnerSelectionModule:
 
         let ticket_range: TicketRange = ticket_range_mapper.get();
         for ticket_id in ticket_range.first_id..=ticket_range.last_id {
-            let actual_ticket_status = self.ticket_status(ticket_id).get();
+            let actual_ticket_status = self.get_ticket_status(ticket_id);
             if actual_ticket_status == WINNING_TICKET {
                 ticket_ids.push(ticket_id);
             }
@@ -200,6 +411,63 @@ pub trait WinnerSelectionModule:
         ticket_ids
     }
 
+    /// Returns the winning ticket IDs in the [from, from + size) ticket ID range
+    #[view(getWinningTickets)]
+    fn get_winning_tickets(&self, from: TicketId, size: usize) -> MultiValueEncoded<TicketId> {
+        let mut ticket_ids = MultiValueEncoded::new();
+        let flags: Flags = self.flags().get();
+        if !flags.were_winners_selected {
+            return ticket_ids;
+        }
+
+        let last_ticket_id = self.last_ticket_id().get();
+        let first_id = core::cmp::max(from, FIRST_TICKET_ID);
+        let last_id = core::cmp::min(from.saturating_add(size as TicketId), last_ticket_id + 1);
+        for ticket_id in first_id..last_id {
+            if self.get_ticket_status(ticket_id) == WINNING_TICKET {
+                ticket_ids.push(ticket_id);
+            }
+        }
+
+        ticket_ids
+    }
+
+    /// Returns the addresses with at least one winning ticket,
+    /// whose first ticket ID is in the [from, from + size) range
+    #[view(getWinnersAddresses)]
+    fn get_winners_addresses(
+        &self,
+        from: TicketId,
+        size: usize,
+    ) -> MultiValueEncoded<ManagedAddress> {
+        let mut addresses = MultiValueEncoded::new();
+        let flags: Flags = self.flags().get();
+        if !flags.were_winners_selected {
+            return addresses;
+        }
+
+        let last_ticket_id = self.last_ticket_id().get();
+        let first_id = core::cmp::max(from, FIRST_TICKET_ID);
+        let last_id = core::cmp::min(from.saturating_add(size as TicketId), last_ticket_id + 1);
+        for ticket_id in first_id..last_id {
+            let ticket_batch_mapper = self.ticket_batch(ticket_id);
+            if ticket_batch_mapper.is_empty() {
+                continue;
+            }
+
+            let ticket_batch: TicketBatch<Self::Api> = ticket_batch_mapper.get();
+            let last_id_in_batch = ticket_id + ticket_batch.nr_tickets as TicketId - 1;
+            for user_ticket_id in ticket_id..=last_id_in_batch {
+                if self.get_ticket_status(user_ticket_id) == WINNING_TICKET {
+                    addresses.push(ticket_batch.address);
+                    break;
+                }
+            }
+        }
+
+        addresses
+    }
+
     fn check_caller_owner_or_user(&self) {
         if self.blockchain().get_owner_address() == self.blockchain().get_caller() {
             return;
@@ -207,4 +475,14 @@ pub trait WinnerSelectionModule:
 
         self.blockchain().check_caller_is_user_account();
     }
+
+    #[storage_mapper("confirmersFirstTicketIds")]
+    fn confirmers_first_ticket_ids(&self) -> VecMapper<TicketId>;
+
+    #[storage_mapper("selectedWinningTickets")]
+    fn selected_winning_tickets(&self, address: &ManagedAddress) -> SingleValueMapper<usize>;
+
+    #[view(getWinnerSelectionProof)]
+    #[storage_mapper("winnerSelectionProof")]
+    fn winner_selection_proof(&self) -> SingleValueMapper<WinnerSelectionProof<Self::Api>>;
 }
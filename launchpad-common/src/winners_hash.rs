@@ -0,0 +1,75 @@
+multiversx_sc::imports!();
+
+use crate::{
+    launch_stage::LaunchStage,
+    ongoing_operation::{OngoingOperationType, CONTINUE_OP, STOP_OP},
+    random::Hash,
+    tickets::{TicketId, TICKET_STATUS_CHUNK_BITS},
+};
+
+#[multiversx_sc::module]
+pub trait WinnersHashModule:
+    crate::launch_stage::LaunchStageModule
+    + crate::config::ConfigModule
+    + crate::tickets::TicketsModule
+    + crate::ongoing_operation::OngoingOperationModule
+{
+    /// Commits to the selection result, before any claim changes the ticket statuses.
+    /// Starting from 32 zero bytes, each winning ticket ID, in ascending order,
+    /// is hashed as `hash = sha256(hash || ticket_id)`, with the ID as 8 big-endian bytes.
+    /// May be called by anyone, multiple times, until it returns `completed`.
+    #[endpoint(computeWinnersHash)]
+    fn compute_winners_hash(&self) -> OperationCompletionStatus {
+        let flags = self.flags().get();
+        require!(
+            flags.were_winners_selected && flags.was_additional_step_completed,
+            "Winners not selected yet"
+        );
+        require!(
+            self.get_launch_stage() < LaunchStage::Claim,
+            "Claim period has started"
+        );
+        require!(
+            self.winners_hash().is_empty(),
+            "Winners hash already computed"
+        );
+
+        let last_chunk_index = self.last_ticket_id().get() / TICKET_STATUS_CHUNK_BITS;
+        let (mut chunk_index, mut hash) = self.load_compute_winners_hash_operation();
+        let run_result = self.run_while_it_has_gas(|| {
+            if chunk_index > last_chunk_index {
+                return STOP_OP;
+            }
+
+            let mut chunk = self.ticket_status_chunk(chunk_index).get();
+            while chunk != 0 {
+                let ticket_bit = chunk.trailing_zeros() as TicketId;
+                chunk &= chunk - 1;
+
+                let ticket_id = chunk_index * TICKET_STATUS_CHUNK_BITS + ticket_bit;
+                let mut hash_input = hash.as_managed_buffer().clone();
+                hash_input.append_bytes(&ticket_id.to_be_bytes());
+                hash = self.crypto().sha256(&hash_input);
+            }
+
+            chunk_index += 1;
+
+            CONTINUE_OP
+        });
+
+        match run_result {
+            OperationCompletionStatus::InterruptedBeforeOutOfGas => {
+                self.save_progress(&OngoingOperationType::ComputeWinnersHash { chunk_index, hash });
+            }
+            OperationCompletionStatus::Completed => {
+                self.winners_hash().set(hash);
+            }
+        }
+
+        run_result
+    }
+
+    #[view(getWinnersHash)]
+    #[storage_mapper("winnersHash")]
+    fn winners_hash(&self) -> SingleValueMapper<Hash<Self::Api>>;
+}
@@ -0,0 +1,88 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+#[derive(TypeAbi, TopEncode, TopDecode, PartialEq, Debug)]
+pub struct AuctionConfig<M: ManagedTypeApi> {
+    pub start_price: BigUint<M>,
+    pub floor_price: BigUint<M>,
+    pub start_round: u64,
+    pub end_round: u64,
+}
+
+#[multiversx_sc::module]
+pub trait AuctionConfigModule {
+    /// The price declines linearly, from the start price at the start round,
+    /// to the floor price at the end round
+    #[view(getCurrentPrice)]
+    fn get_current_price(&self) -> BigUint {
+        let config = self.auction_config().get();
+        let current_round = self.blockchain().get_block_round();
+        if current_round <= config.start_round {
+            return config.start_price;
+        }
+        if current_round >= config.end_round {
+            return config.floor_price;
+        }
+
+        let price_range = &config.start_price - &config.floor_price;
+        let elapsed_rounds = current_round - config.start_round;
+        let auction_rounds = config.end_round - config.start_round;
+        let price_decrease = price_range * elapsed_rounds / auction_rounds;
+
+        config.start_price - price_decrease
+    }
+
+    fn is_auction_active(&self) -> bool {
+        let config = self.auction_config().get();
+        let current_round = self.blockchain().get_block_round();
+
+        current_round >= config.start_round
+            && current_round < config.end_round
+            && !self.is_sold_out()
+    }
+
+    #[view(isAuctionEnded)]
+    fn is_auction_ended(&self) -> bool {
+        let config = self.auction_config().get();
+        let current_round = self.blockchain().get_block_round();
+
+        current_round >= config.end_round
+            || (current_round >= config.start_round && self.is_sold_out())
+    }
+
+    #[view(isSoldOut)]
+    fn is_sold_out(&self) -> bool {
+        let total_tickets = self.total_tickets().get();
+
+        total_tickets > 0 && self.total_tickets_sold().get() == total_tickets
+    }
+
+    #[view(getLaunchpadTokenId)]
+    #[storage_mapper("launchpadTokenId")]
+    fn launchpad_token_id(&self) -> SingleValueMapper<TokenIdentifier>;
+
+    #[view(getLaunchpadTokensPerTicket)]
+    #[storage_mapper("launchpadTokensPerTicket")]
+    fn launchpad_tokens_per_ticket(&self) -> SingleValueMapper<BigUint>;
+
+    #[view(getTicketPaymentToken)]
+    #[storage_mapper("ticketPaymentToken")]
+    fn ticket_payment_token(&self) -> SingleValueMapper<EgldOrEsdtTokenIdentifier>;
+
+    #[view(getAuctionConfig)]
+    #[storage_mapper("auctionConfig")]
+    fn auction_config(&self) -> SingleValueMapper<AuctionConfig<Self::Api>>;
+
+    #[view(isClearingPriceRebateEnabled)]
+    #[storage_mapper("clearingPriceRebateEnabled")]
+    fn clearing_price_rebate_enabled(&self) -> SingleValueMapper<bool>;
+
+    /// Set by the deposited launchpad tokens
+    #[view(getTotalTickets)]
+    #[storage_mapper("totalTickets")]
+    fn total_tickets(&self) -> SingleValueMapper<usize>;
+
+    #[view(getTotalTicketsSold)]
+    #[storage_mapper("totalTicketsSold")]
+    fn total_tickets_sold(&self) -> SingleValueMapper<usize>;
+}
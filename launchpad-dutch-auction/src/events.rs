@@ -0,0 +1,81 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+#[derive(TypeAbi, TopEncode)]
+pub struct BuyTicketsEvent<M: ManagedTypeApi> {
+    user: ManagedAddress<M>,
+    round: u64,
+    epoch: u64,
+    nr_tickets: usize,
+    ticket_price: BigUint<M>,
+    total_price: BigUint<M>,
+}
+
+#[derive(TypeAbi, TopEncode)]
+pub struct ClaimRebateEvent<M: ManagedTypeApi> {
+    user: ManagedAddress<M>,
+    round: u64,
+    epoch: u64,
+    rebate: BigUint<M>,
+}
+
+#[multiversx_sc::module]
+pub trait EventsModule {
+    fn emit_buy_tickets_event(
+        &self,
+        user: ManagedAddress,
+        nr_tickets: usize,
+        ticket_price: BigUint,
+        total_price: BigUint,
+    ) {
+        let round = self.blockchain().get_block_round();
+        let epoch = self.blockchain().get_block_epoch();
+        self.buy_tickets_event(
+            user.clone(),
+            round,
+            epoch,
+            BuyTicketsEvent {
+                user,
+                round,
+                epoch,
+                nr_tickets,
+                ticket_price,
+                total_price,
+            },
+        )
+    }
+
+    fn emit_claim_rebate_event(&self, user: ManagedAddress, rebate: BigUint) {
+        let round = self.blockchain().get_block_round();
+        let epoch = self.blockchain().get_block_epoch();
+        self.claim_rebate_event(
+            user.clone(),
+            round,
+            epoch,
+            ClaimRebateEvent {
+                user,
+                round,
+                epoch,
+                rebate,
+            },
+        )
+    }
+
+    #[event("buyTickets")]
+    fn buy_tickets_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] round: u64,
+        #[indexed] epoch: u64,
+        buy_tickets_event: BuyTicketsEvent<Self::Api>,
+    );
+
+    #[event("claimRebate")]
+    fn claim_rebate_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] round: u64,
+        #[indexed] epoch: u64,
+        claim_rebate_event: ClaimRebateEvent<Self::Api>,
+    );
+}
@@ -0,0 +1,147 @@
+#![no_std]
+
+multiversx_sc::imports!();
+
+pub mod auction_config;
+pub mod events;
+pub mod purchase;
+
+use auction_config::AuctionConfig;
+
+/// Launchpad variant without a lottery. The ticket price declines from a start price
+/// to a floor price over the auction, and anyone may buy at the current price until sold out.
+#[multiversx_sc::contract]
+pub trait LaunchpadDutchAuction:
+    auction_config::AuctionConfigModule
+    + purchase::PurchaseModule
+    + events::EventsModule
+    + multiversx_sc_modules::pause::PauseModule
+{
+    #[allow(clippy::too_many_arguments)]
+    #[init]
+    fn init(
+        &self,
+        launchpad_token_id: TokenIdentifier,
+        launchpad_tokens_per_ticket: BigUint,
+        ticket_payment_token: EgldOrEsdtTokenIdentifier,
+        start_price: BigUint,
+        floor_price: BigUint,
+        start_round: u64,
+        end_round: u64,
+        clearing_price_rebate_enabled: bool,
+    ) {
+        require!(
+            launchpad_token_id.is_valid_esdt_identifier(),
+            "Invalid launchpad token ID"
+        );
+        require!(
+            launchpad_tokens_per_ticket > 0,
+            "Launchpad tokens per ticket cannot be set to zero"
+        );
+        require!(
+            ticket_payment_token.is_valid(),
+            "Invalid ticket payment token"
+        );
+        require!(
+            floor_price > 0 && start_price >= floor_price,
+            "Invalid auction prices"
+        );
+
+        let current_round = self.blockchain().get_block_round();
+        require!(
+            current_round <= start_round && start_round < end_round,
+            "Invalid auction rounds"
+        );
+
+        self.launchpad_token_id().set(launchpad_token_id);
+        self.launchpad_tokens_per_ticket()
+            .set(launchpad_tokens_per_ticket);
+        self.ticket_payment_token().set(ticket_payment_token);
+        self.auction_config().set(AuctionConfig {
+            start_price,
+            floor_price,
+            start_round,
+            end_round,
+        });
+        self.clearing_price_rebate_enabled()
+            .set(clearing_price_rebate_enabled);
+    }
+
+    #[upgrade]
+    fn upgrade(&self) {}
+
+    /// Each `launchpad_tokens_per_ticket` deposited adds a ticket to the auction
+    #[only_owner]
+    #[payable("*")]
+    #[endpoint(depositLaunchpadTokens)]
+    fn deposit_launchpad_tokens(&self) {
+        let config = self.auction_config().get();
+        require!(
+            self.blockchain().get_block_round() < config.start_round,
+            "Auction already started"
+        );
+
+        let (payment_token, payment_amount) = self.call_value().single_fungible_esdt();
+        require!(
+            payment_token == self.launchpad_token_id().get(),
+            "Wrong token"
+        );
+
+        let launchpad_tokens_per_ticket = self.launchpad_tokens_per_ticket().get();
+        require!(
+            &payment_amount % &launchpad_tokens_per_ticket == 0,
+            "Amount must be a multiple of the launchpad tokens per ticket"
+        );
+
+        let nr_tickets = (payment_amount / launchpad_tokens_per_ticket)
+            .to_u64()
+            .unwrap_or_else(|| sc_panic!("Too many tokens")) as usize;
+        self.total_tickets().update(|total| *total += nr_tickets);
+    }
+
+    /// Once the auction ended, sends the ticket payment to the owner,
+    /// minus the buyers' rebates, along with the unsold launchpad tokens
+    #[only_owner]
+    #[endpoint(claimTicketPayment)]
+    fn claim_ticket_payment(&self) {
+        require!(self.is_auction_ended(), "Auction not ended yet");
+        require!(
+            !self.owner_claimed_ticket_payment().get(),
+            "Already claimed"
+        );
+
+        self.owner_claimed_ticket_payment().set(true);
+
+        let owner = self.blockchain().get_caller();
+        let total_tickets_sold = self.total_tickets_sold().get();
+        let ticket_payment = if self.clearing_price_rebate_enabled().get() {
+            self.clearing_price().get() * total_tickets_sold as u32
+        } else {
+            self.total_ticket_payment().get()
+        };
+        if ticket_payment > 0 {
+            self.send().direct(
+                &owner,
+                &self.ticket_payment_token().get(),
+                0,
+                &ticket_payment,
+            );
+        }
+
+        let nr_unsold_tickets = self.total_tickets().get() - total_tickets_sold;
+        if nr_unsold_tickets > 0 {
+            let unsold_launchpad_tokens =
+                self.launchpad_tokens_per_ticket().get() * nr_unsold_tickets as u32;
+            self.send().direct_esdt(
+                &owner,
+                &self.launchpad_token_id().get(),
+                0,
+                &unsold_launchpad_tokens,
+            );
+        }
+    }
+
+    #[view(hasOwnerClaimedTicketPayment)]
+    #[storage_mapper("ownerClaimedTicketPayment")]
+    fn owner_claimed_ticket_payment(&self) -> SingleValueMapper<bool>;
+}
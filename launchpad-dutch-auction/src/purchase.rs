@@ -0,0 +1,119 @@
+multiversx_sc::imports!();
+
+#[multiversx_sc::module]
+pub trait PurchaseModule:
+    crate::auction_config::AuctionConfigModule
+    + crate::events::EventsModule
+    + multiversx_sc_modules::pause::PauseModule
+{
+    /// Buys at the current price. Any payment above the total price is sent back.
+    /// The launchpad tokens are sent right away.
+    #[payable("*")]
+    #[endpoint(buyTickets)]
+    fn buy_tickets(&self, nr_tickets: usize) {
+        self.require_not_paused();
+        require!(self.is_auction_active(), "Auction not active");
+        require!(nr_tickets > 0, "Invalid number of tickets");
+
+        let total_tickets_sold = self.total_tickets_sold().get() + nr_tickets;
+        require!(
+            total_tickets_sold <= self.total_tickets().get(),
+            "Not enough tickets left"
+        );
+
+        let (payment_token, payment_amount) = self.call_value().egld_or_single_fungible_esdt();
+        require!(
+            payment_token == self.ticket_payment_token().get(),
+            "Wrong payment token"
+        );
+
+        let ticket_price = self.get_current_price();
+        let total_price = &ticket_price * nr_tickets as u32;
+        require!(payment_amount >= total_price, "Payment too low");
+
+        let caller = self.blockchain().get_caller();
+        let excess_payment = &payment_amount - &total_price;
+        if excess_payment > 0 {
+            self.send()
+                .direct(&caller, &payment_token, 0, &excess_payment);
+        }
+
+        self.total_tickets_sold().set(total_tickets_sold);
+        self.user_tickets_bought(&caller)
+            .update(|nr_bought| *nr_bought += nr_tickets);
+        self.user_payment(&caller)
+            .update(|paid| *paid += &total_price);
+        self.total_ticket_payment()
+            .update(|total| *total += &total_price);
+        self.clearing_price().set(&ticket_price);
+
+        let launchpad_tokens_amount = self.launchpad_tokens_per_ticket().get() * nr_tickets as u32;
+        self.send().direct_esdt(
+            &caller,
+            &self.launchpad_token_id().get(),
+            0,
+            &launchpad_tokens_amount,
+        );
+
+        self.emit_buy_tickets_event(caller, nr_tickets, ticket_price, total_price);
+    }
+
+    /// With the clearing price rebate, every buyer ends up paying the price of the last sale,
+    /// which is the lowest one, as the price only declines
+    #[endpoint(claimRebate)]
+    fn claim_rebate(&self) {
+        require!(
+            self.clearing_price_rebate_enabled().get(),
+            "Clearing price rebate not enabled"
+        );
+        require!(self.is_auction_ended(), "Auction not ended yet");
+
+        let caller = self.blockchain().get_caller();
+        require!(
+            !self.user_claimed_rebate(&caller).get(),
+            "Rebate already claimed"
+        );
+
+        let rebate = self.get_user_rebate(caller.clone());
+        require!(rebate > 0, "No rebate");
+
+        self.user_claimed_rebate(&caller).set(true);
+        self.send()
+            .direct(&caller, &self.ticket_payment_token().get(), 0, &rebate);
+
+        self.emit_claim_rebate_event(caller, rebate);
+    }
+
+    #[view(getUserRebate)]
+    fn get_user_rebate(&self, address: ManagedAddress) -> BigUint {
+        if !self.clearing_price_rebate_enabled().get() || self.user_claimed_rebate(&address).get() {
+            return BigUint::zero();
+        }
+
+        let nr_tickets_bought = self.user_tickets_bought(&address).get();
+        let paid_at_clearing_price = self.clearing_price().get() * nr_tickets_bought as u32;
+
+        self.user_payment(&address).get() - paid_at_clearing_price
+    }
+
+    /// Price of the last sale, which is final once the auction ended
+    #[view(getClearingPrice)]
+    #[storage_mapper("clearingPrice")]
+    fn clearing_price(&self) -> SingleValueMapper<BigUint>;
+
+    #[view(getUserTicketsBought)]
+    #[storage_mapper("userTicketsBought")]
+    fn user_tickets_bought(&self, address: &ManagedAddress) -> SingleValueMapper<usize>;
+
+    #[view(getUserPayment)]
+    #[storage_mapper("userPayment")]
+    fn user_payment(&self, address: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    #[view(hasUserClaimedRebate)]
+    #[storage_mapper("userClaimedRebate")]
+    fn user_claimed_rebate(&self, address: &ManagedAddress) -> SingleValueMapper<bool>;
+
+    #[view(getTotalTicketPayment)]
+    #[storage_mapper("totalTicketPayment")]
+    fn total_ticket_payment(&self) -> SingleValueMapper<BigUint>;
+}
@@ -0,0 +1,125 @@
+use multiversx_sc::types::{Address, EgldOrEsdtTokenIdentifier};
+
+use launchpad_dutch_auction::{
+    auction_config::AuctionConfigModule, purchase::PurchaseModule, LaunchpadDutchAuction,
+};
+use multiversx_sc_scenario::{
+    managed_biguint, managed_token_id, rust_biguint,
+    testing_framework::{BlockchainStateWrapper, ContractObjWrapper, TxResult},
+    DebugApi,
+};
+
+pub static LAUNCHPAD_TOKEN_ID: &[u8] = b"LAUNCH-123456";
+pub const LAUNCHPAD_TOKENS_PER_TICKET: u64 = 100;
+pub const NR_TICKETS: usize = 5;
+pub const START_PRICE: u64 = 100;
+pub const FLOOR_PRICE: u64 = 20;
+pub const START_ROUND: u64 = 10;
+pub const END_ROUND: u64 = 20;
+
+pub const NR_PARTICIPANTS: usize = 3;
+pub const USER_BALANCE: u64 = 1_000;
+
+pub struct DutchAuctionSetup<DutchAuctionBuilder>
+where
+    DutchAuctionBuilder: 'static + Copy + Fn() -> launchpad_dutch_auction::ContractObj<DebugApi>,
+{
+    pub b_mock: BlockchainStateWrapper,
+    pub owner_address: Address,
+    pub participants: Vec<Address>,
+    pub da_wrapper:
+        ContractObjWrapper<launchpad_dutch_auction::ContractObj<DebugApi>, DutchAuctionBuilder>,
+}
+
+impl<DutchAuctionBuilder> DutchAuctionSetup<DutchAuctionBuilder>
+where
+    DutchAuctionBuilder: 'static + Copy + Fn() -> launchpad_dutch_auction::ContractObj<DebugApi>,
+{
+    pub fn new(clearing_price_rebate_enabled: bool, da_builder: DutchAuctionBuilder) -> Self {
+        let rust_zero = rust_biguint!(0u64);
+        let total_launchpad_tokens = rust_biguint!(LAUNCHPAD_TOKENS_PER_TICKET * NR_TICKETS as u64);
+
+        let mut b_mock = BlockchainStateWrapper::new();
+        let owner_address = b_mock.create_user_account(&rust_zero);
+        let mut participants = Vec::new();
+        for _ in 0..NR_PARTICIPANTS {
+            let addr = b_mock.create_user_account(&rust_biguint!(USER_BALANCE));
+            participants.push(addr);
+        }
+
+        b_mock.set_esdt_balance(&owner_address, LAUNCHPAD_TOKEN_ID, &total_launchpad_tokens);
+
+        let da_wrapper = b_mock.create_sc_account(
+            &rust_zero,
+            Some(&owner_address),
+            da_builder,
+            "dutch auction.wasm",
+        );
+
+        b_mock
+            .execute_tx(&owner_address, &da_wrapper, &rust_zero, |sc| {
+                sc.init(
+                    managed_token_id!(LAUNCHPAD_TOKEN_ID),
+                    managed_biguint!(LAUNCHPAD_TOKENS_PER_TICKET),
+                    EgldOrEsdtTokenIdentifier::egld(),
+                    managed_biguint!(START_PRICE),
+                    managed_biguint!(FLOOR_PRICE),
+                    START_ROUND,
+                    END_ROUND,
+                    clearing_price_rebate_enabled,
+                );
+            })
+            .assert_ok();
+
+        b_mock
+            .execute_esdt_transfer(
+                &owner_address,
+                &da_wrapper,
+                LAUNCHPAD_TOKEN_ID,
+                0,
+                &total_launchpad_tokens,
+                |sc| {
+                    sc.deposit_launchpad_tokens();
+
+                    assert_eq!(sc.total_tickets().get(), NR_TICKETS);
+                },
+            )
+            .assert_ok();
+
+        DutchAuctionSetup {
+            b_mock,
+            owner_address,
+            participants,
+            da_wrapper,
+        }
+    }
+
+    pub fn buy(&mut self, user: &Address, nr_tickets: usize, payment_amount: u64) -> TxResult {
+        self.b_mock.execute_tx(
+            user,
+            &self.da_wrapper,
+            &rust_biguint!(payment_amount),
+            |sc| {
+                sc.buy_tickets(nr_tickets);
+            },
+        )
+    }
+
+    pub fn claim_rebate(&mut self, user: &Address) -> TxResult {
+        self.b_mock
+            .execute_tx(user, &self.da_wrapper, &rust_biguint!(0), |sc| {
+                sc.claim_rebate();
+            })
+    }
+
+    pub fn claim_ticket_payment(&mut self) -> TxResult {
+        self.b_mock.execute_tx(
+            &self.owner_address,
+            &self.da_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.claim_ticket_payment();
+            },
+        )
+    }
+}
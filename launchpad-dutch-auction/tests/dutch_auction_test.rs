@@ -0,0 +1,187 @@
+mod dutch_auction_setup;
+
+use dutch_auction_setup::{
+    DutchAuctionSetup, END_ROUND, LAUNCHPAD_TOKENS_PER_TICKET, LAUNCHPAD_TOKEN_ID, NR_TICKETS,
+    START_ROUND, USER_BALANCE,
+};
+use launchpad_dutch_auction::{
+    auction_config::AuctionConfigModule, purchase::PurchaseModule, LaunchpadDutchAuction,
+};
+use multiversx_sc_scenario::{managed_address, managed_biguint, rust_biguint};
+
+#[test]
+fn init_test() {
+    let _ = DutchAuctionSetup::new(true, launchpad_dutch_auction::contract_obj);
+}
+
+#[test]
+fn price_decline_test() {
+    let mut da_setup = DutchAuctionSetup::new(true, launchpad_dutch_auction::contract_obj);
+
+    for (round, expected_price) in [
+        (0, 100u64),
+        (START_ROUND, 100),
+        (START_ROUND + 5, 60),
+        (START_ROUND + 9, 28),
+        (END_ROUND, 20),
+        (END_ROUND + 10, 20),
+    ] {
+        da_setup.b_mock.set_block_round(round);
+        da_setup
+            .b_mock
+            .execute_query(&da_setup.da_wrapper, |sc| {
+                assert_eq!(sc.get_current_price(), managed_biguint!(expected_price));
+            })
+            .assert_ok();
+    }
+}
+
+#[test]
+fn buy_until_sold_out_with_rebate_test() {
+    let mut da_setup = DutchAuctionSetup::new(true, launchpad_dutch_auction::contract_obj);
+    let participants = da_setup.participants.clone();
+
+    let first_buyer = participants[0].clone();
+    da_setup
+        .buy(&first_buyer, 1, 100)
+        .assert_user_error("Auction not active");
+
+    // 2 tickets at 100, the extra 50 is sent back
+    da_setup.b_mock.set_block_round(START_ROUND);
+    da_setup
+        .buy(&first_buyer, 2, 199)
+        .assert_user_error("Payment too low");
+    da_setup.buy(&first_buyer, 2, 250).assert_ok();
+    da_setup
+        .b_mock
+        .check_egld_balance(&first_buyer, &rust_biguint!(USER_BALANCE - 200));
+    da_setup.b_mock.check_esdt_balance(
+        &first_buyer,
+        LAUNCHPAD_TOKEN_ID,
+        &rust_biguint!(LAUNCHPAD_TOKENS_PER_TICKET * 2),
+    );
+
+    // the remaining 3 tickets at 60
+    let second_buyer = participants[1].clone();
+    da_setup.b_mock.set_block_round(START_ROUND + 5);
+    da_setup
+        .buy(&second_buyer, 4, 400)
+        .assert_user_error("Not enough tickets left");
+    da_setup
+        .claim_rebate(&first_buyer)
+        .assert_user_error("Auction not ended yet");
+    da_setup.buy(&second_buyer, 3, 180).assert_ok();
+
+    da_setup
+        .buy(&participants[2], 1, 60)
+        .assert_user_error("Auction not active");
+
+    da_setup
+        .b_mock
+        .execute_query(&da_setup.da_wrapper, |sc| {
+            assert!(sc.is_sold_out());
+            assert!(sc.is_auction_ended());
+            assert_eq!(sc.clearing_price().get(), managed_biguint!(60));
+            assert_eq!(
+                sc.get_user_rebate(managed_address!(&first_buyer)),
+                managed_biguint!(80)
+            );
+        })
+        .assert_ok();
+
+    // the first buyer ends up paying the clearing price
+    da_setup.claim_rebate(&first_buyer).assert_ok();
+    da_setup
+        .b_mock
+        .check_egld_balance(&first_buyer, &rust_biguint!(USER_BALANCE - 120));
+    da_setup
+        .claim_rebate(&first_buyer)
+        .assert_user_error("Rebate already claimed");
+    da_setup
+        .claim_rebate(&second_buyer)
+        .assert_user_error("No rebate");
+
+    da_setup.claim_ticket_payment().assert_ok();
+    da_setup.b_mock.check_egld_balance(
+        &da_setup.owner_address,
+        &rust_biguint!(60 * NR_TICKETS as u64),
+    );
+    da_setup
+        .b_mock
+        .check_egld_balance(da_setup.da_wrapper.address_ref(), &rust_biguint!(0));
+    da_setup
+        .claim_ticket_payment()
+        .assert_user_error("Already claimed");
+}
+
+#[test]
+fn unsold_tickets_without_rebate_test() {
+    let mut da_setup = DutchAuctionSetup::new(false, launchpad_dutch_auction::contract_obj);
+    let buyer = da_setup.participants[0].clone();
+
+    da_setup.b_mock.set_block_round(START_ROUND + 5);
+    da_setup.buy(&buyer, 2, 120).assert_ok();
+
+    da_setup
+        .claim_ticket_payment()
+        .assert_user_error("Auction not ended yet");
+
+    da_setup.b_mock.set_block_round(END_ROUND);
+    da_setup
+        .buy(&buyer, 1, 20)
+        .assert_user_error("Auction not active");
+    da_setup
+        .claim_rebate(&buyer)
+        .assert_user_error("Clearing price rebate not enabled");
+
+    // the unsold launchpad tokens go back to the owner
+    da_setup.claim_ticket_payment().assert_ok();
+    da_setup
+        .b_mock
+        .check_egld_balance(&da_setup.owner_address, &rust_biguint!(120));
+    da_setup.b_mock.check_esdt_balance(
+        &da_setup.owner_address,
+        LAUNCHPAD_TOKEN_ID,
+        &rust_biguint!(LAUNCHPAD_TOKENS_PER_TICKET * (NR_TICKETS as u64 - 2)),
+    );
+}
+
+#[test]
+fn deposit_launchpad_tokens_test() {
+    let mut da_setup = DutchAuctionSetup::new(true, launchpad_dutch_auction::contract_obj);
+    let owner_address = da_setup.owner_address.clone();
+    da_setup.b_mock.set_esdt_balance(
+        &owner_address,
+        LAUNCHPAD_TOKEN_ID,
+        &rust_biguint!(LAUNCHPAD_TOKENS_PER_TICKET * 2),
+    );
+
+    da_setup
+        .b_mock
+        .execute_esdt_transfer(
+            &owner_address,
+            &da_setup.da_wrapper,
+            LAUNCHPAD_TOKEN_ID,
+            0,
+            &rust_biguint!(LAUNCHPAD_TOKENS_PER_TICKET + 1),
+            |sc| {
+                sc.deposit_launchpad_tokens();
+            },
+        )
+        .assert_user_error("Amount must be a multiple of the launchpad tokens per ticket");
+
+    da_setup.b_mock.set_block_round(START_ROUND);
+    da_setup
+        .b_mock
+        .execute_esdt_transfer(
+            &owner_address,
+            &da_setup.da_wrapper,
+            LAUNCHPAD_TOKEN_ID,
+            0,
+            &rust_biguint!(LAUNCHPAD_TOKENS_PER_TICKET),
+            |sc| {
+                sc.deposit_launchpad_tokens();
+            },
+        )
+        .assert_user_error("Auction already started");
+}
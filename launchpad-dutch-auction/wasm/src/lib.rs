@@ -0,0 +1,50 @@
+// Code generated by the multiversx-sc build system. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+// Init:                                 1
+// Upgrade:                              1
+// Endpoints:                           24
+// Async Callback (empty):               1
+// Total number of exported functions:  27
+
+#![no_std]
+
+multiversx_sc_wasm_adapter::allocator!();
+multiversx_sc_wasm_adapter::panic_handler!();
+
+multiversx_sc_wasm_adapter::endpoints! {
+    launchpad_dutch_auction
+    (
+        init => init
+        upgrade => upgrade
+        depositLaunchpadTokens => deposit_launchpad_tokens
+        claimTicketPayment => claim_ticket_payment
+        hasOwnerClaimedTicketPayment => owner_claimed_ticket_payment
+        getCurrentPrice => get_current_price
+        isAuctionEnded => is_auction_ended
+        isSoldOut => is_sold_out
+        getLaunchpadTokenId => launchpad_token_id
+        getLaunchpadTokensPerTicket => launchpad_tokens_per_ticket
+        getTicketPaymentToken => ticket_payment_token
+        getAuctionConfig => auction_config
+        isClearingPriceRebateEnabled => clearing_price_rebate_enabled
+        getTotalTickets => total_tickets
+        getTotalTicketsSold => total_tickets_sold
+        buyTickets => buy_tickets
+        claimRebate => claim_rebate
+        getUserRebate => get_user_rebate
+        getClearingPrice => clearing_price
+        getUserTicketsBought => user_tickets_bought
+        getUserPayment => user_payment
+        hasUserClaimedRebate => user_claimed_rebate
+        getTotalTicketPayment => total_ticket_payment
+        pause => pause_endpoint
+        unpause => unpause_endpoint
+        isPaused => paused_status
+    )
+}
+
+multiversx_sc_wasm_adapter::async_callback_empty! {}
@@ -0,0 +1,224 @@
+multiversx_sc::imports!();
+
+use launchpad_common::{
+    config::TokenAmountPair,
+    launch_stage::{Flags, LaunchStage},
+    tickets::{FIRST_TICKET_ID, WINNING_TICKET},
+    tickets_layout_migration::LegacyTicketId,
+};
+
+use crate::{
+    guaranteed_ticket_winners::GuaranteedTicketsSelectionOperation, token_release::MAX_PERCENTAGE,
+};
+
+#[multiversx_sc::module]
+pub trait EarlyExitModule:
+    launchpad_common::launch_stage::LaunchStageModule
+    + launchpad_common::config::ConfigModule
+    + launchpad_common::tickets::TicketsModule
+    + launchpad_common::ongoing_operation::OngoingOperationModule
+    + launchpad_common::permissions::PermissionsModule
+    + launchpad_common::action_pause::ActionPauseModule
+    + launchpad_common::blacklist::BlacklistModule
+    + launchpad_common::token_send::TokenSendModule
+    + launchpad_common::user_interactions::UserInteractionsModule
+    + launchpad_common::unclaimed_sweep::UnclaimedSweepModule
+    + launchpad_common::winner_selection::WinnerSelectionModule
+    + launchpad_common::commit_reveal::CommitRevealModule
+    + launchpad_common::common_events::CommonEventsModule
+    + launchpad_common::winners_hash::WinnersHashModule
+    + crate::guaranteed_tickets_init::GuaranteedTicketsInitModule
+    + crate::guaranteed_ticket_winners::GuaranteedTicketWinnersModule
+    + crate::events::EventsModule
+    + multiversx_sc_modules::pause::PauseModule
+{
+    /// The penalty is a percentage of the winning tickets' payment, sent to the treasury
+    #[endpoint(setEarlyExitConfig)]
+    fn set_early_exit_config(&self, enabled: bool, penalty_percentage: u64) {
+        self.require_config_manager();
+        require!(
+            self.get_launch_stage() < LaunchStage::Claim,
+            "Cannot change after claim period started"
+        );
+        require!(
+            penalty_percentage <= MAX_PERCENTAGE,
+            "Invalid penalty percentage"
+        );
+
+        self.early_exit_enabled().set(enabled);
+        self.early_exit_penalty_percentage().set(penalty_percentage);
+    }
+
+    /// Renounces the whole allocation before the claim period starts.
+    /// All the confirmed tickets are refunded, minus the penalty for the winning ones.
+    /// The winning tickets are given to other participants through `distributeRenouncedTickets`,
+    /// so the selection result must first be committed through `computeWinnersHash`.
+    #[endpoint(earlyExit)]
+    fn early_exit(&self) {
+        self.require_not_paused();
+        require!(self.early_exit_enabled().get(), "Early exit not enabled");
+        self.require_all_winners_selected_before_claim();
+        require!(
+            !self.winners_hash().is_empty(),
+            "Winners hash must be computed first"
+        );
+        require!(
+            self.current_ongoing_operation().is_empty(),
+            "Another ongoing operation is in progress"
+        );
+
+        let caller = self.blockchain().get_caller();
+        self.require_caller_not_denied_contract(&caller);
+        require!(!self.has_user_claimed(&caller), "Already claimed");
+        require!(
+            self.user_locked_tickets(&caller).get() == 0,
+            "Tickets paid with locked tokens may not exit early"
+        );
+
+        let ticket_range = self.try_get_ticket_range(&caller);
+        let mut nr_winning_tickets = 0;
+        for ticket_id in ticket_range.first_id..=ticket_range.last_id {
+            if self.get_ticket_status(ticket_id) == WINNING_TICKET {
+                self.clear_ticket_status(ticket_id);
+
+                nr_winning_tickets += 1;
+            }
+
+            self.renounced_ticket(ticket_id).set(true);
+        }
+        require!(nr_winning_tickets > 0, "No winning tickets");
+
+        let nr_confirmed_tickets = self.nr_confirmed_tickets(&caller).take();
+        self.ticket_range_for_address(&caller).clear();
+        self.ticket_batch(ticket_range.first_id).clear();
+        self.mark_user_claimed(&caller, 0);
+
+        self.nr_winning_tickets()
+            .update(|nr_winning| *nr_winning -= nr_winning_tickets);
+        self.renounced_tickets_to_distribute()
+            .update(|nr_renounced| *nr_renounced += nr_winning_tickets);
+
+        let ticket_price: TokenAmountPair<Self::Api> = self.ticket_price().get();
+        let winning_tickets_payment = &ticket_price.amount * nr_winning_tickets as u32;
+        self.claimable_ticket_payment()
+            .update(|claim_amt| *claim_amt -= &winning_tickets_payment);
+
+        let penalty_percentage = self.early_exit_penalty_percentage().get();
+        let penalty_amount = winning_tickets_payment * penalty_percentage / MAX_PERCENTAGE;
+        let user_ticket_payment = ticket_price.amount * nr_confirmed_tickets as u32;
+        self.release_user_ticket_payment(&caller, &user_ticket_payment);
+        let refund_amount = user_ticket_payment - &penalty_amount;
+        if penalty_amount > 0 {
+            let treasury_mapper = self.treasury_address();
+            require!(!treasury_mapper.is_empty(), "Treasury address not set");
+
+            self.send().direct(
+                &treasury_mapper.get(),
+                &ticket_price.token_id,
+                0,
+                &penalty_amount,
+            );
+        }
+        if refund_amount > 0 {
+            self.send()
+                .direct(&caller, &ticket_price.token_id, 0, &refund_amount);
+        }
+
+        self.emit_early_exit_event(
+            nr_winning_tickets,
+            EgldOrEsdtTokenPayment::new(ticket_price.token_id.clone(), 0, refund_amount),
+            EgldOrEsdtTokenPayment::new(ticket_price.token_id, 0, penalty_amount),
+        );
+    }
+
+    /// Leftover-distribution pass for the tickets renounced through `earlyExit`.
+    /// Tickets which cannot be given to anyone else are left unsold.
+    #[endpoint(distributeRenouncedTickets)]
+    fn distribute_renounced_tickets(&self) -> OperationCompletionStatus {
+        self.require_not_paused();
+        self.require_all_winners_selected_before_claim();
+        self.check_caller_owner_or_user();
+
+        let mut current_operation: GuaranteedTicketsSelectionOperation<Self::Api> =
+            if self.current_ongoing_operation().is_empty() {
+                let nr_renounced_tickets = self.renounced_tickets_to_distribute().take();
+                require!(
+                    nr_renounced_tickets > 0,
+                    "No renounced tickets to distribute"
+                );
+
+                GuaranteedTicketsSelectionOperation {
+                    leftover_tickets: nr_renounced_tickets,
+                    ..Default::default()
+                }
+            } else {
+                self.load_additional_selection_operation()
+            };
+
+        let run_result = self.distribute_leftover_tickets(&mut current_operation);
+        match run_result {
+            OperationCompletionStatus::InterruptedBeforeOutOfGas => {
+                self.save_additional_selection_progress(&current_operation);
+            }
+            OperationCompletionStatus::Completed => {
+                let ticket_price: TokenAmountPair<Self::Api> = self.ticket_price().get();
+                let claimable_ticket_payment = ticket_price.amount
+                    * (current_operation.total_additional_winning_tickets as u32);
+                self.claimable_ticket_payment()
+                    .update(|claim_amt| *claim_amt += claimable_ticket_payment);
+
+                self.nr_winning_tickets().update(|nr_winning| {
+                    *nr_winning += current_operation.total_additional_winning_tickets
+                });
+            }
+        };
+
+        run_result
+    }
+
+    fn require_all_winners_selected_before_claim(&self) {
+        let flags = self.flags().get();
+        require!(
+            flags.were_winners_selected && flags.was_additional_step_completed,
+            "Winners not selected yet"
+        );
+        require!(
+            self.get_launch_stage() < LaunchStage::Claim,
+            "Claim period has started"
+        );
+    }
+
+    /// The renounced tickets are keyed by ticket ID, which was 32 bits wide
+    /// before `TICKETS_LAYOUT_STORAGE_VERSION`
+    fn migrate_legacy_renounced_tickets(&self) {
+        let flags: Flags = self.flags().get();
+        if !flags.were_winners_selected {
+            return;
+        }
+
+        let last_ticket_id = self.last_ticket_id().get();
+        for ticket_id in FIRST_TICKET_ID..=last_ticket_id {
+            if self
+                .legacy_renounced_ticket(ticket_id as LegacyTicketId)
+                .take()
+            {
+                self.renounced_ticket(ticket_id).set(true);
+            }
+        }
+    }
+
+    #[view(isEarlyExitEnabled)]
+    #[storage_mapper("earlyExitEnabled")]
+    fn early_exit_enabled(&self) -> SingleValueMapper<bool>;
+
+    #[view(getEarlyExitPenaltyPercentage)]
+    #[storage_mapper("earlyExitPenaltyPercentage")]
+    fn early_exit_penalty_percentage(&self) -> SingleValueMapper<u64>;
+
+    #[view(getRenouncedTicketsToDistribute)]
+    #[storage_mapper("renouncedTicketsToDistribute")]
+    fn renounced_tickets_to_distribute(&self) -> SingleValueMapper<usize>;
+
+    #[storage_mapper("renouncedTicket")]
+    fn legacy_renounced_ticket(&self, ticket_id: LegacyTicketId) -> SingleValueMapper<bool>;
+}
@@ -0,0 +1,194 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::guaranteed_tickets_init::{GuaranteedTicketInfo, MAX_GUARANTEED_TICKETS_ENTRIES};
+
+pub const MAX_ENERGY_BONUS_TIERS: usize = 10;
+
+pub mod energy_factory_proxy {
+    multiversx_sc::imports!();
+
+    #[multiversx_sc::proxy]
+    pub trait EnergyFactoryProxy {
+        #[view(getEnergyAmountForUser)]
+        fn get_energy_amount_for_user(&self, user: ManagedAddress) -> BigUint;
+    }
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, ManagedVecItem)]
+pub struct EnergyBonusTier<M: ManagedTypeApi> {
+    pub min_energy: BigUint<M>,
+    pub bonus_guaranteed_tickets: usize,
+}
+
+#[multiversx_sc::module]
+pub trait EnergyBonusModule:
+    launchpad_common::launch_stage::LaunchStageModule
+    + launchpad_common::config::ConfigModule
+    + launchpad_common::ongoing_operation::OngoingOperationModule
+    + launchpad_common::tickets::TicketsModule
+    + launchpad_common::permissions::PermissionsModule
+    + launchpad_common::action_pause::ActionPauseModule
+    + crate::guaranteed_tickets_init::GuaranteedTicketsInitModule
+    + crate::events::EventsModule
+{
+    #[endpoint(setEnergyFactoryAddress)]
+    fn set_energy_factory_address(&self, sc_address: ManagedAddress) {
+        self.require_config_manager();
+        require!(
+            self.blockchain().is_smart_contract(&sc_address),
+            "Invalid SC address"
+        );
+
+        self.energy_factory_address().set(&sc_address);
+    }
+
+    /// Tiers are given as (min_energy, bonus_guaranteed_tickets) pairs, in ascending order of energy.
+    /// A user gets the bonus of the highest tier they qualify for.
+    #[endpoint(setEnergyBonusTiers)]
+    fn set_energy_bonus_tiers(&self, tiers: MultiValueEncoded<MultiValue2<BigUint, usize>>) {
+        self.require_config_manager();
+        self.require_add_tickets_period();
+        require!(
+            tiers.len() <= MAX_ENERGY_BONUS_TIERS,
+            "Maximum energy bonus tiers exceeded"
+        );
+
+        let mut energy_bonus_tiers = ManagedVec::new();
+        let mut last_min_energy = BigUint::zero();
+        for tier in tiers {
+            let (min_energy, bonus_guaranteed_tickets) = tier.into_tuple();
+            require!(
+                min_energy > last_min_energy && bonus_guaranteed_tickets > 0,
+                "Invalid energy bonus tier"
+            );
+
+            last_min_energy = min_energy.clone();
+            energy_bonus_tiers.push(EnergyBonusTier {
+                min_energy,
+                bonus_guaranteed_tickets,
+            });
+        }
+
+        self.energy_bonus_tiers().set(energy_bonus_tiers);
+    }
+
+    /// Queries the user's energy from the energy factory,
+    /// and reserves bonus guaranteed tickets in the callback
+    #[endpoint(claimEnergyBonus)]
+    fn claim_energy_bonus(&self) {
+        self.require_confirmation_period();
+
+        let caller = self.blockchain().get_caller();
+        require!(
+            !self.user_ticket_status(&caller).is_empty(),
+            "User not found"
+        );
+
+        let energy_factory_mapper = self.energy_factory_address();
+        require!(
+            !energy_factory_mapper.is_empty(),
+            "Energy bonus is not enabled"
+        );
+
+        let energy_bonus_requested_mapper = self.energy_bonus_requested(&caller);
+        require!(
+            !energy_bonus_requested_mapper.get(),
+            "Energy bonus already requested"
+        );
+        energy_bonus_requested_mapper.set(true);
+
+        let energy_factory_address = energy_factory_mapper.get();
+        self.energy_factory_proxy_builder(energy_factory_address)
+            .get_energy_amount_for_user(caller.clone())
+            .with_callback(self.callbacks().energy_bonus_callback(caller))
+            .async_call_and_exit();
+    }
+
+    #[callback]
+    fn energy_bonus_callback(
+        &self,
+        user: ManagedAddress,
+        #[call_result] result: ManagedAsyncCallResult<BigUint>,
+    ) {
+        match result {
+            ManagedAsyncCallResult::Ok(energy) => {
+                self.grant_energy_bonus(&user, &energy);
+            }
+            ManagedAsyncCallResult::Err(_) => {
+                self.energy_bonus_requested(&user).clear();
+            }
+        }
+    }
+
+    fn grant_energy_bonus(&self, user: &ManagedAddress, energy: &BigUint) {
+        let user_ticket_status_mapper = self.user_ticket_status(user);
+        if user_ticket_status_mapper.is_empty() {
+            return;
+        }
+
+        let mut user_ticket_status = user_ticket_status_mapper.get();
+        let bonus_tickets = core::cmp::min(
+            self.get_energy_bonus_tickets(energy),
+            user_ticket_status.total_tickets_allowance,
+        );
+        let nr_winning_tickets = self.nr_winning_tickets().get();
+        if bonus_tickets == 0
+            || bonus_tickets > nr_winning_tickets
+            || user_ticket_status.guaranteed_tickets_info.len() >= MAX_GUARANTEED_TICKETS_ENTRIES
+        {
+            return;
+        }
+
+        user_ticket_status
+            .guaranteed_tickets_info
+            .push(GuaranteedTicketInfo {
+                guaranteed_tickets: bonus_tickets,
+                min_confirmed_tickets: bonus_tickets,
+            });
+        user_ticket_status_mapper.set(user_ticket_status);
+
+        let _ = self.users_with_guaranteed_ticket().insert(user.clone());
+        self.nr_winning_tickets()
+            .set(nr_winning_tickets - bonus_tickets);
+        self.total_guaranteed_tickets()
+            .update(|total| *total += bonus_tickets);
+        self.energy_bonus_tickets(user).set(bonus_tickets);
+
+        self.emit_energy_bonus_granted_event(user.clone(), energy.clone(), bonus_tickets);
+    }
+
+    fn get_energy_bonus_tickets(&self, energy: &BigUint) -> usize {
+        let mut bonus_tickets = 0;
+        for tier in self.energy_bonus_tiers().get().iter() {
+            if energy < &tier.min_energy {
+                break;
+            }
+
+            bonus_tickets = tier.bonus_guaranteed_tickets;
+        }
+
+        bonus_tickets
+    }
+
+    #[view(getEnergyFactoryAddress)]
+    #[storage_mapper("energyFactoryAddress")]
+    fn energy_factory_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[view(getEnergyBonusTiers)]
+    #[storage_mapper("energyBonusTiers")]
+    fn energy_bonus_tiers(&self) -> SingleValueMapper<ManagedVec<EnergyBonusTier<Self::Api>>>;
+
+    #[storage_mapper("energyBonusRequested")]
+    fn energy_bonus_requested(&self, user: &ManagedAddress) -> SingleValueMapper<bool>;
+
+    #[view(getEnergyBonusTickets)]
+    #[storage_mapper("energyBonusTickets")]
+    fn energy_bonus_tickets(&self, user: &ManagedAddress) -> SingleValueMapper<usize>;
+
+    #[proxy]
+    fn energy_factory_proxy_builder(
+        &self,
+        sc_address: ManagedAddress,
+    ) -> energy_factory_proxy::Proxy<Self::Api>;
+}
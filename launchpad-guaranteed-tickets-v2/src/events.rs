@@ -53,6 +53,35 @@ pub struct DistributeGuaranteedTicketsCompletedEvent<M: ManagedTypeApi> {
     total_additional_winning_tickets: usize,
 }
 
+#[derive(TypeAbi, TopEncode)]
+pub struct EnergyBonusGrantedEvent<M: ManagedTypeApi> {
+    user: ManagedAddress<M>,
+    round: u64,
+    epoch: u64,
+    energy: BigUint<M>,
+    bonus_guaranteed_tickets: usize,
+}
+
+#[derive(TypeAbi, TopEncode)]
+pub struct EarlyExitEvent<M: ManagedTypeApi> {
+    user: ManagedAddress<M>,
+    round: u64,
+    epoch: u64,
+    renounced_winning_tickets: usize,
+    refund: EgldOrEsdtTokenPayment<M>,
+    penalty: EgldOrEsdtTokenPayment<M>,
+}
+
+#[derive(TypeAbi, TopEncode)]
+pub struct VestedClaimEvent<M: ManagedTypeApi> {
+    user: ManagedAddress<M>,
+    round: u64,
+    epoch: u64,
+    milestone_index: usize,
+    amount_released: BigUint<M>,
+    remaining_balance: BigUint<M>,
+}
+
 #[multiversx_sc::module]
 pub trait EventsModule {
     fn emit_claim_launchpad_tokens_event(&self, token_payment: EsdtTokenPayment) {
@@ -167,6 +196,76 @@ pub trait EventsModule {
         )
     }
 
+    fn emit_energy_bonus_granted_event(
+        &self,
+        user: ManagedAddress,
+        energy: BigUint,
+        bonus_guaranteed_tickets: usize,
+    ) {
+        let round = self.blockchain().get_block_round();
+        let epoch = self.blockchain().get_block_epoch();
+        self.energy_bonus_granted_event(
+            user.clone(),
+            round,
+            epoch,
+            EnergyBonusGrantedEvent {
+                user,
+                round,
+                epoch,
+                energy,
+                bonus_guaranteed_tickets,
+            },
+        )
+    }
+
+    fn emit_early_exit_event(
+        &self,
+        renounced_winning_tickets: usize,
+        refund: EgldOrEsdtTokenPayment,
+        penalty: EgldOrEsdtTokenPayment,
+    ) {
+        let user = self.blockchain().get_caller();
+        let round = self.blockchain().get_block_round();
+        let epoch = self.blockchain().get_block_epoch();
+        self.early_exit_event(
+            user.clone(),
+            round,
+            epoch,
+            EarlyExitEvent {
+                user,
+                round,
+                epoch,
+                renounced_winning_tickets,
+                refund,
+                penalty,
+            },
+        )
+    }
+
+    fn emit_vested_claim_event(
+        &self,
+        milestone_index: usize,
+        amount_released: BigUint,
+        remaining_balance: BigUint,
+    ) {
+        let user = self.blockchain().get_caller();
+        let round = self.blockchain().get_block_round();
+        let epoch = self.blockchain().get_block_epoch();
+        self.vested_claim_event(
+            user.clone(),
+            round,
+            epoch,
+            VestedClaimEvent {
+                user,
+                round,
+                epoch,
+                milestone_index,
+                amount_released,
+                remaining_balance,
+            },
+        )
+    }
+
     #[event("claimLaunchpadTokens")]
     fn claim_launchpad_tokens_event(
         &self,
@@ -224,4 +323,31 @@ pub trait EventsModule {
             Self::Api,
         >,
     );
+
+    #[event("energyBonusGranted")]
+    fn energy_bonus_granted_event(
+        &self,
+        #[indexed] user: ManagedAddress,
+        #[indexed] round: u64,
+        #[indexed] epoch: u64,
+        energy_bonus_granted_event: EnergyBonusGrantedEvent<Self::Api>,
+    );
+
+    #[event("earlyExit")]
+    fn early_exit_event(
+        &self,
+        #[indexed] user: ManagedAddress,
+        #[indexed] round: u64,
+        #[indexed] epoch: u64,
+        early_exit_event: EarlyExitEvent<Self::Api>,
+    );
+
+    #[event("vestedClaim")]
+    fn vested_claim_event(
+        &self,
+        #[indexed] user: ManagedAddress,
+        #[indexed] round: u64,
+        #[indexed] epoch: u64,
+        vested_claim_event: VestedClaimEvent<Self::Api>,
+    );
 }
@@ -4,7 +4,7 @@ multiversx_sc::derive_imports!();
 use launchpad_common::{
     ongoing_operation::{CONTINUE_OP, STOP_OP},
     random::Random,
-    tickets::{TicketRange, WINNING_TICKET},
+    tickets::{TicketId, TicketRange, WINNING_TICKET},
 };
 use multiversx_sc::api::CryptoApi;
 
@@ -148,9 +148,9 @@ pub trait GuaranteedTicketWinnersModule:
         let mut current_ticket = ticket_range.first_id;
 
         while remaining_tickets > 0 && current_ticket <= ticket_range.last_id {
-            let is_winning_ticket = self.ticket_status(current_ticket).get();
+            let is_winning_ticket = self.get_ticket_status(current_ticket);
             if !is_winning_ticket {
-                self.ticket_status(current_ticket).set(WINNING_TICKET);
+                self.set_ticket_status(current_ticket, WINNING_TICKET);
                 op.total_additional_winning_tickets += 1;
                 remaining_tickets -= 1;
             }
@@ -180,9 +180,14 @@ pub trait GuaranteedTicketWinnersModule:
         &self,
         nr_original_winning_tickets: usize,
         op: &mut GuaranteedTicketsSelectionOperation<Self::Api>,
-        last_ticket_pos: usize,
+        last_ticket_pos: TicketId,
     ) -> bool {
-        if nr_original_winning_tickets + op.total_additional_winning_tickets >= last_ticket_pos {
+        // renounced tickets may not be selected, so there can be fewer tickets left than expected
+        if (nr_original_winning_tickets + op.total_additional_winning_tickets) as TicketId
+            >= last_ticket_pos
+            || (nr_original_winning_tickets + op.leftover_ticket_pos_offset) as TicketId
+                > last_ticket_pos
+        {
             op.leftover_tickets = 0;
         }
 
@@ -193,9 +198,10 @@ pub trait GuaranteedTicketWinnersModule:
         &self,
         op: &mut GuaranteedTicketsSelectionOperation<Self::Api>,
         nr_original_winning_tickets: usize,
-        last_ticket_pos: usize,
+        last_ticket_pos: TicketId,
     ) -> bool {
-        let current_ticket_pos = nr_original_winning_tickets + op.leftover_ticket_pos_offset;
+        let current_ticket_pos =
+            (nr_original_winning_tickets + op.leftover_ticket_pos_offset) as TicketId;
 
         let selection_result =
             self.try_select_winning_ticket(&mut op.rng, current_ticket_pos, last_ticket_pos);
@@ -226,7 +232,7 @@ pub trait GuaranteedTicketWinnersModule:
     fn winning_tickets_in_range(&self, ticket_range: &TicketRange) -> usize {
         let mut winning_tickets_no = 0;
         for ticket_id in ticket_range.first_id..=ticket_range.last_id {
-            let ticket_status = self.ticket_status(ticket_id).get();
+            let ticket_status = self.get_ticket_status(ticket_id);
             if ticket_status == WINNING_TICKET {
                 winning_tickets_no += 1;
             }
@@ -238,17 +244,18 @@ pub trait GuaranteedTicketWinnersModule:
     fn try_select_winning_ticket(
         &self,
         rng: &mut Random<Self::Api>,
-        current_ticket_position: usize,
-        last_ticket_position: usize,
+        current_ticket_position: TicketId,
+        last_ticket_position: TicketId,
     ) -> AdditionalSelectionTryResult {
         let current_ticket_id = self.get_ticket_id_from_pos(current_ticket_position);
-        if self.is_already_winning_ticket(current_ticket_id) {
+        if self.is_unavailable_ticket(current_ticket_id) {
             return AdditionalSelectionTryResult::CurrentAlreadyWinning;
         }
 
-        let rand_pos = rng.next_usize_in_range(current_ticket_position, last_ticket_position + 1);
+        let rand_pos =
+            rng.next_ticket_id_in_range(current_ticket_position, last_ticket_position + 1);
         let selected_ticket_id = self.get_ticket_id_from_pos(rand_pos);
-        if self.is_already_winning_ticket(selected_ticket_id) {
+        if self.is_unavailable_ticket(selected_ticket_id) {
             // Swap tickets positions so that the current position still has a chance in future selections
             self.ticket_pos_to_id(current_ticket_position)
                 .set(selected_ticket_id);
@@ -258,13 +265,21 @@ pub trait GuaranteedTicketWinnersModule:
         }
 
         self.ticket_pos_to_id(rand_pos).set(current_ticket_id);
-        self.ticket_status(selected_ticket_id).set(WINNING_TICKET);
+        self.set_ticket_status(selected_ticket_id, WINNING_TICKET);
 
         AdditionalSelectionTryResult::Ok
     }
 
     #[inline]
-    fn is_already_winning_ticket(&self, ticket_id: usize) -> bool {
-        self.ticket_status(ticket_id).get() == WINNING_TICKET
+    fn is_already_winning_ticket(&self, ticket_id: TicketId) -> bool {
+        self.get_ticket_status(ticket_id) == WINNING_TICKET
     }
+
+    #[inline]
+    fn is_unavailable_ticket(&self, ticket_id: TicketId) -> bool {
+        self.is_already_winning_ticket(ticket_id) || self.renounced_ticket(ticket_id).get()
+    }
+
+    #[storage_mapper("renouncedTicket")]
+    fn renounced_ticket(&self, ticket_id: TicketId) -> SingleValueMapper<bool>;
 }
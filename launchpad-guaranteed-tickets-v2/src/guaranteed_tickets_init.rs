@@ -25,6 +25,14 @@ impl<M: ManagedTypeApi> UserTicketsStatus<M> {
     }
 }
 
+/// Holders of the collection may register during the add tickets period
+#[derive(TopEncode, TopDecode, TypeAbi)]
+pub struct NftHolderTicketsConfig<M: ManagedTypeApi> {
+    pub collection: TokenIdentifier<M>,
+    pub total_tickets_allowance: usize,
+    pub guaranteed_tickets: usize,
+}
+
 pub struct AddTicketsResult {
     pub total_users_count: usize,
     pub total_tickets_added: usize,
@@ -37,7 +45,106 @@ pub trait GuaranteedTicketsInitModule:
     + launchpad_common::config::ConfigModule
     + launchpad_common::ongoing_operation::OngoingOperationModule
     + launchpad_common::tickets::TicketsModule
+    + crate::events::EventsModule
 {
+    #[only_owner]
+    #[endpoint(setNftHolderTicketsConfig)]
+    fn set_nft_holder_tickets_config(
+        &self,
+        collection: TokenIdentifier,
+        total_tickets_allowance: usize,
+        guaranteed_tickets: usize,
+    ) {
+        self.require_add_tickets_period();
+        require!(collection.is_valid_esdt_identifier(), "Invalid collection");
+        require!(
+            guaranteed_tickets > 0
+                && guaranteed_tickets <= total_tickets_allowance
+                && total_tickets_allowance <= MAX_TICKETS_ALLOWANCE,
+            "Invalid NFT holder tickets"
+        );
+
+        self.nft_holder_tickets_config()
+            .set(NftHolderTicketsConfig {
+                collection,
+                total_tickets_allowance,
+                guaranteed_tickets,
+            });
+    }
+
+    /// The NFT is only used as proof of holding, and is sent back right away.
+    /// Users which were already added get the guaranteed tickets on top of their own,
+    /// within their existing allowance. Others are added with the configured allowance.
+    #[payable("*")]
+    #[endpoint(registerNftHolder)]
+    fn register_nft_holder(&self) {
+        self.require_add_tickets_period();
+        self.require_add_tickets_not_finalized();
+
+        let config_mapper = self.nft_holder_tickets_config();
+        require!(
+            !config_mapper.is_empty(),
+            "NFT holder tickets not configured"
+        );
+
+        let config = config_mapper.get();
+        let payment = self.call_value().single_esdt();
+        require!(
+            payment.token_identifier == config.collection && payment.token_nonce > 0,
+            "Invalid NFT"
+        );
+
+        let used_nft_mapper = self.nft_used_for_registration(payment.token_nonce);
+        require!(!used_nft_mapper.get(), "NFT already used for registration");
+
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.registered_nft_holders().insert(caller.clone()),
+            "Already registered as NFT holder"
+        );
+        used_nft_mapper.set(true);
+
+        let mut total_winning_tickets = self.nr_winning_tickets().get();
+        let (tickets_added, guaranteed_tickets_added) =
+            if self.ticket_range_for_address(&caller).is_empty() {
+                let mut guaranteed_ticket_infos = ManagedVec::new();
+                guaranteed_ticket_infos.push(GuaranteedTicketInfo {
+                    guaranteed_tickets: config.guaranteed_tickets,
+                    min_confirmed_tickets: config.guaranteed_tickets,
+                });
+
+                let user_guaranteed_tickets = self.add_user_tickets(
+                    caller.clone(),
+                    config.total_tickets_allowance,
+                    guaranteed_ticket_infos,
+                    &mut total_winning_tickets,
+                );
+
+                (config.total_tickets_allowance, user_guaranteed_tickets)
+            } else {
+                let user_guaranteed_tickets = self.add_guaranteed_tickets_to_existing_user(
+                    &caller,
+                    config.guaranteed_tickets,
+                    &mut total_winning_tickets,
+                );
+
+                (0, user_guaranteed_tickets)
+            };
+
+        self.nr_winning_tickets().set(total_winning_tickets);
+        self.total_guaranteed_tickets()
+            .update(|total| *total += guaranteed_tickets_added);
+
+        self.send().direct_esdt(
+            &caller,
+            &payment.token_identifier,
+            payment.token_nonce,
+            &payment.amount,
+        );
+
+        self.emit_add_tickets_event(1, tickets_added, guaranteed_tickets_added);
+    }
+
     fn add_tickets_with_guaranteed_winners(
         &self,
         address_number_pairs: MultiValueEncoded<
@@ -46,7 +153,6 @@ pub trait GuaranteedTicketsInitModule:
     ) -> AddTicketsResult {
         self.require_add_tickets_period();
 
-        let mut guaranteed_ticket_whitelist = self.users_with_guaranteed_ticket();
         let mut total_winning_tickets = self.nr_winning_tickets().get();
         let mut total_guaranteed_tickets = self.total_guaranteed_tickets().get();
 
@@ -60,56 +166,30 @@ pub trait GuaranteedTicketsInitModule:
                 continue;
             }
 
-            require!(
-                !self.blockchain().is_smart_contract(&buyer),
-                "Only user accounts can participate"
-            );
-            require!(
-                total_tickets_allowance <= MAX_TICKETS_ALLOWANCE,
-                "Total number of tickets exceeds maximum allowed"
-            );
             require!(
                 guaranteed_ticket_raw.len() <= MAX_GUARANTEED_TICKETS_ENTRIES,
                 "Number of guaranteed tickets entries exceeds maximum allowed"
             );
 
-            self.try_create_tickets(buyer.clone(), total_tickets_allowance);
-
-            let mut user_ticket_status = UserTicketsStatus::new(total_tickets_allowance);
-
-            let mut user_guaranteed_tickets = 0;
-
             let mut guaranteed_ticket_infos = ManagedVec::new();
             for info in guaranteed_ticket_raw.into_iter() {
                 let (guaranteed_tickets, min_confirmed_tickets) = info.into_tuple();
-                require!(
-                    guaranteed_tickets <= min_confirmed_tickets,
-                    "Invalid guaranteed ticket min confirmed tickets"
-                );
-                user_guaranteed_tickets += guaranteed_tickets;
-
-                let guaranteed_ticket_info = GuaranteedTicketInfo {
+                guaranteed_ticket_infos.push(GuaranteedTicketInfo {
                     guaranteed_tickets,
                     min_confirmed_tickets,
-                };
-                guaranteed_ticket_infos.push(guaranteed_ticket_info);
+                });
             }
 
-            if user_guaranteed_tickets > 0 {
-                require!(
-                    total_winning_tickets >= user_guaranteed_tickets,
-                    "Not enough winning tickets for guaranteed allocation"
-                );
-                let _ = guaranteed_ticket_whitelist.insert(buyer.clone());
-                total_winning_tickets -= user_guaranteed_tickets;
-                total_guaranteed_tickets += user_guaranteed_tickets;
-                user_ticket_status.guaranteed_tickets_info = guaranteed_ticket_infos;
-                total_guaranteed_tickets_added += user_guaranteed_tickets;
-            }
+            let user_guaranteed_tickets = self.add_user_tickets(
+                buyer,
+                total_tickets_allowance,
+                guaranteed_ticket_infos,
+                &mut total_winning_tickets,
+            );
+            total_guaranteed_tickets += user_guaranteed_tickets;
+            total_guaranteed_tickets_added += user_guaranteed_tickets;
             total_tickets_added += total_tickets_allowance;
-
             total_users_count += 1;
-            self.user_ticket_status(&buyer).set(user_ticket_status);
         }
 
         self.total_guaranteed_tickets()
@@ -123,6 +203,95 @@ pub trait GuaranteedTicketsInitModule:
         }
     }
 
+    /// Returns the number of guaranteed tickets reserved for the user
+    fn add_user_tickets(
+        &self,
+        buyer: ManagedAddress,
+        total_tickets_allowance: usize,
+        guaranteed_ticket_infos: ManagedVec<GuaranteedTicketInfo>,
+        total_winning_tickets: &mut usize,
+    ) -> usize {
+        require!(
+            !self.blockchain().is_smart_contract(&buyer),
+            "Only user accounts can participate"
+        );
+        require!(
+            total_tickets_allowance <= MAX_TICKETS_ALLOWANCE,
+            "Total number of tickets exceeds maximum allowed"
+        );
+
+        self.try_create_tickets(buyer.clone(), total_tickets_allowance);
+
+        let mut user_ticket_status = UserTicketsStatus::new(total_tickets_allowance);
+
+        let mut user_guaranteed_tickets = 0;
+        for info in guaranteed_ticket_infos.iter() {
+            require!(
+                info.guaranteed_tickets <= info.min_confirmed_tickets,
+                "Invalid guaranteed ticket min confirmed tickets"
+            );
+            user_guaranteed_tickets += info.guaranteed_tickets;
+        }
+
+        if user_guaranteed_tickets > 0 {
+            require!(
+                *total_winning_tickets >= user_guaranteed_tickets,
+                "Not enough winning tickets for guaranteed allocation"
+            );
+            let _ = self.users_with_guaranteed_ticket().insert(buyer.clone());
+            *total_winning_tickets -= user_guaranteed_tickets;
+            user_ticket_status.guaranteed_tickets_info = guaranteed_ticket_infos;
+        }
+
+        self.user_ticket_status(&buyer).set(user_ticket_status);
+
+        user_guaranteed_tickets
+    }
+
+    /// The new entry requires confirming all of the user's guaranteed tickets,
+    /// so the guaranteed tickets never exceed the confirmed ones
+    fn add_guaranteed_tickets_to_existing_user(
+        &self,
+        user: &ManagedAddress,
+        guaranteed_tickets: usize,
+        total_winning_tickets: &mut usize,
+    ) -> usize {
+        let user_ticket_status_mapper = self.user_ticket_status(user);
+        require!(!user_ticket_status_mapper.is_empty(), "User is blacklisted");
+
+        let mut user_ticket_status = user_ticket_status_mapper.get();
+        require!(
+            user_ticket_status.guaranteed_tickets_info.len() < MAX_GUARANTEED_TICKETS_ENTRIES,
+            "Number of guaranteed tickets entries exceeds maximum allowed"
+        );
+
+        let current_guaranteed_tickets = user_ticket_status
+            .guaranteed_tickets_info
+            .iter()
+            .fold(0, |acc, info| acc + info.guaranteed_tickets);
+        let available_tickets = user_ticket_status
+            .total_tickets_allowance
+            .saturating_sub(current_guaranteed_tickets);
+        let guaranteed_tickets = core::cmp::min(guaranteed_tickets, available_tickets);
+        require!(guaranteed_tickets > 0, "No tickets left to guarantee");
+        require!(
+            *total_winning_tickets >= guaranteed_tickets,
+            "Not enough winning tickets for guaranteed allocation"
+        );
+
+        *total_winning_tickets -= guaranteed_tickets;
+        user_ticket_status
+            .guaranteed_tickets_info
+            .push(GuaranteedTicketInfo {
+                guaranteed_tickets,
+                min_confirmed_tickets: current_guaranteed_tickets + guaranteed_tickets,
+            });
+        user_ticket_status_mapper.set(user_ticket_status);
+        let _ = self.users_with_guaranteed_ticket().insert(user.clone());
+
+        guaranteed_tickets
+    }
+
     fn clear_users_with_guaranteed_ticket_after_blacklist(
         &self,
         users: &ManagedVec<ManagedAddress>,
@@ -183,6 +352,38 @@ pub trait GuaranteedTicketsInitModule:
             .set(total_guaranteed_tickets);
     }
 
+    fn migrate_guaranteed_tickets(
+        &self,
+        old_address: &ManagedAddress,
+        new_address: &ManagedAddress,
+    ) {
+        let mut whitelist = self.users_with_guaranteed_ticket();
+        if whitelist.swap_remove(old_address) {
+            let _ = whitelist.insert(new_address.clone());
+        }
+
+        let user_ticket_status_mapper = self.user_ticket_status(old_address);
+        if !user_ticket_status_mapper.is_empty() {
+            self.user_ticket_status(new_address)
+                .set(user_ticket_status_mapper.take());
+        }
+    }
+
+    #[view(getNftHolderTicketsConfig)]
+    #[storage_mapper("nftHolderTicketsConfig")]
+    fn nft_holder_tickets_config(&self) -> SingleValueMapper<NftHolderTicketsConfig<Self::Api>>;
+
+    #[view(isRegisteredNftHolder)]
+    fn is_registered_nft_holder(&self, address: ManagedAddress) -> bool {
+        self.registered_nft_holders().contains(&address)
+    }
+
+    #[storage_mapper("registeredNftHolders")]
+    fn registered_nft_holders(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[storage_mapper("nftUsedForRegistration")]
+    fn nft_used_for_registration(&self, nft_nonce: u64) -> SingleValueMapper<bool>;
+
     #[storage_mapper("usersWithGuaranteedTicket")]
     fn users_with_guaranteed_ticket(&self) -> UnorderedSetMapper<ManagedAddress>;
 
@@ -3,14 +3,22 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
-use launchpad_common::{config::TokenAmountPair, launch_stage::Flags, tickets::WINNING_TICKET};
+use launchpad_common::{
+    action_pause::PausableAction, blacklist::BlacklistReason, config::TokenAmountPair,
+    launch_stage::Flags, permissions::Role, tickets::WINNING_TICKET, LaunchConfiguration,
+    UserFullStatus, TICKETS_LAYOUT_STORAGE_VERSION,
+};
 
 use crate::guaranteed_ticket_winners::GuaranteedTicketsSelectionOperation;
 
+pub mod early_exit;
+pub mod energy_bonus;
 pub mod events;
 pub mod guaranteed_ticket_winners;
 pub mod guaranteed_tickets_init;
+pub mod staking_snapshot;
 pub mod token_release;
+pub mod vesting_position;
 
 use crate::guaranteed_tickets_init::GuaranteedTicketInfo;
 
@@ -21,19 +29,51 @@ pub trait LaunchpadGuaranteedTickets:
     launchpad_common::LaunchpadMain
     + launchpad_common::launch_stage::LaunchStageModule
     + launchpad_common::config::ConfigModule
+    + launchpad_common::time_provider::TimeProviderModule
     + launchpad_common::setup::SetupModule
+    + launchpad_common::admin_proposal::AdminProposalModule
     + launchpad_common::tickets::TicketsModule
+    + launchpad_common::tickets_layout_migration::TicketsLayoutMigrationModule
     + launchpad_common::winner_selection::WinnerSelectionModule
+    + launchpad_common::commit_reveal::CommitRevealModule
     + launchpad_common::ongoing_operation::OngoingOperationModule
     + launchpad_common::permissions::PermissionsModule
+    + launchpad_common::timelock::TimelockModule
+    + launchpad_common::guardian::GuardianModule
     + launchpad_common::blacklist::BlacklistModule
     + launchpad_common::token_send::TokenSendModule
     + launchpad_common::user_interactions::UserInteractionsModule
+    + launchpad_common::consolation_airdrop::ConsolationAirdropModule
+    + launchpad_common::participation_badge::ParticipationBadgeModule
+    + launchpad_common::loyalty::LoyaltyModule
+    + launchpad_common::referral::ReferralModule
+    + launchpad_common::kyc::KycModule
+    + launchpad_common::signature_eligibility::SignatureEligibilityModule
+    + launchpad_common::relayer::RelayerModule
+    + launchpad_common::keeper_incentives::KeeperIncentivesModule
+    + launchpad_common::governance::GovernanceModule
+    + launchpad_common::price_oracle::PriceOracleModule
+    + launchpad_common::swap_and_confirm::SwapAndConfirmModule
+    + launchpad_common::locked_token_payment::LockedTokenPaymentModule
+    + launchpad_common::reward_bundle::RewardBundleModule
+    + launchpad_common::token_rescue::TokenRescueModule
+    + launchpad_common::unclaimed_sweep::UnclaimedSweepModule
+    + launchpad_common::storage_cleanup::StorageCleanupModule
+    + launchpad_common::invariants::InvariantsModule
+    + launchpad_common::winners_hash::WinnersHashModule
+    + launchpad_common::tiers::TiersModule
+    + launchpad_common::action_pause::ActionPauseModule
+    + launchpad_common::wallet_migration::WalletMigrationModule
     + guaranteed_tickets_init::GuaranteedTicketsInitModule
+    + staking_snapshot::StakingSnapshotModule
     + guaranteed_ticket_winners::GuaranteedTicketWinnersModule
     + token_release::TokenReleaseModule
+    + vesting_position::VestingPositionModule
+    + energy_bonus::EnergyBonusModule
+    + early_exit::EarlyExitModule
     + events::EventsModule
     + launchpad_common::common_events::CommonEventsModule
+    + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
     + multiversx_sc_modules::pause::PauseModule
 {
     #[allow(clippy::too_many_arguments)]
@@ -63,7 +103,15 @@ pub trait LaunchpadGuaranteedTickets:
     }
 
     #[upgrade]
-    fn upgrade(&self) {}
+    fn upgrade(&self) {
+        self.upgrade_base(Self::migrate_storage);
+    }
+
+    fn migrate_storage(&self, previous_version: u32) {
+        if previous_version < TICKETS_LAYOUT_STORAGE_VERSION {
+            self.migrate_legacy_renounced_tickets();
+        }
+    }
 
     /// The parameter `address_number_pairs` is a list of tuples, where each tuple contains:
     /// the address of the user, total number of tickets to be added, and a list of guaranteed tickets
@@ -98,14 +146,18 @@ pub trait LaunchpadGuaranteedTickets:
     #[endpoint(refundUserTickets)]
     fn refund_user_tickets(&self, users_list: MultiValueEncoded<ManagedAddress>) {
         let users_vec = users_list.to_vec();
-        self.add_users_to_blacklist(&users_vec);
+        self.add_users_to_blacklist(&users_vec, BlacklistReason::RefundRequested);
         self.clear_users_with_guaranteed_ticket_after_blacklist(&users_vec);
     }
 
     #[endpoint(addUsersToBlacklist)]
-    fn add_users_to_blacklist_endpoint(&self, users_list: MultiValueEncoded<ManagedAddress>) {
+    fn add_users_to_blacklist_endpoint(
+        &self,
+        reason: BlacklistReason,
+        users_list: MultiValueEncoded<ManagedAddress>,
+    ) {
         let users_vec = users_list.to_vec();
-        self.add_users_to_blacklist(&users_vec);
+        self.add_users_to_blacklist(&users_vec, reason);
         self.clear_users_with_guaranteed_ticket_after_blacklist(&users_vec);
 
         self.emit_add_users_to_blacklist_event(users_vec);
@@ -123,6 +175,13 @@ pub trait LaunchpadGuaranteedTickets:
         self.emit_remove_guaranteed_users_from_blacklist_event(users_vec);
     }
 
+    /// Single entry point for the whole winner selection, which may be called repeatedly
+    /// until it returns `completed`
+    #[endpoint(completeWinnerSelection)]
+    fn complete_winner_selection_endpoint(&self) -> OperationCompletionStatus {
+        self.complete_winner_selection(Self::distribute_guaranteed_tickets_endpoint)
+    }
+
     #[endpoint(distributeGuaranteedTickets)]
     fn distribute_guaranteed_tickets_endpoint(&self) -> OperationCompletionStatus {
         self.require_not_paused();
@@ -179,21 +238,40 @@ pub trait LaunchpadGuaranteedTickets:
     }
 
     #[endpoint(claimLaunchpadTokens)]
-    fn claim_launchpad_tokens_endpoint(&self) {
+    fn claim_launchpad_tokens_endpoint(&self, opt_receiver: OptionalValue<ManagedAddress>) {
         self.require_not_paused();
+        self.require_action_not_paused(PausableAction::Claims);
+        self.require_before_claim_deadline();
         let caller = self.blockchain().get_caller();
+        self.require_caller_not_denied_contract(&caller);
+        let receiver = self.get_claim_receiver(&caller, opt_receiver);
         let user_results_processed = self.claim_list().contains(&caller);
         if !user_results_processed {
-            self.compute_launchpad_results(&caller);
+            self.compute_launchpad_results(&caller, &receiver);
         };
 
         let claimable_tokens = self.compute_claimable_tokens(&caller);
         if claimable_tokens > 0 {
-            let launchpad_token_id = self.launchpad_token_id().get();
-            self.send()
-                .direct_esdt(&caller, &launchpad_token_id, 0, &claimable_tokens);
             self.user_claimed_balance(&caller)
                 .update(|balance| *balance += &claimable_tokens);
+            self.total_tokens_claimed()
+                .update(|total| *total += &claimable_tokens);
+        }
+
+        self.update_user_vesting_status(&caller);
+
+        if claimable_tokens > 0 {
+            let launchpad_token_id = self.launchpad_token_id().get();
+            self.send()
+                .direct_esdt(&receiver, &launchpad_token_id, 0, &claimable_tokens);
+
+            let remaining_balance = self.user_total_claimable_balance(&caller).get()
+                - self.user_claimed_balance(&caller).get();
+            self.emit_vested_claim(
+                &self.get_effective_unlock_schedule(&caller),
+                claimable_tokens.clone(),
+                remaining_balance,
+            );
 
             self.emit_claim_launchpad_tokens_event(EsdtTokenPayment::new(
                 launchpad_token_id,
@@ -201,9 +279,11 @@ pub trait LaunchpadGuaranteedTickets:
                 claimable_tokens,
             ));
         }
+
+        self.convert_to_vesting_position(&caller, &receiver);
     }
 
-    fn compute_launchpad_results(&self, caller: &ManagedAddress) {
+    fn compute_launchpad_results(&self, caller: &ManagedAddress, receiver: &ManagedAddress) {
         self.require_claim_period();
 
         let ticket_range = self.try_get_ticket_range(caller);
@@ -211,9 +291,9 @@ pub trait LaunchpadGuaranteedTickets:
         let mut nr_redeemable_tickets = 0;
 
         for ticket_id in ticket_range.first_id..=ticket_range.last_id {
-            let ticket_status = self.ticket_status(ticket_id).get();
+            let ticket_status = self.get_ticket_status(ticket_id);
             if ticket_status == WINNING_TICKET {
-                self.ticket_status(ticket_id).clear();
+                self.clear_ticket_status(ticket_id);
 
                 nr_redeemable_tickets += 1;
             }
@@ -230,11 +310,16 @@ pub trait LaunchpadGuaranteedTickets:
                 .update(|nr_winning_tickets| *nr_winning_tickets -= nr_redeemable_tickets);
         }
 
-        self.claim_list().add(caller);
+        self.mark_user_claimed(caller, nr_redeemable_tickets);
 
         let nr_tickets_to_refund = nr_confirmed_tickets - nr_redeemable_tickets;
-        self.refund_ticket_payment(caller, nr_tickets_to_refund);
-
+        let ticket_refund = self.release_user_tickets(caller, nr_tickets_to_refund);
+        let locked_winning_payments = self.release_locked_winning_tickets(caller);
+        self.settle_user_ticket_payment(caller);
+        let nr_consolation_tickets =
+            nr_tickets_to_refund + self.user_consolation_tickets(caller).take();
+        self.accrue_loyalty_points(caller, nr_confirmed_tickets, nr_redeemable_tickets);
+        self.credit_referral_rewards(caller, nr_redeemable_tickets);
         if nr_redeemable_tickets > 0 {
             let tokens_per_winning_ticket = self.launchpad_tokens_per_winning_ticket().get();
             let launchpad_tokens_amount_won =
@@ -243,23 +328,42 @@ pub trait LaunchpadGuaranteedTickets:
             self.user_total_claimable_balance(caller)
                 .set(launchpad_tokens_amount_won);
         }
+
+        // the results are fully recorded before any transfer
+        self.send_ticket_refund(receiver, &ticket_refund);
+        self.send_to_treasury(&locked_winning_payments);
+        self.send_consolation_tokens(receiver, nr_consolation_tickets);
+        if nr_confirmed_tickets > 0 {
+            self.send_participation_badge(receiver);
+        }
+        self.send_extra_rewards(receiver, nr_redeemable_tickets);
+    }
+
+    /// Vesting while part of the user's launchpad tokens are still locked
+    fn update_user_vesting_status(&self, address: &ManagedAddress) {
+        let user_total_claimable_balance = self.user_total_claimable_balance(address).get();
+        if user_total_claimable_balance == 0 {
+            return;
+        }
+
+        let has_locked_tokens =
+            self.user_claimed_balance(address).get() < user_total_claimable_balance;
+        self.update_vesting_claim_status(address, has_locked_tokens);
     }
 
-    #[only_owner]
     #[endpoint(claimTicketPayment)]
     fn claim_ticket_payment_endpoint(&self) {
+        self.require_role(Role::Finance);
         self.require_claim_period();
 
-        let owner = self.blockchain().get_caller();
+        let owner = self.blockchain().get_owner_address();
 
         let ticket_price: TokenAmountPair<Self::Api> = self.ticket_price().get();
-        let ticket_payment_mapper = self.claimable_ticket_payment();
-        let claimable_ticket_payment = ticket_payment_mapper.get();
-        if claimable_ticket_payment > 0 {
-            ticket_payment_mapper.clear();
-
+        let claimable_ticket_payment = self.claimable_ticket_payment().get();
+        let ticket_payment_amount = self.take_claimable_ticket_payment();
+        if ticket_payment_amount > 0 {
             self.send()
-                .direct(&owner, &ticket_price.token_id, 0, &claimable_ticket_payment);
+                .direct(&owner, &ticket_price.token_id, 0, &ticket_payment_amount);
         }
 
         let deposited_tokens_mapper = self.total_launchpad_tokens_deposited();
@@ -271,7 +375,7 @@ pub trait LaunchpadGuaranteedTickets:
         let amount_per_ticket = self.launchpad_tokens_per_winning_ticket().get();
         let total_nr_winning_tickets = claimable_ticket_payment / ticket_price.amount;
 
-        let total_launchpad_tokens_won = total_nr_winning_tickets * amount_per_ticket;
+        let total_launchpad_tokens_won = total_nr_winning_tickets * &amount_per_ticket;
         if total_launchpad_tokens_won >= total_launchpad_tokens_deposited {
             return;
         }
@@ -279,11 +383,36 @@ pub trait LaunchpadGuaranteedTickets:
         let launchpad_token_id = self.launchpad_token_id().get();
         let extra_launchpad_tokens = total_launchpad_tokens_deposited - total_launchpad_tokens_won;
         if extra_launchpad_tokens > 0 {
-            self.send()
-                .direct_esdt(&owner, &launchpad_token_id, 0, &extra_launchpad_tokens);
+            let nr_extra_tickets = &extra_launchpad_tokens / &amount_per_ticket;
+            self.send_extra_rewards(
+                &owner,
+                nr_extra_tickets.to_u64().unwrap_or_default() as usize,
+            );
+            self.send_extra_launchpad_tokens(&owner, launchpad_token_id, extra_launchpad_tokens);
         }
     }
 
+    #[endpoint(rescueTokens)]
+    fn rescue_tokens_endpoint(&self, token_id: EgldOrEsdtTokenIdentifier, token_nonce: u64) {
+        self.require_role(Role::Finance);
+        self.rescue_tokens(token_id, token_nonce);
+    }
+
+    #[endpoint(executePendingChange)]
+    fn execute_pending_change_endpoint(&self, change_id: usize) {
+        self.execute_pending_change(change_id, Self::apply_unlock_schedule_change);
+    }
+
+    #[endpoint(executeAdminProposal)]
+    fn execute_admin_proposal_endpoint(&self, proposal_id: usize) {
+        self.execute_admin_proposal(proposal_id, Self::apply_unlock_schedule_change);
+    }
+
+    #[endpoint(approveWalletMigration)]
+    fn approve_wallet_migration_endpoint(&self, old_address: ManagedAddress) {
+        self.approve_wallet_migration(old_address, Self::migrate_guaranteed_tickets);
+    }
+
     #[view(getUserTicketsStatus)]
     fn user_tickets_status(&self, address: ManagedAddress) -> UserTicketsStatus<Self::Api> {
         let user_ticket_status_mapper = self.user_ticket_status(&address);
@@ -296,4 +425,30 @@ pub trait LaunchpadGuaranteedTickets:
         )
             .into()
     }
+
+    /// The release configuration is the unlock schedule, if set
+    #[view(getConfiguration)]
+    fn get_configuration_view(&self) -> LaunchConfiguration<Self::Api> {
+        let mut release_config = ManagedBuffer::new();
+        let unlock_schedule_mapper = self.unlock_schedule();
+        if !unlock_schedule_mapper.is_empty() {
+            let _ = unlock_schedule_mapper.get().top_encode(&mut release_config);
+        }
+
+        self.get_launch_configuration(release_config)
+    }
+
+    #[view(getUserFullStatus)]
+    fn user_full_status(&self, address: ManagedAddress) -> UserFullStatus<Self::Api> {
+        let user_ticket_status_mapper = self.user_ticket_status(&address);
+        let mut guaranteed_tickets = 0;
+        if !user_ticket_status_mapper.is_empty() {
+            let user_ticket_status = user_ticket_status_mapper.get();
+            for info in user_ticket_status.guaranteed_tickets_info.iter() {
+                guaranteed_tickets += info.guaranteed_tickets;
+            }
+        }
+
+        self.get_user_full_status(address, guaranteed_tickets)
+    }
 }
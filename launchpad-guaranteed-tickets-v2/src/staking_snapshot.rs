@@ -0,0 +1,181 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::guaranteed_tickets_init::GuaranteedTicketInfo;
+
+pub const MAX_STAKE_THRESHOLDS: usize = 10;
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, ManagedVecItem)]
+pub struct StakeThreshold<M: ManagedTypeApi> {
+    pub min_stake: BigUint<M>,
+    pub total_tickets_allowance: usize,
+    pub guaranteed_tickets: usize,
+}
+
+#[multiversx_sc::module]
+pub trait StakingSnapshotModule:
+    launchpad_common::launch_stage::LaunchStageModule
+    + launchpad_common::config::ConfigModule
+    + launchpad_common::ongoing_operation::OngoingOperationModule
+    + launchpad_common::tickets::TicketsModule
+    + launchpad_common::permissions::PermissionsModule
+    + launchpad_common::action_pause::ActionPauseModule
+    + crate::guaranteed_tickets_init::GuaranteedTicketsInitModule
+    + crate::events::EventsModule
+{
+    /// Thresholds are given as (min_stake, total_tickets_allowance, guaranteed_tickets) tuples,
+    /// in ascending order of stake. Can't be changed once the snapshot import has started.
+    #[endpoint(setStakeThresholds)]
+    fn set_stake_thresholds(
+        &self,
+        thresholds: MultiValueEncoded<MultiValue3<BigUint, usize, usize>>,
+    ) {
+        self.require_config_manager();
+        self.require_add_tickets_period();
+        require!(
+            self.snapshot_imported_users().get() == 0,
+            "Snapshot import already started"
+        );
+        require!(
+            thresholds.len() <= MAX_STAKE_THRESHOLDS,
+            "Maximum stake thresholds exceeded"
+        );
+
+        let mut stake_thresholds = ManagedVec::new();
+        let mut last_min_stake = BigUint::zero();
+        let mut last_total_tickets_allowance = 0;
+        for threshold in thresholds {
+            let (min_stake, total_tickets_allowance, guaranteed_tickets) = threshold.into_tuple();
+            require!(
+                min_stake > last_min_stake
+                    && total_tickets_allowance > last_total_tickets_allowance
+                    && guaranteed_tickets <= total_tickets_allowance,
+                "Invalid stake threshold"
+            );
+
+            last_min_stake = min_stake.clone();
+            last_total_tickets_allowance = total_tickets_allowance;
+            stake_thresholds.push(StakeThreshold {
+                min_stake,
+                total_tickets_allowance,
+                guaranteed_tickets,
+            });
+        }
+
+        self.stake_thresholds().set(stake_thresholds);
+    }
+
+    /// The snapshot can be imported over multiple transactions.
+    /// Already imported addresses are skipped, so a batch can safely be re-sent.
+    #[endpoint(importStakingSnapshot)]
+    fn import_staking_snapshot(
+        &self,
+        snapshot_entries: MultiValueEncoded<MultiValue2<ManagedAddress, BigUint>>,
+    ) {
+        self.require_config_manager();
+        self.require_add_tickets_period();
+        require!(
+            !self.stake_thresholds().is_empty(),
+            "Stake thresholds not set"
+        );
+
+        let stake_thresholds = self.stake_thresholds().get();
+        let mut total_winning_tickets = self.nr_winning_tickets().get();
+        let mut total_guaranteed_tickets = self.total_guaranteed_tickets().get();
+
+        let mut imported_users = 0;
+        let mut total_users_count = 0;
+        let mut total_tickets_added = 0;
+        let mut total_guaranteed_tickets_added = 0;
+        for entry in snapshot_entries {
+            let (user, staked_amount) = entry.into_tuple();
+            let snapshot_stake_mapper = self.snapshot_stake(&user);
+            if staked_amount == 0 || !snapshot_stake_mapper.is_empty() {
+                continue;
+            }
+
+            snapshot_stake_mapper.set(&staked_amount);
+            imported_users += 1;
+
+            let opt_threshold = self.find_stake_threshold(&stake_thresholds, &staked_amount);
+            let threshold = match opt_threshold {
+                Some(threshold) => threshold,
+                None => continue,
+            };
+
+            let mut guaranteed_ticket_infos = ManagedVec::new();
+            if threshold.guaranteed_tickets > 0 {
+                guaranteed_ticket_infos.push(GuaranteedTicketInfo {
+                    guaranteed_tickets: threshold.guaranteed_tickets,
+                    min_confirmed_tickets: threshold.guaranteed_tickets,
+                });
+            }
+
+            let user_guaranteed_tickets = self.add_user_tickets(
+                user,
+                threshold.total_tickets_allowance,
+                guaranteed_ticket_infos,
+                &mut total_winning_tickets,
+            );
+            total_guaranteed_tickets += user_guaranteed_tickets;
+            total_guaranteed_tickets_added += user_guaranteed_tickets;
+            total_tickets_added += threshold.total_tickets_allowance;
+            total_users_count += 1;
+        }
+
+        self.total_guaranteed_tickets()
+            .set(total_guaranteed_tickets);
+        self.nr_winning_tickets().set(total_winning_tickets);
+        self.snapshot_imported_users()
+            .update(|users| *users += imported_users);
+
+        self.emit_add_tickets_event(
+            total_users_count,
+            total_tickets_added,
+            total_guaranteed_tickets_added,
+        );
+    }
+
+    /// Returns the (total_tickets_allowance, guaranteed_tickets) pair for the given stake
+    #[view(getTicketsForStake)]
+    fn get_tickets_for_stake(&self, staked_amount: BigUint) -> MultiValue2<usize, usize> {
+        let stake_thresholds = self.stake_thresholds().get();
+        match self.find_stake_threshold(&stake_thresholds, &staked_amount) {
+            Some(threshold) => (
+                threshold.total_tickets_allowance,
+                threshold.guaranteed_tickets,
+            )
+                .into(),
+            None => (0, 0).into(),
+        }
+    }
+
+    fn find_stake_threshold(
+        &self,
+        stake_thresholds: &ManagedVec<StakeThreshold<Self::Api>>,
+        staked_amount: &BigUint,
+    ) -> Option<StakeThreshold<Self::Api>> {
+        let mut opt_threshold = None;
+        for threshold in stake_thresholds.iter() {
+            if staked_amount < &threshold.min_stake {
+                break;
+            }
+
+            opt_threshold = Some(threshold);
+        }
+
+        opt_threshold
+    }
+
+    #[view(getStakeThresholds)]
+    #[storage_mapper("stakeThresholds")]
+    fn stake_thresholds(&self) -> SingleValueMapper<ManagedVec<StakeThreshold<Self::Api>>>;
+
+    #[view(getSnapshotStake)]
+    #[storage_mapper("snapshotStake")]
+    fn snapshot_stake(&self, user: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    #[view(getSnapshotImportedUsers)]
+    #[storage_mapper("snapshotImportedUsers")]
+    fn snapshot_imported_users(&self) -> SingleValueMapper<usize>;
+}
@@ -1,7 +1,9 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
-use launchpad_common::{config, launch_stage};
+use launchpad_common::{
+    config, guardian::GuardedOperationType, launch_stage, timelock::PendingChangeType,
+};
 
 pub const MAX_PERCENTAGE: u64 = 10_000;
 pub const MAX_UNLOCK_MILESTONES_ENTRIES: usize = 60;
@@ -34,39 +36,127 @@ impl<M: ManagedTypeApi> UnlockSchedule<M> {
         UnlockSchedule { milestones }
     }
 
-    fn validate(&self, current_round: u64) -> bool {
+    fn validate(&self, current_round: u64) -> Result<(), &'static str> {
         if self.milestones.is_empty() {
-            return false;
+            return Err("Empty unlock schedule");
         }
 
         let mut total_percentage = 0u64;
-        let mut last_round = 0u64;
+        let mut opt_last_round = None;
 
         for milestone in self.milestones.iter() {
-            if milestone.percentage > MAX_PERCENTAGE
-                || milestone.release_round < current_round
-                || milestone.release_round < last_round
-                || milestone.release_round > current_round + MAX_RELEASE_ROUND_DIFF
-            {
-                return false;
+            if milestone.percentage > MAX_PERCENTAGE {
+                return Err("Invalid unlock milestone percentage");
+            }
+            if milestone.release_round < current_round {
+                return Err("Unlock milestone release round in the past");
+            }
+            if milestone.release_round > current_round + MAX_RELEASE_ROUND_DIFF {
+                return Err("Unlock milestone release round too far in the future");
+            }
+            if let Some(last_round) = opt_last_round {
+                if milestone.release_round <= last_round {
+                    return Err("Unlock milestones must be in strictly increasing order");
+                }
             }
 
-            last_round = milestone.release_round;
+            opt_last_round = Some(milestone.release_round);
             total_percentage += milestone.percentage;
         }
 
-        total_percentage == MAX_PERCENTAGE
+        if total_percentage != MAX_PERCENTAGE {
+            return Err("Unlock percentages must sum up to 100%");
+        }
+
+        Ok(())
+    }
+
+    pub fn get_nr_unlocked_milestones(&self, current_round: u64) -> usize {
+        self.milestones
+            .iter()
+            .take_while(|milestone| milestone.release_round <= current_round)
+            .count()
+    }
+
+    pub fn get_unlocked_percentage(&self, current_round: u64) -> u64 {
+        let mut unlocked_percentage = 0u64;
+        for milestone in self.milestones.iter() {
+            if milestone.release_round > current_round {
+                break;
+            }
+
+            unlocked_percentage += milestone.percentage;
+        }
+
+        unlocked_percentage
     }
 }
 
 #[multiversx_sc::module]
 pub trait TokenReleaseModule:
-    config::ConfigModule + launch_stage::LaunchStageModule + crate::events::EventsModule
+    config::ConfigModule
+    + launch_stage::LaunchStageModule
+    + launchpad_common::permissions::PermissionsModule
+    + launchpad_common::timelock::TimelockModule
+    + launchpad_common::guardian::GuardianModule
+    + crate::events::EventsModule
 {
     #[only_owner]
     #[endpoint(setUnlockSchedule)]
     fn set_unlock_schedule(&self, unlock_milestones: MultiValueEncoded<MultiValue2<u64, u64>>) {
         self.require_add_tickets_period();
+
+        let unlock_schedule = self.build_unlock_schedule(unlock_milestones);
+        let milestones = unlock_schedule.milestones.clone();
+
+        let mut encoded_data = ManagedBuffer::new();
+        let _ = unlock_schedule.top_encode(&mut encoded_data);
+        self.require_guardian_approval(GuardedOperationType::UnlockSchedule, &encoded_data);
+
+        if self.is_timelock_enabled() {
+            let _ = self.queue_pending_change(PendingChangeType::Custom { encoded_data });
+
+            return;
+        }
+
+        self.unlock_schedule().set(unlock_schedule);
+
+        self.emit_set_unlock_schedule_event(milestones);
+    }
+
+    /// Takes precedence over the global schedule for the given user, e.g. for a longer cliff
+    #[only_owner]
+    #[endpoint(setUserUnlockSchedule)]
+    fn set_user_unlock_schedule(
+        &self,
+        address: ManagedAddress,
+        unlock_milestones: MultiValueEncoded<MultiValue2<u64, u64>>,
+    ) {
+        self.require_add_tickets_period();
+
+        let unlock_schedule = self.build_unlock_schedule(unlock_milestones);
+
+        let mut encoded_data = ManagedBuffer::new();
+        let _ = address.dep_encode(&mut encoded_data);
+        let _ = unlock_schedule.dep_encode(&mut encoded_data);
+        self.require_guardian_approval(GuardedOperationType::UnlockSchedule, &encoded_data);
+
+        self.user_unlock_schedule(&address).set(unlock_schedule);
+    }
+
+    /// The user falls back to the global schedule
+    #[only_owner]
+    #[endpoint(clearUserUnlockSchedule)]
+    fn clear_user_unlock_schedule(&self, address: ManagedAddress) {
+        self.require_add_tickets_period();
+
+        self.user_unlock_schedule(&address).clear();
+    }
+
+    fn build_unlock_schedule(
+        &self,
+        unlock_milestones: MultiValueEncoded<MultiValue2<u64, u64>>,
+    ) -> UnlockSchedule<Self::Api> {
         require!(
             unlock_milestones.len() <= MAX_UNLOCK_MILESTONES_ENTRIES,
             "Maximum unlock milestones entries exceeded"
@@ -81,13 +171,26 @@ pub trait TokenReleaseModule:
             });
         }
 
-        let current_round = self.blockchain().get_block_round();
-        let unlock_schedule = UnlockSchedule::new(milestones.clone());
-        require!(
-            unlock_schedule.validate(current_round),
-            "Invalid unlock schedule"
-        );
+        let current_round = self.get_current_time();
+        let unlock_schedule = UnlockSchedule::new(milestones);
+        if let Err(err_msg) = unlock_schedule.validate(current_round) {
+            sc_panic!(err_msg);
+        }
+
+        unlock_schedule
+    }
+
+    fn apply_unlock_schedule_change(&self, encoded_data: ManagedBuffer) {
+        self.require_add_tickets_period();
 
+        let unlock_schedule: UnlockSchedule<Self::Api> = UnlockSchedule::top_decode(encoded_data)
+            .unwrap_or_else(|_| sc_panic!("Failed to deserialize unlock schedule"));
+        let current_round = self.get_current_time();
+        if let Err(err_msg) = unlock_schedule.validate(current_round) {
+            sc_panic!(err_msg);
+        }
+
+        let milestones = unlock_schedule.milestones.clone();
         self.unlock_schedule().set(unlock_schedule);
 
         self.emit_set_unlock_schedule_event(milestones);
@@ -106,28 +209,76 @@ pub trait TokenReleaseModule:
             "Already claimed all tokens"
         );
 
+        let unlock_schedule = self.get_effective_unlock_schedule(address);
+        let claimable_percentage = unlock_schedule.get_unlocked_percentage(self.get_current_time());
+        let current_claimable_tokens =
+            &user_total_claimable_balance * claimable_percentage / MAX_PERCENTAGE;
+
+        current_claimable_tokens - user_claimed_balance
+    }
+
+    /// The milestone index is the one of the last milestone unlocked so far
+    fn emit_vested_claim(
+        &self,
+        unlock_schedule: &UnlockSchedule<Self::Api>,
+        amount_released: BigUint,
+        remaining_balance: BigUint,
+    ) {
+        let nr_unlocked_milestones =
+            unlock_schedule.get_nr_unlocked_milestones(self.get_current_time());
+        self.emit_vested_claim_event(
+            nr_unlocked_milestones.saturating_sub(1),
+            amount_released,
+            remaining_balance,
+        );
+    }
+
+    /// The user's own schedule if set, otherwise the global one
+    #[view(getEffectiveUnlockSchedule)]
+    fn get_effective_unlock_schedule(&self, address: &ManagedAddress) -> UnlockSchedule<Self::Api> {
+        let user_unlock_schedule_mapper = self.user_unlock_schedule(address);
+        if !user_unlock_schedule_mapper.is_empty() {
+            return user_unlock_schedule_mapper.get();
+        }
+
+        self.get_global_unlock_schedule()
+    }
+
+    fn get_global_unlock_schedule(&self) -> UnlockSchedule<Self::Api> {
         let unlock_schedule_mapper = self.unlock_schedule();
-        let unlock_schedule = if unlock_schedule_mapper.is_empty() {
+        if unlock_schedule_mapper.is_empty() {
             UnlockSchedule::default()
         } else {
             unlock_schedule_mapper.get()
-        };
+        }
+    }
 
-        let current_round = self.blockchain().get_block_round();
+    /// Returns the amount released at each milestone, as (release_round, amount) pairs.
+    /// The amounts always add up to the total, as rounding is done on the cumulative amounts.
+    #[view(previewUnlockAmounts)]
+    fn preview_unlock_amounts(
+        &self,
+        total_amount: BigUint,
+        opt_address: OptionalValue<ManagedAddress>,
+    ) -> MultiValueEncoded<MultiValue2<u64, BigUint>> {
+        let unlock_schedule = match opt_address {
+            OptionalValue::Some(address) => self.get_effective_unlock_schedule(&address),
+            OptionalValue::None => self.get_global_unlock_schedule(),
+        };
 
-        let mut claimable_percentage = 0u64;
+        let mut result = MultiValueEncoded::new();
+        let mut unlocked_percentage = 0u64;
+        let mut unlocked_amount = BigUint::zero();
         for milestone in unlock_schedule.milestones.iter() {
-            if milestone.release_round <= current_round {
-                claimable_percentage += milestone.percentage;
-            } else {
-                break;
-            }
-        }
+            unlocked_percentage += milestone.percentage;
+            let new_unlocked_amount = &total_amount * unlocked_percentage / MAX_PERCENTAGE;
+            let milestone_amount = &new_unlocked_amount - &unlocked_amount;
+            result.push((milestone.release_round, milestone_amount).into());
 
-        let current_claimable_tokens =
-            &user_total_claimable_balance * claimable_percentage / MAX_PERCENTAGE;
+            unlocked_amount = new_unlocked_amount;
+        }
 
-        current_claimable_tokens - user_claimed_balance
+        result
     }
 
     #[view(getUserTotalClaimableBalance)]
@@ -141,4 +292,11 @@ pub trait TokenReleaseModule:
     #[view(getUnlockSchedule)]
     #[storage_mapper("unlockSchedule")]
     fn unlock_schedule(&self) -> SingleValueMapper<UnlockSchedule<Self::Api>>;
+
+    #[view(getUserUnlockSchedule)]
+    #[storage_mapper("userUnlockSchedule")]
+    fn user_unlock_schedule(
+        &self,
+        address: &ManagedAddress,
+    ) -> SingleValueMapper<UnlockSchedule<Self::Api>>;
 }
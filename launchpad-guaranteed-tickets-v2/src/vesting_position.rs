@@ -0,0 +1,152 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use launchpad_common::action_pause::PausableAction;
+
+use crate::token_release::{UnlockSchedule, MAX_PERCENTAGE};
+
+pub const VESTING_POSITION_AMOUNT: u32 = 1;
+
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct VestingPositionAttributes<M: ManagedTypeApi> {
+    pub total_amount: BigUint<M>,
+    pub claimed_amount: BigUint<M>,
+    pub unlock_schedule: UnlockSchedule<M>,
+}
+
+#[multiversx_sc::module]
+pub trait VestingPositionModule:
+    launchpad_common::config::ConfigModule
+    + launchpad_common::launch_stage::LaunchStageModule
+    + launchpad_common::permissions::PermissionsModule
+    + launchpad_common::user_interactions::UserInteractionsModule
+    + launchpad_common::action_pause::ActionPauseModule
+    + crate::token_release::TokenReleaseModule
+    + crate::events::EventsModule
+    + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
+    + multiversx_sc_modules::pause::PauseModule
+{
+    /// Once issued, the still locked tokens are given as a position at claim,
+    /// instead of being tracked per address
+    #[payable("EGLD")]
+    #[endpoint(issueVestingPositionToken)]
+    fn issue_vesting_position_token(
+        &self,
+        token_display_name: ManagedBuffer,
+        token_ticker: ManagedBuffer,
+    ) {
+        self.require_extended_permissions();
+
+        let issue_cost = self.call_value().egld_value().clone_value();
+        self.vesting_position_token().issue_and_set_all_roles(
+            EsdtTokenType::Meta,
+            issue_cost,
+            token_display_name,
+            token_ticker,
+            0,
+            None,
+        );
+    }
+
+    /// Moves the user's remaining balance, if any, into a position sent to the receiver
+    fn convert_to_vesting_position(&self, address: &ManagedAddress, receiver: &ManagedAddress) {
+        let position_token_mapper = self.vesting_position_token();
+        if !position_token_mapper.get_token_state().is_set() {
+            return;
+        }
+
+        let total_balance_mapper = self.user_total_claimable_balance(address);
+        let claimed_balance_mapper = self.user_claimed_balance(address);
+        if claimed_balance_mapper.get() >= total_balance_mapper.get() {
+            return;
+        }
+
+        let attributes = VestingPositionAttributes {
+            total_amount: total_balance_mapper.take(),
+            claimed_amount: claimed_balance_mapper.take(),
+            unlock_schedule: self.get_effective_unlock_schedule(address),
+        };
+        let _ = position_token_mapper.nft_create_and_send(
+            receiver,
+            BigUint::from(VESTING_POSITION_AMOUNT),
+            &attributes,
+        );
+    }
+
+    /// The unlocked tokens are sent to the caller, along with the updated position,
+    /// unless it was fully claimed
+    #[payable("*")]
+    #[endpoint(claimFromVestingPosition)]
+    fn claim_from_vesting_position(&self) {
+        self.require_not_paused();
+        self.require_action_not_paused(PausableAction::Claims);
+
+        let payment = self.call_value().single_esdt();
+        let position_token_mapper = self.vesting_position_token();
+        position_token_mapper.require_same_token(&payment.token_identifier);
+
+        let mut attributes: VestingPositionAttributes<Self::Api> =
+            position_token_mapper.get_token_attributes(payment.token_nonce);
+        let claimable_tokens = self.compute_position_claimable_tokens(&attributes);
+        require!(claimable_tokens > 0, "No tokens to claim");
+
+        position_token_mapper.nft_burn(payment.token_nonce, &payment.amount);
+        attributes.claimed_amount += &claimable_tokens;
+
+        let caller = self.blockchain().get_caller();
+        if attributes.claimed_amount < attributes.total_amount {
+            let _ = position_token_mapper.nft_create_and_send(
+                &caller,
+                BigUint::from(VESTING_POSITION_AMOUNT),
+                &attributes,
+            );
+        }
+
+        let launchpad_token_id = self.launchpad_token_id().get();
+        self.send()
+            .direct_esdt(&caller, &launchpad_token_id, 0, &claimable_tokens);
+        self.total_tokens_claimed()
+            .update(|total| *total += &claimable_tokens);
+
+        let remaining_balance = &attributes.total_amount - &attributes.claimed_amount;
+        self.emit_vested_claim(
+            &attributes.unlock_schedule,
+            claimable_tokens.clone(),
+            remaining_balance,
+        );
+
+        self.emit_claim_launchpad_tokens_event(EsdtTokenPayment::new(
+            launchpad_token_id,
+            0,
+            claimable_tokens,
+        ));
+    }
+
+    #[view(getVestingPositionClaimableTokens)]
+    fn get_vesting_position_claimable_tokens(&self, position_nonce: u64) -> BigUint {
+        let attributes: VestingPositionAttributes<Self::Api> = self
+            .vesting_position_token()
+            .get_token_attributes(position_nonce);
+
+        self.compute_position_claimable_tokens(&attributes)
+    }
+
+    fn compute_position_claimable_tokens(
+        &self,
+        attributes: &VestingPositionAttributes<Self::Api>,
+    ) -> BigUint {
+        let unlocked_percentage = attributes
+            .unlock_schedule
+            .get_unlocked_percentage(self.get_current_time());
+        let unlocked_tokens = &attributes.total_amount * unlocked_percentage / MAX_PERCENTAGE;
+        if unlocked_tokens <= attributes.claimed_amount {
+            return BigUint::zero();
+        }
+
+        unlocked_tokens - &attributes.claimed_amount
+    }
+
+    #[view(getVestingPositionTokenId)]
+    #[storage_mapper("vestingPositionToken")]
+    fn vesting_position_token(&self) -> NonFungibleTokenMapper<Self::Api>;
+}
@@ -1,6 +1,6 @@
 use multiversx_sc::types::{
     Address, EgldOrEsdtTokenIdentifier, MultiValueEncoded, MultiValueEncodedCounted,
-    OperationCompletionStatus,
+    OperationCompletionStatus, OptionalValue,
 };
 
 use launchpad_common::{
@@ -9,6 +9,7 @@ use launchpad_common::{
     tickets::{TicketsModule, WINNING_TICKET},
     user_interactions::UserInteractionsModule,
     winner_selection::WinnerSelectionModule,
+    winners_hash::WinnersHashModule,
 };
 use launchpad_guaranteed_tickets_v2::token_release::TokenReleaseModule;
 use launchpad_guaranteed_tickets_v2::{
@@ -161,7 +162,7 @@ where
             &self.lp_wrapper,
             &rust_biguint!(TICKET_COST * nr_tickets as u64),
             |sc| {
-                sc.confirm_tickets(nr_tickets);
+                sc.confirm_tickets(OptionalValue::Some(nr_tickets), OptionalValue::None);
             },
         )
     }
@@ -185,8 +186,8 @@ where
             &rust_biguint!(0),
             |sc| {
                 let base_winning = NR_WINNING_TICKETS - nr_whales;
-                for ticket_id in 1..=base_winning {
-                    sc.ticket_status(ticket_id).set(WINNING_TICKET);
+                for ticket_id in 1..=base_winning as u64 {
+                    sc.set_ticket_status(ticket_id, WINNING_TICKET);
                 }
 
                 sc.claimable_ticket_payment()
@@ -226,10 +227,22 @@ where
         )
     }
 
+    pub fn compute_winners_hash(&mut self) -> TxResult {
+        self.b_mock.execute_tx(
+            &self.owner_address,
+            &self.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let result = sc.compute_winners_hash();
+                assert_eq!(result, OperationCompletionStatus::Completed);
+            },
+        )
+    }
+
     pub fn claim_user(&mut self, user: &Address) -> TxResult {
         self.b_mock
             .execute_tx(user, &self.lp_wrapper, &rust_biguint!(0), |sc| {
-                sc.claim_launchpad_tokens_endpoint();
+                sc.claim_launchpad_tokens_endpoint(OptionalValue::None);
             })
     }
 
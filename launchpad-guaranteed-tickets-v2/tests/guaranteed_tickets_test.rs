@@ -7,23 +7,39 @@ use guaranteed_tickets_setup::{
     LAUNCHPAD_TOKEN_ID, MAX_TIER_TICKETS, TICKET_COST, WINNER_SELECTION_START_ROUND,
 };
 use launchpad_common::{
+    blacklist::BlacklistReason,
     config::ConfigModule,
+    launch_stage::LaunchStageModule,
+    ongoing_operation::OngoingOperationModule,
     setup::SetupModule,
     tickets::{TicketsModule, WINNING_TICKET},
+    unclaimed_sweep::UnclaimedSweepModule,
+    user_interactions::{ClaimStatus, UserInteractionsModule},
+    wallet_migration::WalletMigrationModule,
     winner_selection::WinnerSelectionModule,
 };
 use launchpad_guaranteed_tickets_v2::{
+    early_exit::EarlyExitModule,
+    energy_bonus::EnergyBonusModule,
     guaranteed_ticket_winners::{
         GuaranteedTicketWinnersModule, GuaranteedTicketsSelectionOperation,
     },
     guaranteed_tickets_init::GuaranteedTicketsInitModule,
-    token_release::TokenReleaseModule,
+    staking_snapshot::StakingSnapshotModule,
+    token_release::{TokenReleaseModule, UnlockMilestone, UnlockSchedule},
+    vesting_position::{VestingPositionAttributes, VestingPositionModule},
     LaunchpadGuaranteedTickets,
 };
+use multiversx_sc::codec::Empty;
+use multiversx_sc::contract_base::ContractBase;
 use multiversx_sc::types::{
-    EgldOrEsdtTokenIdentifier, MultiValueEncoded, MultiValueEncodedCounted,
+    EgldOrEsdtTokenIdentifier, EsdtLocalRole, ManagedAsyncCallError, ManagedAsyncCallResult,
+    ManagedVec, MultiValueEncoded, MultiValueEncodedCounted, OperationCompletionStatus,
+    OptionalValue,
+};
+use multiversx_sc_scenario::{
+    managed_address, managed_biguint, managed_buffer, managed_token_id, rust_biguint, DebugApi,
 };
-use multiversx_sc_scenario::{managed_address, managed_biguint, rust_biguint};
 
 use crate::guaranteed_tickets_setup::NR_WINNING_TICKETS;
 
@@ -60,12 +76,12 @@ fn confirm_all_test() {
     lp_setup
         .b_mock
         .execute_query(&lp_setup.lp_wrapper, |sc| {
-            assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(2).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(3).get(), false);
-            assert_eq!(sc.ticket_status(4).get(), false);
-            assert_eq!(sc.ticket_status(5).get(), false);
-            assert_eq!(sc.ticket_status(6).get(), false);
+            assert_eq!(sc.get_ticket_status(1), WINNING_TICKET);
+            assert_eq!(sc.get_ticket_status(2), WINNING_TICKET);
+            assert_eq!(sc.get_ticket_status(3), false);
+            assert_eq!(sc.get_ticket_status(4), false);
+            assert_eq!(sc.get_ticket_status(5), false);
+            assert_eq!(sc.get_ticket_status(6), false);
 
             assert_eq!(
                 sc.get_number_of_winning_tickets_for_address(managed_address!(&participants[0])),
@@ -90,12 +106,12 @@ fn confirm_all_test() {
     lp_setup
         .b_mock
         .execute_query(&lp_setup.lp_wrapper, |sc| {
-            assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(2).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(3).get(), false);
-            assert_eq!(sc.ticket_status(4).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(5).get(), false);
-            assert_eq!(sc.ticket_status(6).get(), false);
+            assert_eq!(sc.get_ticket_status(1), WINNING_TICKET);
+            assert_eq!(sc.get_ticket_status(2), WINNING_TICKET);
+            assert_eq!(sc.get_ticket_status(3), false);
+            assert_eq!(sc.get_ticket_status(4), WINNING_TICKET);
+            assert_eq!(sc.get_ticket_status(5), false);
+            assert_eq!(sc.get_ticket_status(6), false);
 
             assert_eq!(
                 sc.get_number_of_winning_tickets_for_address(managed_address!(&participants[0])),
@@ -178,11 +194,11 @@ fn redistribute_test() {
     lp_setup
         .b_mock
         .execute_query(&lp_setup.lp_wrapper, |sc| {
-            assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(2).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(3).get(), false);
-            assert_eq!(sc.ticket_status(4).get(), false);
-            assert_eq!(sc.ticket_status(5).get(), false);
+            assert_eq!(sc.get_ticket_status(1), WINNING_TICKET);
+            assert_eq!(sc.get_ticket_status(2), WINNING_TICKET);
+            assert_eq!(sc.get_ticket_status(3), false);
+            assert_eq!(sc.get_ticket_status(4), false);
+            assert_eq!(sc.get_ticket_status(5), false);
 
             assert_eq!(
                 sc.get_number_of_winning_tickets_for_address(managed_address!(&participants[0])),
@@ -208,11 +224,11 @@ fn redistribute_test() {
     lp_setup
         .b_mock
         .execute_query(&lp_setup.lp_wrapper, |sc| {
-            assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(2).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(3).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(4).get(), false);
-            assert_eq!(sc.ticket_status(5).get(), false);
+            assert_eq!(sc.get_ticket_status(1), WINNING_TICKET);
+            assert_eq!(sc.get_ticket_status(2), WINNING_TICKET);
+            assert_eq!(sc.get_ticket_status(3), WINNING_TICKET);
+            assert_eq!(sc.get_ticket_status(4), false);
+            assert_eq!(sc.get_ticket_status(5), false);
 
             assert_eq!(
                 sc.get_number_of_winning_tickets_for_address(managed_address!(&participants[0])),
@@ -302,13 +318,13 @@ fn combined_scenario_test() {
     lp_setup
         .b_mock
         .execute_query(&lp_setup.lp_wrapper, |sc| {
-            assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(2).get(), false);
-            assert_eq!(sc.ticket_status(3).get(), false);
-            assert_eq!(sc.ticket_status(4).get(), false);
-            assert_eq!(sc.ticket_status(5).get(), false);
-            assert_eq!(sc.ticket_status(6).get(), false);
-            assert_eq!(sc.ticket_status(7).get(), false);
+            assert_eq!(sc.get_ticket_status(1), WINNING_TICKET);
+            assert_eq!(sc.get_ticket_status(2), false);
+            assert_eq!(sc.get_ticket_status(3), false);
+            assert_eq!(sc.get_ticket_status(4), false);
+            assert_eq!(sc.get_ticket_status(5), false);
+            assert_eq!(sc.get_ticket_status(6), false);
+            assert_eq!(sc.get_ticket_status(7), false);
 
             assert_eq!(sc.nr_winning_tickets().get(), NR_WINNING_TICKETS - 2);
             assert_eq!(sc.users_with_guaranteed_ticket().len(), 2);
@@ -329,13 +345,13 @@ fn combined_scenario_test() {
                 sc.select_guaranteed_tickets(&mut op);
 
                 // user[3]'s first ticket was selected
-                assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET);
-                assert_eq!(sc.ticket_status(2).get(), false);
-                assert_eq!(sc.ticket_status(3).get(), false);
-                assert_eq!(sc.ticket_status(4).get(), WINNING_TICKET);
-                assert_eq!(sc.ticket_status(5).get(), false);
-                assert_eq!(sc.ticket_status(6).get(), false);
-                assert_eq!(sc.ticket_status(7).get(), false);
+                assert_eq!(sc.get_ticket_status(1), WINNING_TICKET);
+                assert_eq!(sc.get_ticket_status(2), false);
+                assert_eq!(sc.get_ticket_status(3), false);
+                assert_eq!(sc.get_ticket_status(4), WINNING_TICKET);
+                assert_eq!(sc.get_ticket_status(5), false);
+                assert_eq!(sc.get_ticket_status(6), false);
+                assert_eq!(sc.get_ticket_status(7), false);
 
                 assert_eq!(op.leftover_tickets, 1);
                 assert_eq!(op.total_additional_winning_tickets, 1);
@@ -345,13 +361,13 @@ fn combined_scenario_test() {
                 sc.distribute_leftover_tickets(&mut op);
 
                 // ticket ID 2 was selected as winner
-                assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET);
-                assert_eq!(sc.ticket_status(2).get(), WINNING_TICKET);
-                assert_eq!(sc.ticket_status(3).get(), false);
-                assert_eq!(sc.ticket_status(4).get(), WINNING_TICKET);
-                assert_eq!(sc.ticket_status(5).get(), false);
-                assert_eq!(sc.ticket_status(6).get(), false);
-                assert_eq!(sc.ticket_status(7).get(), false);
+                assert_eq!(sc.get_ticket_status(1), WINNING_TICKET);
+                assert_eq!(sc.get_ticket_status(2), WINNING_TICKET);
+                assert_eq!(sc.get_ticket_status(3), false);
+                assert_eq!(sc.get_ticket_status(4), WINNING_TICKET);
+                assert_eq!(sc.get_ticket_status(5), false);
+                assert_eq!(sc.get_ticket_status(6), false);
+                assert_eq!(sc.get_ticket_status(7), false);
 
                 assert_eq!(op.leftover_tickets, 0);
                 assert_eq!(op.total_additional_winning_tickets, 2);
@@ -443,16 +459,16 @@ fn add_migration_guaranteed_tickets_distribution_isolated_steps_scenario_test()
     lp_setup
         .b_mock
         .execute_query(&lp_setup.lp_wrapper, |sc| {
-            assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(2).get(), false);
-            assert_eq!(sc.ticket_status(3).get(), false);
-            assert_eq!(sc.ticket_status(4).get(), false);
-            assert_eq!(sc.ticket_status(5).get(), false);
-            assert_eq!(sc.ticket_status(6).get(), false);
-            assert_eq!(sc.ticket_status(7).get(), false);
-            assert_eq!(sc.ticket_status(8).get(), false);
-            assert_eq!(sc.ticket_status(9).get(), false);
-            assert_eq!(sc.ticket_status(10).get(), false);
+            assert_eq!(sc.get_ticket_status(1), WINNING_TICKET);
+            assert_eq!(sc.get_ticket_status(2), false);
+            assert_eq!(sc.get_ticket_status(3), false);
+            assert_eq!(sc.get_ticket_status(4), false);
+            assert_eq!(sc.get_ticket_status(5), false);
+            assert_eq!(sc.get_ticket_status(6), false);
+            assert_eq!(sc.get_ticket_status(7), false);
+            assert_eq!(sc.get_ticket_status(8), false);
+            assert_eq!(sc.get_ticket_status(9), false);
+            assert_eq!(sc.get_ticket_status(10), false);
 
             assert_eq!(
                 sc.nr_winning_tickets().get(),
@@ -480,16 +496,16 @@ fn add_migration_guaranteed_tickets_distribution_isolated_steps_scenario_test()
                 // first step
                 sc.select_guaranteed_tickets(&mut op);
 
-                assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET); // randomly selected -> leftover_ticket
-                assert_eq!(sc.ticket_status(2).get(), false);
-                assert_eq!(sc.ticket_status(3).get(), false);
-                assert_eq!(sc.ticket_status(4).get(), WINNING_TICKET); // migration guaranteed ticket -> additional_winning_tickets
-                assert_eq!(sc.ticket_status(5).get(), WINNING_TICKET); // staking guaranteed ticket -> additional_winning_tickets
-                assert_eq!(sc.ticket_status(6).get(), WINNING_TICKET); // migration guaranteed ticket -> additional_winning_tickets
-                assert_eq!(sc.ticket_status(7).get(), false);
-                assert_eq!(sc.ticket_status(8).get(), false);
-                assert_eq!(sc.ticket_status(9).get(), false);
-                assert_eq!(sc.ticket_status(10).get(), false);
+                assert_eq!(sc.get_ticket_status(1), WINNING_TICKET); // randomly selected -> leftover_ticket
+                assert_eq!(sc.get_ticket_status(2), false);
+                assert_eq!(sc.get_ticket_status(3), false);
+                assert_eq!(sc.get_ticket_status(4), WINNING_TICKET); // migration guaranteed ticket -> additional_winning_tickets
+                assert_eq!(sc.get_ticket_status(5), WINNING_TICKET); // staking guaranteed ticket -> additional_winning_tickets
+                assert_eq!(sc.get_ticket_status(6), WINNING_TICKET); // migration guaranteed ticket -> additional_winning_tickets
+                assert_eq!(sc.get_ticket_status(7), false);
+                assert_eq!(sc.get_ticket_status(8), false);
+                assert_eq!(sc.get_ticket_status(9), false);
+                assert_eq!(sc.get_ticket_status(10), false);
 
                 assert_eq!(op.leftover_tickets, 1);
                 assert_eq!(op.total_additional_winning_tickets, 3);
@@ -498,16 +514,16 @@ fn add_migration_guaranteed_tickets_distribution_isolated_steps_scenario_test()
                 // second step
                 sc.distribute_leftover_tickets(&mut op);
 
-                assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET);
-                assert_eq!(sc.ticket_status(2).get(), false);
-                assert_eq!(sc.ticket_status(3).get(), false);
-                assert_eq!(sc.ticket_status(4).get(), WINNING_TICKET);
-                assert_eq!(sc.ticket_status(5).get(), WINNING_TICKET);
-                assert_eq!(sc.ticket_status(6).get(), WINNING_TICKET);
-                assert_eq!(sc.ticket_status(7).get(), false);
-                assert_eq!(sc.ticket_status(8).get(), false);
-                assert_eq!(sc.ticket_status(9).get(), WINNING_TICKET); // randomly selected in distribute_leftover_tickets
-                assert_eq!(sc.ticket_status(10).get(), false);
+                assert_eq!(sc.get_ticket_status(1), WINNING_TICKET);
+                assert_eq!(sc.get_ticket_status(2), false);
+                assert_eq!(sc.get_ticket_status(3), false);
+                assert_eq!(sc.get_ticket_status(4), WINNING_TICKET);
+                assert_eq!(sc.get_ticket_status(5), WINNING_TICKET);
+                assert_eq!(sc.get_ticket_status(6), WINNING_TICKET);
+                assert_eq!(sc.get_ticket_status(7), false);
+                assert_eq!(sc.get_ticket_status(8), false);
+                assert_eq!(sc.get_ticket_status(9), WINNING_TICKET); // randomly selected in distribute_leftover_tickets
+                assert_eq!(sc.get_ticket_status(10), false);
 
                 assert_eq!(op.leftover_tickets, 0);
                 assert_eq!(op.total_additional_winning_tickets, 4);
@@ -996,7 +1012,7 @@ fn blacklist_scenario_test() {
             |sc| {
                 let mut blacklist = MultiValueEncoded::new();
                 blacklist.push(managed_address!(&participants[4]));
-                sc.add_users_to_blacklist_endpoint(blacklist);
+                sc.add_users_to_blacklist_endpoint(BlacklistReason::Unspecified, blacklist);
             },
         )
         .assert_error(4, "Permission denied");
@@ -1033,7 +1049,7 @@ fn blacklist_scenario_test() {
             |sc| {
                 let mut blacklist = MultiValueEncoded::new();
                 blacklist.push(managed_address!(&second_new_participant));
-                sc.add_users_to_blacklist_endpoint(blacklist);
+                sc.add_users_to_blacklist_endpoint(BlacklistReason::Unspecified, blacklist);
             },
         )
         .assert_ok();
@@ -1121,7 +1137,7 @@ fn blacklist_scenario_test() {
             |sc| {
                 let mut blacklist = MultiValueEncoded::new();
                 blacklist.push(managed_address!(&second_new_participant));
-                sc.add_users_to_blacklist_endpoint(blacklist);
+                sc.add_users_to_blacklist_endpoint(BlacklistReason::Unspecified, blacklist);
             },
         )
         .assert_error(4, "May only modify blacklist before winner selection");
@@ -1132,13 +1148,13 @@ fn blacklist_scenario_test() {
     lp_setup
         .b_mock
         .execute_query(&lp_setup.lp_wrapper, |sc| {
-            assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(2).get(), false);
-            assert_eq!(sc.ticket_status(3).get(), false);
-            assert_eq!(sc.ticket_status(4).get(), false);
-            assert_eq!(sc.ticket_status(5).get(), false);
-            assert_eq!(sc.ticket_status(6).get(), false);
-            assert_eq!(sc.ticket_status(7).get(), false);
+            assert_eq!(sc.get_ticket_status(1), WINNING_TICKET);
+            assert_eq!(sc.get_ticket_status(2), false);
+            assert_eq!(sc.get_ticket_status(3), false);
+            assert_eq!(sc.get_ticket_status(4), false);
+            assert_eq!(sc.get_ticket_status(5), false);
+            assert_eq!(sc.get_ticket_status(6), false);
+            assert_eq!(sc.get_ticket_status(7), false);
 
             assert_eq!(sc.users_with_guaranteed_ticket().len(), 3);
         })
@@ -1149,12 +1165,12 @@ fn blacklist_scenario_test() {
     lp_setup
         .b_mock
         .execute_query(&lp_setup.lp_wrapper, |sc| {
-            assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(2).get(), false);
-            assert_eq!(sc.ticket_status(3).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(4).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(5).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(6).get(), false);
+            assert_eq!(sc.get_ticket_status(1), WINNING_TICKET);
+            assert_eq!(sc.get_ticket_status(2), false);
+            assert_eq!(sc.get_ticket_status(3), WINNING_TICKET);
+            assert_eq!(sc.get_ticket_status(4), WINNING_TICKET);
+            assert_eq!(sc.get_ticket_status(5), WINNING_TICKET);
+            assert_eq!(sc.get_ticket_status(6), false);
 
             assert_eq!(sc.users_with_guaranteed_ticket().len(), 0);
         })
@@ -1234,7 +1250,7 @@ fn remove_from_blacklist_underflow_and_empty_storage_test() {
                 let mut blacklist = MultiValueEncoded::new();
                 blacklist.push(managed_address!(&participants[0]));
                 blacklist.push(managed_address!(&new_participant2));
-                sc.add_users_to_blacklist_endpoint(blacklist);
+                sc.add_users_to_blacklist_endpoint(BlacklistReason::Unspecified, blacklist);
             },
         )
         .assert_ok();
@@ -1660,7 +1676,7 @@ fn unlock_milestones_wrong_step_and_order_test() {
                 sc.set_unlock_schedule(unlock_schedule);
             },
         )
-        .assert_user_error("Invalid unlock schedule");
+        .assert_user_error("Unlock milestones must be in strictly increasing order");
 
     // Retry after setup period has passed
     lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND);
@@ -2264,3 +2280,1063 @@ fn no_participants_test() {
         &rust_biguint!(0),
     );
 }
+
+#[test]
+fn energy_bonus_test() {
+    let mut lp_setup = LaunchpadSetup::new(
+        NR_WINNING_TICKETS,
+        launchpad_guaranteed_tickets_v2::contract_obj,
+    );
+    let participants = lp_setup.participants.clone();
+    let energy_factory = lp_setup.b_mock.create_sc_account(
+        &rust_biguint!(0),
+        None,
+        launchpad_guaranteed_tickets_v2::contract_obj,
+        "energy factory mock",
+    );
+    let energy_factory_address = energy_factory.address_ref().clone();
+
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &participants[0],
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_energy_factory_address(managed_address!(&energy_factory_address));
+            },
+        )
+        .assert_error(4, "Permission denied");
+
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &participants[0],
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut tiers = MultiValueEncoded::new();
+                tiers.push((managed_biguint!(100), 1).into());
+                sc.set_energy_bonus_tiers(tiers);
+            },
+        )
+        .assert_error(4, "Permission denied");
+
+    // tiers have to be in ascending order of energy
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &lp_setup.owner_address,
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut tiers = MultiValueEncoded::new();
+                tiers.push((managed_biguint!(1_000), 2).into());
+                tiers.push((managed_biguint!(100), 1).into());
+                sc.set_energy_bonus_tiers(tiers);
+            },
+        )
+        .assert_user_error("Invalid energy bonus tier");
+
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &lp_setup.owner_address,
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_energy_factory_address(managed_address!(&energy_factory_address));
+
+                let mut tiers = MultiValueEncoded::new();
+                tiers.push((managed_biguint!(100), 1).into());
+                tiers.push((managed_biguint!(1_000), 2).into());
+                sc.set_energy_bonus_tiers(tiers);
+            },
+        )
+        .assert_ok();
+
+    // the energy factory's answer, as received in the callback
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &lp_setup.owner_address,
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let second_user = managed_address!(&participants[1]);
+                sc.energy_bonus_requested(&second_user).set(true);
+                sc.energy_bonus_callback(
+                    second_user.clone(),
+                    ManagedAsyncCallResult::Ok(managed_biguint!(1_500)),
+                );
+
+                assert_eq!(sc.energy_bonus_tickets(&second_user).get(), 2);
+                assert!(sc.users_with_guaranteed_ticket().contains(&second_user));
+                assert_eq!(sc.nr_winning_tickets().get(), NR_WINNING_TICKETS - 3);
+                assert_eq!(sc.total_guaranteed_tickets().get(), 3);
+
+                // no winning tickets are left for another bonus
+                let first_user = managed_address!(&participants[0]);
+                sc.energy_bonus_requested(&first_user).set(true);
+                sc.energy_bonus_callback(
+                    first_user.clone(),
+                    ManagedAsyncCallResult::Ok(managed_biguint!(100)),
+                );
+                assert_eq!(sc.energy_bonus_tickets(&first_user).get(), 0);
+                assert!(sc.energy_bonus_requested(&first_user).get());
+
+                // a failed query may be retried
+                sc.energy_bonus_callback(
+                    first_user.clone(),
+                    ManagedAsyncCallResult::Err(ManagedAsyncCallError {
+                        err_code: 4,
+                        err_msg: managed_buffer!(b"energy factory error"),
+                    }),
+                );
+                assert!(!sc.energy_bonus_requested(&first_user).get());
+            },
+        )
+        .assert_ok();
+}
+
+#[test]
+fn staking_snapshot_import_test() {
+    let mut lp_setup = LaunchpadSetup::new(
+        NR_WINNING_TICKETS,
+        launchpad_guaranteed_tickets_v2::contract_obj,
+    );
+    let big_staker = lp_setup.b_mock.create_user_account(&rust_biguint!(0));
+    let small_staker = lp_setup.b_mock.create_user_account(&rust_biguint!(0));
+
+    lp_setup
+        .b_mock
+        .execute_tx(&big_staker, &lp_setup.lp_wrapper, &rust_biguint!(0), |sc| {
+            let mut snapshot = MultiValueEncoded::new();
+            snapshot.push((managed_address!(&big_staker), managed_biguint!(1_000)).into());
+            sc.import_staking_snapshot(snapshot);
+        })
+        .assert_error(4, "Permission denied");
+
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &lp_setup.owner_address,
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut snapshot = MultiValueEncoded::new();
+                snapshot.push((managed_address!(&big_staker), managed_biguint!(1_000)).into());
+                sc.import_staking_snapshot(snapshot);
+            },
+        )
+        .assert_error(4, "Stake thresholds not set");
+
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &lp_setup.owner_address,
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut thresholds = MultiValueEncoded::new();
+                thresholds.push((managed_biguint!(100), 1, 0).into());
+                thresholds.push((managed_biguint!(1_000), MAX_TIER_TICKETS, 1).into());
+                sc.set_stake_thresholds(thresholds);
+
+                let mut snapshot = MultiValueEncoded::new();
+                snapshot.push((managed_address!(&big_staker), managed_biguint!(1_500)).into());
+                snapshot.push((managed_address!(&small_staker), managed_biguint!(50)).into());
+                sc.import_staking_snapshot(snapshot);
+            },
+        )
+        .assert_ok();
+
+    // re-sending the same batch doesn't add the tickets twice
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &lp_setup.owner_address,
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut snapshot = MultiValueEncoded::new();
+                snapshot.push((managed_address!(&big_staker), managed_biguint!(1_500)).into());
+                sc.import_staking_snapshot(snapshot);
+            },
+        )
+        .assert_ok();
+
+    lp_setup
+        .b_mock
+        .execute_query(&lp_setup.lp_wrapper, |sc| {
+            assert_eq!(sc.snapshot_imported_users().get(), 2);
+            assert_eq!(
+                sc.snapshot_stake(&managed_address!(&big_staker)).get(),
+                managed_biguint!(1_500)
+            );
+            assert_eq!(
+                sc.get_total_number_of_tickets_for_address(&managed_address!(&big_staker)),
+                MAX_TIER_TICKETS
+            );
+            assert_eq!(
+                sc.get_total_number_of_tickets_for_address(&managed_address!(&small_staker)),
+                0
+            );
+            assert!(sc
+                .users_with_guaranteed_ticket()
+                .contains(&managed_address!(&big_staker)));
+            assert_eq!(sc.nr_winning_tickets().get(), NR_WINNING_TICKETS - 2);
+            assert_eq!(sc.total_guaranteed_tickets().get(), 2);
+        })
+        .assert_ok();
+
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &lp_setup.owner_address,
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut thresholds = MultiValueEncoded::new();
+                thresholds.push((managed_biguint!(10), 1, 0).into());
+                sc.set_stake_thresholds(thresholds);
+            },
+        )
+        .assert_error(4, "Snapshot import already started");
+}
+
+#[test]
+fn wallet_migration_guaranteed_ticket_test() {
+    let mut lp_setup = LaunchpadSetup::new(
+        NR_WINNING_TICKETS,
+        launchpad_guaranteed_tickets_v2::contract_obj,
+    );
+    let participants = lp_setup.participants.clone();
+    let new_address = lp_setup.b_mock.create_user_account(&rust_biguint!(0));
+
+    lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND);
+    lp_setup.confirm(&participants[2], 3).assert_ok();
+
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &participants[2],
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.request_wallet_migration(managed_address!(&new_address));
+            },
+        )
+        .assert_ok();
+
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &lp_setup.owner_address,
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.approve_wallet_migration_endpoint(managed_address!(&participants[2]));
+            },
+        )
+        .assert_ok();
+
+    // the guaranteed ticket follows the tickets to the new address
+    lp_setup
+        .b_mock
+        .execute_query(&lp_setup.lp_wrapper, |sc| {
+            let old_address = managed_address!(&participants[2]);
+            let new_address = managed_address!(&new_address);
+
+            assert!(!sc.users_with_guaranteed_ticket().contains(&old_address));
+            assert!(sc.users_with_guaranteed_ticket().contains(&new_address));
+            assert_eq!(sc.nr_confirmed_tickets(&new_address).get(), 3);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn early_exit_test() {
+    let mut lp_setup = LaunchpadSetup::new(
+        NR_WINNING_TICKETS,
+        launchpad_guaranteed_tickets_v2::contract_obj,
+    );
+    let unlock_milestones = vec![(0, 10000)];
+    lp_setup.set_unlock_schedule(unlock_milestones);
+    let participants = lp_setup.participants.clone();
+    let treasury_address = lp_setup.b_mock.create_user_account(&rust_biguint!(0));
+
+    // 10% penalty
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &lp_setup.owner_address,
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_treasury_address(managed_address!(&treasury_address));
+                sc.set_early_exit_config(true, 1_000);
+            },
+        )
+        .assert_ok();
+
+    lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND);
+    for (i, p) in participants.iter().enumerate() {
+        lp_setup.confirm(p, i + 1).assert_ok();
+    }
+
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &participants[2],
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.early_exit();
+            },
+        )
+        .assert_user_error("Winners not selected yet");
+
+    // tickets 1, 2 and 4 are winning
+    lp_setup
+        .b_mock
+        .set_block_round(WINNER_SELECTION_START_ROUND);
+    lp_setup.filter_tickets().assert_ok();
+    lp_setup.select_base_winners_mock(1).assert_ok();
+    lp_setup.distribute_tickets().assert_ok();
+
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &participants[2],
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.early_exit();
+            },
+        )
+        .assert_user_error("Winners hash must be computed first");
+
+    lp_setup.compute_winners_hash().assert_ok();
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &participants[2],
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.early_exit();
+            },
+        )
+        .assert_ok();
+
+    // all 3 tickets refunded, minus the penalty for the winning one
+    lp_setup
+        .b_mock
+        .check_egld_balance(&participants[2], &rust_biguint!(TICKET_COST * 3 - 1));
+    lp_setup
+        .b_mock
+        .check_egld_balance(&treasury_address, &rust_biguint!(1));
+
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &participants[2],
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.early_exit();
+            },
+        )
+        .assert_user_error("Already claimed");
+
+    lp_setup
+        .b_mock
+        .execute_query(&lp_setup.lp_wrapper, |sc| {
+            assert_eq!(sc.get_ticket_status(4), false);
+            assert_eq!(sc.nr_winning_tickets().get(), NR_WINNING_TICKETS - 1);
+            assert_eq!(sc.renounced_tickets_to_distribute().get(), 1);
+        })
+        .assert_ok();
+
+    // the only ticket left to win is the second user's ticket 3
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &lp_setup.owner_address,
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let result = sc.distribute_renounced_tickets();
+                assert_eq!(result, OperationCompletionStatus::Completed);
+            },
+        )
+        .assert_ok();
+
+    lp_setup
+        .b_mock
+        .execute_query(&lp_setup.lp_wrapper, |sc| {
+            assert_eq!(sc.get_ticket_status(3), WINNING_TICKET);
+            assert_eq!(sc.get_ticket_status(5), false);
+            assert_eq!(sc.get_ticket_status(6), false);
+            assert_eq!(sc.nr_winning_tickets().get(), NR_WINNING_TICKETS);
+            assert_eq!(sc.renounced_tickets_to_distribute().get(), 0);
+        })
+        .assert_ok();
+
+    lp_setup.b_mock.set_block_round(CLAIM_START_ROUND);
+    lp_setup.claim_user(&participants[1]).assert_ok();
+    lp_setup.claim_owner().assert_ok();
+
+    let base_user_balance = rust_biguint!(TICKET_COST * MAX_TIER_TICKETS as u64);
+    lp_setup
+        .b_mock
+        .check_egld_balance(&participants[1], &(base_user_balance - TICKET_COST * 2));
+    lp_setup.b_mock.check_esdt_balance(
+        &participants[1],
+        LAUNCHPAD_TOKEN_ID,
+        &rust_biguint!(LAUNCHPAD_TOKENS_PER_TICKET * 2),
+    );
+    lp_setup.b_mock.check_egld_balance(
+        &lp_setup.owner_address,
+        &rust_biguint!(TICKET_COST * NR_WINNING_TICKETS as u64),
+    );
+}
+
+#[test]
+fn complete_winner_selection_test() {
+    let mut lp_setup = LaunchpadSetup::new(
+        NR_WINNING_TICKETS,
+        launchpad_guaranteed_tickets_v2::contract_obj,
+    );
+    let participants = lp_setup.participants.clone();
+
+    lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND);
+    for (i, p) in participants.iter().enumerate() {
+        lp_setup.confirm(p, i + 1).assert_ok();
+    }
+
+    lp_setup
+        .b_mock
+        .set_block_round(WINNER_SELECTION_START_ROUND);
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &lp_setup.owner_address,
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let result = sc.complete_winner_selection_endpoint();
+                assert_eq!(result, OperationCompletionStatus::Completed);
+
+                let flags = sc.flags().get();
+                assert!(flags.were_tickets_filtered);
+                assert!(flags.were_winners_selected);
+                assert!(flags.was_additional_step_completed);
+                assert!(sc.current_ongoing_operation().is_empty());
+            },
+        )
+        .assert_ok();
+
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &lp_setup.owner_address,
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let _ = sc.complete_winner_selection_endpoint();
+            },
+        )
+        .assert_user_error("Winner selection already completed");
+}
+
+#[cfg(feature = "mock-random")]
+#[test]
+fn nft_holder_guaranteed_tickets_test() {
+    let mut lp_setup = LaunchpadSetup::new(
+        NR_WINNING_TICKETS + 2,
+        launchpad_guaranteed_tickets_v2::contract_obj,
+    );
+    let participants = lp_setup.participants.clone();
+    let holder_collection = b"HOLDER-123456";
+    let new_holder = lp_setup.b_mock.create_user_account(&rust_biguint!(0));
+    for (address, nonce) in [
+        (&new_holder, 1),
+        (&participants[0], 1),
+        (&participants[1], 2),
+    ] {
+        lp_setup.b_mock.set_nft_balance(
+            address,
+            holder_collection,
+            nonce,
+            &rust_biguint!(1),
+            &Empty,
+        );
+    }
+
+    lp_setup
+        .b_mock
+        .execute_esdt_transfer(
+            &new_holder,
+            &lp_setup.lp_wrapper,
+            holder_collection,
+            1,
+            &rust_biguint!(1),
+            |sc| {
+                sc.register_nft_holder();
+            },
+        )
+        .assert_user_error("NFT holder tickets not configured");
+
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &lp_setup.owner_address,
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_nft_holder_tickets_config(managed_token_id!(holder_collection), 2, 1);
+            },
+        )
+        .assert_ok();
+
+    // users without tickets get the configured allowance
+    lp_setup
+        .b_mock
+        .execute_esdt_transfer(
+            &new_holder,
+            &lp_setup.lp_wrapper,
+            holder_collection,
+            1,
+            &rust_biguint!(1),
+            |sc| {
+                sc.register_nft_holder();
+            },
+        )
+        .assert_ok();
+    lp_setup.b_mock.check_nft_balance::<Empty>(
+        &new_holder,
+        holder_collection,
+        1,
+        &rust_biguint!(1),
+        None,
+    );
+
+    lp_setup
+        .b_mock
+        .execute_esdt_transfer(
+            &participants[0],
+            &lp_setup.lp_wrapper,
+            holder_collection,
+            1,
+            &rust_biguint!(1),
+            |sc| {
+                sc.register_nft_holder();
+            },
+        )
+        .assert_user_error("NFT already used for registration");
+
+    // already added users get the guaranteed tickets on top of their own
+    lp_setup
+        .b_mock
+        .execute_esdt_transfer(
+            &participants[1],
+            &lp_setup.lp_wrapper,
+            holder_collection,
+            2,
+            &rust_biguint!(1),
+            |sc| {
+                sc.register_nft_holder();
+            },
+        )
+        .assert_ok();
+
+    lp_setup
+        .b_mock
+        .execute_query(&lp_setup.lp_wrapper, |sc| {
+            assert_eq!(
+                sc.get_total_number_of_tickets_for_address(&managed_address!(&new_holder)),
+                2
+            );
+            assert_eq!(
+                sc.get_total_number_of_tickets_for_address(&managed_address!(&participants[1])),
+                2
+            );
+            assert!(sc.is_registered_nft_holder(managed_address!(&participants[1])));
+
+            let user_ticket_status = sc
+                .user_ticket_status(&managed_address!(&participants[1]))
+                .get();
+            assert_eq!(user_ticket_status.guaranteed_tickets_info.len(), 1);
+            assert_eq!(
+                user_ticket_status
+                    .guaranteed_tickets_info
+                    .get(0)
+                    .min_confirmed_tickets,
+                1
+            );
+
+            assert_eq!(sc.users_with_guaranteed_ticket().len(), 3);
+            assert_eq!(sc.nr_winning_tickets().get(), NR_WINNING_TICKETS - 1);
+            assert_eq!(sc.total_guaranteed_tickets().get(), 3);
+        })
+        .assert_ok();
+
+    lp_setup
+        .b_mock
+        .execute_esdt_transfer(
+            &participants[1],
+            &lp_setup.lp_wrapper,
+            holder_collection,
+            2,
+            &rust_biguint!(1),
+            |sc| {
+                sc.register_nft_holder();
+            },
+        )
+        .assert_user_error("NFT already used for registration");
+}
+
+#[test]
+fn user_unlock_schedule_test() {
+    let mut lp_setup = LaunchpadSetup::new(
+        NR_WINNING_TICKETS,
+        launchpad_guaranteed_tickets_v2::contract_obj,
+    );
+    let participants = lp_setup.participants.clone();
+    let cliff_round = CLAIM_START_ROUND + 10;
+    lp_setup.set_unlock_schedule(vec![(0, 10_000)]);
+
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &lp_setup.owner_address,
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut milestones = MultiValueEncoded::new();
+                milestones.push((cliff_round, 5_000).into());
+                sc.set_user_unlock_schedule(managed_address!(&participants[0]), milestones);
+            },
+        )
+        .assert_user_error("Unlock percentages must sum up to 100%");
+
+    // strategic investors may get a longer cliff than the global schedule
+    for participant in &participants[..2] {
+        lp_setup
+            .b_mock
+            .execute_tx(
+                &lp_setup.owner_address,
+                &lp_setup.lp_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    let mut milestones = MultiValueEncoded::new();
+                    milestones.push((cliff_round, 10_000).into());
+                    sc.set_user_unlock_schedule(managed_address!(participant), milestones);
+                },
+            )
+            .assert_ok();
+    }
+
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &lp_setup.owner_address,
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.clear_user_unlock_schedule(managed_address!(&participants[1]));
+            },
+        )
+        .assert_ok();
+
+    lp_setup.b_mock.set_block_round(CLAIM_START_ROUND);
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &lp_setup.owner_address,
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                for participant in &participants {
+                    sc.user_total_claimable_balance(&managed_address!(participant))
+                        .set(managed_biguint!(LAUNCHPAD_TOKENS_PER_TICKET));
+                }
+            },
+        )
+        .assert_ok();
+
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &lp_setup.owner_address,
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.clear_user_unlock_schedule(managed_address!(&participants[0]));
+            },
+        )
+        .assert_user_error("Add tickets period has passed");
+
+    lp_setup
+        .b_mock
+        .execute_query(&lp_setup.lp_wrapper, |sc| {
+            assert!(!sc
+                .user_unlock_schedule(&managed_address!(&participants[0]))
+                .is_empty());
+            assert!(sc
+                .user_unlock_schedule(&managed_address!(&participants[1]))
+                .is_empty());
+
+            assert_eq!(
+                sc.compute_claimable_tokens(&managed_address!(&participants[0])),
+                managed_biguint!(0)
+            );
+            assert_eq!(
+                sc.compute_claimable_tokens(&managed_address!(&participants[1])),
+                managed_biguint!(LAUNCHPAD_TOKENS_PER_TICKET)
+            );
+        })
+        .assert_ok();
+
+    lp_setup.b_mock.set_block_round(cliff_round);
+    lp_setup
+        .b_mock
+        .execute_query(&lp_setup.lp_wrapper, |sc| {
+            assert_eq!(
+                sc.compute_claimable_tokens(&managed_address!(&participants[0])),
+                managed_biguint!(LAUNCHPAD_TOKENS_PER_TICKET)
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn vesting_position_test() {
+    let mut lp_setup = LaunchpadSetup::new(
+        NR_WINNING_TICKETS,
+        launchpad_guaranteed_tickets_v2::contract_obj,
+    );
+    let unlock_round = CLAIM_START_ROUND + 10;
+    lp_setup.set_unlock_schedule(vec![(0, 5_000), (unlock_round, 5_000)]);
+    let participants = lp_setup.participants.clone();
+    let new_wallet = lp_setup.b_mock.create_user_account(&rust_biguint!(0));
+    let position_token_id = b"VESTING-123456";
+
+    lp_setup.b_mock.set_esdt_local_roles(
+        lp_setup.lp_wrapper.address_ref(),
+        position_token_id,
+        &[EsdtLocalRole::NftCreate, EsdtLocalRole::NftBurn],
+    );
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &lp_setup.owner_address,
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.vesting_position_token()
+                    .set_token_id(managed_token_id!(position_token_id));
+            },
+        )
+        .assert_ok();
+
+    // the only confirmed tickets, so all of them win
+    lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND);
+    lp_setup
+        .confirm(&participants[2], MAX_TIER_TICKETS)
+        .assert_ok();
+
+    lp_setup
+        .b_mock
+        .set_block_round(WINNER_SELECTION_START_ROUND);
+    lp_setup.filter_tickets().assert_ok();
+    lp_setup.select_winners().assert_ok();
+    lp_setup.distribute_tickets().assert_ok();
+
+    // the unlocked half is sent right away, the rest as a position
+    let total_won = LAUNCHPAD_TOKENS_PER_TICKET * MAX_TIER_TICKETS as u64;
+    lp_setup.b_mock.set_block_round(CLAIM_START_ROUND);
+    lp_setup.claim_user(&participants[2]).assert_ok();
+    lp_setup.b_mock.check_esdt_balance(
+        &participants[2],
+        LAUNCHPAD_TOKEN_ID,
+        &rust_biguint!(total_won / 2),
+    );
+    lp_setup.b_mock.check_nft_balance::<Empty>(
+        &participants[2],
+        position_token_id,
+        1,
+        &rust_biguint!(1),
+        None,
+    );
+
+    lp_setup
+        .b_mock
+        .execute_query(&lp_setup.lp_wrapper, |sc| {
+            assert_eq!(
+                sc.user_total_claimable_balance(&managed_address!(&participants[2]))
+                    .get(),
+                managed_biguint!(0)
+            );
+            assert_eq!(
+                sc.get_vesting_position_claimable_tokens(1),
+                managed_biguint!(0)
+            );
+        })
+        .assert_ok();
+
+    lp_setup
+        .b_mock
+        .execute_esdt_transfer(
+            &participants[2],
+            &lp_setup.lp_wrapper,
+            position_token_id,
+            1,
+            &rust_biguint!(1),
+            |sc| {
+                sc.claim_from_vesting_position();
+            },
+        )
+        .assert_user_error("No tokens to claim");
+
+    // the position is moved to another wallet, which claims the rest
+    let attributes = VestingPositionAttributes::<DebugApi> {
+        total_amount: managed_biguint!(total_won),
+        claimed_amount: managed_biguint!(total_won / 2),
+        unlock_schedule: UnlockSchedule::new(ManagedVec::from(vec![
+            UnlockMilestone {
+                release_round: 0,
+                percentage: 5_000,
+            },
+            UnlockMilestone {
+                release_round: unlock_round,
+                percentage: 5_000,
+            },
+        ])),
+    };
+    lp_setup.b_mock.set_nft_balance(
+        &participants[2],
+        position_token_id,
+        1,
+        &rust_biguint!(0),
+        &attributes,
+    );
+    lp_setup.b_mock.set_nft_balance(
+        &new_wallet,
+        position_token_id,
+        1,
+        &rust_biguint!(1),
+        &attributes,
+    );
+
+    lp_setup.b_mock.set_block_round(unlock_round);
+    lp_setup
+        .b_mock
+        .execute_esdt_transfer(
+            &new_wallet,
+            &lp_setup.lp_wrapper,
+            position_token_id,
+            1,
+            &rust_biguint!(1),
+            |sc| {
+                sc.claim_from_vesting_position();
+            },
+        )
+        .assert_ok();
+    lp_setup.b_mock.check_esdt_balance(
+        &new_wallet,
+        LAUNCHPAD_TOKEN_ID,
+        &rust_biguint!(total_won / 2),
+    );
+    lp_setup.b_mock.check_nft_balance::<Empty>(
+        &new_wallet,
+        position_token_id,
+        1,
+        &rust_biguint!(0),
+        None,
+    );
+}
+
+#[test]
+fn unlock_schedule_validation_and_preview_test() {
+    let mut lp_setup = LaunchpadSetup::new(
+        NR_WINNING_TICKETS,
+        launchpad_guaranteed_tickets_v2::contract_obj,
+    );
+
+    let invalid_schedules = [
+        (
+            vec![(10, 5_000), (10, 5_000)],
+            "Unlock milestones must be in strictly increasing order",
+        ),
+        (
+            vec![(10, 5_000), (20, 4_000)],
+            "Unlock percentages must sum up to 100%",
+        ),
+        (vec![(10, 10_001)], "Invalid unlock milestone percentage"),
+        (vec![], "Empty unlock schedule"),
+    ];
+    for (unlock_milestones, err_msg) in invalid_schedules {
+        lp_setup
+            .b_mock
+            .execute_tx(
+                &lp_setup.owner_address,
+                &lp_setup.lp_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    let mut unlock_schedule = MultiValueEncoded::new();
+                    for milestone in unlock_milestones {
+                        unlock_schedule.push(milestone.into());
+                    }
+                    sc.set_unlock_schedule(unlock_schedule);
+                },
+            )
+            .assert_user_error(err_msg);
+    }
+
+    lp_setup.set_unlock_schedule(vec![(10, 3_333), (20, 3_333), (30, 3_334)]);
+
+    lp_setup
+        .b_mock
+        .execute_query(&lp_setup.lp_wrapper, |sc| {
+            let preview: Vec<(u64, u64)> = sc
+                .preview_unlock_amounts(managed_biguint!(100), OptionalValue::None)
+                .into_iter()
+                .map(|entry| {
+                    let (release_round, amount) = entry.into_tuple();
+                    (release_round, amount.to_u64().unwrap())
+                })
+                .collect();
+            assert_eq!(preview, vec![(10, 33), (20, 33), (30, 34)]);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn user_claim_status_test() {
+    let mut lp_setup = LaunchpadSetup::new(
+        NR_WINNING_TICKETS,
+        launchpad_guaranteed_tickets_v2::contract_obj,
+    );
+    let unlock_round = CLAIM_START_ROUND + 10;
+    lp_setup.set_unlock_schedule(vec![(0, 5_000), (unlock_round, 5_000)]);
+    let participants = lp_setup.participants.clone();
+
+    lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND);
+    for (i, p) in participants.iter().enumerate() {
+        lp_setup.confirm(p, i + 1).assert_ok();
+    }
+
+    lp_setup
+        .b_mock
+        .set_block_round(WINNER_SELECTION_START_ROUND);
+    lp_setup.filter_tickets().assert_ok();
+    lp_setup.select_base_winners_mock(1).assert_ok();
+    lp_setup.distribute_tickets().assert_ok();
+
+    // user[0]'s only ticket loses
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &lp_setup.owner_address,
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.clear_ticket_status(1);
+            },
+        )
+        .assert_ok();
+
+    lp_setup.b_mock.set_block_round(CLAIM_START_ROUND);
+    lp_setup.claim_user(&participants[0]).assert_ok();
+    lp_setup.claim_user(&participants[1]).assert_ok();
+
+    let expected_statuses = [
+        ClaimStatus::RefundedOnly,
+        ClaimStatus::Vesting,
+        ClaimStatus::NotClaimed,
+    ];
+    lp_setup
+        .b_mock
+        .execute_query(&lp_setup.lp_wrapper, |sc| {
+            for (p, expected_status) in participants.iter().zip(expected_statuses) {
+                assert_eq!(
+                    sc.get_user_claim_status(managed_address!(p)),
+                    expected_status
+                );
+            }
+        })
+        .assert_ok();
+
+    // the rest is unlocked
+    lp_setup.b_mock.set_block_round(unlock_round);
+    lp_setup.claim_user(&participants[1]).assert_ok();
+    lp_setup.claim_user(&participants[2]).assert_ok();
+
+    lp_setup
+        .b_mock
+        .execute_query(&lp_setup.lp_wrapper, |sc| {
+            for p in &participants[1..] {
+                assert_eq!(
+                    sc.get_user_claim_status(managed_address!(p)),
+                    ClaimStatus::ClaimedTokens
+                );
+            }
+        })
+        .assert_ok();
+}
+
+#[test]
+fn vested_claim_milestones_test() {
+    let nr_winning_tickets = 1;
+    let mut lp_setup = LaunchpadSetup::new(
+        nr_winning_tickets,
+        launchpad_guaranteed_tickets_v2::contract_obj,
+    );
+
+    lp_setup.set_unlock_schedule(vec![(15, 2_500), (20, 5_000), (30, 2_500)]);
+    let participant = &lp_setup.participants[0].clone();
+
+    lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND);
+    lp_setup.confirm(participant, 1).assert_ok();
+
+    lp_setup
+        .b_mock
+        .set_block_round(WINNER_SELECTION_START_ROUND);
+    lp_setup.filter_tickets().assert_ok();
+    lp_setup.select_base_winners_mock(1).assert_ok();
+    lp_setup.distribute_tickets().assert_ok();
+
+    // (round, last unlocked milestone index, claimed so far)
+    let claims = [(15, 0, 25), (25, 1, 75), (30, 2, 100)];
+    for (round, milestone_index, claimed) in claims {
+        lp_setup.b_mock.set_block_round(round);
+        lp_setup.claim_user(participant).assert_ok();
+        lp_setup.b_mock.check_esdt_balance(
+            participant,
+            LAUNCHPAD_TOKEN_ID,
+            &rust_biguint!(claimed),
+        );
+
+        lp_setup
+            .b_mock
+            .execute_query(&lp_setup.lp_wrapper, |sc| {
+                let user = managed_address!(participant);
+                let unlock_schedule = sc.get_effective_unlock_schedule(&user);
+                assert_eq!(
+                    unlock_schedule.get_nr_unlocked_milestones(round),
+                    milestone_index + 1
+                );
+
+                let remaining_balance = sc.user_total_claimable_balance(&user).get()
+                    - sc.user_claimed_balance(&user).get();
+                assert_eq!(
+                    remaining_balance,
+                    managed_biguint!(LAUNCHPAD_TOKENS_PER_TICKET - claimed)
+                );
+            })
+            .assert_ok();
+    }
+}
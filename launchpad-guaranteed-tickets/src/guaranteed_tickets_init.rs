@@ -4,6 +4,12 @@ multiversx_sc::derive_imports!();
 pub const STAKING_GUARANTEED_TICKETS_NO: usize = 1;
 pub const MIGRATION_GUARANTEED_TICKETS_NO: usize = 1;
 
+#[derive(TypeAbi, TopEncode, TopDecode)]
+pub struct GuaranteedTicketsRatio {
+    pub confirmed_tickets_per_guaranteed_ticket: usize,
+    pub max_guaranteed_tickets_per_user: usize,
+}
+
 #[derive(TopEncode, TopDecode)]
 pub struct UserTicketsStatus {
     pub staking_tickets_allowance: usize,
@@ -30,6 +36,32 @@ pub trait GuaranteedTicketsInitModule:
     + launchpad_common::ongoing_operation::OngoingOperationModule
     + launchpad_common::tickets::TicketsModule
 {
+    /// Replaces the fixed staking guaranteed ticket with
+    /// one guaranteed ticket per `confirmed_tickets_per_guaranteed_ticket` staking tickets,
+    /// capped at `max_guaranteed_tickets_per_user`
+    #[only_owner]
+    #[endpoint(setGuaranteedTicketsRatio)]
+    fn set_guaranteed_tickets_ratio(
+        &self,
+        confirmed_tickets_per_guaranteed_ticket: usize,
+        max_guaranteed_tickets_per_user: usize,
+    ) {
+        self.require_add_tickets_period();
+        require!(
+            self.last_ticket_id().is_empty(),
+            "Ratio must be set before adding tickets"
+        );
+        require!(
+            confirmed_tickets_per_guaranteed_ticket > 0 && max_guaranteed_tickets_per_user > 0,
+            "Invalid guaranteed tickets ratio"
+        );
+
+        self.guaranteed_tickets_ratio().set(GuaranteedTicketsRatio {
+            confirmed_tickets_per_guaranteed_ticket,
+            max_guaranteed_tickets_per_user,
+        });
+    }
+
     fn add_tickets_with_guaranteed_winners(
         &self,
         address_number_pairs: MultiValueEncoded<MultiValue4<ManagedAddress, usize, usize, bool>>,
@@ -49,15 +81,19 @@ pub trait GuaranteedTicketsInitModule:
             let mut user_ticket_status =
                 UserTicketsStatus::new(nr_staking_tickets, nr_energy_tickets);
 
-            if nr_staking_tickets >= min_confirmed_for_guaranteed_ticket {
+            let staking_guaranteed_tickets = self.get_staking_guaranteed_tickets(
+                nr_staking_tickets,
+                min_confirmed_for_guaranteed_ticket,
+            );
+            if staking_guaranteed_tickets > 0 {
                 require!(
-                    total_winning_tickets > 0,
+                    total_winning_tickets >= staking_guaranteed_tickets,
                     "Too many users with guaranteed ticket"
                 );
                 let _ = guaranteed_ticket_whitelist.insert(buyer.clone());
-                total_winning_tickets -= STAKING_GUARANTEED_TICKETS_NO;
-                total_guaranteed_tickets += STAKING_GUARANTEED_TICKETS_NO;
-                user_ticket_status.staking_guaranteed_tickets = STAKING_GUARANTEED_TICKETS_NO;
+                total_winning_tickets -= staking_guaranteed_tickets;
+                total_guaranteed_tickets += staking_guaranteed_tickets;
+                user_ticket_status.staking_guaranteed_tickets = staking_guaranteed_tickets;
             }
 
             if has_migrated_tokens {
@@ -79,6 +115,27 @@ pub trait GuaranteedTicketsInitModule:
         self.nr_winning_tickets().set(total_winning_tickets);
     }
 
+    fn get_staking_guaranteed_tickets(
+        &self,
+        nr_staking_tickets: usize,
+        min_confirmed_for_guaranteed_ticket: usize,
+    ) -> usize {
+        let ratio_mapper = self.guaranteed_tickets_ratio();
+        if ratio_mapper.is_empty() {
+            if nr_staking_tickets >= min_confirmed_for_guaranteed_ticket {
+                return STAKING_GUARANTEED_TICKETS_NO;
+            }
+
+            return 0;
+        }
+
+        let ratio = ratio_mapper.get();
+        core::cmp::min(
+            nr_staking_tickets / ratio.confirmed_tickets_per_guaranteed_ticket,
+            ratio.max_guaranteed_tickets_per_user,
+        )
+    }
+
     fn clear_users_with_guaranteed_ticket_after_blacklist(
         &self,
         users: &ManagedVec<ManagedAddress>,
@@ -107,7 +164,13 @@ pub trait GuaranteedTicketsInitModule:
             .set(total_guaranteed_tickets);
     }
 
-    fn remove_guaranteed_tickets_from_blacklist(&self, users: &ManagedVec<ManagedAddress>) {
+    /// Without restoring, the users keep their ticket allowances,
+    /// but their guaranteed tickets stay in the base selection pool
+    fn remove_guaranteed_tickets_from_blacklist(
+        &self,
+        users: &ManagedVec<ManagedAddress>,
+        restore_guaranteed_tickets: bool,
+    ) {
         let mut nr_winning_tickets = self.nr_winning_tickets().get();
         let mut total_guaranteed_tickets = self.total_guaranteed_tickets().get();
         let mut whitelist = self.users_with_guaranteed_ticket();
@@ -119,8 +182,23 @@ pub trait GuaranteedTicketsInitModule:
                 continue;
             }
 
+            let mut user_ticket_status = self.blacklist_user_ticket_status(&user).take();
+            if !restore_guaranteed_tickets {
+                user_ticket_status.staking_guaranteed_tickets = 0;
+                user_ticket_status.migration_guaranteed_tickets = 0;
+                user_ticket_status_mapper.set(user_ticket_status);
+
+                continue;
+            }
+
             if whitelist.insert(user.clone()) {
-                let user_ticket_status = self.blacklist_user_ticket_status(&user).take();
+                let user_guaranteed_tickets = user_ticket_status.staking_guaranteed_tickets
+                    + user_ticket_status.migration_guaranteed_tickets;
+                require!(
+                    nr_winning_tickets >= user_guaranteed_tickets,
+                    "Too many users with guaranteed ticket"
+                );
+
                 nr_winning_tickets -= user_ticket_status.staking_guaranteed_tickets;
                 nr_winning_tickets -= user_ticket_status.migration_guaranteed_tickets;
                 total_guaranteed_tickets += user_ticket_status.staking_guaranteed_tickets;
@@ -134,9 +212,30 @@ pub trait GuaranteedTicketsInitModule:
             .set(total_guaranteed_tickets);
     }
 
+    fn migrate_guaranteed_tickets(
+        &self,
+        old_address: &ManagedAddress,
+        new_address: &ManagedAddress,
+    ) {
+        let mut whitelist = self.users_with_guaranteed_ticket();
+        if whitelist.swap_remove(old_address) {
+            let _ = whitelist.insert(new_address.clone());
+        }
+
+        let user_ticket_status_mapper = self.user_ticket_status(old_address);
+        if !user_ticket_status_mapper.is_empty() {
+            self.user_ticket_status(new_address)
+                .set(user_ticket_status_mapper.take());
+        }
+    }
+
     #[storage_mapper("minConfirmedForGuaranteedTicket")]
     fn min_confirmed_for_guaranteed_ticket(&self) -> SingleValueMapper<usize>;
 
+    #[view(getGuaranteedTicketsRatio)]
+    #[storage_mapper("guaranteedTicketsRatio")]
+    fn guaranteed_tickets_ratio(&self) -> SingleValueMapper<GuaranteedTicketsRatio>;
+
     #[storage_mapper("usersWithGuaranteedTicket")]
     fn users_with_guaranteed_ticket(&self) -> UnorderedSetMapper<ManagedAddress>;
 
@@ -1,7 +1,7 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
-use launchpad_common::config;
+use launchpad_common::{config, guardian::GuardedOperationType, timelock::PendingChangeType};
 
 pub const MAX_PERCENTAGE: u64 = 10_000;
 
@@ -33,7 +33,12 @@ impl UnlockSchedule {
 }
 
 #[multiversx_sc::module]
-pub trait TokenReleaseModule: config::ConfigModule {
+pub trait TokenReleaseModule:
+    config::ConfigModule
+    + launchpad_common::permissions::PermissionsModule
+    + launchpad_common::timelock::TimelockModule
+    + launchpad_common::guardian::GuardianModule
+{
     #[only_owner]
     #[endpoint(setUnlockSchedule)]
     fn set_unlock_schedule(
@@ -44,18 +49,9 @@ pub trait TokenReleaseModule: config::ConfigModule {
         vesting_release_percentage: u64,
         vesting_release_period: u64,
     ) {
-        let configuration = self.configuration();
-        require!(
-            !configuration.is_empty(),
-            "Timeline configuration is not set"
-        );
-        let confirmation_period_start_round = configuration.get().confirmation_period_start_round;
+        self.require_can_change_unlock_schedule();
 
-        let current_round = self.blockchain().get_block_round();
-        require!(
-            current_round < confirmation_period_start_round || self.unlock_schedule().is_empty(),
-            "Can't change the unlock schedule"
-        );
+        let current_round = self.get_current_time();
         require!(
             claim_start_round >= current_round,
             "Wrong claim start round"
@@ -81,9 +77,42 @@ pub trait TokenReleaseModule: config::ConfigModule {
             vesting_release_period,
         );
 
+        let mut encoded_data = ManagedBuffer::new();
+        let _ = unlock_schedule.top_encode(&mut encoded_data);
+        self.require_guardian_approval(GuardedOperationType::UnlockSchedule, &encoded_data);
+
+        if self.is_timelock_enabled() {
+            let _ = self.queue_pending_change(PendingChangeType::Custom { encoded_data });
+
+            return;
+        }
+
         self.unlock_schedule().set(unlock_schedule);
     }
 
+    fn apply_unlock_schedule_change(&self, encoded_data: ManagedBuffer) {
+        self.require_can_change_unlock_schedule();
+
+        let unlock_schedule = UnlockSchedule::top_decode(encoded_data)
+            .unwrap_or_else(|_| sc_panic!("Failed to deserialize unlock schedule"));
+        self.unlock_schedule().set(unlock_schedule);
+    }
+
+    fn require_can_change_unlock_schedule(&self) {
+        let configuration = self.configuration();
+        require!(
+            !configuration.is_empty(),
+            "Timeline configuration is not set"
+        );
+        let confirmation_period_start_round = configuration.get().confirmation_period_start_round;
+
+        let current_round = self.get_current_time();
+        require!(
+            current_round < confirmation_period_start_round || self.unlock_schedule().is_empty(),
+            "Can't change the unlock schedule"
+        );
+    }
+
     #[view(getClaimableTokens)]
     fn compute_claimable_tokens(&self, address: &ManagedAddress) -> BigUint {
         let user_total_claimable_balance = self.user_total_claimable_balance(address).get();
@@ -102,7 +131,7 @@ pub trait TokenReleaseModule: config::ConfigModule {
             return BigUint::zero();
         }
         let unlock_schedule = unlock_schedule_mapper.get();
-        let current_round = self.blockchain().get_block_round();
+        let current_round = self.get_current_time();
         if unlock_schedule.claim_start_round > current_round {
             return BigUint::zero();
         }
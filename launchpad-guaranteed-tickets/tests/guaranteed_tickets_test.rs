@@ -7,6 +7,7 @@ use guaranteed_tickets_setup::{
     LAUNCHPAD_TOKEN_ID, MAX_TIER_TICKETS, TICKET_COST, WINNER_SELECTION_START_ROUND,
 };
 use launchpad_common::{
+    blacklist::BlacklistReason,
     config::ConfigModule,
     setup::SetupModule,
     tickets::{TicketsModule, WINNING_TICKET},
@@ -16,7 +17,7 @@ use launchpad_guaranteed_tickets::{
     guaranteed_ticket_winners::{
         GuaranteedTicketWinnersModule, GuaranteedTicketsSelectionOperation,
     },
-    guaranteed_tickets_init::GuaranteedTicketsInitModule,
+    guaranteed_tickets_init::{GuaranteedTicketsInitModule, GuaranteedTicketsRatio},
     LaunchpadGuaranteedTickets,
 };
 use multiversx_sc::types::{EgldOrEsdtTokenIdentifier, MultiValueEncoded};
@@ -55,12 +56,12 @@ fn confirm_all_test() {
     lp_setup
         .b_mock
         .execute_query(&lp_setup.lp_wrapper, |sc| {
-            assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(2).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(3).get(), false);
-            assert_eq!(sc.ticket_status(4).get(), false);
-            assert_eq!(sc.ticket_status(5).get(), false);
-            assert_eq!(sc.ticket_status(6).get(), false);
+            assert_eq!(sc.get_ticket_status(1), WINNING_TICKET);
+            assert_eq!(sc.get_ticket_status(2), WINNING_TICKET);
+            assert_eq!(sc.get_ticket_status(3), false);
+            assert_eq!(sc.get_ticket_status(4), false);
+            assert_eq!(sc.get_ticket_status(5), false);
+            assert_eq!(sc.get_ticket_status(6), false);
 
             assert_eq!(
                 sc.get_number_of_winning_tickets_for_address(managed_address!(&participants[0])),
@@ -85,12 +86,12 @@ fn confirm_all_test() {
     lp_setup
         .b_mock
         .execute_query(&lp_setup.lp_wrapper, |sc| {
-            assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(2).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(3).get(), false);
-            assert_eq!(sc.ticket_status(4).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(5).get(), false);
-            assert_eq!(sc.ticket_status(6).get(), false);
+            assert_eq!(sc.get_ticket_status(1), WINNING_TICKET);
+            assert_eq!(sc.get_ticket_status(2), WINNING_TICKET);
+            assert_eq!(sc.get_ticket_status(3), false);
+            assert_eq!(sc.get_ticket_status(4), WINNING_TICKET);
+            assert_eq!(sc.get_ticket_status(5), false);
+            assert_eq!(sc.get_ticket_status(6), false);
 
             assert_eq!(
                 sc.get_number_of_winning_tickets_for_address(managed_address!(&participants[0])),
@@ -171,11 +172,11 @@ fn redistribute_test() {
     lp_setup
         .b_mock
         .execute_query(&lp_setup.lp_wrapper, |sc| {
-            assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(2).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(3).get(), false);
-            assert_eq!(sc.ticket_status(4).get(), false);
-            assert_eq!(sc.ticket_status(5).get(), false);
+            assert_eq!(sc.get_ticket_status(1), WINNING_TICKET);
+            assert_eq!(sc.get_ticket_status(2), WINNING_TICKET);
+            assert_eq!(sc.get_ticket_status(3), false);
+            assert_eq!(sc.get_ticket_status(4), false);
+            assert_eq!(sc.get_ticket_status(5), false);
 
             assert_eq!(
                 sc.get_number_of_winning_tickets_for_address(managed_address!(&participants[0])),
@@ -201,11 +202,11 @@ fn redistribute_test() {
     lp_setup
         .b_mock
         .execute_query(&lp_setup.lp_wrapper, |sc| {
-            assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(2).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(3).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(4).get(), false);
-            assert_eq!(sc.ticket_status(5).get(), false);
+            assert_eq!(sc.get_ticket_status(1), WINNING_TICKET);
+            assert_eq!(sc.get_ticket_status(2), WINNING_TICKET);
+            assert_eq!(sc.get_ticket_status(3), WINNING_TICKET);
+            assert_eq!(sc.get_ticket_status(4), false);
+            assert_eq!(sc.get_ticket_status(5), false);
 
             assert_eq!(
                 sc.get_number_of_winning_tickets_for_address(managed_address!(&participants[0])),
@@ -287,13 +288,13 @@ fn combined_scenario_test() {
     lp_setup
         .b_mock
         .execute_query(&lp_setup.lp_wrapper, |sc| {
-            assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(2).get(), false);
-            assert_eq!(sc.ticket_status(3).get(), false);
-            assert_eq!(sc.ticket_status(4).get(), false);
-            assert_eq!(sc.ticket_status(5).get(), false);
-            assert_eq!(sc.ticket_status(6).get(), false);
-            assert_eq!(sc.ticket_status(7).get(), false);
+            assert_eq!(sc.get_ticket_status(1), WINNING_TICKET);
+            assert_eq!(sc.get_ticket_status(2), false);
+            assert_eq!(sc.get_ticket_status(3), false);
+            assert_eq!(sc.get_ticket_status(4), false);
+            assert_eq!(sc.get_ticket_status(5), false);
+            assert_eq!(sc.get_ticket_status(6), false);
+            assert_eq!(sc.get_ticket_status(7), false);
 
             assert_eq!(sc.nr_winning_tickets().get(), NR_WINNING_TICKETS - 2);
             assert_eq!(sc.users_with_guaranteed_ticket().len(), 2);
@@ -314,13 +315,13 @@ fn combined_scenario_test() {
                 sc.select_guaranteed_tickets(&mut op);
 
                 // user[3]'s first ticket was selected
-                assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET);
-                assert_eq!(sc.ticket_status(2).get(), false);
-                assert_eq!(sc.ticket_status(3).get(), false);
-                assert_eq!(sc.ticket_status(4).get(), WINNING_TICKET);
-                assert_eq!(sc.ticket_status(5).get(), false);
-                assert_eq!(sc.ticket_status(6).get(), false);
-                assert_eq!(sc.ticket_status(7).get(), false);
+                assert_eq!(sc.get_ticket_status(1), WINNING_TICKET);
+                assert_eq!(sc.get_ticket_status(2), false);
+                assert_eq!(sc.get_ticket_status(3), false);
+                assert_eq!(sc.get_ticket_status(4), WINNING_TICKET);
+                assert_eq!(sc.get_ticket_status(5), false);
+                assert_eq!(sc.get_ticket_status(6), false);
+                assert_eq!(sc.get_ticket_status(7), false);
 
                 assert_eq!(op.leftover_tickets, 1);
                 assert_eq!(op.total_additional_winning_tickets, 1);
@@ -330,13 +331,13 @@ fn combined_scenario_test() {
                 sc.distribute_leftover_tickets(&mut op);
 
                 // ticket ID 2 was selected as winner
-                assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET);
-                assert_eq!(sc.ticket_status(2).get(), WINNING_TICKET);
-                assert_eq!(sc.ticket_status(3).get(), false);
-                assert_eq!(sc.ticket_status(4).get(), WINNING_TICKET);
-                assert_eq!(sc.ticket_status(5).get(), false);
-                assert_eq!(sc.ticket_status(6).get(), false);
-                assert_eq!(sc.ticket_status(7).get(), false);
+                assert_eq!(sc.get_ticket_status(1), WINNING_TICKET);
+                assert_eq!(sc.get_ticket_status(2), WINNING_TICKET);
+                assert_eq!(sc.get_ticket_status(3), false);
+                assert_eq!(sc.get_ticket_status(4), WINNING_TICKET);
+                assert_eq!(sc.get_ticket_status(5), false);
+                assert_eq!(sc.get_ticket_status(6), false);
+                assert_eq!(sc.get_ticket_status(7), false);
 
                 assert_eq!(op.leftover_tickets, 0);
                 assert_eq!(op.total_additional_winning_tickets, 2);
@@ -417,16 +418,16 @@ fn add_migration_guaranteed_tickets_distribution_isolated_steps_scenario_test()
     lp_setup
         .b_mock
         .execute_query(&lp_setup.lp_wrapper, |sc| {
-            assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(2).get(), false);
-            assert_eq!(sc.ticket_status(3).get(), false);
-            assert_eq!(sc.ticket_status(4).get(), false);
-            assert_eq!(sc.ticket_status(5).get(), false);
-            assert_eq!(sc.ticket_status(6).get(), false);
-            assert_eq!(sc.ticket_status(7).get(), false);
-            assert_eq!(sc.ticket_status(8).get(), false);
-            assert_eq!(sc.ticket_status(9).get(), false);
-            assert_eq!(sc.ticket_status(10).get(), false);
+            assert_eq!(sc.get_ticket_status(1), WINNING_TICKET);
+            assert_eq!(sc.get_ticket_status(2), false);
+            assert_eq!(sc.get_ticket_status(3), false);
+            assert_eq!(sc.get_ticket_status(4), false);
+            assert_eq!(sc.get_ticket_status(5), false);
+            assert_eq!(sc.get_ticket_status(6), false);
+            assert_eq!(sc.get_ticket_status(7), false);
+            assert_eq!(sc.get_ticket_status(8), false);
+            assert_eq!(sc.get_ticket_status(9), false);
+            assert_eq!(sc.get_ticket_status(10), false);
 
             assert_eq!(
                 sc.nr_winning_tickets().get(),
@@ -454,16 +455,16 @@ fn add_migration_guaranteed_tickets_distribution_isolated_steps_scenario_test()
                 // first step
                 sc.select_guaranteed_tickets(&mut op);
 
-                assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET); // randomly selected -> leftover_ticket
-                assert_eq!(sc.ticket_status(2).get(), false);
-                assert_eq!(sc.ticket_status(3).get(), false);
-                assert_eq!(sc.ticket_status(4).get(), WINNING_TICKET); // migration guaranteed ticket -> additional_winning_tickets
-                assert_eq!(sc.ticket_status(5).get(), WINNING_TICKET); // staking guaranteed ticket -> additional_winning_tickets
-                assert_eq!(sc.ticket_status(6).get(), WINNING_TICKET); // migration guaranteed ticket -> additional_winning_tickets
-                assert_eq!(sc.ticket_status(7).get(), false);
-                assert_eq!(sc.ticket_status(8).get(), false);
-                assert_eq!(sc.ticket_status(9).get(), false);
-                assert_eq!(sc.ticket_status(10).get(), false);
+                assert_eq!(sc.get_ticket_status(1), WINNING_TICKET); // randomly selected -> leftover_ticket
+                assert_eq!(sc.get_ticket_status(2), false);
+                assert_eq!(sc.get_ticket_status(3), false);
+                assert_eq!(sc.get_ticket_status(4), WINNING_TICKET); // migration guaranteed ticket -> additional_winning_tickets
+                assert_eq!(sc.get_ticket_status(5), WINNING_TICKET); // staking guaranteed ticket -> additional_winning_tickets
+                assert_eq!(sc.get_ticket_status(6), WINNING_TICKET); // migration guaranteed ticket -> additional_winning_tickets
+                assert_eq!(sc.get_ticket_status(7), false);
+                assert_eq!(sc.get_ticket_status(8), false);
+                assert_eq!(sc.get_ticket_status(9), false);
+                assert_eq!(sc.get_ticket_status(10), false);
 
                 assert_eq!(op.leftover_tickets, 1);
                 assert_eq!(op.total_additional_winning_tickets, 3);
@@ -472,16 +473,16 @@ fn add_migration_guaranteed_tickets_distribution_isolated_steps_scenario_test()
                 // second step
                 sc.distribute_leftover_tickets(&mut op);
 
-                assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET);
-                assert_eq!(sc.ticket_status(2).get(), false);
-                assert_eq!(sc.ticket_status(3).get(), false);
-                assert_eq!(sc.ticket_status(4).get(), WINNING_TICKET);
-                assert_eq!(sc.ticket_status(5).get(), WINNING_TICKET);
-                assert_eq!(sc.ticket_status(6).get(), WINNING_TICKET);
-                assert_eq!(sc.ticket_status(7).get(), false);
-                assert_eq!(sc.ticket_status(8).get(), WINNING_TICKET); // randomly selected in distribute_leftover_tickets
-                assert_eq!(sc.ticket_status(9).get(), false);
-                assert_eq!(sc.ticket_status(10).get(), false);
+                assert_eq!(sc.get_ticket_status(1), WINNING_TICKET);
+                assert_eq!(sc.get_ticket_status(2), false);
+                assert_eq!(sc.get_ticket_status(3), false);
+                assert_eq!(sc.get_ticket_status(4), WINNING_TICKET);
+                assert_eq!(sc.get_ticket_status(5), WINNING_TICKET);
+                assert_eq!(sc.get_ticket_status(6), WINNING_TICKET);
+                assert_eq!(sc.get_ticket_status(7), false);
+                assert_eq!(sc.get_ticket_status(8), WINNING_TICKET); // randomly selected in distribute_leftover_tickets
+                assert_eq!(sc.get_ticket_status(9), false);
+                assert_eq!(sc.get_ticket_status(10), false);
 
                 assert_eq!(op.leftover_tickets, 0);
                 assert_eq!(op.total_additional_winning_tickets, 4);
@@ -912,7 +913,7 @@ fn blacklist_scenario_test() {
             |sc| {
                 let mut blacklist = MultiValueEncoded::new();
                 blacklist.push(managed_address!(&participants[4]));
-                sc.add_users_to_blacklist_endpoint(blacklist);
+                sc.add_users_to_blacklist_endpoint(BlacklistReason::Unspecified, blacklist);
             },
         )
         .assert_error(4, "Permission denied");
@@ -949,7 +950,7 @@ fn blacklist_scenario_test() {
             |sc| {
                 let mut blacklist = MultiValueEncoded::new();
                 blacklist.push(managed_address!(&second_new_participant));
-                sc.add_users_to_blacklist_endpoint(blacklist);
+                sc.add_users_to_blacklist_endpoint(BlacklistReason::Unspecified, blacklist);
             },
         )
         .assert_ok();
@@ -1000,7 +1001,7 @@ fn blacklist_scenario_test() {
             |sc| {
                 let mut blacklist = MultiValueEncoded::new();
                 blacklist.push(managed_address!(&second_new_participant));
-                sc.remove_guaranteed_users_from_blacklist_endpoint(blacklist);
+                sc.remove_guaranteed_users_from_blacklist_endpoint(true, blacklist);
             },
         )
         .assert_error(4, "Permission denied");
@@ -1015,7 +1016,7 @@ fn blacklist_scenario_test() {
             |sc| {
                 let mut blacklist = MultiValueEncoded::new();
                 blacklist.push(managed_address!(&second_new_participant));
-                sc.remove_guaranteed_users_from_blacklist_endpoint(blacklist);
+                sc.remove_guaranteed_users_from_blacklist_endpoint(true, blacklist);
             },
         )
         .assert_ok();
@@ -1037,7 +1038,7 @@ fn blacklist_scenario_test() {
             |sc| {
                 let mut blacklist = MultiValueEncoded::new();
                 blacklist.push(managed_address!(&second_new_participant));
-                sc.add_users_to_blacklist_endpoint(blacklist);
+                sc.add_users_to_blacklist_endpoint(BlacklistReason::Unspecified, blacklist);
             },
         )
         .assert_error(4, "May only modify blacklist before winner selection");
@@ -1048,13 +1049,13 @@ fn blacklist_scenario_test() {
     lp_setup
         .b_mock
         .execute_query(&lp_setup.lp_wrapper, |sc| {
-            assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(2).get(), false);
-            assert_eq!(sc.ticket_status(3).get(), false);
-            assert_eq!(sc.ticket_status(4).get(), false);
-            assert_eq!(sc.ticket_status(5).get(), false);
-            assert_eq!(sc.ticket_status(6).get(), false);
-            assert_eq!(sc.ticket_status(7).get(), false);
+            assert_eq!(sc.get_ticket_status(1), WINNING_TICKET);
+            assert_eq!(sc.get_ticket_status(2), false);
+            assert_eq!(sc.get_ticket_status(3), false);
+            assert_eq!(sc.get_ticket_status(4), false);
+            assert_eq!(sc.get_ticket_status(5), false);
+            assert_eq!(sc.get_ticket_status(6), false);
+            assert_eq!(sc.get_ticket_status(7), false);
 
             assert_eq!(sc.users_with_guaranteed_ticket().len(), 3);
         })
@@ -1065,12 +1066,12 @@ fn blacklist_scenario_test() {
     lp_setup
         .b_mock
         .execute_query(&lp_setup.lp_wrapper, |sc| {
-            assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(2).get(), false);
-            assert_eq!(sc.ticket_status(3).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(4).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(5).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(6).get(), false);
+            assert_eq!(sc.get_ticket_status(1), WINNING_TICKET);
+            assert_eq!(sc.get_ticket_status(2), false);
+            assert_eq!(sc.get_ticket_status(3), WINNING_TICKET);
+            assert_eq!(sc.get_ticket_status(4), WINNING_TICKET);
+            assert_eq!(sc.get_ticket_status(5), WINNING_TICKET);
+            assert_eq!(sc.get_ticket_status(6), false);
 
             assert_eq!(sc.users_with_guaranteed_ticket().len(), 0);
         })
@@ -1228,3 +1229,161 @@ fn confirm_less_tickets_than_total_available_with_vesting_scenario_test() {
         &rust_biguint!(0),
     );
 }
+
+#[test]
+fn proportional_guaranteed_tickets_test() {
+    let mut lp_setup = LaunchpadSetup::new(
+        NR_WINNING_TICKETS,
+        launchpad_guaranteed_tickets::contract_obj,
+    );
+
+    // tickets were already added during setup
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &lp_setup.owner_address,
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_guaranteed_tickets_ratio(2, 3);
+            },
+        )
+        .assert_error(4, "Add tickets period has passed");
+
+    lp_setup
+        .b_mock
+        .execute_query(&lp_setup.lp_wrapper, |sc| {
+            sc.guaranteed_tickets_ratio().set(GuaranteedTicketsRatio {
+                confirmed_tickets_per_guaranteed_ticket: 2,
+                max_guaranteed_tickets_per_user: 3,
+            });
+
+            assert_eq!(sc.get_staking_guaranteed_tickets(1, MAX_TIER_TICKETS), 0);
+            assert_eq!(sc.get_staking_guaranteed_tickets(5, MAX_TIER_TICKETS), 2);
+            assert_eq!(sc.get_staking_guaranteed_tickets(10, MAX_TIER_TICKETS), 3);
+
+            sc.guaranteed_tickets_ratio().clear();
+
+            assert_eq!(sc.get_staking_guaranteed_tickets(1, MAX_TIER_TICKETS), 0);
+            assert_eq!(sc.get_staking_guaranteed_tickets(10, MAX_TIER_TICKETS), 1);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn remove_from_blacklist_without_restoring_guaranteed_tickets_test() {
+    let nr_winning_tickets = 3;
+    let mut lp_setup = LaunchpadSetup::new(
+        nr_winning_tickets,
+        launchpad_guaranteed_tickets::contract_obj,
+    );
+    let new_participant = lp_setup
+        .b_mock
+        .create_user_account(&rust_biguint!(TICKET_COST));
+
+    lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND - 1);
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &lp_setup.owner_address,
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut args = MultiValueEncoded::new();
+                args.push((managed_address!(&new_participant), 1, 0, true).into());
+                sc.add_tickets_endpoint(args);
+
+                let mut blacklist = MultiValueEncoded::new();
+                blacklist.push(managed_address!(&new_participant));
+                sc.add_users_to_blacklist_endpoint(BlacklistReason::Unspecified, blacklist);
+            },
+        )
+        .assert_ok();
+
+    let (nr_winning_before, total_guaranteed_before) = {
+        let mut values = (0, 0);
+        lp_setup
+            .b_mock
+            .execute_query(&lp_setup.lp_wrapper, |sc| {
+                values = (
+                    sc.nr_winning_tickets().get(),
+                    sc.total_guaranteed_tickets().get(),
+                );
+            })
+            .assert_ok();
+        values
+    };
+
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &lp_setup.owner_address,
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut users = MultiValueEncoded::new();
+                users.push(managed_address!(&new_participant));
+                sc.remove_guaranteed_users_from_blacklist_endpoint(false, users);
+
+                let user_ticket_status = sc
+                    .user_ticket_status(&managed_address!(&new_participant))
+                    .get();
+                assert_eq!(user_ticket_status.staking_tickets_allowance, 1);
+                assert_eq!(user_ticket_status.migration_guaranteed_tickets, 0);
+                assert!(!sc
+                    .users_with_guaranteed_ticket()
+                    .contains(&managed_address!(&new_participant)));
+                assert_eq!(sc.nr_winning_tickets().get(), nr_winning_before);
+                assert_eq!(sc.total_guaranteed_tickets().get(), total_guaranteed_before);
+            },
+        )
+        .assert_ok();
+}
+
+#[test]
+fn contract_pause_test() {
+    let mut lp_setup = LaunchpadSetup::new(
+        NR_WINNING_TICKETS,
+        launchpad_guaranteed_tickets::contract_obj,
+    );
+    lp_setup.set_unlock_schedule(5, 10_000, 0, 0, 0);
+    let participants = lp_setup.participants.clone();
+
+    lp_setup.pause_contract();
+    lp_setup
+        .confirm(&participants[0], 1)
+        .assert_user_error("Contract is paused");
+    lp_setup.unpause_contract();
+
+    for (i, p) in participants.iter().enumerate() {
+        lp_setup.confirm(p, i + 1).assert_ok();
+    }
+
+    lp_setup
+        .b_mock
+        .set_block_round(WINNER_SELECTION_START_ROUND);
+    lp_setup.filter_tickets().assert_ok();
+    lp_setup.select_base_winners_mock(1).assert_ok();
+
+    lp_setup.pause_contract();
+    lp_setup
+        .distribute_tickets()
+        .assert_user_error("Contract is paused");
+    lp_setup.unpause_contract();
+    lp_setup.distribute_tickets().assert_ok();
+
+    lp_setup.b_mock.set_block_round(CLAIM_START_ROUND);
+
+    lp_setup.pause_contract();
+    lp_setup
+        .claim_user(&participants[0])
+        .assert_user_error("Contract is paused");
+    lp_setup.unpause_contract();
+
+    lp_setup.claim_user(&participants[0]).assert_ok();
+    lp_setup.b_mock.check_esdt_balance(
+        &participants[0],
+        LAUNCHPAD_TOKEN_ID,
+        &rust_biguint!(LAUNCHPAD_TOKENS_PER_TICKET),
+    );
+}
@@ -0,0 +1,35 @@
+use serde::Deserialize;
+use std::{fs::File, io::Read};
+
+const CONFIG_FILE: &str = "config.toml";
+
+#[derive(Debug, Deserialize)]
+pub struct Config {
+    pub gateway_uri: String,
+    pub owner_pem: String,
+    pub launchpad_mxsc: String,
+    pub launch: LaunchConfig,
+}
+
+/// Arguments of the launchpad's `init`. Amounts are given as decimal strings.
+#[derive(Debug, Deserialize)]
+pub struct LaunchConfig {
+    pub launchpad_token_id: String,
+    pub launchpad_tokens_per_winning_ticket: String,
+    pub ticket_payment_token: String,
+    pub ticket_price: String,
+    pub nr_winning_tickets: usize,
+    pub confirmation_period_start_round: u64,
+    pub winner_selection_start_round: u64,
+    pub claim_start_round: u64,
+}
+
+impl Config {
+    pub fn load_config() -> Self {
+        let mut file = File::open(CONFIG_FILE).unwrap();
+        let mut content = String::new();
+        file.read_to_string(&mut content).unwrap();
+
+        toml::from_str(&content).unwrap()
+    }
+}
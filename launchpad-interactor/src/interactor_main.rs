@@ -0,0 +1,200 @@
+mod interactor_config;
+mod interactor_state;
+
+use clap::{Parser, Subcommand};
+use interactor_config::{Config, LaunchConfig};
+use interactor_state::State;
+use multiversx_sc_snippets::imports::*;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+const DEPLOY_GAS: u64 = 200_000_000;
+const ADD_TICKETS_GAS: u64 = 600_000_000;
+const OPERATION_GAS: u64 = 600_000_000;
+const DEFAULT_ADD_TICKETS_BATCH_SIZE: usize = 100;
+
+type AddTicketsArgs = MultiValueEncoded<StaticApi, MultiValue2<ManagedAddress<StaticApi>, usize>>;
+
+#[derive(Parser)]
+#[command(about = "Launch operations for the launchpad contract")]
+struct Args {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Deploys a launchpad with the parameters from config.toml
+    Deploy,
+    /// Adds the tickets from a CSV file with `address,nr_tickets` lines
+    AddTickets {
+        csv_path: PathBuf,
+        #[arg(long, default_value_t = DEFAULT_ADD_TICKETS_BATCH_SIZE)]
+        batch_size: usize,
+    },
+    /// Calls filterTickets until it completes
+    FilterTickets,
+    /// Calls selectWinners until it completes
+    SelectWinners,
+}
+
+#[tokio::main]
+async fn main() {
+    let args = Args::parse();
+    let mut interactor = LaunchpadInteractor::new(Config::load_config()).await;
+    match args.command {
+        Command::Deploy => interactor.deploy().await,
+        Command::AddTickets {
+            csv_path,
+            batch_size,
+        } => interactor.add_tickets_from_csv(&csv_path, batch_size).await,
+        Command::FilterTickets => interactor.run_until_completed("filterTickets").await,
+        Command::SelectWinners => interactor.run_until_completed("selectWinners").await,
+    }
+}
+
+struct LaunchpadInteractor {
+    interactor: Interactor,
+    owner_address: Address,
+    launchpad_code: BytesValue,
+    launch_config: LaunchConfig,
+    state: State,
+}
+
+impl LaunchpadInteractor {
+    async fn new(config: Config) -> Self {
+        let mut interactor = Interactor::new(&config.gateway_uri).await;
+        let owner_address = interactor
+            .register_wallet(Wallet::from_pem_file(&config.owner_pem).unwrap())
+            .await;
+        let launchpad_code = BytesValue::interpret_from(
+            format!("mxsc:{}", config.launchpad_mxsc).as_str(),
+            &InterpreterContext::default(),
+        );
+
+        LaunchpadInteractor {
+            interactor,
+            owner_address,
+            launchpad_code,
+            launch_config: config.launch,
+            state: State::load_state(),
+        }
+    }
+
+    async fn deploy(&mut self) {
+        let launch = &self.launch_config;
+        let new_address = self
+            .interactor
+            .tx()
+            .from(&self.owner_address)
+            .gas(DEPLOY_GAS)
+            .raw_deploy()
+            .argument(&TokenIdentifier::<StaticApi>::from(
+                launch.launchpad_token_id.as_str(),
+            ))
+            .argument(&parse_amount(&launch.launchpad_tokens_per_winning_ticket))
+            .argument(&EgldOrEsdtTokenIdentifier::<StaticApi>::parse(
+                ManagedBuffer::from(launch.ticket_payment_token.as_str()),
+            ))
+            .argument(&parse_amount(&launch.ticket_price))
+            .argument(&launch.nr_winning_tickets)
+            .argument(&launch.confirmation_period_start_round)
+            .argument(&launch.winner_selection_start_round)
+            .argument(&launch.claim_start_round)
+            .code(&self.launchpad_code)
+            .code_metadata(CodeMetadata::UPGRADEABLE)
+            .returns(ReturnsNewBech32Address)
+            .run()
+            .await;
+
+        println!("new launchpad address: {new_address}");
+        self.state.set_launchpad_address(new_address);
+    }
+
+    /// Each batch is sent in a separate transaction, after the previous one was executed
+    async fn add_tickets_from_csv(&mut self, csv_path: &Path, batch_size: usize) {
+        assert!(batch_size > 0, "invalid batch size");
+
+        let entries = read_tickets_csv(csv_path);
+        let launchpad_address = self.state.launchpad_address().clone();
+        for (batch_index, batch) in entries.chunks(batch_size).enumerate() {
+            let mut args = AddTicketsArgs::new();
+            for (address, nr_tickets) in batch {
+                args.push((ManagedAddress::from(address), *nr_tickets).into());
+            }
+
+            self.interactor
+                .tx()
+                .from(&self.owner_address)
+                .to(&launchpad_address)
+                .gas(ADD_TICKETS_GAS)
+                .raw_call("addTickets")
+                .argument(&args)
+                .run()
+                .await;
+
+            println!(
+                "added tickets batch {} ({} addresses)",
+                batch_index,
+                batch.len()
+            );
+        }
+    }
+
+    /// For the endpoints which save their progress when running out of gas
+    async fn run_until_completed(&mut self, endpoint_name: &str) {
+        let launchpad_address = self.state.launchpad_address().clone();
+        loop {
+            let status = self
+                .interactor
+                .tx()
+                .from(&self.owner_address)
+                .to(&launchpad_address)
+                .gas(OPERATION_GAS)
+                .raw_call(endpoint_name)
+                .returns(ReturnsResultAs::<OperationCompletionStatus>::new())
+                .run()
+                .await;
+
+            if status == OperationCompletionStatus::Completed {
+                println!("{endpoint_name}: completed");
+                break;
+            }
+
+            println!("{endpoint_name}: interrupted before running out of gas, calling again");
+        }
+    }
+}
+
+fn parse_amount(amount: &str) -> BigUint<StaticApi> {
+    let amount = RustBigUint::from_str(amount).expect("invalid amount");
+
+    BigUint::from_bytes_be(&amount.to_bytes_be())
+}
+
+fn read_tickets_csv(csv_path: &Path) -> Vec<(Address, usize)> {
+    let content = fs::read_to_string(csv_path).expect("failed to read the CSV file");
+    let mut entries = Vec::new();
+    for (line_index, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        let (address, nr_tickets) = line
+            .split_once(',')
+            .unwrap_or_else(|| panic!("invalid CSV line {}", line_index + 1));
+        let address = Bech32Address::from_bech32_string(address.trim().to_string());
+        let nr_tickets = nr_tickets
+            .trim()
+            .parse::<usize>()
+            .unwrap_or_else(|_| panic!("invalid number of tickets on line {}", line_index + 1));
+
+        entries.push((address.to_address(), nr_tickets));
+    }
+
+    entries
+}
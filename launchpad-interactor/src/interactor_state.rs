@@ -0,0 +1,46 @@
+use multiversx_sc_snippets::imports::Bech32Address;
+use serde::{Deserialize, Serialize};
+use std::{
+    fs::File,
+    io::{Read, Write},
+};
+
+const STATE_FILE: &str = "state.toml";
+
+/// Kept between runs, so the launch operations target the last deployed contract
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct State {
+    launchpad_address: Option<Bech32Address>,
+}
+
+impl State {
+    pub fn load_state() -> Self {
+        match File::open(STATE_FILE) {
+            Ok(mut file) => {
+                let mut content = String::new();
+                file.read_to_string(&mut content).unwrap();
+
+                toml::from_str(&content).unwrap()
+            }
+            Err(_) => Self::default(),
+        }
+    }
+
+    pub fn set_launchpad_address(&mut self, address: Bech32Address) {
+        self.launchpad_address = Some(address);
+    }
+
+    pub fn launchpad_address(&self) -> &Bech32Address {
+        self.launchpad_address
+            .as_ref()
+            .expect("no launchpad deployed, run `deploy` first")
+    }
+}
+
+impl Drop for State {
+    fn drop(&mut self) {
+        let mut file = File::create(STATE_FILE).unwrap();
+        file.write_all(toml::to_string(self).unwrap().as_bytes())
+            .unwrap();
+    }
+}
@@ -3,7 +3,10 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
-use launchpad_common::launch_stage::Flags;
+use launchpad_common::{
+    blacklist::BlacklistReason, launch_stage::Flags, permissions::Role, LaunchConfiguration,
+    UserFullStatus,
+};
 use launchpad_guaranteed_tickets::guaranteed_ticket_winners::GuaranteedTicketsSelectionOperation;
 
 #[multiversx_sc::contract]
@@ -11,18 +14,48 @@ pub trait LaunchpadLockedTokensAndGuaranteedTickets:
     launchpad_common::LaunchpadMain
     + launchpad_common::launch_stage::LaunchStageModule
     + launchpad_common::config::ConfigModule
+    + launchpad_common::time_provider::TimeProviderModule
     + launchpad_common::setup::SetupModule
+    + launchpad_common::admin_proposal::AdminProposalModule
     + launchpad_common::tickets::TicketsModule
+    + launchpad_common::tickets_layout_migration::TicketsLayoutMigrationModule
     + launchpad_common::winner_selection::WinnerSelectionModule
+    + launchpad_common::commit_reveal::CommitRevealModule
     + launchpad_common::ongoing_operation::OngoingOperationModule
     + launchpad_common::permissions::PermissionsModule
+    + launchpad_common::timelock::TimelockModule
+    + launchpad_common::guardian::GuardianModule
     + launchpad_common::blacklist::BlacklistModule
     + launchpad_common::token_send::TokenSendModule
     + launchpad_common::user_interactions::UserInteractionsModule
+    + launchpad_common::consolation_airdrop::ConsolationAirdropModule
+    + launchpad_common::participation_badge::ParticipationBadgeModule
+    + launchpad_common::loyalty::LoyaltyModule
+    + launchpad_common::referral::ReferralModule
+    + launchpad_common::kyc::KycModule
+    + launchpad_common::signature_eligibility::SignatureEligibilityModule
+    + launchpad_common::relayer::RelayerModule
+    + launchpad_common::keeper_incentives::KeeperIncentivesModule
+    + launchpad_common::governance::GovernanceModule
+    + launchpad_common::price_oracle::PriceOracleModule
+    + launchpad_common::swap_and_confirm::SwapAndConfirmModule
+    + launchpad_common::locked_token_payment::LockedTokenPaymentModule
+    + launchpad_common::reward_bundle::RewardBundleModule
+    + launchpad_common::token_rescue::TokenRescueModule
+    + launchpad_common::unclaimed_sweep::UnclaimedSweepModule
+    + launchpad_common::storage_cleanup::StorageCleanupModule
+    + launchpad_common::invariants::InvariantsModule
+    + launchpad_common::winners_hash::WinnersHashModule
+    + launchpad_common::tiers::TiersModule
+    + launchpad_common::action_pause::ActionPauseModule
+    + launchpad_common::fcfs_round::FcfsRoundModule
+    + launchpad_common::wallet_migration::WalletMigrationModule
+    + launchpad_common::results_distribution::ResultsDistributionModule
     + launchpad_common::common_events::CommonEventsModule
     + launchpad_guaranteed_tickets::guaranteed_tickets_init::GuaranteedTicketsInitModule
     + launchpad_guaranteed_tickets::guaranteed_ticket_winners::GuaranteedTicketWinnersModule
     + launchpad_locked_tokens::locked_launchpad_token_send::LockedLaunchpadTokenSend
+    + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
     + multiversx_sc_modules::pause::PauseModule
 {
     #[allow(clippy::too_many_arguments)]
@@ -87,12 +120,23 @@ pub trait LaunchpadLockedTokensAndGuaranteedTickets:
     }
 
     #[endpoint(addUsersToBlacklist)]
-    fn add_users_to_blacklist_endpoint(&self, users_list: MultiValueEncoded<ManagedAddress>) {
+    fn add_users_to_blacklist_endpoint(
+        &self,
+        reason: BlacklistReason,
+        users_list: MultiValueEncoded<ManagedAddress>,
+    ) {
         let users_vec = users_list.to_vec();
-        self.add_users_to_blacklist(&users_vec);
+        self.add_users_to_blacklist(&users_vec, reason);
         self.clear_users_with_guaranteed_ticket_after_blacklist(&users_vec);
     }
 
+    /// Single entry point for the whole winner selection, which may be called repeatedly
+    /// until it returns `completed`
+    #[endpoint(completeWinnerSelection)]
+    fn complete_winner_selection_endpoint(&self) -> OperationCompletionStatus {
+        self.complete_winner_selection(Self::distribute_guaranteed_tickets_endpoint)
+    }
+
     #[endpoint(distributeGuaranteedTickets)]
     fn distribute_guaranteed_tickets_endpoint(&self) -> OperationCompletionStatus {
         self.require_winner_selection_period();
@@ -142,13 +186,81 @@ pub trait LaunchpadLockedTokensAndGuaranteedTickets:
     }
 
     #[endpoint(claimLaunchpadTokens)]
-    fn claim_launchpad_tokens_endpoint(&self) {
-        self.claim_launchpad_tokens(Self::send_locked_launchpad_tokens);
+    fn claim_launchpad_tokens_endpoint(&self, opt_receiver: OptionalValue<ManagedAddress>) {
+        self.claim_launchpad_tokens(opt_receiver, Self::send_locked_launchpad_tokens);
+    }
+
+    #[payable("*")]
+    #[endpoint(buyFcfsTickets)]
+    fn buy_fcfs_tickets_endpoint(&self, nr_tickets: usize) {
+        self.buy_fcfs_tickets(nr_tickets, Self::send_locked_launchpad_tokens);
+    }
+
+    #[endpoint(claimLaunchpadTokensFor)]
+    fn claim_launchpad_tokens_for_endpoint(&self, addresses: MultiValueEncoded<ManagedAddress>) {
+        self.claim_launchpad_tokens_for(addresses, Self::send_locked_launchpad_tokens);
+    }
+
+    #[endpoint(distributeResults)]
+    fn distribute_results_endpoint(&self) -> OperationCompletionStatus {
+        self.require_role(Role::Finance);
+        self.distribute_results(Self::send_locked_launchpad_tokens)
     }
 
-    #[only_owner]
     #[endpoint(claimTicketPayment)]
     fn claim_ticket_payment_endpoint(&self) {
+        self.require_role(Role::Finance);
         self.claim_ticket_payment();
     }
+
+    #[endpoint(rescueTokens)]
+    fn rescue_tokens_endpoint(&self, token_id: EgldOrEsdtTokenIdentifier, token_nonce: u64) {
+        self.require_role(Role::Finance);
+        self.rescue_tokens(token_id, token_nonce);
+    }
+
+    #[endpoint(executePendingChange)]
+    fn execute_pending_change_endpoint(&self, change_id: usize) {
+        self.execute_pending_change(change_id, Self::default_custom_change_fn);
+    }
+
+    #[endpoint(executeAdminProposal)]
+    fn execute_admin_proposal_endpoint(&self, proposal_id: usize) {
+        self.execute_admin_proposal(proposal_id, Self::default_custom_change_fn);
+    }
+
+    #[endpoint(approveWalletMigration)]
+    fn approve_wallet_migration_endpoint(&self, old_address: ManagedAddress) {
+        self.approve_wallet_migration(old_address, Self::migrate_guaranteed_tickets);
+    }
+
+    /// The release configuration is the lock percentage and the unlock epoch
+    #[view(getConfiguration)]
+    fn get_configuration_view(&self) -> LaunchConfiguration<Self::Api> {
+        let mut release_config = ManagedBuffer::new();
+        let _ = self
+            .launchpad_tokens_lock_percentage()
+            .get()
+            .dep_encode(&mut release_config);
+        let _ = self
+            .launchpad_tokens_unlock_epoch()
+            .get()
+            .dep_encode(&mut release_config);
+
+        self.get_launch_configuration(release_config)
+    }
+
+    #[view(getUserFullStatus)]
+    fn user_full_status(&self, address: ManagedAddress) -> UserFullStatus<Self::Api> {
+        let user_ticket_status_mapper = self.user_ticket_status(&address);
+        let guaranteed_tickets = if user_ticket_status_mapper.is_empty() {
+            0
+        } else {
+            let user_ticket_status = user_ticket_status_mapper.get();
+            user_ticket_status.staking_guaranteed_tickets
+                + user_ticket_status.migration_guaranteed_tickets
+        };
+
+        self.get_user_full_status(address, guaranteed_tickets)
+    }
 }
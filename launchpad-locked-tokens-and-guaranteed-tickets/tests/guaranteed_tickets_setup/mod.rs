@@ -8,7 +8,8 @@ use launchpad_common::{
 use launchpad_guaranteed_tickets::guaranteed_tickets_init::GuaranteedTicketsInitModule;
 use launchpad_locked_tokens_and_guaranteed_tickets::LaunchpadLockedTokensAndGuaranteedTickets;
 use multiversx_sc::types::{
-    Address, EgldOrEsdtTokenIdentifier, EsdtLocalRole, MultiValueEncoded, OperationCompletionStatus,
+    Address, EgldOrEsdtTokenIdentifier, EsdtLocalRole, MultiValueEncoded,
+    OperationCompletionStatus, OptionalValue,
 };
 use multiversx_sc_scenario::{
     managed_address, managed_biguint, managed_token_id, rust_biguint,
@@ -160,7 +161,7 @@ where
             &self.lp_wrapper,
             &rust_biguint!(TICKET_COST * nr_tickets as u64),
             |sc| {
-                sc.confirm_tickets(nr_tickets);
+                sc.confirm_tickets(OptionalValue::Some(nr_tickets), OptionalValue::None);
             },
         )
     }
@@ -184,8 +185,8 @@ where
             &rust_biguint!(0),
             |sc| {
                 let base_winning = NR_WINNING_TICKETS - nr_whales;
-                for ticket_id in 1..=base_winning {
-                    sc.ticket_status(ticket_id).set(WINNING_TICKET);
+                for ticket_id in 1..=base_winning as u64 {
+                    sc.set_ticket_status(ticket_id, WINNING_TICKET);
                 }
 
                 sc.claimable_ticket_payment()
@@ -216,7 +217,7 @@ where
     pub fn claim_user(&mut self, user: &Address) -> TxResult {
         self.b_mock
             .execute_tx(user, &self.lp_wrapper, &rust_biguint!(0), |sc| {
-                sc.claim_launchpad_tokens_endpoint();
+                sc.claim_launchpad_tokens_endpoint(OptionalValue::None);
             })
     }
 
@@ -50,12 +50,12 @@ fn confirm_all_test() {
     lp_setup
         .b_mock
         .execute_query(&lp_setup.lp_wrapper, |sc| {
-            assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(2).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(3).get(), false);
-            assert_eq!(sc.ticket_status(4).get(), false);
-            assert_eq!(sc.ticket_status(5).get(), false);
-            assert_eq!(sc.ticket_status(6).get(), false);
+            assert_eq!(sc.get_ticket_status(1), WINNING_TICKET);
+            assert_eq!(sc.get_ticket_status(2), WINNING_TICKET);
+            assert_eq!(sc.get_ticket_status(3), false);
+            assert_eq!(sc.get_ticket_status(4), false);
+            assert_eq!(sc.get_ticket_status(5), false);
+            assert_eq!(sc.get_ticket_status(6), false);
 
             assert_eq!(
                 sc.get_number_of_winning_tickets_for_address(managed_address!(&participants[0])),
@@ -80,12 +80,12 @@ fn confirm_all_test() {
     lp_setup
         .b_mock
         .execute_query(&lp_setup.lp_wrapper, |sc| {
-            assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(2).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(3).get(), false);
-            assert_eq!(sc.ticket_status(4).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(5).get(), false);
-            assert_eq!(sc.ticket_status(6).get(), false);
+            assert_eq!(sc.get_ticket_status(1), WINNING_TICKET);
+            assert_eq!(sc.get_ticket_status(2), WINNING_TICKET);
+            assert_eq!(sc.get_ticket_status(3), false);
+            assert_eq!(sc.get_ticket_status(4), WINNING_TICKET);
+            assert_eq!(sc.get_ticket_status(5), false);
+            assert_eq!(sc.get_ticket_status(6), false);
 
             assert_eq!(
                 sc.get_number_of_winning_tickets_for_address(managed_address!(&participants[0])),
@@ -180,11 +180,11 @@ fn redistribute_test() {
     lp_setup
         .b_mock
         .execute_query(&lp_setup.lp_wrapper, |sc| {
-            assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(2).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(3).get(), false);
-            assert_eq!(sc.ticket_status(4).get(), false);
-            assert_eq!(sc.ticket_status(5).get(), false);
+            assert_eq!(sc.get_ticket_status(1), WINNING_TICKET);
+            assert_eq!(sc.get_ticket_status(2), WINNING_TICKET);
+            assert_eq!(sc.get_ticket_status(3), false);
+            assert_eq!(sc.get_ticket_status(4), false);
+            assert_eq!(sc.get_ticket_status(5), false);
 
             assert_eq!(
                 sc.get_number_of_winning_tickets_for_address(managed_address!(&participants[0])),
@@ -210,11 +210,11 @@ fn redistribute_test() {
     lp_setup
         .b_mock
         .execute_query(&lp_setup.lp_wrapper, |sc| {
-            assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(2).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(3).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(4).get(), false);
-            assert_eq!(sc.ticket_status(5).get(), false);
+            assert_eq!(sc.get_ticket_status(1), WINNING_TICKET);
+            assert_eq!(sc.get_ticket_status(2), WINNING_TICKET);
+            assert_eq!(sc.get_ticket_status(3), WINNING_TICKET);
+            assert_eq!(sc.get_ticket_status(4), false);
+            assert_eq!(sc.get_ticket_status(5), false);
 
             assert_eq!(
                 sc.get_number_of_winning_tickets_for_address(managed_address!(&participants[0])),
@@ -294,13 +294,13 @@ fn combined_scenario_test() {
     lp_setup
         .b_mock
         .execute_query(&lp_setup.lp_wrapper, |sc| {
-            assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(2).get(), false);
-            assert_eq!(sc.ticket_status(3).get(), false);
-            assert_eq!(sc.ticket_status(4).get(), false);
-            assert_eq!(sc.ticket_status(5).get(), false);
-            assert_eq!(sc.ticket_status(6).get(), false);
-            assert_eq!(sc.ticket_status(7).get(), false);
+            assert_eq!(sc.get_ticket_status(1), WINNING_TICKET);
+            assert_eq!(sc.get_ticket_status(2), false);
+            assert_eq!(sc.get_ticket_status(3), false);
+            assert_eq!(sc.get_ticket_status(4), false);
+            assert_eq!(sc.get_ticket_status(5), false);
+            assert_eq!(sc.get_ticket_status(6), false);
+            assert_eq!(sc.get_ticket_status(7), false);
 
             assert_eq!(sc.nr_winning_tickets().get(), NR_WINNING_TICKETS - 2);
             assert_eq!(sc.users_with_guaranteed_ticket().len(), 2);
@@ -321,13 +321,13 @@ fn combined_scenario_test() {
                 sc.select_guaranteed_tickets(&mut op);
 
                 // user[3]'s first ticket was selected
-                assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET);
-                assert_eq!(sc.ticket_status(2).get(), false);
-                assert_eq!(sc.ticket_status(3).get(), false);
-                assert_eq!(sc.ticket_status(4).get(), WINNING_TICKET);
-                assert_eq!(sc.ticket_status(5).get(), false);
-                assert_eq!(sc.ticket_status(6).get(), false);
-                assert_eq!(sc.ticket_status(7).get(), false);
+                assert_eq!(sc.get_ticket_status(1), WINNING_TICKET);
+                assert_eq!(sc.get_ticket_status(2), false);
+                assert_eq!(sc.get_ticket_status(3), false);
+                assert_eq!(sc.get_ticket_status(4), WINNING_TICKET);
+                assert_eq!(sc.get_ticket_status(5), false);
+                assert_eq!(sc.get_ticket_status(6), false);
+                assert_eq!(sc.get_ticket_status(7), false);
 
                 assert_eq!(op.leftover_tickets, 1);
                 assert_eq!(op.total_additional_winning_tickets, 1);
@@ -337,13 +337,13 @@ fn combined_scenario_test() {
                 sc.distribute_leftover_tickets(&mut op);
 
                 // ticket ID 2 was selected as winner
-                assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET);
-                assert_eq!(sc.ticket_status(2).get(), WINNING_TICKET);
-                assert_eq!(sc.ticket_status(3).get(), false);
-                assert_eq!(sc.ticket_status(4).get(), WINNING_TICKET);
-                assert_eq!(sc.ticket_status(5).get(), false);
-                assert_eq!(sc.ticket_status(6).get(), false);
-                assert_eq!(sc.ticket_status(7).get(), false);
+                assert_eq!(sc.get_ticket_status(1), WINNING_TICKET);
+                assert_eq!(sc.get_ticket_status(2), WINNING_TICKET);
+                assert_eq!(sc.get_ticket_status(3), false);
+                assert_eq!(sc.get_ticket_status(4), WINNING_TICKET);
+                assert_eq!(sc.get_ticket_status(5), false);
+                assert_eq!(sc.get_ticket_status(6), false);
+                assert_eq!(sc.get_ticket_status(7), false);
 
                 assert_eq!(op.leftover_tickets, 0);
                 assert_eq!(op.total_additional_winning_tickets, 2);
@@ -5,9 +5,9 @@
 ////////////////////////////////////////////////////
 
 // Init:                                 1
-// Endpoints:                           36
-// Async Callback (empty):               1
-// Total number of exported functions:  38
+// Endpoints:                          245
+// Async Callback:                       1
+// Total number of exported functions: 247
 
 #![no_std]
 
@@ -21,40 +21,249 @@ multiversx_sc_wasm_adapter::endpoints! {
         addTickets => add_tickets_endpoint
         depositLaunchpadTokens => deposit_launchpad_tokens_endpoint
         addUsersToBlacklist => add_users_to_blacklist_endpoint
+        completeWinnerSelection => complete_winner_selection_endpoint
         distributeGuaranteedTickets => distribute_guaranteed_tickets_endpoint
         claimLaunchpadTokens => claim_launchpad_tokens_endpoint
+        buyFcfsTickets => buy_fcfs_tickets_endpoint
+        claimLaunchpadTokensFor => claim_launchpad_tokens_for_endpoint
+        distributeResults => distribute_results_endpoint
         claimTicketPayment => claim_ticket_payment_endpoint
+        rescueTokens => rescue_tokens_endpoint
+        executePendingChange => execute_pending_change_endpoint
+        executeAdminProposal => execute_admin_proposal_endpoint
+        approveWalletMigration => approve_wallet_migration_endpoint
+        getUserFullStatus => user_full_status
+        getStorageVersion => storage_version
+        getCurrentStage => get_current_stage
+        getLaunchPhases => get_launch_phases
+        getTimeUnit => time_unit
         getLaunchStageFlags => flags
-        getConfiguration => configuration
+        isAllowedSmartContractCaller => is_allowed_smart_contract_caller
+        getConfiguration => get_configuration_view
         getLaunchpadTokenId => launchpad_token_id
         getLaunchpadTokensPerWinningTicket => launchpad_tokens_per_winning_ticket
         getTicketPrice => ticket_price
         getNumberOfWinningTickets => nr_winning_tickets
         getTotalLaunchpadTokensDeposited => total_launchpad_tokens_deposited
+        getClaimDeadlineRound => claim_deadline_round
+        getRefundWindowStartRound => refund_window_start_round
+        getBurnUnsoldLaunchpadTokens => burn_unsold_launchpad_tokens
+        getDynamicWinningTickets => dynamic_winning_tickets
+        getGuaranteedTicketPerConfirmer => guaranteed_ticket_per_confirmer
+        isOrderedSelectionMode => ordered_selection_mode
+        getMaxWinningTicketsPerAddress => max_winning_tickets_per_address
+        getMaxConfirmedTicketsPerAddress => max_confirmed_tickets_per_address
+        getHardCap => hard_cap
+        getFcfsRound => fcfs_round
+        getClaimOnBehalfGraceRounds => claim_on_behalf_grace_rounds
+        getDenySmartContractCallers => deny_smart_contract_callers
         setTicketPrice => set_ticket_price
         setLaunchpadTokensPerWinningTicket => set_launchpad_tokens_per_winning_ticket
+        setLaunchPhases => set_launch_phases
+        setTimeUnit => set_time_unit
         setConfirmationPeriodStartRound => set_confirmation_period_start_round
         setWinnerSelectionStartRound => set_winner_selection_start_round
         setClaimStartRound => set_claim_start_round
+        setClaimDeadlineRound => set_claim_deadline_round
+        setRefundWindowStartRound => set_refund_window_start_round
+        setBurnUnsoldLaunchpadTokens => set_burn_unsold_launchpad_tokens
+        setDynamicWinningTickets => set_dynamic_winning_tickets
+        setGuaranteedTicketPerConfirmer => set_guaranteed_ticket_per_confirmer
+        setOrderedSelectionMode => set_ordered_selection_mode
+        setMaxWinningTicketsPerAddress => set_max_winning_tickets_per_address
+        setHardCap => set_hard_cap
+        setMaxConfirmedTicketsPerAddress => set_max_confirmed_tickets_per_address
+        setClaimOnBehalfGraceRounds => set_claim_on_behalf_grace_rounds
+        setDenySmartContractCallers => set_deny_smart_contract_callers
+        addAllowedSmartContractCallers => add_allowed_smart_contract_callers
+        removeAllowedSmartContractCallers => remove_allowed_smart_contract_callers
+        setAdminProposalQuorum => set_admin_proposal_quorum
+        proposeAdminAction => propose_admin_action
+        approveAdminProposal => approve_admin_proposal
+        cancelAdminProposal => cancel_admin_proposal
+        getAdminProposalQuorum => admin_proposal_quorum
+        getAdminProposal => admin_proposal
+        addTicketsToExistingUser => add_tickets_to_existing_user
+        removeTicketsFromUser => remove_tickets_from_user
+        finalizeAddTickets => finalize_add_tickets
+        getAddTicketsProgress => get_add_tickets_progress
+        getOngoingOperationStatus => get_ongoing_operation_status
+        setMinGasToSaveProgress => set_min_gas_to_save_progress
+        getMinGasToSaveProgress => get_min_gas_to_save_progress
         getTicketRangeForAddress => get_ticket_range_for_address
         getTotalNumberOfTicketsForAddress => get_total_number_of_tickets_for_address
+        getTicketStatusBatch => get_ticket_status_batch
         getTotalNumberOfTickets => last_ticket_id
         getNumberOfConfirmedTicketsForAddress => nr_confirmed_tickets
+        isAddTicketsFinalized => add_tickets_finalized
         filterTickets => filter_tickets
         selectWinners => select_winners
+        getTicketOwner => get_ticket_owner_view
         getNumberOfWinningTicketsForAddress => get_number_of_winning_tickets_for_address
         getWinningTicketIdsForAddress => get_winning_ticket_ids_for_address
-        setSupportAddress => add_support_address
-        getSupportAddress => support_address
+        getWinningTickets => get_winning_tickets
+        getWinnersAddresses => get_winners_addresses
+        getWinnerSelectionProof => winner_selection_proof
+        setUserEntropyCommitsEnabled => set_user_entropy_commits_enabled
+        commitEntropy => commit_entropy
+        revealEntropy => reveal_entropy
+        getUserEntropyCommitsEnabled => user_entropy_commits_enabled
+        getEntropyCommitment => entropy_commitment
+        getRevealedEntropy => revealed_entropy
+        proposeSupportAddress => propose_support_address
+        acceptSupportAddress => accept_support_address
+        removeSupportAddress => remove_support_address
+        addRole => add_role
+        acceptAdminRole => accept_admin_role
+        removeRole => remove_role
+        hasRole => has_role
+        getSupportAddresses => get_support_addresses
+        isSupportAddress => is_support_address
+        getPendingSupportAddress => pending_support_address
+        getRoleMembers => role_members
+        getPendingAdmins => pending_admins
+        setTimelockDelay => set_timelock_delay
+        cancelPendingChange => cancel_pending_change
+        getPendingChanges => get_pending_changes
+        getTimelockDelay => timelock_delay
+        getPendingChange => pending_change
+        setGuardian => set_guardian
+        removeGuardian => remove_guardian
+        approveOperation => approve_operation
+        revokeOperationApproval => revoke_operation_approval
+        isOperationApproved => is_operation_approved
+        getGuardianConfig => guardian_config
         isUserBlacklisted => is_user_blacklisted
+        getBlacklistedUsers => get_blacklisted_users
+        getNrBlacklistedUsers => get_nr_blacklisted_users
+        getBlacklistEntry => blacklist_entry
         confirmTickets => confirm_tickets
+        confirmAllTickets => confirm_all_tickets
+        confirmTicketsForUser => confirm_tickets_for_user
+        confirmTicketsFor => confirm_tickets_for
+        confirmTicketsWithSignature => confirm_tickets_with_signature
+        confirmTicketsWithLockedTokens => confirm_tickets_with_locked_tokens
+        unconfirmTickets => unconfirm_tickets
+        claimRefund => claim_refund
+        getRemainingRaiseCapacity => get_remaining_raise_capacity
+        getUserRefundableAmount => get_user_refundable_amount
+        getClaimableTicketPayment => claimable_ticket_payment
+        getUserTicketPayment => user_ticket_payment
+        getTotalRefundableTicketPayment => total_refundable_ticket_payment
         hasUserClaimedTokens => has_user_claimed
+        getUserClaimStatus => get_user_claim_status
+        getTotalConfirmedTickets => total_confirmed_tickets
+        getTotalParticipants => total_participants
+        getConfirmedUsers => get_confirmed_users
+        getTotalPaymentRaised => total_payment_raised
+        getTotalTokensClaimed => total_tokens_claimed
+        depositConsolationTokens => deposit_consolation_tokens
+        getConsolationPool => consolation_pool
+        getConsolationTokensPerTicket => consolation_tokens_per_ticket
+        getUserConsolationTickets => user_consolation_tickets
+        issueParticipationBadge => issue_participation_badge
+        setParticipationBadgeTransferRole => set_participation_badge_transfer_role
+        getParticipationBadgeTokenId => participation_badge
+        getParticipationBadgeNonce => participation_badge_nonce
+        setLoyaltyPointsConfig => set_loyalty_points_config
+        addLoyaltySourceLaunchpads => add_loyalty_source_launchpads
+        removeLoyaltySourceLaunchpads => remove_loyalty_source_launchpads
+        setLoyaltyBonusTiers => set_loyalty_bonus_tiers
+        grantLoyaltyBonusTickets => grant_loyalty_bonus_tickets
+        getAccumulatedLoyaltyPoints => get_accumulated_loyalty_points
+        getLoyaltyPoints => loyalty_points
+        getLoyaltyPointsConfig => loyalty_points_config
+        getLoyaltySourceLaunchpads => loyalty_source_launchpads
+        getLoyaltyBonusTiers => loyalty_bonus_tiers
+        getLoyaltyBonusTickets => loyalty_bonus_tickets
+        setReferralRewardPercentage => set_referral_reward_percentage
+        depositReferralRewards => deposit_referral_rewards
+        withdrawReferralRewardPool => withdraw_referral_reward_pool
+        claimReferralRewards => claim_referral_rewards
+        getReferralRewardPercentage => referral_reward_percentage
+        getReferralRewardPool => referral_reward_pool
+        getUserReferrer => user_referrer
+        getNrReferredUsers => nr_referred_users
+        getTotalReferralRewards => total_referral_rewards
+        getReferralRewards => referral_rewards
+        setKycRequired => set_kyc_required
+        setKycPublicKey => set_kyc_public_key
+        markKycPassed => mark_kyc_passed
+        revokeKyc => revoke_kyc
+        submitKycSignature => submit_kyc_signature
+        isKycPassed => is_kyc_passed
+        isKycRequired => kyc_required
+        getKycPublicKey => kyc_public_key
+        setEligibilitySigner => set_eligibility_signer
+        getEligibilitySigner => eligibility_signer
+        getRelayedConfirmationNonce => relayed_confirmation_nonce
+        setKeeperBounty => set_keeper_bounty
+        depositKeeperBountyPool => deposit_keeper_bounty_pool
+        withdrawKeeperBountyPool => withdraw_keeper_bounty_pool
+        getKeeperBounty => keeper_bounty
+        getKeeperBountyPool => keeper_bounty_pool
+        getKeeperBountiesPaid => keeper_bounties_paid
+        setGovernanceContract => set_governance_contract
+        removeGovernanceContract => remove_governance_contract
+        getGovernanceContract => governance_contract
+        setUsdTicketPricing => set_usd_ticket_pricing
+        removeUsdTicketPricing => remove_usd_ticket_pricing
+        getUsdTicketPriceQuote => get_usd_ticket_price_quote
+        getUsdPricingConfig => usd_pricing_config
+        isUsdTicketPriceFixed => usd_ticket_price_fixed
+        setSwapRouterAddress => set_swap_router_address
+        addSwapTokens => add_swap_tokens
+        removeSwapTokens => remove_swap_tokens
+        swapAndConfirmTickets => swap_and_confirm_tickets
+        getSwapRouterAddress => swap_router_address
+        getSwapTokenPair => swap_token_pair
+        setLockedTokenPayment => set_locked_token_payment
+        removeLockedTokenPayment => remove_locked_token_payment
+        getOwnerClaimableTicketPayment => get_owner_claimable_ticket_payment
+        getOutstandingRefunds => get_outstanding_refunds
+        getLockedPaymentTokenId => locked_payment_token_id
+        getLockedTokensPerTicket => locked_tokens_per_ticket
+        getUserLockedTickets => user_locked_tickets
+        getUserLockedPayments => user_locked_payments
+        getTotalLockedTickets => total_locked_tickets
+        getSettledLockedWinningTickets => settled_locked_winning_tickets
+        getTotalTicketPaymentClaimed => total_ticket_payment_claimed
+        setExtraRewardsPerWinningTicket => set_extra_rewards_per_winning_ticket
+        getRewardBundlePerWinningTicket => get_reward_bundle_per_winning_ticket
+        getExtraRewardsPerWinningTicket => extra_rewards_per_winning_ticket
+        setTreasuryAddress => set_treasury_address
+        sweepUnclaimed => sweep_unclaimed
+        cleanupStorage => cleanup_storage
+        verifyInvariants => verify_invariants
+        computeWinnersHash => compute_winners_hash
+        getWinnersHash => winners_hash
+        setTier => set_tier
+        removeTier => remove_tier
+        getAllTiers => get_all_tiers
+        getUserTierInfo => get_user_tier_info
+        getTier => tier
+        getUserTier => user_tier
+        wasStorageCleanedUp => storage_cleaned_up
+        getTreasuryAddress => treasury_address
+        setFcfsRound => set_fcfs_round
+        removeFcfsRound => remove_fcfs_round
+        getFcfsTicketsAvailable => get_fcfs_tickets_available
+        getFcfsTicketsBought => fcfs_tickets_bought
+        getTotalFcfsTicketsSold => total_fcfs_tickets_sold
+        requestWalletMigration => request_wallet_migration
+        cancelWalletMigration => cancel_wallet_migration
+        getWalletMigrationRequest => wallet_migration_request
+        setGuaranteedTicketsRatio => set_guaranteed_tickets_ratio
+        getGuaranteedTicketsRatio => guaranteed_tickets_ratio
         getLaunchpadTokensLockPercentage => launchpad_tokens_lock_percentage
         getLaunchpadTokensUnlockEpoch => launchpad_tokens_unlock_epoch
+        pauseAction => pause_action
+        unpauseAction => unpause_action
+        isActionPaused => action_paused
         pause => pause_endpoint
         unpause => unpause_endpoint
         isPaused => paused_status
     )
 }
 
-multiversx_sc_wasm_adapter::async_callback_empty! {}
+multiversx_sc_wasm_adapter::async_callback! { launchpad_locked_tokens_and_guaranteed_tickets }
@@ -0,0 +1,78 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::token_release::UnlockMilestone;
+
+#[derive(TypeAbi, TopEncode)]
+pub struct SetUnlockScheduleEvent<M: ManagedTypeApi> {
+    admin: ManagedAddress<M>,
+    round: u64,
+    epoch: u64,
+    milestones: ManagedVec<M, UnlockMilestone>,
+}
+
+#[derive(TypeAbi, TopEncode)]
+pub struct ClaimUnlockedTokensEvent<M: ManagedTypeApi> {
+    user: ManagedAddress<M>,
+    round: u64,
+    epoch: u64,
+    token_payment: EsdtTokenPayment<M>,
+}
+
+#[multiversx_sc::module]
+pub trait EventsModule {
+    fn emit_set_unlock_schedule_event(&self, milestones: ManagedVec<UnlockMilestone>) {
+        let admin = self.blockchain().get_caller();
+        let round = self.blockchain().get_block_round();
+        let epoch = self.blockchain().get_block_epoch();
+        self.set_unlock_schedule_event(
+            admin.clone(),
+            round,
+            epoch,
+            SetUnlockScheduleEvent {
+                admin,
+                round,
+                epoch,
+                milestones,
+            },
+        )
+    }
+
+    fn emit_claim_unlocked_tokens_event(
+        &self,
+        user: ManagedAddress,
+        token_payment: EsdtTokenPayment,
+    ) {
+        let round = self.blockchain().get_block_round();
+        let epoch = self.blockchain().get_block_epoch();
+        self.claim_unlocked_tokens_event(
+            user.clone(),
+            round,
+            epoch,
+            ClaimUnlockedTokensEvent {
+                user,
+                round,
+                epoch,
+                token_payment,
+            },
+        )
+    }
+
+    #[event("setUnlockSchedule")]
+    fn set_unlock_schedule_event(
+        &self,
+        #[indexed] admin: ManagedAddress,
+        #[indexed] round: u64,
+        #[indexed] epoch: u64,
+        set_unlock_schedule_event: SetUnlockScheduleEvent<Self::Api>,
+    );
+
+    #[event("claimUnlockedTokens")]
+    fn claim_unlocked_tokens_event(
+        &self,
+        #[indexed] user: ManagedAddress,
+        #[indexed] round: u64,
+        #[indexed] epoch: u64,
+        claim_unlocked_tokens_event: ClaimUnlockedTokensEvent<Self::Api>,
+    );
+}
@@ -3,25 +3,64 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
-use launchpad_common::{launch_stage::Flags, *};
+use launchpad_common::{
+    blacklist::BlacklistReason, launch_stage::Flags, permissions::Role, tiers::TierId, *,
+};
 
+pub mod events;
+pub mod lock_tiers;
 pub mod locked_launchpad_token_send;
+pub mod token_release;
 
 #[multiversx_sc::contract]
 pub trait LaunchpadLockedTokens:
     launchpad_common::LaunchpadMain
     + launch_stage::LaunchStageModule
     + config::ConfigModule
+    + time_provider::TimeProviderModule
     + setup::SetupModule
+    + admin_proposal::AdminProposalModule
     + tickets::TicketsModule
+    + tickets_layout_migration::TicketsLayoutMigrationModule
     + winner_selection::WinnerSelectionModule
+    + commit_reveal::CommitRevealModule
     + ongoing_operation::OngoingOperationModule
     + permissions::PermissionsModule
+    + timelock::TimelockModule
+    + guardian::GuardianModule
     + blacklist::BlacklistModule
     + token_send::TokenSendModule
     + user_interactions::UserInteractionsModule
-    + locked_launchpad_token_send::LockedLaunchpadTokenSend
+    + consolation_airdrop::ConsolationAirdropModule
+    + participation_badge::ParticipationBadgeModule
+    + loyalty::LoyaltyModule
+    + referral::ReferralModule
+    + kyc::KycModule
+    + signature_eligibility::SignatureEligibilityModule
+    + relayer::RelayerModule
+    + keeper_incentives::KeeperIncentivesModule
+    + governance::GovernanceModule
+    + price_oracle::PriceOracleModule
+    + swap_and_confirm::SwapAndConfirmModule
+    + locked_token_payment::LockedTokenPaymentModule
+    + reward_bundle::RewardBundleModule
+    + token_rescue::TokenRescueModule
+    + unclaimed_sweep::UnclaimedSweepModule
+    + storage_cleanup::StorageCleanupModule
+    + invariants::InvariantsModule
+    + winners_hash::WinnersHashModule
+    + tiers::TiersModule
+    + action_pause::ActionPauseModule
+    + fcfs_round::FcfsRoundModule
+    + legacy_import::LegacyImportModule
+    + wallet_migration::WalletMigrationModule
+    + results_distribution::ResultsDistributionModule
+    + launchpad_token_issuance::LaunchpadTokenIssuanceModule
+    + token_release::TokenReleaseModule
+    + lock_tiers::LockTiersModule
+    + events::EventsModule
     + common_events::CommonEventsModule
+    + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
     + multiversx_sc_modules::pause::PauseModule
 {
     #[allow(clippy::too_many_arguments)]
@@ -36,9 +75,7 @@ pub trait LaunchpadLockedTokens:
         confirmation_period_start_round: u64,
         winner_selection_start_round: u64,
         claim_start_round: u64,
-        launchpad_tokens_lock_percentage: u32,
-        launchpad_tokens_unlock_epoch: u64,
-        simple_lock_sc_address: ManagedAddress,
+        unlock_milestones: MultiValueEncoded<MultiValue2<u64, u64>>,
     ) {
         let flags = Flags {
             has_winner_selection_process_started: false,
@@ -58,9 +95,19 @@ pub trait LaunchpadLockedTokens:
             flags,
         );
 
-        self.try_set_launchpad_tokens_lock_percentage(launchpad_tokens_lock_percentage);
-        self.try_set_launchpad_tokens_unlock_epoch(launchpad_tokens_unlock_epoch);
-        self.try_set_simple_lock_sc_address(simple_lock_sc_address);
+        let unlock_schedule = self.build_unlock_schedule(unlock_milestones);
+        self.try_set_unlock_schedule(unlock_schedule);
+    }
+
+    #[upgrade]
+    fn upgrade(&self) {
+        self.upgrade_base(Self::migrate_storage);
+    }
+
+    fn migrate_storage(&self, previous_version: u32) {
+        if previous_version < TICKETS_LAYOUT_STORAGE_VERSION {
+            self.migrate_legacy_ticket_batch_lock_tiers();
+        }
     }
 
     #[only_owner]
@@ -72,6 +119,27 @@ pub trait LaunchpadLockedTokens:
         self.add_tickets(address_number_pairs);
     }
 
+    #[only_owner]
+    #[endpoint(addTicketsPacked)]
+    fn add_tickets_packed_endpoint(&self, packed_entries: ManagedBuffer) {
+        self.add_tickets_packed(packed_entries);
+    }
+
+    #[only_owner]
+    #[endpoint(addTicketsWithTiers)]
+    fn add_tickets_with_tiers_endpoint(
+        &self,
+        address_tier_pairs: MultiValueEncoded<MultiValue2<ManagedAddress, TierId>>,
+    ) {
+        self.add_tickets_with_tiers(address_tier_pairs);
+    }
+
+    #[only_owner]
+    #[endpoint(addTicketsChunk)]
+    fn add_tickets_chunk_endpoint(&self, chunk_index: usize, packed_entries: ManagedBuffer) {
+        self.add_tickets_chunk(chunk_index, packed_entries);
+    }
+
     #[only_owner]
     #[payable("*")]
     #[endpoint(depositLaunchpadTokens)]
@@ -80,19 +148,94 @@ pub trait LaunchpadLockedTokens:
         self.deposit_launchpad_tokens(nr_winning_tickets);
     }
 
+    #[only_owner]
+    #[endpoint(mintLaunchpadTokens)]
+    fn mint_launchpad_tokens_endpoint(&self) {
+        let nr_winning_tickets = self.nr_winning_tickets().get();
+        self.mint_launchpad_tokens(nr_winning_tickets);
+    }
+
     #[endpoint(claimLaunchpadTokens)]
-    fn claim_launchpad_tokens_endpoint(&self) {
-        self.claim_launchpad_tokens(Self::send_locked_launchpad_tokens);
+    fn claim_launchpad_tokens_endpoint(&self, opt_receiver: OptionalValue<ManagedAddress>) {
+        self.claim_launchpad_tokens(opt_receiver, Self::send_vested_launchpad_tokens);
+    }
+
+    #[payable("*")]
+    #[endpoint(buyFcfsTickets)]
+    fn buy_fcfs_tickets_endpoint(&self, nr_tickets: usize) {
+        self.buy_fcfs_tickets(nr_tickets, Self::send_vested_launchpad_tokens);
+    }
+
+    #[endpoint(claimLaunchpadTokensFor)]
+    fn claim_launchpad_tokens_for_endpoint(&self, addresses: MultiValueEncoded<ManagedAddress>) {
+        self.claim_launchpad_tokens_for(addresses, Self::send_vested_launchpad_tokens);
+    }
+
+    #[endpoint(distributeResults)]
+    fn distribute_results_endpoint(&self) -> OperationCompletionStatus {
+        self.require_role(Role::Finance);
+        self.distribute_results(Self::send_vested_launchpad_tokens)
     }
 
-    #[only_owner]
     #[endpoint(claimTicketPayment)]
     fn claim_ticket_payment_endpoint(&self) {
+        self.require_role(Role::Finance);
         self.claim_ticket_payment();
     }
 
+    #[endpoint(rescueTokens)]
+    fn rescue_tokens_endpoint(&self, token_id: EgldOrEsdtTokenIdentifier, token_nonce: u64) {
+        self.require_role(Role::Finance);
+        self.rescue_tokens(token_id, token_nonce);
+    }
+
+    #[endpoint(executePendingChange)]
+    fn execute_pending_change_endpoint(&self, change_id: usize) {
+        self.execute_pending_change(change_id, Self::apply_unlock_schedule_change);
+    }
+
+    #[endpoint(executeAdminProposal)]
+    fn execute_admin_proposal_endpoint(&self, proposal_id: usize) {
+        self.execute_admin_proposal(proposal_id, Self::apply_unlock_schedule_change);
+    }
+
+    #[endpoint(approveWalletMigration)]
+    fn approve_wallet_migration_endpoint(&self, old_address: ManagedAddress) {
+        self.approve_wallet_migration(old_address, Self::default_migrate_wallet_fn);
+    }
+
     #[endpoint(addUsersToBlacklist)]
-    fn add_users_to_blacklist_endpoint(&self, users_list: MultiValueEncoded<ManagedAddress>) {
-        self.add_users_to_blacklist(&users_list.to_vec());
+    fn add_users_to_blacklist_endpoint(
+        &self,
+        reason: BlacklistReason,
+        users_list: MultiValueEncoded<ManagedAddress>,
+    ) {
+        self.add_users_to_blacklist(&users_list.to_vec(), reason);
+    }
+
+    /// The release configuration is the lock percentage and the unlock epoch,
+    /// followed by the unlock schedule, if set
+    #[view(getConfiguration)]
+    fn get_configuration_view(&self) -> LaunchConfiguration<Self::Api> {
+        let mut release_config = ManagedBuffer::new();
+        let _ = self
+            .launchpad_tokens_lock_percentage()
+            .get()
+            .dep_encode(&mut release_config);
+        let _ = self
+            .launchpad_tokens_unlock_epoch()
+            .get()
+            .dep_encode(&mut release_config);
+        let unlock_schedule_mapper = self.unlock_schedule();
+        if !unlock_schedule_mapper.is_empty() {
+            let _ = unlock_schedule_mapper.get().dep_encode(&mut release_config);
+        }
+
+        self.get_launch_configuration(release_config)
+    }
+
+    #[view(getUserFullStatus)]
+    fn user_full_status(&self, address: ManagedAddress) -> UserFullStatus<Self::Api> {
+        self.get_user_full_status(address, 0)
     }
 }
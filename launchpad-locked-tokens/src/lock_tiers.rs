@@ -0,0 +1,101 @@
+multiversx_sc::imports!();
+
+use launchpad_common::{
+    launch_stage::LaunchStage, tickets::TicketId, tickets_layout_migration::LegacyTicketId,
+};
+
+use crate::token_release::MAX_PERCENTAGE;
+
+#[multiversx_sc::module]
+pub trait LockTiersModule:
+    launchpad_common::config::ConfigModule
+    + launchpad_common::launch_stage::LaunchStageModule
+    + launchpad_common::tickets::TicketsModule
+    + launchpad_common::winner_selection::WinnerSelectionModule
+{
+    /// Percentage of the claimed launchpad tokens which follows the unlock schedule.
+    /// Tier IDs start from 1.
+    #[only_owner]
+    #[endpoint(setLockTierPercentage)]
+    fn set_lock_tier_percentage(&self, tier: usize, lock_percentage: u64) {
+        self.require_before_claim_for_lock_tiers();
+        require!(tier > 0, "Invalid lock tier");
+        require!(lock_percentage <= MAX_PERCENTAGE, "Invalid lock percentage");
+
+        let _ = self.lock_tiers().insert(tier, lock_percentage);
+    }
+
+    /// Users without a tier have all their launchpad tokens locked
+    #[only_owner]
+    #[endpoint(setUserLockTiers)]
+    fn set_user_lock_tiers(
+        &self,
+        user_tiers: MultiValueEncoded<MultiValue2<ManagedAddress, usize>>,
+    ) {
+        self.require_before_claim_for_lock_tiers();
+
+        let lock_tiers_mapper = self.lock_tiers();
+        for user_tier in user_tiers {
+            let (address, tier) = user_tier.into_tuple();
+            require!(lock_tiers_mapper.contains_key(&tier), "Unknown lock tier");
+
+            let ticket_range = self.try_get_ticket_range(&address);
+            self.ticket_batch_lock_tier(ticket_range.first_id).set(tier);
+        }
+    }
+
+    /// The tier of the batch is cleared, as the batch is only claimed once
+    fn take_ticket_batch_lock_percentage(&self, ticket_batch_id: TicketId) -> u64 {
+        let tier = self.ticket_batch_lock_tier(ticket_batch_id).take();
+        if tier == 0 {
+            return MAX_PERCENTAGE;
+        }
+
+        self.lock_tiers().get(&tier).unwrap_or(MAX_PERCENTAGE)
+    }
+
+    /// The lock tiers are keyed by the first ticket ID of the batch, which was 32 bits wide
+    /// before `TICKETS_LAYOUT_STORAGE_VERSION`
+    fn migrate_legacy_ticket_batch_lock_tiers(&self) {
+        let batch_first_ticket_ids = self.batch_first_ticket_ids();
+        let confirmers_first_ticket_ids = self.confirmers_first_ticket_ids();
+        for first_ticket_id in batch_first_ticket_ids
+            .iter()
+            .chain(confirmers_first_ticket_ids.iter())
+        {
+            let legacy_lock_tier_mapper =
+                self.legacy_ticket_batch_lock_tier(first_ticket_id as LegacyTicketId);
+            if !legacy_lock_tier_mapper.is_empty() {
+                self.ticket_batch_lock_tier(first_ticket_id)
+                    .set(legacy_lock_tier_mapper.take());
+            }
+        }
+    }
+
+    fn require_before_claim_for_lock_tiers(&self) {
+        require!(
+            self.get_launch_stage() < LaunchStage::Claim,
+            "Cannot change after claim period started"
+        );
+    }
+
+    #[view(getLockTierPercentage)]
+    fn get_lock_tier_percentage(&self, tier: usize) -> u64 {
+        self.lock_tiers()
+            .get(&tier)
+            .unwrap_or_else(|| sc_panic!("Unknown lock tier"))
+    }
+
+    #[storage_mapper("lockTiers")]
+    fn lock_tiers(&self) -> MapMapper<usize, u64>;
+
+    #[view(getTicketBatchLockTier)]
+    #[storage_mapper("ticketBatchLockTier")]
+    fn ticket_batch_lock_tier(&self, ticket_batch_id: TicketId) -> SingleValueMapper<usize>;
+
+    #[storage_mapper("ticketBatchLockTier")]
+    fn legacy_ticket_batch_lock_tier(
+        &self,
+        ticket_batch_id: LegacyTicketId,
+    ) -> SingleValueMapper<usize>;
+}
@@ -1,5 +1,7 @@
 multiversx_sc::imports!();
 
+use launchpad_common::tickets::TicketId;
+
 const MAX_PERCENTAGE: u32 = 10_000; // 100%
 
 pub mod simple_lock_proxy {
@@ -44,6 +46,7 @@ pub trait LockedLaunchpadTokenSend {
         &self,
         dest_address: &ManagedAddress,
         launchpad_tokens: &EsdtTokenPayment<Self::Api>,
+        _ticket_batch_id: TicketId,
     ) {
         let mut unlocked_amount = launchpad_tokens.amount.clone();
 
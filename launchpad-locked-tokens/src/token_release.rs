@@ -0,0 +1,339 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use launchpad_common::{
+    action_pause::PausableAction,
+    config,
+    guardian::GuardedOperationType,
+    launch_stage::{self, LaunchStage},
+    tickets::TicketId,
+    timelock::PendingChangeType,
+};
+
+use crate::locked_launchpad_token_send::simple_lock_proxy;
+
+pub const MAX_PERCENTAGE: u64 = 10_000; // 100%
+pub const MAX_UNLOCK_MILESTONES_ENTRIES: usize = 60;
+pub const MAX_UNLOCK_EPOCH_DIFF: u64 = 1_825; // 5 years in epochs
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, ManagedVecItem)]
+pub struct UnlockMilestone {
+    pub unlock_epoch: u64,
+    pub percentage: u64,
+}
+
+#[derive(TopEncode, TopDecode, TypeAbi, NestedEncode, NestedDecode)]
+pub struct UnlockSchedule<M: ManagedTypeApi> {
+    milestones: ManagedVec<M, UnlockMilestone>,
+}
+
+impl<M: ManagedTypeApi> UnlockSchedule<M> {
+    pub fn new(milestones: ManagedVec<M, UnlockMilestone>) -> Self {
+        UnlockSchedule { milestones }
+    }
+
+    fn validate(&self, current_epoch: u64) -> bool {
+        if self.milestones.is_empty() {
+            return false;
+        }
+
+        let mut total_percentage = 0u64;
+        let mut last_epoch = 0u64;
+
+        for milestone in self.milestones.iter() {
+            if milestone.percentage > MAX_PERCENTAGE
+                || milestone.unlock_epoch < current_epoch
+                || milestone.unlock_epoch < last_epoch
+                || milestone.unlock_epoch > current_epoch + MAX_UNLOCK_EPOCH_DIFF
+            {
+                return false;
+            }
+
+            last_epoch = milestone.unlock_epoch;
+            total_percentage += milestone.percentage;
+        }
+
+        total_percentage == MAX_PERCENTAGE
+    }
+
+    fn get_unlocked_percentage(&self, current_epoch: u64) -> u64 {
+        let mut unlocked_percentage = 0u64;
+        for milestone in self.milestones.iter() {
+            if milestone.unlock_epoch > current_epoch {
+                break;
+            }
+
+            unlocked_percentage += milestone.percentage;
+        }
+
+        unlocked_percentage
+    }
+}
+
+#[multiversx_sc::module]
+pub trait TokenReleaseModule:
+    config::ConfigModule
+    + launch_stage::LaunchStageModule
+    + launchpad_common::permissions::PermissionsModule
+    + launchpad_common::timelock::TimelockModule
+    + launchpad_common::guardian::GuardianModule
+    + launchpad_common::user_interactions::UserInteractionsModule
+    + crate::lock_tiers::LockTiersModule
+    + crate::events::EventsModule
+{
+    /// Pairs of (unlock epoch, percentage), where the percentages add up to 10_000 (100%)
+    #[only_owner]
+    #[endpoint(setUnlockSchedule)]
+    fn set_unlock_schedule(&self, unlock_milestones: MultiValueEncoded<MultiValue2<u64, u64>>) {
+        self.require_add_tickets_period();
+
+        let unlock_schedule = self.build_unlock_schedule(unlock_milestones);
+        let mut encoded_data = ManagedBuffer::new();
+        let _ = unlock_schedule.top_encode(&mut encoded_data);
+        self.require_guardian_approval(GuardedOperationType::UnlockSchedule, &encoded_data);
+
+        if self.is_timelock_enabled() {
+            let _ = self.queue_pending_change(PendingChangeType::Custom { encoded_data });
+
+            return;
+        }
+
+        self.try_set_unlock_schedule(unlock_schedule);
+    }
+
+    fn apply_unlock_schedule_change(&self, encoded_data: ManagedBuffer) {
+        self.require_add_tickets_period();
+
+        let unlock_schedule: UnlockSchedule<Self::Api> = UnlockSchedule::top_decode(encoded_data)
+            .unwrap_or_else(|_| sc_panic!("Failed to deserialize unlock schedule"));
+        self.try_set_unlock_schedule(unlock_schedule);
+    }
+
+    fn build_unlock_schedule(
+        &self,
+        unlock_milestones: MultiValueEncoded<MultiValue2<u64, u64>>,
+    ) -> UnlockSchedule<Self::Api> {
+        require!(
+            unlock_milestones.len() <= MAX_UNLOCK_MILESTONES_ENTRIES,
+            "Maximum unlock milestones entries exceeded"
+        );
+
+        let mut milestones = ManagedVec::new();
+        for unlock_milestone in unlock_milestones {
+            let (unlock_epoch, percentage) = unlock_milestone.into_tuple();
+            milestones.push(UnlockMilestone {
+                unlock_epoch,
+                percentage,
+            });
+        }
+
+        UnlockSchedule::new(milestones)
+    }
+
+    fn try_set_unlock_schedule(&self, unlock_schedule: UnlockSchedule<Self::Api>) {
+        let current_epoch = self.blockchain().get_block_epoch();
+        require!(
+            unlock_schedule.validate(current_epoch),
+            "Invalid unlock schedule"
+        );
+
+        let milestones = unlock_schedule.milestones.clone();
+        self.unlock_schedule().set(unlock_schedule);
+
+        self.emit_set_unlock_schedule_event(milestones);
+    }
+
+    /// Once set, the still locked milestones are delivered at claim
+    /// as LOCKED tokens of the simple-lock contract, instead of being kept by the launchpad
+    #[only_owner]
+    #[endpoint(setSimpleLockScAddress)]
+    fn set_simple_lock_sc_address(&self, sc_address: ManagedAddress) {
+        require!(
+            self.get_launch_stage() < LaunchStage::Claim,
+            "Cannot change after claim period started"
+        );
+        require!(
+            !sc_address.is_zero() && self.blockchain().is_smart_contract(&sc_address),
+            "Invalid SC address"
+        );
+
+        self.simple_lock_sc_address().set(&sc_address);
+    }
+
+    /// Only the lock tier percentage of the ticket batch follows the unlock schedule.
+    /// Of that, the part unlocked so far is sent right away. The rest is either locked
+    /// through the simple-lock contract, or kept by the launchpad until claimed.
+    fn send_vested_launchpad_tokens(
+        &self,
+        dest_address: &ManagedAddress,
+        launchpad_tokens: &EsdtTokenPayment<Self::Api>,
+        ticket_batch_id: TicketId,
+    ) {
+        let lock_percentage = self.take_ticket_batch_lock_percentage(ticket_batch_id);
+        let lock_amount = &launchpad_tokens.amount * lock_percentage / MAX_PERCENTAGE;
+        let free_amount = &launchpad_tokens.amount - &lock_amount;
+        if free_amount > 0 {
+            self.send().direct_esdt(
+                dest_address,
+                &launchpad_tokens.token_identifier,
+                launchpad_tokens.token_nonce,
+                &free_amount,
+            );
+        }
+
+        if lock_amount == 0 {
+            return;
+        }
+
+        let locked_tokens = EsdtTokenPayment::new(
+            launchpad_tokens.token_identifier.clone(),
+            launchpad_tokens.token_nonce,
+            lock_amount,
+        );
+        if !self.simple_lock_sc_address().is_empty() {
+            self.send_locked_milestones(dest_address, &locked_tokens);
+
+            return;
+        }
+
+        self.user_total_claimable_balance(dest_address)
+            .update(|balance| *balance += &locked_tokens.amount);
+
+        let _ = self.send_unlocked_tokens(dest_address);
+        self.update_user_vesting_status(dest_address);
+    }
+
+    fn send_locked_milestones(
+        &self,
+        dest_address: &ManagedAddress,
+        launchpad_tokens: &EsdtTokenPayment<Self::Api>,
+    ) {
+        let sc_address = self.simple_lock_sc_address().get();
+        let unlock_schedule = self.unlock_schedule().get();
+        let current_epoch = self.blockchain().get_block_epoch();
+        let mut unlocked_amount = launchpad_tokens.amount.clone();
+        for milestone in unlock_schedule.milestones.iter() {
+            if milestone.unlock_epoch <= current_epoch {
+                continue;
+            }
+
+            let lock_amount = &launchpad_tokens.amount * milestone.percentage / MAX_PERCENTAGE;
+            if lock_amount == 0 {
+                continue;
+            }
+
+            unlocked_amount -= &lock_amount;
+
+            let _: IgnoreValue = self
+                .simple_lock_proxy_builder(sc_address.clone())
+                .lock_tokens(milestone.unlock_epoch, dest_address.clone())
+                .with_esdt_transfer((
+                    launchpad_tokens.token_identifier.clone(),
+                    launchpad_tokens.token_nonce,
+                    lock_amount,
+                ))
+                .execute_on_dest_context();
+        }
+
+        if unlocked_amount > 0 {
+            self.send().direct_esdt(
+                dest_address,
+                &launchpad_tokens.token_identifier,
+                launchpad_tokens.token_nonce,
+                &unlocked_amount,
+            );
+        }
+    }
+
+    /// Sends the launchpad tokens unlocked since the last claim
+    #[endpoint(claimUnlockedTokens)]
+    fn claim_unlocked_tokens(&self) -> EsdtTokenPayment {
+        self.require_action_not_paused(PausableAction::Claims);
+        self.require_claim_period();
+
+        let caller = self.blockchain().get_caller();
+        let unlocked_tokens = self.send_unlocked_tokens(&caller);
+        require!(unlocked_tokens.amount > 0, "No tokens to claim");
+        self.update_user_vesting_status(&caller);
+
+        unlocked_tokens
+    }
+
+    fn send_unlocked_tokens(&self, address: &ManagedAddress) -> EsdtTokenPayment {
+        let launchpad_token_id = self.launchpad_token_id().get();
+        let claimable_tokens = self.compute_claimable_tokens(address.clone());
+        let unlocked_tokens = EsdtTokenPayment::new(launchpad_token_id, 0, claimable_tokens);
+        if unlocked_tokens.amount == 0 {
+            return unlocked_tokens;
+        }
+
+        self.user_claimed_balance(address)
+            .update(|balance| *balance += &unlocked_tokens.amount);
+        self.send().direct_esdt(
+            address,
+            &unlocked_tokens.token_identifier,
+            0,
+            &unlocked_tokens.amount,
+        );
+
+        self.emit_claim_unlocked_tokens_event(address.clone(), unlocked_tokens.clone());
+
+        unlocked_tokens
+    }
+
+    /// Vesting while part of the user's launchpad tokens are still locked
+    fn update_user_vesting_status(&self, address: &ManagedAddress) {
+        let user_total_claimable_balance = self.user_total_claimable_balance(address).get();
+        if user_total_claimable_balance == 0 {
+            return;
+        }
+
+        let has_locked_tokens =
+            self.user_claimed_balance(address).get() < user_total_claimable_balance;
+        self.update_vesting_claim_status(address, has_locked_tokens);
+    }
+
+    #[view(getClaimableTokens)]
+    fn compute_claimable_tokens(&self, address: ManagedAddress) -> BigUint {
+        let user_total_claimable_balance = self.user_total_claimable_balance(&address).get();
+        if user_total_claimable_balance == 0 {
+            return BigUint::zero();
+        }
+
+        let current_epoch = self.blockchain().get_block_epoch();
+        let unlocked_percentage = self
+            .unlock_schedule()
+            .get()
+            .get_unlocked_percentage(current_epoch);
+        let unlocked_tokens = user_total_claimable_balance * unlocked_percentage / MAX_PERCENTAGE;
+
+        let user_claimed_balance = self.user_claimed_balance(&address).get();
+        if unlocked_tokens <= user_claimed_balance {
+            return BigUint::zero();
+        }
+
+        unlocked_tokens - user_claimed_balance
+    }
+
+    #[view(getUserTotalClaimableBalance)]
+    #[storage_mapper("userTotalClaimableBalance")]
+    fn user_total_claimable_balance(&self, address: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    #[view(getUserClaimedBalance)]
+    #[storage_mapper("userClaimedBalance")]
+    fn user_claimed_balance(&self, address: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    #[view(getUnlockSchedule)]
+    #[storage_mapper("unlockSchedule")]
+    fn unlock_schedule(&self) -> SingleValueMapper<UnlockSchedule<Self::Api>>;
+
+    #[view(getSimpleLockScAddress)]
+    #[storage_mapper("simpleLockScAddress")]
+    fn simple_lock_sc_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[proxy]
+    fn simple_lock_proxy_builder(
+        &self,
+        sc_address: ManagedAddress,
+    ) -> simple_lock_proxy::Proxy<Self::Api>;
+}
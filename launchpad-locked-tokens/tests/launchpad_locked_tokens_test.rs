@@ -1,17 +1,20 @@
 multiversx_sc::derive_imports!();
 
 use launchpad_common::{
-    config::ConfigModule, user_interactions::UserInteractionsModule,
+    config::ConfigModule, launchpad_token_issuance::LaunchpadTokenIssuanceModule,
+    tickets::TicketsModule, tiers::TiersModule, user_interactions::UserInteractionsModule,
     winner_selection::WinnerSelectionModule,
 };
-use launchpad_locked_tokens::LaunchpadLockedTokens;
+use launchpad_locked_tokens::{
+    lock_tiers::LockTiersModule, token_release::TokenReleaseModule, LaunchpadLockedTokens,
+};
 use multiversx_sc::{
     api::ManagedTypeApi,
     codec::{TopDecode, TopEncode},
     contract_base::{CallableContract, ContractBase},
     types::{
-        EgldOrEsdtTokenIdentifier, EsdtLocalRole, EsdtTokenPayment, ManagedAddress,
-        MultiValueEncoded,
+        EgldOrEsdtTokenIdentifier, EsdtLocalRole, EsdtTokenPayment, ManagedAddress, ManagedBuffer,
+        MultiValue2, MultiValueEncoded, OptionalValue,
     },
 };
 use multiversx_sc_scenario::{
@@ -23,6 +26,7 @@ use multiversx_sc_scenario::{
 
 static LOCK_FN_NAME: &str = "lockTokens";
 static LOCKED_TOKEN_ID: &[u8] = b"LKTOK-123456";
+
 static LAUNCHPAD_TOKEN_ID: &[u8] = b"LAUNCH-123456";
 const LAUNCHPAD_TOKENS_PER_TICKET: u64 = 100_000;
 const TICKET_PRICE: u64 = 100;
@@ -30,11 +34,249 @@ const NR_WINNING_TICKETS: usize = 1;
 const CONFIRM_START_ROUND: u64 = 10;
 const WINNER_SELECTION_START_ROUND: u64 = 20;
 const CLAIM_START_ROUND: u64 = 30;
-const LOCK_PERCENTAGE: u32 = 5_000; // 50%
+const FIRST_UNLOCK_PERCENTAGE: u64 = 5_000; // 50%
 const UNLOCK_EPOCH: u64 = 10;
 
 #[test]
-fn launchpad_with_locked_tokens_out_test() {
+fn launchpad_with_vested_tokens_test() {
+    let _ = DebugApi::dummy();
+    let mut b_mock = BlockchainStateWrapper::new();
+    let rust_zero = rust_biguint!(0);
+
+    let owner = b_mock.create_user_account(&rust_zero);
+    let user = b_mock.create_user_account(&rust_biguint!(TICKET_PRICE));
+    let lp_sc = b_mock.create_sc_account(
+        &rust_zero,
+        Some(&owner),
+        launchpad_locked_tokens::contract_obj,
+        "launchpad wasm",
+    );
+
+    // setup
+    b_mock
+        .execute_tx(&owner, &lp_sc, &rust_zero, |sc| {
+            sc.init(
+                managed_token_id!(LAUNCHPAD_TOKEN_ID),
+                managed_biguint!(LAUNCHPAD_TOKENS_PER_TICKET),
+                managed_egld_token_id!(),
+                managed_biguint!(TICKET_PRICE),
+                NR_WINNING_TICKETS,
+                CONFIRM_START_ROUND,
+                WINNER_SELECTION_START_ROUND,
+                CLAIM_START_ROUND,
+                unlock_milestones(),
+            );
+
+            let mut tickets = MultiValueEncoded::new();
+            tickets.push((managed_address!(&user), 1).into());
+            sc.add_tickets_endpoint(tickets);
+
+            sc.launchpad_tokens_deposited().set(true);
+        })
+        .assert_ok();
+
+    b_mock.set_esdt_balance(
+        lp_sc.address_ref(),
+        LAUNCHPAD_TOKEN_ID,
+        &rust_biguint!(NR_WINNING_TICKETS as u64 * LAUNCHPAD_TOKENS_PER_TICKET),
+    );
+
+    // user confirm
+    b_mock.set_block_round(CONFIRM_START_ROUND);
+
+    b_mock
+        .execute_tx(&user, &lp_sc, &rust_biguint!(TICKET_PRICE), |sc| {
+            sc.confirm_tickets(OptionalValue::Some(1), OptionalValue::None);
+        })
+        .assert_ok();
+
+    // filter + select winners
+    b_mock.set_block_round(WINNER_SELECTION_START_ROUND);
+
+    b_mock
+        .execute_tx(&owner, &lp_sc, &rust_zero, |sc| {
+            sc.filter_tickets();
+            sc.select_winners();
+        })
+        .assert_ok();
+
+    // user claim
+    b_mock.set_block_round(CLAIM_START_ROUND);
+
+    b_mock
+        .execute_tx(&user, &lp_sc, &rust_zero, |sc| {
+            sc.claim_launchpad_tokens_endpoint(OptionalValue::None);
+        })
+        .assert_ok();
+
+    // only the first milestone is unlocked
+    b_mock.check_esdt_balance(
+        &user,
+        LAUNCHPAD_TOKEN_ID,
+        &rust_biguint!(LAUNCHPAD_TOKENS_PER_TICKET / 2),
+    );
+
+    b_mock
+        .execute_tx(&user, &lp_sc, &rust_zero, |sc| {
+            let _ = sc.claim_unlocked_tokens();
+        })
+        .assert_user_error("No tokens to claim");
+
+    b_mock.set_block_epoch(UNLOCK_EPOCH);
+
+    b_mock
+        .execute_query(&lp_sc, |sc| {
+            assert_eq!(
+                sc.compute_claimable_tokens(managed_address!(&user)),
+                managed_biguint!(LAUNCHPAD_TOKENS_PER_TICKET / 2)
+            );
+        })
+        .assert_ok();
+
+    b_mock
+        .execute_tx(&user, &lp_sc, &rust_zero, |sc| {
+            let _ = sc.claim_unlocked_tokens();
+        })
+        .assert_ok();
+
+    b_mock.check_esdt_balance(
+        &user,
+        LAUNCHPAD_TOKEN_ID,
+        &rust_biguint!(LAUNCHPAD_TOKENS_PER_TICKET),
+    );
+    b_mock.check_esdt_balance(lp_sc.address_ref(), LAUNCHPAD_TOKEN_ID, &rust_zero);
+}
+
+#[test]
+fn add_tickets_packed_test() {
+    let _ = DebugApi::dummy();
+    let mut b_mock = BlockchainStateWrapper::new();
+    let rust_zero = rust_biguint!(0);
+
+    let owner = b_mock.create_user_account(&rust_zero);
+    let first_user = b_mock.create_user_account(&rust_zero);
+    let second_user = b_mock.create_user_account(&rust_zero);
+    let lp_sc = b_mock.create_sc_account(
+        &rust_zero,
+        Some(&owner),
+        launchpad_locked_tokens::contract_obj,
+        "launchpad wasm",
+    );
+
+    let mut packed_entries = Vec::new();
+    packed_entries.extend_from_slice(first_user.as_bytes());
+    packed_entries.extend_from_slice(&2u16.to_be_bytes());
+    packed_entries.extend_from_slice(second_user.as_bytes());
+    packed_entries.extend_from_slice(&300u16.to_be_bytes());
+
+    b_mock
+        .execute_tx(&owner, &lp_sc, &rust_zero, |sc| {
+            sc.init(
+                managed_token_id!(LAUNCHPAD_TOKEN_ID),
+                managed_biguint!(LAUNCHPAD_TOKENS_PER_TICKET),
+                managed_egld_token_id!(),
+                managed_biguint!(TICKET_PRICE),
+                NR_WINNING_TICKETS,
+                CONFIRM_START_ROUND,
+                WINNER_SELECTION_START_ROUND,
+                CLAIM_START_ROUND,
+                unlock_milestones(),
+            );
+
+            sc.add_tickets_packed_endpoint(ManagedBuffer::new_from_bytes(&packed_entries));
+
+            assert_eq!(
+                sc.get_total_number_of_tickets_for_address(&managed_address!(&first_user)),
+                2
+            );
+            assert_eq!(
+                sc.get_total_number_of_tickets_for_address(&managed_address!(&second_user)),
+                300
+            );
+            assert_eq!(sc.last_ticket_id().get(), 302);
+        })
+        .assert_ok();
+
+    b_mock
+        .execute_tx(&owner, &lp_sc, &rust_zero, |sc| {
+            sc.add_tickets_packed_endpoint(ManagedBuffer::new_from_bytes(
+                &packed_entries[..packed_entries.len() - 1],
+            ));
+        })
+        .assert_user_error("Invalid packed tickets length");
+}
+
+#[test]
+fn add_tickets_chunk_test() {
+    let _ = DebugApi::dummy();
+    let mut b_mock = BlockchainStateWrapper::new();
+    let rust_zero = rust_biguint!(0);
+
+    let owner = b_mock.create_user_account(&rust_zero);
+    let first_user = b_mock.create_user_account(&rust_zero);
+    let second_user = b_mock.create_user_account(&rust_zero);
+    let lp_sc = b_mock.create_sc_account(
+        &rust_zero,
+        Some(&owner),
+        launchpad_locked_tokens::contract_obj,
+        "launchpad wasm",
+    );
+
+    let mut first_chunk = first_user.as_bytes().to_vec();
+    first_chunk.extend_from_slice(&1u16.to_be_bytes());
+    let mut second_chunk = second_user.as_bytes().to_vec();
+    second_chunk.extend_from_slice(&2u16.to_be_bytes());
+
+    b_mock
+        .execute_tx(&owner, &lp_sc, &rust_zero, |sc| {
+            sc.init(
+                managed_token_id!(LAUNCHPAD_TOKEN_ID),
+                managed_biguint!(LAUNCHPAD_TOKENS_PER_TICKET),
+                managed_egld_token_id!(),
+                managed_biguint!(TICKET_PRICE),
+                NR_WINNING_TICKETS,
+                CONFIRM_START_ROUND,
+                WINNER_SELECTION_START_ROUND,
+                CLAIM_START_ROUND,
+                unlock_milestones(),
+            );
+
+            sc.add_tickets_chunk_endpoint(0, ManagedBuffer::new_from_bytes(&first_chunk));
+        })
+        .assert_ok();
+
+    // same chunk sent twice
+    b_mock
+        .execute_tx(&owner, &lp_sc, &rust_zero, |sc| {
+            sc.add_tickets_chunk_endpoint(0, ManagedBuffer::new_from_bytes(&first_chunk));
+        })
+        .assert_user_error("Invalid chunk index");
+
+    b_mock
+        .execute_tx(&owner, &lp_sc, &rust_zero, |sc| {
+            sc.add_tickets_chunk_endpoint(1, ManagedBuffer::new_from_bytes(&second_chunk));
+
+            let (nr_processed_chunks, nr_added_users) = sc.get_add_tickets_progress().into_tuple();
+            assert_eq!(nr_processed_chunks, 2);
+            assert_eq!(nr_added_users, 2);
+            assert_eq!(sc.last_ticket_id().get(), 3);
+
+            sc.finalize_add_tickets();
+            assert!(sc.add_tickets_finalized().get());
+        })
+        .assert_ok();
+
+    b_mock
+        .execute_tx(&owner, &lp_sc, &rust_zero, |sc| {
+            let mut tickets = MultiValueEncoded::new();
+            tickets.push((managed_address!(&owner), 1).into());
+            sc.add_tickets_endpoint(tickets);
+        })
+        .assert_user_error("Adding tickets was finalized");
+}
+
+#[test]
+fn launchpad_with_simple_lock_test() {
     let _ = DebugApi::dummy();
     let mut b_mock = BlockchainStateWrapper::new();
     let rust_zero = rust_biguint!(0);
@@ -62,10 +304,9 @@ fn launchpad_with_locked_tokens_out_test() {
                 CONFIRM_START_ROUND,
                 WINNER_SELECTION_START_ROUND,
                 CLAIM_START_ROUND,
-                LOCK_PERCENTAGE,
-                UNLOCK_EPOCH,
-                managed_address!(simple_lock_sc.address_ref()),
+                unlock_milestones(),
             );
+            sc.set_simple_lock_sc_address(managed_address!(simple_lock_sc.address_ref()));
 
             let mut tickets = MultiValueEncoded::new();
             tickets.push((managed_address!(&user), 1).into());
@@ -92,7 +333,7 @@ fn launchpad_with_locked_tokens_out_test() {
 
     b_mock
         .execute_tx(&user, &lp_sc, &rust_biguint!(TICKET_PRICE), |sc| {
-            sc.confirm_tickets(1);
+            sc.confirm_tickets(OptionalValue::Some(1), OptionalValue::None);
         })
         .assert_ok();
 
@@ -111,7 +352,7 @@ fn launchpad_with_locked_tokens_out_test() {
 
     b_mock
         .execute_tx(&user, &lp_sc, &rust_zero, |sc| {
-            sc.claim_launchpad_tokens_endpoint();
+            sc.claim_launchpad_tokens_endpoint(OptionalValue::None);
         })
         .assert_ok();
 
@@ -133,6 +374,306 @@ fn launchpad_with_locked_tokens_out_test() {
             unlock_epoch: UNLOCK_EPOCH,
         }),
     );
+
+    // nothing is kept by the launchpad
+    b_mock.check_esdt_balance(lp_sc.address_ref(), LAUNCHPAD_TOKEN_ID, &rust_zero);
+    b_mock
+        .execute_query(&lp_sc, |sc| {
+            assert_eq!(
+                sc.compute_claimable_tokens(managed_address!(&user)),
+                managed_biguint!(0)
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn mint_launchpad_tokens_test() {
+    let _ = DebugApi::dummy();
+    let mut b_mock = BlockchainStateWrapper::new();
+    let rust_zero = rust_biguint!(0);
+
+    let owner = b_mock.create_user_account(&rust_zero);
+    let lp_sc = b_mock.create_sc_account(
+        &rust_zero,
+        Some(&owner),
+        launchpad_locked_tokens::contract_obj,
+        "launchpad wasm",
+    );
+
+    // launchpad token to be issued by the contract
+    b_mock
+        .execute_tx(&owner, &lp_sc, &rust_zero, |sc| {
+            sc.init(
+                managed_token_id!(b""),
+                managed_biguint!(LAUNCHPAD_TOKENS_PER_TICKET),
+                managed_egld_token_id!(),
+                managed_biguint!(TICKET_PRICE),
+                NR_WINNING_TICKETS,
+                CONFIRM_START_ROUND,
+                WINNER_SELECTION_START_ROUND,
+                CLAIM_START_ROUND,
+                unlock_milestones(),
+            );
+        })
+        .assert_ok();
+
+    b_mock
+        .execute_tx(&owner, &lp_sc, &rust_zero, |sc| {
+            sc.mint_launchpad_tokens_endpoint();
+        })
+        .assert_user_error("Launchpad token not issued");
+
+    // simulate the issue callback
+    b_mock
+        .execute_tx(&owner, &lp_sc, &rust_zero, |sc| {
+            sc.issued_launchpad_token()
+                .set_token_id(managed_token_id!(LAUNCHPAD_TOKEN_ID));
+        })
+        .assert_ok();
+    b_mock.set_esdt_local_roles(
+        lp_sc.address_ref(),
+        LAUNCHPAD_TOKEN_ID,
+        &[EsdtLocalRole::Mint, EsdtLocalRole::Burn],
+    );
+
+    b_mock
+        .execute_tx(&owner, &lp_sc, &rust_zero, |sc| {
+            sc.mint_launchpad_tokens_endpoint();
+
+            assert_eq!(
+                sc.launchpad_token_id().get(),
+                managed_token_id!(LAUNCHPAD_TOKEN_ID)
+            );
+            assert!(sc.were_launchpad_tokens_deposited());
+        })
+        .assert_ok();
+
+    b_mock.check_esdt_balance(
+        lp_sc.address_ref(),
+        LAUNCHPAD_TOKEN_ID,
+        &rust_biguint!(NR_WINNING_TICKETS as u64 * LAUNCHPAD_TOKENS_PER_TICKET),
+    );
+
+    b_mock
+        .execute_tx(&owner, &lp_sc, &rust_zero, |sc| {
+            sc.mint_launchpad_tokens_endpoint();
+        })
+        .assert_user_error("Tokens already deposited");
+}
+
+#[test]
+fn lock_tiers_test() {
+    let _ = DebugApi::dummy();
+    let mut b_mock = BlockchainStateWrapper::new();
+    let rust_zero = rust_biguint!(0);
+
+    let owner = b_mock.create_user_account(&rust_zero);
+    let user = b_mock.create_user_account(&rust_biguint!(TICKET_PRICE));
+    let lp_sc = b_mock.create_sc_account(
+        &rust_zero,
+        Some(&owner),
+        launchpad_locked_tokens::contract_obj,
+        "launchpad wasm",
+    );
+
+    // setup
+    b_mock
+        .execute_tx(&owner, &lp_sc, &rust_zero, |sc| {
+            sc.init(
+                managed_token_id!(LAUNCHPAD_TOKEN_ID),
+                managed_biguint!(LAUNCHPAD_TOKENS_PER_TICKET),
+                managed_egld_token_id!(),
+                managed_biguint!(TICKET_PRICE),
+                NR_WINNING_TICKETS,
+                CONFIRM_START_ROUND,
+                WINNER_SELECTION_START_ROUND,
+                CLAIM_START_ROUND,
+                unlock_milestones(),
+            );
+
+            let mut tickets = MultiValueEncoded::new();
+            tickets.push((managed_address!(&user), 1).into());
+            sc.add_tickets_endpoint(tickets);
+
+            sc.launchpad_tokens_deposited().set(true);
+        })
+        .assert_ok();
+
+    b_mock
+        .execute_tx(&owner, &lp_sc, &rust_zero, |sc| {
+            let mut user_tiers = MultiValueEncoded::new();
+            user_tiers.push((managed_address!(&user), 1).into());
+            sc.set_user_lock_tiers(user_tiers);
+        })
+        .assert_user_error("Unknown lock tier");
+
+    // 20% of the tokens follow the unlock schedule
+    b_mock
+        .execute_tx(&owner, &lp_sc, &rust_zero, |sc| {
+            sc.set_lock_tier_percentage(1, 2_000);
+
+            let mut user_tiers = MultiValueEncoded::new();
+            user_tiers.push((managed_address!(&user), 1).into());
+            sc.set_user_lock_tiers(user_tiers);
+
+            assert_eq!(sc.ticket_batch_lock_tier(1).get(), 1);
+        })
+        .assert_ok();
+
+    b_mock.set_esdt_balance(
+        lp_sc.address_ref(),
+        LAUNCHPAD_TOKEN_ID,
+        &rust_biguint!(NR_WINNING_TICKETS as u64 * LAUNCHPAD_TOKENS_PER_TICKET),
+    );
+
+    b_mock.set_block_round(CONFIRM_START_ROUND);
+
+    b_mock
+        .execute_tx(&user, &lp_sc, &rust_biguint!(TICKET_PRICE), |sc| {
+            sc.confirm_tickets(OptionalValue::Some(1), OptionalValue::None);
+        })
+        .assert_ok();
+
+    b_mock.set_block_round(WINNER_SELECTION_START_ROUND);
+
+    b_mock
+        .execute_tx(&owner, &lp_sc, &rust_zero, |sc| {
+            sc.filter_tickets();
+            sc.select_winners();
+        })
+        .assert_ok();
+
+    b_mock.set_block_round(CLAIM_START_ROUND);
+
+    b_mock
+        .execute_tx(&user, &lp_sc, &rust_zero, |sc| {
+            sc.claim_launchpad_tokens_endpoint(OptionalValue::None);
+        })
+        .assert_ok();
+
+    // 80% free + half of the locked 20%
+    b_mock.check_esdt_balance(
+        &user,
+        LAUNCHPAD_TOKEN_ID,
+        &rust_biguint!(LAUNCHPAD_TOKENS_PER_TICKET * 9 / 10),
+    );
+
+    b_mock.set_block_epoch(UNLOCK_EPOCH);
+
+    b_mock
+        .execute_tx(&user, &lp_sc, &rust_zero, |sc| {
+            let _ = sc.claim_unlocked_tokens();
+            assert!(sc.ticket_batch_lock_tier(1).is_empty());
+        })
+        .assert_ok();
+
+    b_mock.check_esdt_balance(
+        &user,
+        LAUNCHPAD_TOKEN_ID,
+        &rust_biguint!(LAUNCHPAD_TOKENS_PER_TICKET),
+    );
+}
+
+#[test]
+fn tiers_test() {
+    let _ = DebugApi::dummy();
+    let mut b_mock = BlockchainStateWrapper::new();
+    let rust_zero = rust_biguint!(0);
+
+    let owner = b_mock.create_user_account(&rust_zero);
+    let user = b_mock.create_user_account(&rust_biguint!(TICKET_PRICE * 3));
+    let lp_sc = b_mock.create_sc_account(
+        &rust_zero,
+        Some(&owner),
+        launchpad_locked_tokens::contract_obj,
+        "launchpad wasm",
+    );
+
+    b_mock
+        .execute_tx(&owner, &lp_sc, &rust_zero, |sc| {
+            sc.init(
+                managed_token_id!(LAUNCHPAD_TOKEN_ID),
+                managed_biguint!(LAUNCHPAD_TOKENS_PER_TICKET),
+                managed_egld_token_id!(),
+                managed_biguint!(TICKET_PRICE),
+                NR_WINNING_TICKETS,
+                CONFIRM_START_ROUND,
+                WINNER_SELECTION_START_ROUND,
+                CLAIM_START_ROUND,
+                unlock_milestones(),
+            );
+
+            sc.set_tier(1, ManagedBuffer::from(b"gold"), 3, 10_000, 1, 0);
+
+            let mut tickets = MultiValueEncoded::new();
+            tickets.push((managed_address!(&user), 1).into());
+            sc.add_tickets_with_tiers_endpoint(tickets);
+
+            assert_eq!(
+                sc.get_total_number_of_tickets_for_address(&managed_address!(&user)),
+                3
+            );
+            assert_eq!(sc.user_tier(&managed_address!(&user)).get(), 1);
+
+            sc.launchpad_tokens_deposited().set(true);
+        })
+        .assert_ok();
+
+    b_mock
+        .execute_tx(&owner, &lp_sc, &rust_zero, |sc| {
+            let mut tickets = MultiValueEncoded::new();
+            tickets.push((managed_address!(&owner), 2).into());
+            sc.add_tickets_with_tiers_endpoint(tickets);
+        })
+        .assert_user_error("Unknown tier");
+
+    b_mock
+        .execute_tx(&owner, &lp_sc, &rust_zero, |sc| {
+            sc.set_tier(1, ManagedBuffer::from(b"gold"), 4, 10_000, 1, 0);
+        })
+        .assert_user_error("Cannot raise max tickets of an assigned tier");
+
+    b_mock
+        .execute_tx(&owner, &lp_sc, &rust_zero, |sc| {
+            sc.remove_tier(1);
+        })
+        .assert_user_error("Tier has assigned users");
+
+    b_mock
+        .execute_tx(&user, &lp_sc, &rust_zero, |sc| {
+            sc.set_tier(1, ManagedBuffer::from(b"gold"), 2, 10_000, 1, 0);
+        })
+        .assert_user_error("Permission denied");
+
+    // lowering the tier's max tickets caps the confirmations, without re-adding the user
+    b_mock
+        .execute_tx(&owner, &lp_sc, &rust_zero, |sc| {
+            sc.set_tier(1, ManagedBuffer::from(b"gold"), 2, 10_000, 1, 0);
+        })
+        .assert_ok();
+
+    b_mock.set_block_round(CONFIRM_START_ROUND);
+
+    b_mock
+        .execute_tx(&user, &lp_sc, &rust_biguint!(TICKET_PRICE * 3), |sc| {
+            sc.confirm_tickets(OptionalValue::Some(3), OptionalValue::None);
+        })
+        .assert_user_error("Tier max tickets exceeded");
+
+    b_mock
+        .execute_tx(&user, &lp_sc, &rust_biguint!(TICKET_PRICE * 2), |sc| {
+            sc.confirm_tickets(OptionalValue::Some(2), OptionalValue::None);
+        })
+        .assert_ok();
+}
+
+fn unlock_milestones() -> MultiValueEncoded<DebugApi, MultiValue2<u64, u64>> {
+    let mut milestones = MultiValueEncoded::new();
+    milestones.push((0, FIRST_UNLOCK_PERCENTAGE).into());
+    milestones.push((UNLOCK_EPOCH, 10_000 - FIRST_UNLOCK_PERCENTAGE).into());
+
+    milestones
 }
 
 #[derive(Clone, Default)]
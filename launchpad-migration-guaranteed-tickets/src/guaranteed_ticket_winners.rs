@@ -4,7 +4,7 @@ multiversx_sc::derive_imports!();
 use launchpad_common::{
     ongoing_operation::{CONTINUE_OP, STOP_OP},
     random::Random,
-    tickets::{TicketRange, WINNING_TICKET},
+    tickets::{TicketId, TicketRange, WINNING_TICKET},
 };
 use multiversx_sc::api::CryptoApi;
 
@@ -111,9 +111,9 @@ pub trait GuaranteedTicketWinnersModule:
 
                 let mut current_ticket = ticket_range.first_id;
                 while remaining_tickets_to_be_won > 0 {
-                    let is_winning_ticket = self.ticket_status(current_ticket).get();
+                    let is_winning_ticket = self.get_ticket_status(current_ticket);
                     if !is_winning_ticket {
-                        self.ticket_status(current_ticket).set(WINNING_TICKET);
+                        self.set_ticket_status(current_ticket, WINNING_TICKET);
                         op.total_additional_winning_tickets += 1;
                         remaining_tickets_to_be_won -= 1;
                     }
@@ -132,7 +132,8 @@ pub trait GuaranteedTicketWinnersModule:
         let last_ticket_pos = self.get_total_tickets();
 
         self.run_while_it_has_gas(|| {
-            if nr_original_winning_tickets + op.total_additional_winning_tickets >= last_ticket_pos
+            if (nr_original_winning_tickets + op.total_additional_winning_tickets) as TicketId
+                >= last_ticket_pos
             {
                 op.leftover_tickets = 0;
             }
@@ -141,7 +142,8 @@ pub trait GuaranteedTicketWinnersModule:
                 return STOP_OP;
             }
 
-            let current_ticket_pos = nr_original_winning_tickets + op.leftover_ticket_pos_offset;
+            let current_ticket_pos =
+                (nr_original_winning_tickets + op.leftover_ticket_pos_offset) as TicketId;
 
             let selection_result =
                 self.try_select_winning_ticket(&mut op.rng, current_ticket_pos, last_ticket_pos);
@@ -164,7 +166,7 @@ pub trait GuaranteedTicketWinnersModule:
     fn winning_tickets_in_range(&self, ticket_range: &TicketRange) -> usize {
         let mut winning_tickets_no = 0;
         for ticket_id in ticket_range.first_id..=ticket_range.last_id {
-            let ticket_status = self.ticket_status(ticket_id).get();
+            let ticket_status = self.get_ticket_status(ticket_id);
             if ticket_status == WINNING_TICKET {
                 winning_tickets_no += 1;
             }
@@ -176,28 +178,29 @@ pub trait GuaranteedTicketWinnersModule:
     fn try_select_winning_ticket(
         &self,
         rng: &mut Random<Self::Api>,
-        current_ticket_position: usize,
-        last_ticket_position: usize,
+        current_ticket_position: TicketId,
+        last_ticket_position: TicketId,
     ) -> AdditionalSelectionTryResult {
         let current_ticket_id = self.get_ticket_id_from_pos(current_ticket_position);
         if self.is_already_winning_ticket(current_ticket_id) {
             return AdditionalSelectionTryResult::CurrentAlreadyWinning;
         }
 
-        let rand_pos = rng.next_usize_in_range(current_ticket_position, last_ticket_position + 1);
+        let rand_pos =
+            rng.next_ticket_id_in_range(current_ticket_position, last_ticket_position + 1);
         let winning_ticket_id = self.get_ticket_id_from_pos(rand_pos);
         if self.is_already_winning_ticket(winning_ticket_id) {
             return AdditionalSelectionTryResult::NewlySelectedAlreadyWinning;
         }
 
         self.ticket_pos_to_id(rand_pos).set(current_ticket_id);
-        self.ticket_status(winning_ticket_id).set(WINNING_TICKET);
+        self.set_ticket_status(winning_ticket_id, WINNING_TICKET);
 
         AdditionalSelectionTryResult::Ok
     }
 
     #[inline]
-    fn is_already_winning_ticket(&self, ticket_id: usize) -> bool {
-        self.ticket_status(ticket_id).get() == WINNING_TICKET
+    fn is_already_winning_ticket(&self, ticket_id: TicketId) -> bool {
+        self.get_ticket_status(ticket_id) == WINNING_TICKET
     }
 }
@@ -134,6 +134,23 @@ pub trait GuaranteedTicketsInitModule:
             .set(total_guaranteed_tickets);
     }
 
+    fn migrate_guaranteed_tickets(
+        &self,
+        old_address: &ManagedAddress,
+        new_address: &ManagedAddress,
+    ) {
+        let mut whitelist = self.users_with_guaranteed_ticket();
+        if whitelist.swap_remove(old_address) {
+            let _ = whitelist.insert(new_address.clone());
+        }
+
+        let user_ticket_status_mapper = self.user_ticket_status(old_address);
+        if !user_ticket_status_mapper.is_empty() {
+            self.user_ticket_status(new_address)
+                .set(user_ticket_status_mapper.take());
+        }
+    }
+
     #[storage_mapper("minConfirmedForGuaranteedTicket")]
     fn min_confirmed_for_guaranteed_ticket(&self) -> SingleValueMapper<usize>;
 
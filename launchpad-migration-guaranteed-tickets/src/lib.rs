@@ -3,7 +3,10 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
-use launchpad_common::launch_stage::Flags;
+use launchpad_common::{
+    blacklist::BlacklistReason, launch_stage::Flags, permissions::Role, LaunchConfiguration,
+    UserFullStatus,
+};
 
 use crate::guaranteed_ticket_winners::GuaranteedTicketsSelectionOperation;
 
@@ -17,17 +20,49 @@ pub trait LaunchpadMigrationGuaranteedTickets:
     launchpad_common::LaunchpadMain
     + launchpad_common::launch_stage::LaunchStageModule
     + launchpad_common::config::ConfigModule
+    + launchpad_common::time_provider::TimeProviderModule
     + launchpad_common::setup::SetupModule
+    + launchpad_common::admin_proposal::AdminProposalModule
     + launchpad_common::tickets::TicketsModule
+    + launchpad_common::tickets_layout_migration::TicketsLayoutMigrationModule
     + launchpad_common::winner_selection::WinnerSelectionModule
+    + launchpad_common::commit_reveal::CommitRevealModule
     + launchpad_common::ongoing_operation::OngoingOperationModule
     + launchpad_common::permissions::PermissionsModule
+    + launchpad_common::timelock::TimelockModule
+    + launchpad_common::guardian::GuardianModule
     + launchpad_common::blacklist::BlacklistModule
     + launchpad_common::token_send::TokenSendModule
     + launchpad_common::user_interactions::UserInteractionsModule
+    + launchpad_common::consolation_airdrop::ConsolationAirdropModule
+    + launchpad_common::participation_badge::ParticipationBadgeModule
+    + launchpad_common::loyalty::LoyaltyModule
+    + launchpad_common::referral::ReferralModule
+    + launchpad_common::kyc::KycModule
+    + launchpad_common::signature_eligibility::SignatureEligibilityModule
+    + launchpad_common::relayer::RelayerModule
+    + launchpad_common::keeper_incentives::KeeperIncentivesModule
+    + launchpad_common::governance::GovernanceModule
+    + launchpad_common::price_oracle::PriceOracleModule
+    + launchpad_common::swap_and_confirm::SwapAndConfirmModule
+    + launchpad_common::locked_token_payment::LockedTokenPaymentModule
+    + launchpad_common::reward_bundle::RewardBundleModule
+    + launchpad_common::token_rescue::TokenRescueModule
+    + launchpad_common::unclaimed_sweep::UnclaimedSweepModule
+    + launchpad_common::storage_cleanup::StorageCleanupModule
+    + launchpad_common::invariants::InvariantsModule
+    + launchpad_common::winners_hash::WinnersHashModule
+    + launchpad_common::tiers::TiersModule
+    + launchpad_common::action_pause::ActionPauseModule
+    + launchpad_common::fcfs_round::FcfsRoundModule
+    + launchpad_common::wallet_migration::WalletMigrationModule
+    + launchpad_common::results_distribution::ResultsDistributionModule
+    + launchpad_common::claim_and_stake::ClaimAndStakeModule
+    + launchpad_common::liquidity_bootstrap::LiquidityBootstrapModule
     + launchpad_common::common_events::CommonEventsModule
     + guaranteed_tickets_init::GuaranteedTicketsInitModule
     + guaranteed_ticket_winners::GuaranteedTicketWinnersModule
+    + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
     + multiversx_sc_modules::pause::PauseModule
 {
     #[allow(clippy::too_many_arguments)]
@@ -85,9 +120,13 @@ pub trait LaunchpadMigrationGuaranteedTickets:
     }
 
     #[endpoint(addUsersToBlacklist)]
-    fn add_users_to_blacklist_endpoint(&self, users_list: MultiValueEncoded<ManagedAddress>) {
+    fn add_users_to_blacklist_endpoint(
+        &self,
+        reason: BlacklistReason,
+        users_list: MultiValueEncoded<ManagedAddress>,
+    ) {
         let users_vec = users_list.to_vec();
-        self.add_users_to_blacklist(&users_vec);
+        self.add_users_to_blacklist(&users_vec, reason);
         self.clear_users_with_guaranteed_ticket_after_blacklist(&users_vec);
     }
 
@@ -101,6 +140,13 @@ pub trait LaunchpadMigrationGuaranteedTickets:
         self.remove_guaranteed_tickets_from_blacklist(&users_vec);
     }
 
+    /// Single entry point for the whole winner selection, which may be called repeatedly
+    /// until it returns `completed`
+    #[endpoint(completeWinnerSelection)]
+    fn complete_winner_selection_endpoint(&self) -> OperationCompletionStatus {
+        self.complete_winner_selection(Self::distribute_guaranteed_tickets_endpoint)
+    }
+
     #[endpoint(distributeGuaranteedTickets)]
     fn distribute_guaranteed_tickets_endpoint(&self) -> OperationCompletionStatus {
         self.require_winner_selection_period();
@@ -150,16 +196,62 @@ pub trait LaunchpadMigrationGuaranteedTickets:
     }
 
     #[endpoint(claimLaunchpadTokens)]
-    fn claim_launchpad_tokens_endpoint(&self) {
-        self.claim_launchpad_tokens(Self::default_send_launchpad_tokens_fn);
+    fn claim_launchpad_tokens_endpoint(&self, opt_receiver: OptionalValue<ManagedAddress>) {
+        self.claim_launchpad_tokens(opt_receiver, Self::default_send_launchpad_tokens_fn);
+    }
+
+    #[payable("*")]
+    #[endpoint(buyFcfsTickets)]
+    fn buy_fcfs_tickets_endpoint(&self, nr_tickets: usize) {
+        self.buy_fcfs_tickets(nr_tickets, Self::default_send_launchpad_tokens_fn);
+    }
+
+    #[endpoint(claimLaunchpadTokensFor)]
+    fn claim_launchpad_tokens_for_endpoint(&self, addresses: MultiValueEncoded<ManagedAddress>) {
+        self.claim_launchpad_tokens_for(addresses, Self::default_send_launchpad_tokens_fn);
+    }
+
+    #[endpoint(distributeResults)]
+    fn distribute_results_endpoint(&self) -> OperationCompletionStatus {
+        self.require_role(Role::Finance);
+        self.distribute_results(Self::default_send_launchpad_tokens_fn)
     }
 
-    #[only_owner]
     #[endpoint(claimTicketPayment)]
     fn claim_ticket_payment_endpoint(&self) {
+        self.require_role(Role::Finance);
         self.claim_ticket_payment();
     }
 
+    #[endpoint(rescueTokens)]
+    fn rescue_tokens_endpoint(&self, token_id: EgldOrEsdtTokenIdentifier, token_nonce: u64) {
+        self.require_role(Role::Finance);
+
+        let locked_lp_tokens_mapper = self.locked_lp_tokens();
+        require!(
+            locked_lp_tokens_mapper.is_empty()
+                || token_id != locked_lp_tokens_mapper.get().token_identifier,
+            "Cannot rescue the launch tokens"
+        );
+
+        self.rescue_tokens(token_id, token_nonce);
+    }
+
+    #[endpoint(executePendingChange)]
+    fn execute_pending_change_endpoint(&self, change_id: usize) {
+        self.execute_pending_change(change_id, Self::default_custom_change_fn);
+    }
+
+    #[endpoint(executeAdminProposal)]
+    fn execute_admin_proposal_endpoint(&self, proposal_id: usize) {
+        self.execute_admin_proposal(proposal_id, Self::default_custom_change_fn);
+    }
+
+    #[endpoint(approveWalletMigration)]
+    fn approve_wallet_migration_endpoint(&self, old_address: ManagedAddress) {
+        self.approve_wallet_migration(old_address, Self::migrate_guaranteed_tickets);
+    }
+
     #[view(getUserTicketsStatus)]
     fn user_tickets_status(&self, address: ManagedAddress) -> UserTicketsStatus {
         let user_ticket_status_mapper = self.user_ticket_status(&address);
@@ -176,4 +268,23 @@ pub trait LaunchpadMigrationGuaranteedTickets:
         )
             .into()
     }
+
+    #[view(getConfiguration)]
+    fn get_configuration_view(&self) -> LaunchConfiguration<Self::Api> {
+        self.get_launch_configuration(ManagedBuffer::new())
+    }
+
+    #[view(getUserFullStatus)]
+    fn user_full_status(&self, address: ManagedAddress) -> UserFullStatus<Self::Api> {
+        let user_ticket_status_mapper = self.user_ticket_status(&address);
+        let guaranteed_tickets = if user_ticket_status_mapper.is_empty() {
+            0
+        } else {
+            let user_ticket_status = user_ticket_status_mapper.get();
+            user_ticket_status.staking_guaranteed_tickets
+                + user_ticket_status.migration_guaranteed_tickets
+        };
+
+        self.get_user_full_status(address, guaranteed_tickets)
+    }
 }
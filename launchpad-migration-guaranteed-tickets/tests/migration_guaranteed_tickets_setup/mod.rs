@@ -1,5 +1,5 @@
 use multiversx_sc::types::{
-    Address, EgldOrEsdtTokenIdentifier, MultiValueEncoded, OperationCompletionStatus,
+    Address, EgldOrEsdtTokenIdentifier, MultiValueEncoded, OperationCompletionStatus, OptionalValue,
 };
 
 use launchpad_common::{
@@ -139,7 +139,7 @@ where
             &self.lp_wrapper,
             &rust_biguint!(TICKET_COST * nr_tickets as u64),
             |sc| {
-                sc.confirm_tickets(nr_tickets);
+                sc.confirm_tickets(OptionalValue::Some(nr_tickets), OptionalValue::None);
             },
         )
     }
@@ -163,8 +163,8 @@ where
             &rust_biguint!(0),
             |sc| {
                 let base_winning = NR_WINNING_TICKETS - nr_whales;
-                for ticket_id in 1..=base_winning {
-                    sc.ticket_status(ticket_id).set(WINNING_TICKET);
+                for ticket_id in 1..=base_winning as u64 {
+                    sc.set_ticket_status(ticket_id, WINNING_TICKET);
                 }
 
                 sc.claimable_ticket_payment()
@@ -195,7 +195,7 @@ where
     pub fn claim_user(&mut self, user: &Address) -> TxResult {
         self.b_mock
             .execute_tx(user, &self.lp_wrapper, &rust_biguint!(0), |sc| {
-                sc.claim_launchpad_tokens_endpoint();
+                sc.claim_launchpad_tokens_endpoint(OptionalValue::None);
             })
     }
 
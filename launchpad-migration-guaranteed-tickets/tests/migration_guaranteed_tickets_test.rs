@@ -3,9 +3,13 @@
 mod migration_guaranteed_tickets_setup;
 
 use launchpad_common::{
+    blacklist::BlacklistReason,
+    claim_and_stake::ClaimAndStakeModule,
     config::ConfigModule,
+    liquidity_bootstrap::LiquidityBootstrapModule,
     setup::SetupModule,
     tickets::{TicketsModule, WINNING_TICKET},
+    user_interactions::UserInteractionsModule,
     winner_selection::WinnerSelectionModule,
 };
 use launchpad_migration_guaranteed_tickets::{
@@ -19,8 +23,10 @@ use migration_guaranteed_tickets_setup::{
     LaunchpadSetup, CLAIM_START_ROUND, CONFIRM_START_ROUND, LAUNCHPAD_TOKENS_PER_TICKET,
     LAUNCHPAD_TOKEN_ID, MAX_TIER_TICKETS, TICKET_COST, WINNER_SELECTION_START_ROUND,
 };
-use multiversx_sc::types::{EgldOrEsdtTokenIdentifier, MultiValueEncoded};
-use multiversx_sc_scenario::{managed_address, managed_biguint, rust_biguint};
+use multiversx_sc::types::{
+    EgldOrEsdtTokenIdentifier, MultiValueEncoded, OperationCompletionStatus, OptionalValue,
+};
+use multiversx_sc_scenario::{managed_address, managed_biguint, managed_buffer, rust_biguint};
 
 use crate::migration_guaranteed_tickets_setup::NR_WINNING_TICKETS;
 
@@ -54,12 +60,12 @@ fn confirm_all_test() {
     lp_setup
         .b_mock
         .execute_query(&lp_setup.lp_wrapper, |sc| {
-            assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(2).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(3).get(), false);
-            assert_eq!(sc.ticket_status(4).get(), false);
-            assert_eq!(sc.ticket_status(5).get(), false);
-            assert_eq!(sc.ticket_status(6).get(), false);
+            assert_eq!(sc.get_ticket_status(1), WINNING_TICKET);
+            assert_eq!(sc.get_ticket_status(2), WINNING_TICKET);
+            assert_eq!(sc.get_ticket_status(3), false);
+            assert_eq!(sc.get_ticket_status(4), false);
+            assert_eq!(sc.get_ticket_status(5), false);
+            assert_eq!(sc.get_ticket_status(6), false);
 
             assert_eq!(
                 sc.get_number_of_winning_tickets_for_address(managed_address!(&participants[0])),
@@ -84,12 +90,12 @@ fn confirm_all_test() {
     lp_setup
         .b_mock
         .execute_query(&lp_setup.lp_wrapper, |sc| {
-            assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(2).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(3).get(), false);
-            assert_eq!(sc.ticket_status(4).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(5).get(), false);
-            assert_eq!(sc.ticket_status(6).get(), false);
+            assert_eq!(sc.get_ticket_status(1), WINNING_TICKET);
+            assert_eq!(sc.get_ticket_status(2), WINNING_TICKET);
+            assert_eq!(sc.get_ticket_status(3), false);
+            assert_eq!(sc.get_ticket_status(4), WINNING_TICKET);
+            assert_eq!(sc.get_ticket_status(5), false);
+            assert_eq!(sc.get_ticket_status(6), false);
 
             assert_eq!(
                 sc.get_number_of_winning_tickets_for_address(managed_address!(&participants[0])),
@@ -170,11 +176,11 @@ fn redistribute_test() {
     lp_setup
         .b_mock
         .execute_query(&lp_setup.lp_wrapper, |sc| {
-            assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(2).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(3).get(), false);
-            assert_eq!(sc.ticket_status(4).get(), false);
-            assert_eq!(sc.ticket_status(5).get(), false);
+            assert_eq!(sc.get_ticket_status(1), WINNING_TICKET);
+            assert_eq!(sc.get_ticket_status(2), WINNING_TICKET);
+            assert_eq!(sc.get_ticket_status(3), false);
+            assert_eq!(sc.get_ticket_status(4), false);
+            assert_eq!(sc.get_ticket_status(5), false);
 
             assert_eq!(
                 sc.get_number_of_winning_tickets_for_address(managed_address!(&participants[0])),
@@ -200,11 +206,11 @@ fn redistribute_test() {
     lp_setup
         .b_mock
         .execute_query(&lp_setup.lp_wrapper, |sc| {
-            assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(2).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(3).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(4).get(), false);
-            assert_eq!(sc.ticket_status(5).get(), false);
+            assert_eq!(sc.get_ticket_status(1), WINNING_TICKET);
+            assert_eq!(sc.get_ticket_status(2), WINNING_TICKET);
+            assert_eq!(sc.get_ticket_status(3), WINNING_TICKET);
+            assert_eq!(sc.get_ticket_status(4), false);
+            assert_eq!(sc.get_ticket_status(5), false);
 
             assert_eq!(
                 sc.get_number_of_winning_tickets_for_address(managed_address!(&participants[0])),
@@ -286,13 +292,13 @@ fn combined_scenario_test() {
     lp_setup
         .b_mock
         .execute_query(&lp_setup.lp_wrapper, |sc| {
-            assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(2).get(), false);
-            assert_eq!(sc.ticket_status(3).get(), false);
-            assert_eq!(sc.ticket_status(4).get(), false);
-            assert_eq!(sc.ticket_status(5).get(), false);
-            assert_eq!(sc.ticket_status(6).get(), false);
-            assert_eq!(sc.ticket_status(7).get(), false);
+            assert_eq!(sc.get_ticket_status(1), WINNING_TICKET);
+            assert_eq!(sc.get_ticket_status(2), false);
+            assert_eq!(sc.get_ticket_status(3), false);
+            assert_eq!(sc.get_ticket_status(4), false);
+            assert_eq!(sc.get_ticket_status(5), false);
+            assert_eq!(sc.get_ticket_status(6), false);
+            assert_eq!(sc.get_ticket_status(7), false);
 
             assert_eq!(sc.nr_winning_tickets().get(), NR_WINNING_TICKETS - 2);
             assert_eq!(sc.users_with_guaranteed_ticket().len(), 2);
@@ -313,13 +319,13 @@ fn combined_scenario_test() {
                 sc.select_guaranteed_tickets(&mut op);
 
                 // user[3]'s first ticket was selected
-                assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET);
-                assert_eq!(sc.ticket_status(2).get(), false);
-                assert_eq!(sc.ticket_status(3).get(), false);
-                assert_eq!(sc.ticket_status(4).get(), WINNING_TICKET);
-                assert_eq!(sc.ticket_status(5).get(), false);
-                assert_eq!(sc.ticket_status(6).get(), false);
-                assert_eq!(sc.ticket_status(7).get(), false);
+                assert_eq!(sc.get_ticket_status(1), WINNING_TICKET);
+                assert_eq!(sc.get_ticket_status(2), false);
+                assert_eq!(sc.get_ticket_status(3), false);
+                assert_eq!(sc.get_ticket_status(4), WINNING_TICKET);
+                assert_eq!(sc.get_ticket_status(5), false);
+                assert_eq!(sc.get_ticket_status(6), false);
+                assert_eq!(sc.get_ticket_status(7), false);
 
                 assert_eq!(op.leftover_tickets, 1);
                 assert_eq!(op.total_additional_winning_tickets, 1);
@@ -329,13 +335,13 @@ fn combined_scenario_test() {
                 sc.distribute_leftover_tickets(&mut op);
 
                 // ticket ID 2 was selected as winner
-                assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET);
-                assert_eq!(sc.ticket_status(2).get(), WINNING_TICKET);
-                assert_eq!(sc.ticket_status(3).get(), false);
-                assert_eq!(sc.ticket_status(4).get(), WINNING_TICKET);
-                assert_eq!(sc.ticket_status(5).get(), false);
-                assert_eq!(sc.ticket_status(6).get(), false);
-                assert_eq!(sc.ticket_status(7).get(), false);
+                assert_eq!(sc.get_ticket_status(1), WINNING_TICKET);
+                assert_eq!(sc.get_ticket_status(2), WINNING_TICKET);
+                assert_eq!(sc.get_ticket_status(3), false);
+                assert_eq!(sc.get_ticket_status(4), WINNING_TICKET);
+                assert_eq!(sc.get_ticket_status(5), false);
+                assert_eq!(sc.get_ticket_status(6), false);
+                assert_eq!(sc.get_ticket_status(7), false);
 
                 assert_eq!(op.leftover_tickets, 0);
                 assert_eq!(op.total_additional_winning_tickets, 2);
@@ -415,16 +421,16 @@ fn add_migration_guaranteed_tickets_distribution_isolated_steps_scenario_test()
     lp_setup
         .b_mock
         .execute_query(&lp_setup.lp_wrapper, |sc| {
-            assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(2).get(), false);
-            assert_eq!(sc.ticket_status(3).get(), false);
-            assert_eq!(sc.ticket_status(4).get(), false);
-            assert_eq!(sc.ticket_status(5).get(), false);
-            assert_eq!(sc.ticket_status(6).get(), false);
-            assert_eq!(sc.ticket_status(7).get(), false);
-            assert_eq!(sc.ticket_status(8).get(), false);
-            assert_eq!(sc.ticket_status(9).get(), false);
-            assert_eq!(sc.ticket_status(10).get(), false);
+            assert_eq!(sc.get_ticket_status(1), WINNING_TICKET);
+            assert_eq!(sc.get_ticket_status(2), false);
+            assert_eq!(sc.get_ticket_status(3), false);
+            assert_eq!(sc.get_ticket_status(4), false);
+            assert_eq!(sc.get_ticket_status(5), false);
+            assert_eq!(sc.get_ticket_status(6), false);
+            assert_eq!(sc.get_ticket_status(7), false);
+            assert_eq!(sc.get_ticket_status(8), false);
+            assert_eq!(sc.get_ticket_status(9), false);
+            assert_eq!(sc.get_ticket_status(10), false);
 
             assert_eq!(
                 sc.nr_winning_tickets().get(),
@@ -452,16 +458,16 @@ fn add_migration_guaranteed_tickets_distribution_isolated_steps_scenario_test()
                 // first step
                 sc.select_guaranteed_tickets(&mut op);
 
-                assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET); // randomly selected -> leftover_ticket
-                assert_eq!(sc.ticket_status(2).get(), false);
-                assert_eq!(sc.ticket_status(3).get(), false);
-                assert_eq!(sc.ticket_status(4).get(), WINNING_TICKET); // migration guaranteed ticket -> additional_winning_tickets
-                assert_eq!(sc.ticket_status(5).get(), WINNING_TICKET); // staking guaranteed ticket -> additional_winning_tickets
-                assert_eq!(sc.ticket_status(6).get(), WINNING_TICKET); // migration guaranteed ticket -> additional_winning_tickets
-                assert_eq!(sc.ticket_status(7).get(), false);
-                assert_eq!(sc.ticket_status(8).get(), false);
-                assert_eq!(sc.ticket_status(9).get(), false);
-                assert_eq!(sc.ticket_status(10).get(), false);
+                assert_eq!(sc.get_ticket_status(1), WINNING_TICKET); // randomly selected -> leftover_ticket
+                assert_eq!(sc.get_ticket_status(2), false);
+                assert_eq!(sc.get_ticket_status(3), false);
+                assert_eq!(sc.get_ticket_status(4), WINNING_TICKET); // migration guaranteed ticket -> additional_winning_tickets
+                assert_eq!(sc.get_ticket_status(5), WINNING_TICKET); // staking guaranteed ticket -> additional_winning_tickets
+                assert_eq!(sc.get_ticket_status(6), WINNING_TICKET); // migration guaranteed ticket -> additional_winning_tickets
+                assert_eq!(sc.get_ticket_status(7), false);
+                assert_eq!(sc.get_ticket_status(8), false);
+                assert_eq!(sc.get_ticket_status(9), false);
+                assert_eq!(sc.get_ticket_status(10), false);
 
                 assert_eq!(op.leftover_tickets, 1);
                 assert_eq!(op.total_additional_winning_tickets, 3);
@@ -470,16 +476,16 @@ fn add_migration_guaranteed_tickets_distribution_isolated_steps_scenario_test()
                 // second step
                 sc.distribute_leftover_tickets(&mut op);
 
-                assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET);
-                assert_eq!(sc.ticket_status(2).get(), false);
-                assert_eq!(sc.ticket_status(3).get(), false);
-                assert_eq!(sc.ticket_status(4).get(), WINNING_TICKET);
-                assert_eq!(sc.ticket_status(5).get(), WINNING_TICKET);
-                assert_eq!(sc.ticket_status(6).get(), WINNING_TICKET);
-                assert_eq!(sc.ticket_status(7).get(), false);
-                assert_eq!(sc.ticket_status(8).get(), WINNING_TICKET); // randomly selected in distribute_leftover_tickets
-                assert_eq!(sc.ticket_status(9).get(), false);
-                assert_eq!(sc.ticket_status(10).get(), false);
+                assert_eq!(sc.get_ticket_status(1), WINNING_TICKET);
+                assert_eq!(sc.get_ticket_status(2), false);
+                assert_eq!(sc.get_ticket_status(3), false);
+                assert_eq!(sc.get_ticket_status(4), WINNING_TICKET);
+                assert_eq!(sc.get_ticket_status(5), WINNING_TICKET);
+                assert_eq!(sc.get_ticket_status(6), WINNING_TICKET);
+                assert_eq!(sc.get_ticket_status(7), false);
+                assert_eq!(sc.get_ticket_status(8), WINNING_TICKET); // randomly selected in distribute_leftover_tickets
+                assert_eq!(sc.get_ticket_status(9), false);
+                assert_eq!(sc.get_ticket_status(10), false);
 
                 assert_eq!(op.leftover_tickets, 0);
                 assert_eq!(op.total_additional_winning_tickets, 4);
@@ -907,7 +913,7 @@ fn blacklist_scenario_test() {
             |sc| {
                 let mut blacklist = MultiValueEncoded::new();
                 blacklist.push(managed_address!(&participants[4]));
-                sc.add_users_to_blacklist_endpoint(blacklist);
+                sc.add_users_to_blacklist_endpoint(BlacklistReason::Unspecified, blacklist);
             },
         )
         .assert_error(4, "Permission denied");
@@ -944,7 +950,7 @@ fn blacklist_scenario_test() {
             |sc| {
                 let mut blacklist = MultiValueEncoded::new();
                 blacklist.push(managed_address!(&second_new_participant));
-                sc.add_users_to_blacklist_endpoint(blacklist);
+                sc.add_users_to_blacklist_endpoint(BlacklistReason::Unspecified, blacklist);
             },
         )
         .assert_ok();
@@ -1032,7 +1038,7 @@ fn blacklist_scenario_test() {
             |sc| {
                 let mut blacklist = MultiValueEncoded::new();
                 blacklist.push(managed_address!(&second_new_participant));
-                sc.add_users_to_blacklist_endpoint(blacklist);
+                sc.add_users_to_blacklist_endpoint(BlacklistReason::Unspecified, blacklist);
             },
         )
         .assert_error(4, "May only modify blacklist before winner selection");
@@ -1043,13 +1049,13 @@ fn blacklist_scenario_test() {
     lp_setup
         .b_mock
         .execute_query(&lp_setup.lp_wrapper, |sc| {
-            assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(2).get(), false);
-            assert_eq!(sc.ticket_status(3).get(), false);
-            assert_eq!(sc.ticket_status(4).get(), false);
-            assert_eq!(sc.ticket_status(5).get(), false);
-            assert_eq!(sc.ticket_status(6).get(), false);
-            assert_eq!(sc.ticket_status(7).get(), false);
+            assert_eq!(sc.get_ticket_status(1), WINNING_TICKET);
+            assert_eq!(sc.get_ticket_status(2), false);
+            assert_eq!(sc.get_ticket_status(3), false);
+            assert_eq!(sc.get_ticket_status(4), false);
+            assert_eq!(sc.get_ticket_status(5), false);
+            assert_eq!(sc.get_ticket_status(6), false);
+            assert_eq!(sc.get_ticket_status(7), false);
 
             assert_eq!(sc.users_with_guaranteed_ticket().len(), 3);
         })
@@ -1060,12 +1066,12 @@ fn blacklist_scenario_test() {
     lp_setup
         .b_mock
         .execute_query(&lp_setup.lp_wrapper, |sc| {
-            assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(2).get(), false);
-            assert_eq!(sc.ticket_status(3).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(4).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(5).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(6).get(), false);
+            assert_eq!(sc.get_ticket_status(1), WINNING_TICKET);
+            assert_eq!(sc.get_ticket_status(2), false);
+            assert_eq!(sc.get_ticket_status(3), WINNING_TICKET);
+            assert_eq!(sc.get_ticket_status(4), WINNING_TICKET);
+            assert_eq!(sc.get_ticket_status(5), WINNING_TICKET);
+            assert_eq!(sc.get_ticket_status(6), false);
 
             assert_eq!(sc.users_with_guaranteed_ticket().len(), 0);
         })
@@ -1184,3 +1190,386 @@ fn confirm_less_tickets_than_total_available_scenario_test() {
         &rust_biguint!(0),
     );
 }
+
+#[test]
+fn claim_for_test() {
+    let mut lp_setup = LaunchpadSetup::new(
+        NR_WINNING_TICKETS,
+        launchpad_migration_guaranteed_tickets::contract_obj,
+    );
+    let participants = lp_setup.participants.clone();
+    let grace_rounds = 5;
+
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &lp_setup.owner_address,
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_claim_on_behalf_grace_rounds(grace_rounds);
+            },
+        )
+        .assert_ok();
+
+    for (i, p) in participants.iter().enumerate() {
+        lp_setup.confirm(p, i + 1).assert_ok();
+    }
+
+    lp_setup
+        .b_mock
+        .set_block_round(WINNER_SELECTION_START_ROUND);
+    lp_setup.filter_tickets().assert_ok();
+    lp_setup.select_base_winners_mock(1).assert_ok();
+    lp_setup.distribute_tickets().assert_ok();
+
+    lp_setup.b_mock.set_block_round(CLAIM_START_ROUND);
+    lp_setup.claim_user(&participants[0]).assert_ok();
+
+    for (round, expected_err) in [
+        (
+            CLAIM_START_ROUND,
+            Some("Claim grace period has not passed yet"),
+        ),
+        (CLAIM_START_ROUND + grace_rounds, None),
+    ] {
+        lp_setup.b_mock.set_block_round(round);
+
+        let result = lp_setup.b_mock.execute_tx(
+            &lp_setup.owner_address,
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut addresses = MultiValueEncoded::new();
+                for p in &participants {
+                    addresses.push(managed_address!(p));
+                }
+
+                sc.claim_launchpad_tokens_for_endpoint(addresses);
+            },
+        );
+        match expected_err {
+            Some(err) => result.assert_user_error(err),
+            None => result.assert_ok(),
+        }
+    }
+
+    // each user won 1 ticket, the first user had already claimed
+    let base_user_balance = rust_biguint!(TICKET_COST * MAX_TIER_TICKETS as u64);
+    for p in participants.iter() {
+        lp_setup
+            .b_mock
+            .check_egld_balance(p, &(&base_user_balance - TICKET_COST));
+        lp_setup.b_mock.check_esdt_balance(
+            p,
+            LAUNCHPAD_TOKEN_ID,
+            &rust_biguint!(LAUNCHPAD_TOKENS_PER_TICKET),
+        );
+    }
+
+    lp_setup
+        .b_mock
+        .execute_query(&lp_setup.lp_wrapper, |sc| {
+            for p in participants.iter() {
+                assert!(sc.has_user_claimed(&managed_address!(p)));
+            }
+        })
+        .assert_ok();
+
+    lp_setup
+        .claim_user(&participants[1])
+        .assert_user_error("Already claimed");
+}
+
+#[test]
+fn claim_to_receiver_test() {
+    let mut lp_setup = LaunchpadSetup::new(
+        NR_WINNING_TICKETS,
+        launchpad_migration_guaranteed_tickets::contract_obj,
+    );
+    let participants = lp_setup.participants.clone();
+    let receiver = lp_setup.b_mock.create_user_account(&rust_biguint!(0));
+
+    for (i, p) in participants.iter().enumerate() {
+        lp_setup.confirm(p, i + 1).assert_ok();
+    }
+
+    lp_setup
+        .b_mock
+        .set_block_round(WINNER_SELECTION_START_ROUND);
+    lp_setup.filter_tickets().assert_ok();
+    lp_setup.select_base_winners_mock(1).assert_ok();
+    lp_setup.distribute_tickets().assert_ok();
+
+    lp_setup.b_mock.set_block_round(CLAIM_START_ROUND);
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &participants[1],
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.claim_launchpad_tokens_endpoint(OptionalValue::Some(managed_address!(
+                    &receiver
+                )));
+            },
+        )
+        .assert_ok();
+
+    // second user confirmed 2 tickets and won 1
+    let base_user_balance = rust_biguint!(TICKET_COST * MAX_TIER_TICKETS as u64);
+    lp_setup
+        .b_mock
+        .check_egld_balance(&participants[1], &(&base_user_balance - TICKET_COST * 2));
+    lp_setup
+        .b_mock
+        .check_esdt_balance(&participants[1], LAUNCHPAD_TOKEN_ID, &rust_biguint!(0));
+    lp_setup
+        .b_mock
+        .check_egld_balance(&receiver, &rust_biguint!(TICKET_COST));
+    lp_setup.b_mock.check_esdt_balance(
+        &receiver,
+        LAUNCHPAD_TOKEN_ID,
+        &rust_biguint!(LAUNCHPAD_TOKENS_PER_TICKET),
+    );
+}
+
+#[test]
+fn distribute_results_test() {
+    let mut lp_setup = LaunchpadSetup::new(
+        NR_WINNING_TICKETS,
+        launchpad_migration_guaranteed_tickets::contract_obj,
+    );
+    let participants = lp_setup.participants.clone();
+
+    for (i, p) in participants.iter().enumerate() {
+        lp_setup.confirm(p, i + 1).assert_ok();
+    }
+
+    lp_setup
+        .b_mock
+        .set_block_round(WINNER_SELECTION_START_ROUND);
+    lp_setup.filter_tickets().assert_ok();
+    lp_setup.select_base_winners_mock(1).assert_ok();
+    lp_setup.distribute_tickets().assert_ok();
+
+    lp_setup.b_mock.set_block_round(CLAIM_START_ROUND);
+    lp_setup.claim_user(&participants[0]).assert_ok();
+
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &participants[0],
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let _ = sc.distribute_results_endpoint();
+            },
+        )
+        .assert_user_error("Permission denied");
+
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &lp_setup.owner_address,
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let result = sc.distribute_results_endpoint();
+                assert_eq!(result, OperationCompletionStatus::Completed);
+            },
+        )
+        .assert_ok();
+
+    // each user won 1 ticket
+    let base_user_balance = rust_biguint!(TICKET_COST * MAX_TIER_TICKETS as u64);
+    for p in participants.iter() {
+        lp_setup
+            .b_mock
+            .check_egld_balance(p, &(&base_user_balance - TICKET_COST));
+        lp_setup.b_mock.check_esdt_balance(
+            p,
+            LAUNCHPAD_TOKEN_ID,
+            &rust_biguint!(LAUNCHPAD_TOKENS_PER_TICKET),
+        );
+    }
+
+    lp_setup
+        .claim_user(&participants[2])
+        .assert_user_error("Already claimed");
+}
+
+#[test]
+fn claim_and_stake_config_test() {
+    let mut lp_setup = LaunchpadSetup::new(
+        NR_WINNING_TICKETS,
+        launchpad_migration_guaranteed_tickets::contract_obj,
+    );
+    let participants = lp_setup.participants.clone();
+
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &lp_setup.owner_address,
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_staking_contract(
+                    managed_address!(&participants[0]),
+                    managed_buffer!(b"stakeFarm"),
+                );
+            },
+        )
+        .assert_user_error("Invalid SC address");
+
+    lp_setup.b_mock.set_block_round(CLAIM_START_ROUND);
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &participants[0],
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.claim_and_stake();
+            },
+        )
+        .assert_user_error("Staking is not enabled");
+
+    let staking_sc_address = lp_setup.lp_wrapper.address_ref().clone();
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &participants[0],
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_staking_contract(
+                    managed_address!(&staking_sc_address),
+                    managed_buffer!(b"stakeFarm"),
+                );
+            },
+        )
+        .assert_user_error("Permission denied");
+
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &lp_setup.owner_address,
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_staking_contract(
+                    managed_address!(&staking_sc_address),
+                    managed_buffer!(b"stakeFarm"),
+                );
+
+                let staking_contract = sc.staking_contract().get();
+                assert_eq!(
+                    staking_contract.address,
+                    managed_address!(&staking_sc_address)
+                );
+                assert_eq!(
+                    staking_contract.endpoint_name,
+                    managed_buffer!(b"stakeFarm")
+                );
+            },
+        )
+        .assert_ok();
+}
+
+#[test]
+fn liquidity_bootstrap_config_test() {
+    let mut lp_setup = LaunchpadSetup::new(
+        NR_WINNING_TICKETS,
+        launchpad_migration_guaranteed_tickets::contract_obj,
+    );
+    let participants = lp_setup.participants.clone();
+    let pair_sc_address = lp_setup.lp_wrapper.address_ref().clone();
+
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &participants[0],
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_liquidity_bootstrap_config(managed_address!(&pair_sc_address), 5_000, 10);
+            },
+        )
+        .assert_user_error("Permission denied");
+
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &lp_setup.owner_address,
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_liquidity_bootstrap_config(managed_address!(&participants[0]), 5_000, 10);
+            },
+        )
+        .assert_user_error("Invalid SC address");
+
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &lp_setup.owner_address,
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_liquidity_bootstrap_config(managed_address!(&pair_sc_address), 10_001, 10);
+            },
+        )
+        .assert_user_error("Invalid payment percentage");
+
+    // ticket payment token is EGLD
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &lp_setup.owner_address,
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_liquidity_bootstrap_config(managed_address!(&pair_sc_address), 5_000, 10);
+            },
+        )
+        .assert_user_error("Ticket payment token must be an ESDT");
+
+    // winners not selected yet
+    lp_setup.b_mock.set_block_round(CLAIM_START_ROUND);
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &participants[0],
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.bootstrap_liquidity();
+            },
+        )
+        .assert_user_error("Permission denied");
+
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &lp_setup.owner_address,
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.bootstrap_liquidity();
+            },
+        )
+        .assert_user_error("Not in claim period");
+
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &lp_setup.owner_address,
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.claim_lp_tokens();
+            },
+        )
+        .assert_user_error("No LP tokens");
+}
@@ -12,7 +12,7 @@ use multiversx_sc::{
     storage::mappers::StorageTokenWrapper,
     types::{
         Address, EgldOrEsdtTokenIdentifier, EsdtLocalRole, MultiValueEncoded,
-        OperationCompletionStatus,
+        OperationCompletionStatus, OptionalValue,
     },
 };
 use multiversx_sc_scenario::{
@@ -163,7 +163,7 @@ where
                     &lp_wrapper,
                     &rust_biguint!(nr_tickets as u64 * BASE_TICKET_COST),
                     |sc| {
-                        sc.confirm_tickets(nr_tickets);
+                        sc.confirm_tickets(OptionalValue::Some(nr_tickets), OptionalValue::None);
                     },
                 )
                 .assert_ok();
@@ -236,7 +236,7 @@ where
     pub fn claim(&mut self, caller: &Address) -> TxResult {
         self.b_mock
             .execute_tx(caller, &self.lp_wrapper, &rust_biguint!(0), |sc| {
-                sc.claim_launchpad_tokens_endpoint();
+                sc.claim_launchpad_tokens_endpoint(OptionalValue::None);
             })
     }
 
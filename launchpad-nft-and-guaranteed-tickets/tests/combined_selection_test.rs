@@ -89,12 +89,12 @@ fn combined_selection_test() {
     lp_setup
         .b_mock
         .execute_query(&lp_setup.lp_wrapper, |sc| {
-            assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(2).get(), false);
-            assert_eq!(sc.ticket_status(3).get(), false);
-            assert_eq!(sc.ticket_status(4).get(), false);
-            assert_eq!(sc.ticket_status(5).get(), false);
-            assert_eq!(sc.ticket_status(6).get(), false);
+            assert_eq!(sc.get_ticket_status(1), WINNING_TICKET);
+            assert_eq!(sc.get_ticket_status(2), false);
+            assert_eq!(sc.get_ticket_status(3), false);
+            assert_eq!(sc.get_ticket_status(4), false);
+            assert_eq!(sc.get_ticket_status(5), false);
+            assert_eq!(sc.get_ticket_status(6), false);
 
             assert_eq!(sc.nr_winning_tickets().get(), NR_WINNING_TICKETS - 1);
 
@@ -114,12 +114,12 @@ fn combined_selection_test() {
     lp_setup
         .b_mock
         .execute_query(&lp_setup.lp_wrapper, |sc| {
-            assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(2).get(), false);
-            assert_eq!(sc.ticket_status(3).get(), false);
-            assert_eq!(sc.ticket_status(4).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(5).get(), false);
-            assert_eq!(sc.ticket_status(6).get(), false);
+            assert_eq!(sc.get_ticket_status(1), WINNING_TICKET);
+            assert_eq!(sc.get_ticket_status(2), false);
+            assert_eq!(sc.get_ticket_status(3), false);
+            assert_eq!(sc.get_ticket_status(4), WINNING_TICKET);
+            assert_eq!(sc.get_ticket_status(5), false);
+            assert_eq!(sc.get_ticket_status(6), false);
 
             assert_eq!(sc.nr_winning_tickets().get(), NR_WINNING_TICKETS);
 
@@ -0,0 +1,84 @@
+#![no_std]
+
+multiversx_sc::imports!();
+
+pub use launchpad_common::{
+    config::TokenAmountPair,
+    launch_stage::{CurrentStage, Flags},
+    tickets::{TicketId, TicketRange},
+    LaunchConfiguration, UserFullStatus,
+};
+
+/// Call proxy for the user facing endpoints and views shared by all the launchpad variants,
+/// for smart contracts which participate in launches on behalf of their users,
+/// e.g. DAOs or vault aggregators. Declare it in the caller contract as
+/// `#[proxy] fn launchpad_proxy(&self, sc_address: ManagedAddress) -> launchpad_proxy::Proxy<Self::Api>;`
+///
+/// Note that the launchpad may only allow allowlisted smart contracts to confirm and claim.
+#[multiversx_sc::proxy]
+pub trait LaunchpadProxy {
+    #[payable("*")]
+    #[endpoint(confirmTickets)]
+    fn confirm_tickets(
+        &self,
+        opt_nr_tickets_to_confirm: OptionalValue<usize>,
+        opt_referrer: OptionalValue<ManagedAddress>,
+    );
+
+    #[endpoint(unconfirmTickets)]
+    fn unconfirm_tickets(&self, nr_tickets_to_unconfirm: usize);
+
+    #[endpoint(claimRefund)]
+    fn claim_refund(&self);
+
+    #[endpoint(claimLaunchpadTokens)]
+    fn claim_launchpad_tokens(&self, opt_receiver: OptionalValue<ManagedAddress>);
+
+    #[view(getConfiguration)]
+    fn get_configuration(&self) -> LaunchConfiguration<Self::Api>;
+
+    #[view(getLaunchStageFlags)]
+    fn flags(&self) -> Flags;
+
+    #[view(getCurrentStage)]
+    fn get_current_stage(&self) -> MultiValue2<CurrentStage, u64>;
+
+    #[view(getLaunchpadTokenId)]
+    fn launchpad_token_id(&self) -> TokenIdentifier;
+
+    #[view(getLaunchpadTokensPerWinningTicket)]
+    fn launchpad_tokens_per_winning_ticket(&self) -> BigUint;
+
+    #[view(getTicketPrice)]
+    fn ticket_price(&self) -> TokenAmountPair<Self::Api>;
+
+    #[view(getNumberOfWinningTickets)]
+    fn nr_winning_tickets(&self) -> usize;
+
+    #[view(getTicketRangeForAddress)]
+    fn get_ticket_range_for_address(
+        &self,
+        address: ManagedAddress,
+    ) -> OptionalValue<MultiValue2<TicketId, TicketId>>;
+
+    #[view(getTotalNumberOfTicketsForAddress)]
+    fn get_total_number_of_tickets_for_address(&self, address: ManagedAddress) -> usize;
+
+    #[view(getNumberOfConfirmedTicketsForAddress)]
+    fn nr_confirmed_tickets(&self, address: ManagedAddress) -> usize;
+
+    #[view(getNumberOfWinningTicketsForAddress)]
+    fn get_number_of_winning_tickets_for_address(&self, address: ManagedAddress) -> usize;
+
+    #[view(getWinningTicketIdsForAddress)]
+    fn get_winning_ticket_ids_for_address(
+        &self,
+        address: ManagedAddress,
+    ) -> MultiValueEncoded<TicketId>;
+
+    #[view(hasUserClaimedTokens)]
+    fn has_user_claimed(&self, address: ManagedAddress) -> bool;
+
+    #[view(getUserFullStatus)]
+    fn user_full_status(&self, address: ManagedAddress) -> UserFullStatus<Self::Api>;
+}
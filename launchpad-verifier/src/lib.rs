@@ -0,0 +1,7 @@
+pub mod random;
+pub mod selection;
+pub mod winners_hash;
+
+pub use random::Random;
+pub use selection::{select_winners, SelectionParams, SelectionResult, TicketBatch, TicketId};
+pub use winners_hash::winners_hash;
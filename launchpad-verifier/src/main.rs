@@ -0,0 +1,77 @@
+use std::io::{self, BufRead};
+use std::process;
+
+use launchpad_verifier::{select_winners, winners_hash, SelectionParams, TicketBatch};
+
+const USAGE: &str = "Usage: launchpad-verifier <seed_hex> <seed_index> <nr_winning_tickets> \
+<nr_guaranteed_tickets> <max_winning_tickets_per_address> < ticket_batches.txt
+
+Each line of the input has the form `<address> <nr_tickets>`, \
+in the order of the ticket batches after filtering.";
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.len() != 5 {
+        exit_with_error(USAGE);
+    }
+
+    let params = SelectionParams {
+        seed: hex::decode(&args[0]).unwrap_or_else(|_| exit_with_error("Invalid seed")),
+        seed_index: parse_number(&args[1]),
+        nr_winning_tickets: parse_number(&args[2]),
+        nr_guaranteed_tickets: parse_number(&args[3]),
+        max_winning_tickets_per_address: parse_number(&args[4]),
+    };
+    let batches = read_ticket_batches();
+
+    let result = select_winners(&params, &batches);
+    for batch in &batches {
+        let nr_winning_tickets = result
+            .winning_tickets_per_address
+            .get(&batch.address)
+            .copied()
+            .unwrap_or_default();
+        println!("{} {}", batch.address, nr_winning_tickets);
+    }
+
+    let winning_ticket_ids: Vec<String> = result
+        .winning_ticket_ids
+        .iter()
+        .map(|ticket_id| ticket_id.to_string())
+        .collect();
+    println!("winning ticket IDs: {}", winning_ticket_ids.join(" "));
+    println!(
+        "winners hash: {}",
+        hex::encode(winners_hash(&result.winning_ticket_ids))
+    );
+}
+
+fn read_ticket_batches() -> Vec<TicketBatch> {
+    let mut batches = Vec::new();
+    for line in io::stdin().lock().lines() {
+        let line = line.unwrap_or_else(|_| exit_with_error("Failed to read input"));
+        let mut parts = line.split_whitespace();
+        let (address, nr_tickets) = match (parts.next(), parts.next()) {
+            (Some(address), Some(nr_tickets)) => (address, nr_tickets),
+            (None, _) => continue,
+            _ => exit_with_error("Invalid ticket batch line"),
+        };
+
+        batches.push(TicketBatch {
+            address: address.to_string(),
+            nr_tickets: parse_number(nr_tickets),
+        });
+    }
+
+    batches
+}
+
+fn parse_number(arg: &str) -> usize {
+    arg.parse()
+        .unwrap_or_else(|_| exit_with_error("Invalid number argument"))
+}
+
+fn exit_with_error(message: &str) -> ! {
+    eprintln!("{message}");
+    process::exit(1);
+}
@@ -0,0 +1,80 @@
+use sha2::{Digest, Sha256};
+
+use crate::selection::TicketId;
+
+const USIZE_BYTES: usize = 4;
+const U64_BYTES: usize = 8;
+pub const HASH_LEN: usize = 32;
+
+/// Mirrors `launchpad_common::random::Random`, which reads 32 or 64-bit big-endian numbers
+/// from the seed and re-hashes the seed once it runs out of bytes
+pub struct Random {
+    pub seed: Vec<u8>,
+    pub index: usize,
+}
+
+impl Random {
+    pub fn new(seed: Vec<u8>, index: usize) -> Self {
+        Self { seed, index }
+    }
+
+    pub fn next_usize(&mut self) -> usize {
+        if self.index + USIZE_BYTES > HASH_LEN {
+            self.hash_seed();
+        }
+
+        let mut raw_bytes = [0u8; USIZE_BYTES];
+        raw_bytes.copy_from_slice(&self.seed[self.index..self.index + USIZE_BYTES]);
+        let rand = u32::from_be_bytes(raw_bytes) as usize;
+
+        self.index += USIZE_BYTES;
+
+        rand
+    }
+
+    /// Range is [min, max)
+    pub fn next_usize_in_range(&mut self, min: usize, max: usize) -> usize {
+        let rand = self.next_usize();
+
+        if min >= max {
+            min
+        } else {
+            min + rand % (max - min)
+        }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        if self.index + U64_BYTES > HASH_LEN {
+            self.hash_seed();
+        }
+
+        let mut raw_bytes = [0u8; U64_BYTES];
+        raw_bytes.copy_from_slice(&self.seed[self.index..self.index + U64_BYTES]);
+        let rand = u64::from_be_bytes(raw_bytes);
+
+        self.index += U64_BYTES;
+
+        rand
+    }
+
+    /// Range is [min, max). Only ranges larger than 32 bits consume 64-bit numbers.
+    pub fn next_ticket_id_in_range(&mut self, min: TicketId, max: TicketId) -> TicketId {
+        if min >= max {
+            return min;
+        }
+
+        let range_len = max - min;
+        let rand = if range_len <= u32::MAX as u64 {
+            self.next_usize() as u64
+        } else {
+            self.next_u64()
+        };
+
+        min + rand % range_len
+    }
+
+    fn hash_seed(&mut self) {
+        self.seed = Sha256::digest(&self.seed).to_vec();
+        self.index = 0;
+    }
+}
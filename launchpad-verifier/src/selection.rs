@@ -0,0 +1,107 @@
+use std::collections::HashMap;
+
+use crate::random::Random;
+
+pub type TicketId = u64;
+
+pub const FIRST_TICKET_ID: TicketId = 1;
+
+/// A user's tickets after filtering, in ticket ID order
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TicketBatch {
+    pub address: String,
+    pub nr_tickets: usize,
+}
+
+/// The values returned by the `getWinnerSelectionProof` view
+#[derive(Clone, Debug)]
+pub struct SelectionParams {
+    pub seed: Vec<u8>,
+    pub seed_index: usize,
+    pub nr_winning_tickets: usize,
+    pub nr_guaranteed_tickets: usize,
+    pub max_winning_tickets_per_address: usize,
+}
+
+#[derive(Debug, Default)]
+pub struct SelectionResult {
+    pub winning_ticket_ids: Vec<TicketId>,
+    pub winning_tickets_per_address: HashMap<String, usize>,
+}
+
+/// Reproduces `WinnerSelectionModule::select_winners` from `launchpad-common`
+pub fn select_winners(params: &SelectionParams, batches: &[TicketBatch]) -> SelectionResult {
+    let batch_first_ticket_ids = get_batch_first_ticket_ids(batches);
+    let last_ticket_position = batches
+        .iter()
+        .map(|batch| batch.nr_tickets as TicketId)
+        .sum::<TicketId>();
+
+    let mut rng = Random::new(params.seed.clone(), params.seed_index);
+    let mut ticket_pos_to_id = HashMap::new();
+    let mut result = SelectionResult::default();
+    let mut ticket_position = FIRST_TICKET_ID;
+    let mut nr_selected_tickets = 0;
+    while params.nr_winning_tickets > 0 && ticket_position <= last_ticket_position {
+        let selected_pos = if ticket_position <= params.nr_guaranteed_tickets as TicketId {
+            batch_first_ticket_ids[(ticket_position - 1) as usize]
+        } else {
+            rng.next_ticket_id_in_range(ticket_position, last_ticket_position + 1)
+        };
+
+        let selected_ticket_id = get_ticket_id_from_pos(&ticket_pos_to_id, selected_pos);
+        let owner = &batches[find_batch_index(&batch_first_ticket_ids, selected_ticket_id)].address;
+        let owner_winning_tickets = result
+            .winning_tickets_per_address
+            .entry(owner.clone())
+            .or_default();
+        if params.max_winning_tickets_per_address == 0
+            || *owner_winning_tickets < params.max_winning_tickets_per_address
+        {
+            *owner_winning_tickets += 1;
+            result.winning_ticket_ids.push(selected_ticket_id);
+            nr_selected_tickets += 1;
+        }
+
+        let current_ticket_id = get_ticket_id_from_pos(&ticket_pos_to_id, ticket_position);
+        ticket_pos_to_id.insert(selected_pos, current_ticket_id);
+
+        if nr_selected_tickets == params.nr_winning_tickets {
+            break;
+        }
+
+        ticket_position += 1;
+    }
+
+    result
+        .winning_tickets_per_address
+        .retain(|_, nr_tickets| *nr_tickets > 0);
+    result.winning_ticket_ids.sort_unstable();
+
+    result
+}
+
+fn get_batch_first_ticket_ids(batches: &[TicketBatch]) -> Vec<TicketId> {
+    let mut first_ticket_ids = Vec::with_capacity(batches.len());
+    let mut next_first_ticket_id = FIRST_TICKET_ID;
+    for batch in batches {
+        first_ticket_ids.push(next_first_ticket_id);
+        next_first_ticket_id += batch.nr_tickets as TicketId;
+    }
+
+    first_ticket_ids
+}
+
+fn get_ticket_id_from_pos(
+    ticket_pos_to_id: &HashMap<TicketId, TicketId>,
+    ticket_pos: TicketId,
+) -> TicketId {
+    *ticket_pos_to_id.get(&ticket_pos).unwrap_or(&ticket_pos)
+}
+
+fn find_batch_index(batch_first_ticket_ids: &[TicketId], ticket_id: TicketId) -> usize {
+    match batch_first_ticket_ids.binary_search(&ticket_id) {
+        Ok(index) => index,
+        Err(index) => index - 1,
+    }
+}
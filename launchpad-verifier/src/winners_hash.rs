@@ -0,0 +1,20 @@
+use sha2::{Digest, Sha256};
+
+use crate::{random::HASH_LEN, selection::TicketId};
+
+/// Mirrors `launchpad_common::winners_hash`: starting from zeroes, each winning ticket ID,
+/// in ascending order, is hashed together with the previous hash as 8 big-endian bytes
+pub fn winners_hash(winning_ticket_ids: &[TicketId]) -> [u8; HASH_LEN] {
+    let mut sorted_ticket_ids = winning_ticket_ids.to_vec();
+    sorted_ticket_ids.sort_unstable();
+
+    let mut hash = [0u8; HASH_LEN];
+    for ticket_id in sorted_ticket_ids {
+        let mut hasher = Sha256::new();
+        hasher.update(hash);
+        hasher.update(ticket_id.to_be_bytes());
+        hash = hasher.finalize().into();
+    }
+
+    hash
+}
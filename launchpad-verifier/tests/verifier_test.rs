@@ -0,0 +1,137 @@
+use launchpad_verifier::{select_winners, winners_hash, Random, SelectionParams, TicketBatch};
+
+fn test_seed() -> Vec<u8> {
+    (0u8..32).collect()
+}
+
+fn test_batches() -> Vec<TicketBatch> {
+    vec![
+        TicketBatch {
+            address: "alice".to_string(),
+            nr_tickets: 1,
+        },
+        TicketBatch {
+            address: "bob".to_string(),
+            nr_tickets: 2,
+        },
+        TicketBatch {
+            address: "carol".to_string(),
+            nr_tickets: 3,
+        },
+    ]
+}
+
+fn test_params() -> SelectionParams {
+    SelectionParams {
+        seed: test_seed(),
+        seed_index: 0,
+        nr_winning_tickets: 4,
+        nr_guaranteed_tickets: 0,
+        max_winning_tickets_per_address: 0,
+    }
+}
+
+#[test]
+fn random_test() {
+    let mut rng = Random::new(test_seed(), 0);
+    assert_eq!(rng.next_usize(), 0x00010203);
+    assert_eq!(rng.next_usize_in_range(5, 5), 5);
+
+    // the seed is hashed after all its bytes are used
+    let mut rng = Random::new(test_seed(), 28);
+    assert_eq!(rng.next_usize(), 0x1c1d1e1f);
+    let _ = rng.next_usize();
+    assert_eq!(rng.index, 4);
+    assert_ne!(rng.seed, test_seed());
+}
+
+#[test]
+fn random_ticket_id_test() {
+    // ranges which fit in 32 bits consume 4 bytes
+    let mut rng = Random::new(test_seed(), 0);
+    assert_eq!(
+        rng.next_ticket_id_in_range(0, u32::MAX as u64),
+        0x00010203 % u32::MAX as u64
+    );
+    assert_eq!(rng.index, 4);
+
+    // larger ranges consume 8 bytes
+    let mut rng = Random::new(test_seed(), 0);
+    assert_eq!(
+        rng.next_ticket_id_in_range(0, u32::MAX as u64 + 2),
+        0x0001020304050607 % (u32::MAX as u64 + 2)
+    );
+    assert_eq!(rng.index, 8);
+}
+
+#[test]
+fn select_winners_large_range_test() {
+    let batches = vec![
+        TicketBatch {
+            address: "alice".to_string(),
+            nr_tickets: 3_000_000_000,
+        },
+        TicketBatch {
+            address: "bob".to_string(),
+            nr_tickets: 3_000_000_000,
+        },
+    ];
+    let mut params = test_params();
+    params.nr_winning_tickets = 2;
+
+    let result = select_winners(&params, &batches);
+    assert_eq!(result.winning_ticket_ids, vec![952_306_184, 5_753_420_413]);
+    assert_eq!(result.winning_tickets_per_address["alice"], 1);
+    assert_eq!(result.winning_tickets_per_address["bob"], 1);
+}
+
+#[test]
+fn select_winners_test() {
+    let result = select_winners(&test_params(), &test_batches());
+    assert_eq!(result.winning_ticket_ids, vec![1, 2, 4, 6]);
+    assert_eq!(result.winning_tickets_per_address["alice"], 1);
+    assert_eq!(result.winning_tickets_per_address["bob"], 1);
+    assert_eq!(result.winning_tickets_per_address["carol"], 2);
+
+    let mut params = test_params();
+    params.seed_index = 28;
+    let result = select_winners(&params, &test_batches());
+    assert_eq!(result.winning_ticket_ids, vec![1, 2, 3, 5]);
+}
+
+#[test]
+fn guaranteed_ticket_per_confirmer_test() {
+    let mut params = test_params();
+    params.nr_guaranteed_tickets = 3;
+
+    let result = select_winners(&params, &test_batches());
+    assert_eq!(result.winning_ticket_ids, vec![1, 2, 3, 4]);
+    assert_eq!(result.winning_tickets_per_address["bob"], 2);
+    assert_eq!(result.winning_tickets_per_address["carol"], 1);
+}
+
+#[test]
+fn max_winning_tickets_per_address_test() {
+    let mut params = test_params();
+    params.max_winning_tickets_per_address = 1;
+
+    let result = select_winners(&params, &test_batches());
+    assert_eq!(result.winning_ticket_ids, vec![1, 2, 4]);
+    for nr_winning_tickets in result.winning_tickets_per_address.values() {
+        assert_eq!(*nr_winning_tickets, 1);
+    }
+}
+
+#[test]
+fn winners_hash_test() {
+    assert_eq!(winners_hash(&[]), [0u8; 32]);
+
+    let result = select_winners(&test_params(), &test_batches());
+    assert_eq!(
+        hex::encode(winners_hash(&result.winning_ticket_ids)),
+        "01151c4782ae2e5498971320dadfc5a01c0f1dd52c8e2f0d03642d49f7665b06"
+    );
+
+    // hashed in ascending order, whatever the input order
+    assert_eq!(winners_hash(&[4, 1, 2]), winners_hash(&[1, 2, 4]));
+}
@@ -1,11 +1,14 @@
 multiversx_sc::imports!();
 
+use launchpad_common::action_pause::PausableAction;
+
 #[multiversx_sc::module]
 pub trait ConfirmNftModule:
     launchpad_common::launch_stage::LaunchStageModule
     + launchpad_common::config::ConfigModule
     + launchpad_common::tickets::TicketsModule
     + launchpad_common::permissions::PermissionsModule
+    + launchpad_common::action_pause::ActionPauseModule
     + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
     + crate::nft_config::NftConfigModule
     + crate::mystery_sft::MysterySftModule
@@ -13,6 +16,7 @@ pub trait ConfirmNftModule:
     #[payable("*")]
     #[endpoint(confirmNft)]
     fn confirm_nft(&self) {
+        self.require_action_not_paused(PausableAction::Confirmations);
         self.require_confirmation_period();
         self.require_all_sft_setup_steps_complete();
 
@@ -30,6 +34,13 @@ pub trait ConfirmNftModule:
         self.require_exact_nft_cost(&payment);
     }
 
+    fn migrate_nft_confirmation(&self, old_address: &ManagedAddress, new_address: &ManagedAddress) {
+        let mut confirmed_nft_user_list = self.confirmed_nft_user_list();
+        if confirmed_nft_user_list.swap_remove(old_address) {
+            let _ = confirmed_nft_user_list.insert(new_address.clone());
+        }
+    }
+
     fn claim_nft_payment(&self) {
         self.require_claim_period();
 
@@ -39,7 +50,7 @@ pub trait ConfirmNftModule:
             let mut payment = self.nft_cost().get();
             payment.amount = claimable_amount;
 
-            let owner = self.blockchain().get_caller();
+            let owner = self.blockchain().get_owner_address();
             self.send().direct(
                 &owner,
                 &payment.token_identifier,
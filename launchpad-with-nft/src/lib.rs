@@ -4,7 +4,10 @@ multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
 use crate::mystery_sft::SftSetupSteps;
-use launchpad_common::{launch_stage::Flags, random::Random};
+use launchpad_common::{
+    blacklist::BlacklistReason, launch_stage::Flags, permissions::Role, random::Random,
+    tiers::TierId, LaunchConfiguration, UserFullStatus,
+};
 
 pub mod claim_nft;
 pub mod confirm_nft;
@@ -18,14 +21,44 @@ pub trait Launchpad:
     launchpad_common::LaunchpadMain
     + launchpad_common::launch_stage::LaunchStageModule
     + launchpad_common::config::ConfigModule
+    + launchpad_common::time_provider::TimeProviderModule
     + launchpad_common::setup::SetupModule
+    + launchpad_common::admin_proposal::AdminProposalModule
     + launchpad_common::tickets::TicketsModule
+    + launchpad_common::tickets_layout_migration::TicketsLayoutMigrationModule
     + launchpad_common::winner_selection::WinnerSelectionModule
+    + launchpad_common::commit_reveal::CommitRevealModule
     + launchpad_common::ongoing_operation::OngoingOperationModule
     + launchpad_common::permissions::PermissionsModule
+    + launchpad_common::timelock::TimelockModule
+    + launchpad_common::guardian::GuardianModule
     + launchpad_common::blacklist::BlacklistModule
     + launchpad_common::token_send::TokenSendModule
     + launchpad_common::user_interactions::UserInteractionsModule
+    + launchpad_common::consolation_airdrop::ConsolationAirdropModule
+    + launchpad_common::participation_badge::ParticipationBadgeModule
+    + launchpad_common::loyalty::LoyaltyModule
+    + launchpad_common::referral::ReferralModule
+    + launchpad_common::kyc::KycModule
+    + launchpad_common::signature_eligibility::SignatureEligibilityModule
+    + launchpad_common::relayer::RelayerModule
+    + launchpad_common::keeper_incentives::KeeperIncentivesModule
+    + launchpad_common::governance::GovernanceModule
+    + launchpad_common::price_oracle::PriceOracleModule
+    + launchpad_common::swap_and_confirm::SwapAndConfirmModule
+    + launchpad_common::locked_token_payment::LockedTokenPaymentModule
+    + launchpad_common::reward_bundle::RewardBundleModule
+    + launchpad_common::token_rescue::TokenRescueModule
+    + launchpad_common::unclaimed_sweep::UnclaimedSweepModule
+    + launchpad_common::storage_cleanup::StorageCleanupModule
+    + launchpad_common::invariants::InvariantsModule
+    + launchpad_common::winners_hash::WinnersHashModule
+    + launchpad_common::tiers::TiersModule
+    + launchpad_common::action_pause::ActionPauseModule
+    + launchpad_common::fcfs_round::FcfsRoundModule
+    + launchpad_common::legacy_import::LegacyImportModule
+    + launchpad_common::wallet_migration::WalletMigrationModule
+    + launchpad_common::launchpad_token_issuance::LaunchpadTokenIssuanceModule
     + launchpad_common::common_events::CommonEventsModule
     + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
     + multiversx_sc_modules::pause::PauseModule
@@ -87,6 +120,27 @@ pub trait Launchpad:
         self.add_tickets(address_number_pairs);
     }
 
+    #[only_owner]
+    #[endpoint(addTicketsPacked)]
+    fn add_tickets_packed_endpoint(&self, packed_entries: ManagedBuffer) {
+        self.add_tickets_packed(packed_entries);
+    }
+
+    #[only_owner]
+    #[endpoint(addTicketsWithTiers)]
+    fn add_tickets_with_tiers_endpoint(
+        &self,
+        address_tier_pairs: MultiValueEncoded<MultiValue2<ManagedAddress, TierId>>,
+    ) {
+        self.add_tickets_with_tiers(address_tier_pairs);
+    }
+
+    #[only_owner]
+    #[endpoint(addTicketsChunk)]
+    fn add_tickets_chunk_endpoint(&self, chunk_index: usize, packed_entries: ManagedBuffer) {
+        self.add_tickets_chunk(chunk_index, packed_entries);
+    }
+
     #[only_owner]
     #[payable("*")]
     #[endpoint(depositLaunchpadTokens)]
@@ -95,10 +149,21 @@ pub trait Launchpad:
         self.deposit_launchpad_tokens(nr_winning_tickets);
     }
 
+    #[only_owner]
+    #[endpoint(mintLaunchpadTokens)]
+    fn mint_launchpad_tokens_endpoint(&self) {
+        let nr_winning_tickets = self.nr_winning_tickets().get();
+        self.mint_launchpad_tokens(nr_winning_tickets);
+    }
+
     #[endpoint(addUsersToBlacklist)]
-    fn add_users_to_blacklist_endpoint(&self, users_list: MultiValueEncoded<ManagedAddress>) {
+    fn add_users_to_blacklist_endpoint(
+        &self,
+        reason: BlacklistReason,
+        users_list: MultiValueEncoded<ManagedAddress>,
+    ) {
         let users_list_vec = users_list.to_vec();
-        self.add_users_to_blacklist(&users_list_vec);
+        self.add_users_to_blacklist(&users_list_vec, reason);
         self.refund_nft_cost_after_blacklist(&users_list_vec);
     }
 
@@ -139,15 +204,59 @@ pub trait Launchpad:
     }
 
     #[endpoint(claimLaunchpadTokens)]
-    fn claim_launchpad_tokens_endpoint(&self) {
-        self.claim_launchpad_tokens(Self::default_send_launchpad_tokens_fn);
+    fn claim_launchpad_tokens_endpoint(&self, opt_receiver: OptionalValue<ManagedAddress>) {
+        self.claim_launchpad_tokens(opt_receiver, Self::default_send_launchpad_tokens_fn);
         self.claim_nft();
     }
 
-    #[only_owner]
+    #[payable("*")]
+    #[endpoint(buyFcfsTickets)]
+    fn buy_fcfs_tickets_endpoint(&self, nr_tickets: usize) {
+        self.buy_fcfs_tickets(nr_tickets, Self::default_send_launchpad_tokens_fn);
+    }
+
     #[endpoint(claimTicketPayment)]
     fn claim_ticket_payment_endpoint(&self) {
+        self.require_role(Role::Finance);
         self.claim_ticket_payment();
         self.claim_nft_payment();
     }
+
+    #[endpoint(rescueTokens)]
+    fn rescue_tokens_endpoint(&self, token_id: EgldOrEsdtTokenIdentifier, token_nonce: u64) {
+        self.require_role(Role::Finance);
+
+        let nft_cost = self.nft_cost().get();
+        require!(
+            token_id != nft_cost.token_identifier,
+            "Cannot rescue the launch tokens"
+        );
+
+        self.rescue_tokens(token_id, token_nonce);
+    }
+
+    #[endpoint(executePendingChange)]
+    fn execute_pending_change_endpoint(&self, change_id: usize) {
+        self.execute_pending_change(change_id, Self::default_custom_change_fn);
+    }
+
+    #[endpoint(executeAdminProposal)]
+    fn execute_admin_proposal_endpoint(&self, proposal_id: usize) {
+        self.execute_admin_proposal(proposal_id, Self::default_custom_change_fn);
+    }
+
+    #[endpoint(approveWalletMigration)]
+    fn approve_wallet_migration_endpoint(&self, old_address: ManagedAddress) {
+        self.approve_wallet_migration(old_address, Self::migrate_nft_confirmation);
+    }
+
+    #[view(getConfiguration)]
+    fn get_configuration_view(&self) -> LaunchConfiguration<Self::Api> {
+        self.get_launch_configuration(ManagedBuffer::new())
+    }
+
+    #[view(getUserFullStatus)]
+    fn user_full_status(&self, address: ManagedAddress) -> UserFullStatus<Self::Api> {
+        self.get_user_full_status(address, 0)
+    }
 }
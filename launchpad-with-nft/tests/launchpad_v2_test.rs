@@ -2,6 +2,7 @@
 
 mod launchpad_with_nft_setup;
 
+use launchpad_common::blacklist::BlacklistReason;
 use launchpad_common::tickets::{TicketsModule, WINNING_TICKET};
 use launchpad_with_nft::{
     confirm_nft::ConfirmNftModule, mystery_sft::MysterySftTypes,
@@ -71,9 +72,9 @@ fn select_winners_test() {
     lp_setup
         .b_mock
         .execute_query(&lp_setup.lp_wrapper, |sc| {
-            assert_eq!(sc.ticket_status(1).get(), WINNING_TICKET);
-            assert_eq!(sc.ticket_status(2).get(), false);
-            assert_eq!(sc.ticket_status(3).get(), false);
+            assert_eq!(sc.get_ticket_status(1), WINNING_TICKET);
+            assert_eq!(sc.get_ticket_status(2), false);
+            assert_eq!(sc.get_ticket_status(3), false);
         })
         .assert_ok();
 
@@ -220,7 +221,7 @@ fn blacklist_refund_test() {
                 let mut args = MultiValueEncoded::new();
                 args.push(managed_address!(&users[0]));
 
-                sc.add_users_to_blacklist_endpoint(args);
+                sc.add_users_to_blacklist_endpoint(BlacklistReason::Unspecified, args);
             },
         )
         .assert_ok();
@@ -11,7 +11,7 @@ use multiversx_sc::{
     storage::mappers::StorageTokenWrapper,
     types::{
         Address, EgldOrEsdtTokenIdentifier, EsdtLocalRole, MultiValueEncoded,
-        OperationCompletionStatus,
+        OperationCompletionStatus, OptionalValue,
     },
 };
 use multiversx_sc_scenario::{
@@ -148,7 +148,7 @@ where
         for p in &participants {
             b_mock
                 .execute_tx(p, &lp_wrapper, &rust_biguint!(BASE_TICKET_COST), |sc| {
-                    sc.confirm_tickets(1);
+                    sc.confirm_tickets(OptionalValue::Some(1), OptionalValue::None);
                 })
                 .assert_ok();
         }
@@ -211,7 +211,7 @@ where
     pub fn claim(&mut self, caller: &Address) -> TxResult {
         self.b_mock
             .execute_tx(caller, &self.lp_wrapper, &rust_biguint!(0), |sc| {
-                sc.claim_launchpad_tokens_endpoint();
+                sc.claim_launchpad_tokens_endpoint(OptionalValue::None);
             })
     }
 }
@@ -203,15 +203,23 @@ claimLaunchpadTokensUser() {
 
 #params
 #   $1 = Support address
-setSupportAddress() {
+proposeSupportAddress() {
     local NEW_SUPPORT_ADDRESS_HEX="0x$(erdpy wallet bech32 --decode $1)"
     
     erdpy --verbose contract call ${ADDRESS} --recall-nonce --pem=${OWNER_PEM_PATH} \
-    --gas-limit=25000000 --function="setSupportAddress" \
+    --gas-limit=25000000 --function="proposeSupportAddress" \
     --arguments ${NEW_SUPPORT_ADDRESS_HEX} \
     --send --proxy=${PROXY} --chain=${CHAIN_ID}
 }
 
+#params
+#   $1 = Support address pem path
+acceptSupportAddress() {
+    erdpy --verbose contract call ${ADDRESS} --recall-nonce --pem=$1 \
+    --gas-limit=25000000 --function="acceptSupportAddress" \
+    --send --proxy=${PROXY} --chain=${CHAIN_ID}
+}
+
 #params
 #   $1 = New owner address
 changeSCOwner() {
@@ -3,22 +3,58 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
-use launchpad_common::{launch_stage::Flags, *};
+use launchpad_common::{
+    blacklist::BlacklistReason, launch_stage::Flags, permissions::Role, tiers::TierId, *,
+};
 
 #[multiversx_sc::contract]
 pub trait Launchpad:
     launchpad_common::LaunchpadMain
     + launch_stage::LaunchStageModule
     + config::ConfigModule
+    + time_provider::TimeProviderModule
     + setup::SetupModule
+    + admin_proposal::AdminProposalModule
     + tickets::TicketsModule
+    + tickets_layout_migration::TicketsLayoutMigrationModule
     + winner_selection::WinnerSelectionModule
+    + commit_reveal::CommitRevealModule
     + ongoing_operation::OngoingOperationModule
     + permissions::PermissionsModule
+    + timelock::TimelockModule
+    + guardian::GuardianModule
     + blacklist::BlacklistModule
     + token_send::TokenSendModule
     + user_interactions::UserInteractionsModule
+    + consolation_airdrop::ConsolationAirdropModule
+    + participation_badge::ParticipationBadgeModule
+    + loyalty::LoyaltyModule
+    + referral::ReferralModule
+    + kyc::KycModule
+    + signature_eligibility::SignatureEligibilityModule
+    + relayer::RelayerModule
+    + keeper_incentives::KeeperIncentivesModule
+    + governance::GovernanceModule
+    + price_oracle::PriceOracleModule
+    + swap_and_confirm::SwapAndConfirmModule
+    + locked_token_payment::LockedTokenPaymentModule
+    + reward_bundle::RewardBundleModule
+    + token_rescue::TokenRescueModule
+    + unclaimed_sweep::UnclaimedSweepModule
+    + storage_cleanup::StorageCleanupModule
+    + invariants::InvariantsModule
+    + winners_hash::WinnersHashModule
+    + tiers::TiersModule
+    + action_pause::ActionPauseModule
+    + fcfs_round::FcfsRoundModule
+    + legacy_import::LegacyImportModule
+    + wallet_migration::WalletMigrationModule
+    + results_distribution::ResultsDistributionModule
+    + launchpad_token_issuance::LaunchpadTokenIssuanceModule
+    + claim_and_stake::ClaimAndStakeModule
+    + liquidity_bootstrap::LiquidityBootstrapModule
     + common_events::CommonEventsModule
+    + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
     + multiversx_sc_modules::pause::PauseModule
 {
     #[allow(clippy::too_many_arguments)]
@@ -53,6 +89,11 @@ pub trait Launchpad:
         );
     }
 
+    #[upgrade]
+    fn upgrade(&self) {
+        self.upgrade_base(Self::default_migrate_storage_fn);
+    }
+
     #[only_owner]
     #[endpoint(addTickets)]
     fn add_tickets_endpoint(
@@ -62,6 +103,27 @@ pub trait Launchpad:
         self.add_tickets(address_number_pairs);
     }
 
+    #[only_owner]
+    #[endpoint(addTicketsPacked)]
+    fn add_tickets_packed_endpoint(&self, packed_entries: ManagedBuffer) {
+        self.add_tickets_packed(packed_entries);
+    }
+
+    #[only_owner]
+    #[endpoint(addTicketsWithTiers)]
+    fn add_tickets_with_tiers_endpoint(
+        &self,
+        address_tier_pairs: MultiValueEncoded<MultiValue2<ManagedAddress, TierId>>,
+    ) {
+        self.add_tickets_with_tiers(address_tier_pairs);
+    }
+
+    #[only_owner]
+    #[endpoint(addTicketsChunk)]
+    fn add_tickets_chunk_endpoint(&self, chunk_index: usize, packed_entries: ManagedBuffer) {
+        self.add_tickets_chunk(chunk_index, packed_entries);
+    }
+
     #[only_owner]
     #[payable("*")]
     #[endpoint(depositLaunchpadTokens)]
@@ -70,19 +132,86 @@ pub trait Launchpad:
         self.deposit_launchpad_tokens(nr_winning_tickets);
     }
 
+    #[only_owner]
+    #[endpoint(mintLaunchpadTokens)]
+    fn mint_launchpad_tokens_endpoint(&self) {
+        let nr_winning_tickets = self.nr_winning_tickets().get();
+        self.mint_launchpad_tokens(nr_winning_tickets);
+    }
+
     #[endpoint(claimLaunchpadTokens)]
-    fn claim_launchpad_tokens_endpoint(&self) {
-        self.claim_launchpad_tokens(Self::default_send_launchpad_tokens_fn);
+    fn claim_launchpad_tokens_endpoint(&self, opt_receiver: OptionalValue<ManagedAddress>) {
+        self.claim_launchpad_tokens(opt_receiver, Self::default_send_launchpad_tokens_fn);
+    }
+
+    #[payable("*")]
+    #[endpoint(buyFcfsTickets)]
+    fn buy_fcfs_tickets_endpoint(&self, nr_tickets: usize) {
+        self.buy_fcfs_tickets(nr_tickets, Self::default_send_launchpad_tokens_fn);
+    }
+
+    #[endpoint(claimLaunchpadTokensFor)]
+    fn claim_launchpad_tokens_for_endpoint(&self, addresses: MultiValueEncoded<ManagedAddress>) {
+        self.claim_launchpad_tokens_for(addresses, Self::default_send_launchpad_tokens_fn);
+    }
+
+    #[endpoint(distributeResults)]
+    fn distribute_results_endpoint(&self) -> OperationCompletionStatus {
+        self.require_role(Role::Finance);
+        self.distribute_results(Self::default_send_launchpad_tokens_fn)
     }
 
-    #[only_owner]
     #[endpoint(claimTicketPayment)]
     fn claim_ticket_payment_endpoint(&self) {
+        self.require_role(Role::Finance);
         self.claim_ticket_payment();
     }
 
+    #[endpoint(rescueTokens)]
+    fn rescue_tokens_endpoint(&self, token_id: EgldOrEsdtTokenIdentifier, token_nonce: u64) {
+        self.require_role(Role::Finance);
+
+        let locked_lp_tokens_mapper = self.locked_lp_tokens();
+        require!(
+            locked_lp_tokens_mapper.is_empty()
+                || token_id != locked_lp_tokens_mapper.get().token_identifier,
+            "Cannot rescue the launch tokens"
+        );
+
+        self.rescue_tokens(token_id, token_nonce);
+    }
+
+    #[endpoint(executePendingChange)]
+    fn execute_pending_change_endpoint(&self, change_id: usize) {
+        self.execute_pending_change(change_id, Self::default_custom_change_fn);
+    }
+
+    #[endpoint(executeAdminProposal)]
+    fn execute_admin_proposal_endpoint(&self, proposal_id: usize) {
+        self.execute_admin_proposal(proposal_id, Self::default_custom_change_fn);
+    }
+
+    #[endpoint(approveWalletMigration)]
+    fn approve_wallet_migration_endpoint(&self, old_address: ManagedAddress) {
+        self.approve_wallet_migration(old_address, Self::default_migrate_wallet_fn);
+    }
+
     #[endpoint(addUsersToBlacklist)]
-    fn add_users_to_blacklist_endpoint(&self, users_list: MultiValueEncoded<ManagedAddress>) {
-        self.add_users_to_blacklist(&users_list.to_vec());
+    fn add_users_to_blacklist_endpoint(
+        &self,
+        reason: BlacklistReason,
+        users_list: MultiValueEncoded<ManagedAddress>,
+    ) {
+        self.add_users_to_blacklist(&users_list.to_vec(), reason);
+    }
+
+    #[view(getConfiguration)]
+    fn get_configuration_view(&self) -> LaunchConfiguration<Self::Api> {
+        self.get_launch_configuration(ManagedBuffer::new())
+    }
+
+    #[view(getUserFullStatus)]
+    fn user_full_status(&self, address: ManagedAddress) -> UserFullStatus<Self::Api> {
+        self.get_user_full_status(address, 0)
     }
 }
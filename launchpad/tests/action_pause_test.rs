@@ -0,0 +1,104 @@
+mod launchpad_setup;
+
+use launchpad_common::{
+    action_pause::{ActionPauseModule, PausableAction},
+    setup::SetupModule,
+    user_interactions::UserInteractionsModule,
+};
+use launchpad_setup::{
+    LaunchpadSetup, CLAIM_START_ROUND, CONFIRM_START_ROUND, NR_WINNING_TICKETS, TICKET_COST,
+    WINNER_SELECTION_START_ROUND,
+};
+use multiversx_sc::types::EgldOrEsdtTokenIdentifier;
+use multiversx_sc_scenario::managed_biguint;
+
+#[test]
+fn action_pause_test() {
+    let mut lp_setup = LaunchpadSetup::new(NR_WINNING_TICKETS);
+    let participants = lp_setup.participants.clone();
+
+    lp_setup
+        .call(&participants[0], |sc| {
+            sc.pause_action(PausableAction::Claims);
+        })
+        .assert_user_error("Permission denied");
+
+    // admin setters
+    lp_setup
+        .call_owner(|sc| {
+            sc.pause_action(PausableAction::AdminSetters);
+        })
+        .assert_ok();
+    lp_setup
+        .call_owner(|sc| {
+            sc.set_ticket_price(
+                EgldOrEsdtTokenIdentifier::egld(),
+                managed_biguint!(TICKET_COST),
+            );
+        })
+        .assert_user_error("Admin setters are paused");
+    lp_setup
+        .call_owner(|sc| {
+            sc.unpause_action(PausableAction::AdminSetters);
+            sc.set_ticket_price(
+                EgldOrEsdtTokenIdentifier::egld(),
+                managed_biguint!(TICKET_COST),
+            );
+        })
+        .assert_ok();
+
+    // confirmations
+    lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND);
+    lp_setup.confirm(&participants[1], 2).assert_ok();
+    lp_setup
+        .call_owner(|sc| {
+            sc.pause_action(PausableAction::Confirmations);
+            assert!(sc.action_paused(PausableAction::Confirmations).get());
+            assert!(!sc.action_paused(PausableAction::Claims).get());
+        })
+        .assert_ok();
+    lp_setup
+        .call_owner(|sc| {
+            sc.pause_action(PausableAction::Confirmations);
+        })
+        .assert_user_error("Action already paused");
+    lp_setup
+        .confirm(&participants[0], 1)
+        .assert_user_error("Confirmations are paused");
+    lp_setup
+        .call(&participants[1], |sc| {
+            sc.unconfirm_tickets(1);
+        })
+        .assert_user_error("Confirmations are paused");
+
+    lp_setup
+        .call_owner(|sc| {
+            sc.unpause_action(PausableAction::Confirmations);
+        })
+        .assert_ok();
+    lp_setup.confirm(&participants[0], 1).assert_ok();
+
+    lp_setup
+        .b_mock
+        .set_block_round(WINNER_SELECTION_START_ROUND);
+    lp_setup.filter_tickets().assert_ok();
+    lp_setup.select_winners().assert_ok();
+
+    // claims
+    lp_setup.b_mock.set_block_round(CLAIM_START_ROUND);
+    lp_setup
+        .call_owner(|sc| {
+            sc.pause_action(PausableAction::Claims);
+        })
+        .assert_ok();
+    lp_setup
+        .claim_user(&participants[0])
+        .assert_user_error("Claims are paused");
+
+    lp_setup
+        .call_owner(|sc| {
+            sc.unpause_action(PausableAction::Claims);
+        })
+        .assert_ok();
+    lp_setup.claim_user(&participants[0]).assert_ok();
+}
@@ -0,0 +1,86 @@
+mod launchpad_setup;
+
+use launchpad::Launchpad;
+use launchpad_common::{
+    admin_proposal::AdminProposalModule,
+    config::ConfigModule,
+    permissions::{PermissionsModule, Role},
+    setup::SetupModule,
+    timelock::PendingChangeType,
+};
+use launchpad_setup::{LaunchpadSetup, NR_WINNING_TICKETS, TICKET_COST};
+use multiversx_sc::types::{EgldOrEsdtTokenIdentifier, MultiValueEncoded};
+use multiversx_sc_scenario::{managed_address, managed_biguint, rust_biguint};
+
+#[test]
+fn admin_proposal_test() {
+    let mut lp_setup = LaunchpadSetup::new(NR_WINNING_TICKETS);
+    let participants = lp_setup.participants.clone();
+    let owner_address = lp_setup.owner_address.clone();
+    let admin_address = lp_setup.b_mock.create_user_account(&rust_biguint!(0));
+    let new_ticket_cost = TICKET_COST * 2;
+
+    lp_setup
+        .call(&participants[0], |sc| {
+            sc.set_admin_proposal_quorum(1);
+        })
+        .assert_error(4, "Permission denied");
+
+    lp_setup
+        .call_owner(|sc| {
+            sc.set_admin_proposal_quorum(2);
+
+            let mut addresses = MultiValueEncoded::new();
+            addresses.push(managed_address!(&admin_address));
+            sc.add_role(Role::Admin, addresses);
+
+            let proposal_id = sc.propose_admin_action(PendingChangeType::TicketPrice {
+                token_id: EgldOrEsdtTokenIdentifier::egld(),
+                amount: managed_biguint!(new_ticket_cost),
+            });
+            assert_eq!(proposal_id, 1);
+        })
+        .assert_ok();
+
+    lp_setup
+        .call(&participants[0], |sc| {
+            sc.execute_admin_proposal_endpoint(1);
+        })
+        .assert_user_error("Quorum not reached");
+
+    // the direct setter is disabled once proposals are enabled
+    lp_setup
+        .call_owner(|sc| {
+            sc.set_ticket_price(
+                EgldOrEsdtTokenIdentifier::egld(),
+                managed_biguint!(new_ticket_cost),
+            );
+        })
+        .assert_user_error("Admin proposals enabled, use proposeAdminAction");
+
+    lp_setup
+        .call(&admin_address, |sc| {
+            sc.accept_admin_role();
+            sc.approve_admin_proposal(1);
+        })
+        .assert_ok();
+
+    lp_setup
+        .call(&admin_address, |sc| {
+            sc.approve_admin_proposal(1);
+        })
+        .assert_user_error("Proposal already approved");
+
+    // anyone may execute
+    lp_setup
+        .call(&participants[0], |sc| {
+            sc.execute_admin_proposal_endpoint(1);
+
+            assert_eq!(
+                sc.ticket_price().get().amount,
+                managed_biguint!(new_ticket_cost)
+            );
+            assert!(sc.admin_proposal(1).is_empty());
+        })
+        .assert_ok();
+}
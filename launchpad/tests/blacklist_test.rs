@@ -0,0 +1,158 @@
+mod launchpad_setup;
+
+use launchpad::Launchpad;
+use launchpad_common::{
+    blacklist::{BlacklistModule, BlacklistReason},
+    config::ConfigModule,
+    tickets::TicketsModule,
+};
+use launchpad_setup::{
+    LaunchpadSetup, CONFIRM_START_ROUND, MAX_TIER_TICKETS, NR_WINNING_TICKETS, TICKET_COST,
+};
+use multiversx_sc::types::MultiValueEncoded;
+use multiversx_sc_scenario::{managed_address, managed_biguint, rust_biguint};
+
+#[test]
+fn blacklist_reason_and_history_test() {
+    let mut lp_setup = LaunchpadSetup::new(NR_WINNING_TICKETS);
+    let participants = lp_setup.participants.clone();
+
+    lp_setup.b_mock.set_block_timestamp(100);
+    lp_setup
+        .call_owner(|sc| {
+            let mut blacklist = MultiValueEncoded::new();
+            blacklist.push(managed_address!(&participants[0]));
+            sc.add_users_to_blacklist_endpoint(BlacklistReason::Fraud, blacklist);
+        })
+        .assert_ok();
+
+    lp_setup.b_mock.set_block_timestamp(200);
+    lp_setup
+        .call_owner(|sc| {
+            let mut blacklist = MultiValueEncoded::new();
+            blacklist.push(managed_address!(&participants[1]));
+            sc.add_users_to_blacklist_endpoint(BlacklistReason::Sanctions, blacklist);
+        })
+        .assert_ok();
+
+    lp_setup
+        .query(|sc| {
+            assert_eq!(sc.get_nr_blacklisted_users(), 2);
+
+            assert_eq!(sc.get_blacklisted_users(0, 10).into_iter().count(), 2);
+
+            let mut entries = sc.get_blacklisted_users(1, 1).into_iter();
+            let (address, reason, timestamp) = entries.next().unwrap().into_tuple();
+            assert_eq!(address, managed_address!(&participants[1]));
+            assert_eq!(reason, BlacklistReason::Sanctions);
+            assert_eq!(timestamp, 200);
+            assert!(entries.next().is_none());
+
+            assert_eq!(sc.get_blacklisted_users(2, 10).into_iter().count(), 0);
+        })
+        .assert_ok();
+
+    lp_setup
+        .call_owner(|sc| {
+            let _ = sc.get_blacklisted_users(3, 10);
+        })
+        .assert_user_error("Invalid start index");
+
+    lp_setup
+        .call_owner(|sc| {
+            let mut users = MultiValueEncoded::new();
+            users.push(managed_address!(&participants[0]));
+            sc.remove_users_from_blacklist(users);
+
+            assert_eq!(sc.get_nr_blacklisted_users(), 1);
+            assert!(sc
+                .blacklist_entry(&managed_address!(&participants[0]))
+                .is_empty());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn blacklist_state_finalized_before_refund_test() {
+    let mut lp_setup = LaunchpadSetup::new(NR_WINNING_TICKETS);
+    let participants = lp_setup.participants.clone();
+    let user_balance = TICKET_COST * MAX_TIER_TICKETS as u64;
+
+    lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND);
+    lp_setup.confirm(&participants[1], 2).assert_ok();
+
+    lp_setup
+        .call_owner(|sc| {
+            let mut blacklist = MultiValueEncoded::new();
+            blacklist.push(managed_address!(&participants[1]));
+            sc.add_users_to_blacklist_endpoint(BlacklistReason::Unspecified, blacklist);
+        })
+        .assert_ok();
+
+    lp_setup
+        .b_mock
+        .check_egld_balance(&participants[1], &rust_biguint!(user_balance));
+    lp_setup
+        .query(|sc| {
+            let address = managed_address!(&participants[1]);
+            assert_eq!(sc.nr_confirmed_tickets(&address).get(), 0);
+            assert!(!sc.confirmed_users().contains(&address));
+            assert!(sc.is_user_blacklisted(&address));
+            assert_eq!(sc.user_ticket_payment(&address).get(), managed_biguint!(0));
+            assert_eq!(
+                sc.total_refundable_ticket_payment().get(),
+                managed_biguint!(0)
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn blacklist_updates_totals_test() {
+    let mut lp_setup = LaunchpadSetup::new(NR_WINNING_TICKETS);
+    let participants = lp_setup.participants.clone();
+
+    lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND);
+    lp_setup.confirm(&participants[0], 1).assert_ok();
+    lp_setup.confirm(&participants[1], 2).assert_ok();
+
+    lp_setup
+        .call_owner(|sc| {
+            let mut blacklist = MultiValueEncoded::new();
+            blacklist.push(managed_address!(&participants[1]));
+            sc.add_users_to_blacklist_endpoint(BlacklistReason::Unspecified, blacklist);
+
+            // the refunded payment no longer counts towards the totals
+            assert_eq!(sc.total_participants().get(), 1);
+            assert_eq!(sc.total_confirmed_tickets().get(), 1);
+            assert_eq!(
+                sc.total_payment_raised().get(),
+                managed_biguint!(TICKET_COST)
+            );
+        })
+        .assert_ok();
+
+    lp_setup
+        .call_owner(|sc| {
+            let mut users = MultiValueEncoded::new();
+            users.push(managed_address!(&participants[1]));
+            sc.remove_users_from_blacklist(users);
+
+            assert_eq!(sc.total_participants().get(), 1);
+            assert_eq!(sc.total_confirmed_tickets().get(), 1);
+        })
+        .assert_ok();
+
+    // counted again once the tickets are confirmed again
+    lp_setup.confirm(&participants[1], 2).assert_ok();
+    lp_setup
+        .query(|sc| {
+            assert_eq!(sc.total_participants().get(), 2);
+            assert_eq!(sc.total_confirmed_tickets().get(), 3);
+            assert_eq!(
+                sc.total_payment_raised().get(),
+                managed_biguint!(TICKET_COST * 3)
+            );
+        })
+        .assert_ok();
+}
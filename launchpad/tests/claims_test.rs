@@ -0,0 +1,49 @@
+mod launchpad_setup;
+
+use launchpad_common::{
+    config::ConfigModule, tickets::TicketsModule, user_interactions::UserInteractionsModule,
+};
+use launchpad_setup::{
+    LaunchpadSetup, CLAIM_START_ROUND, LAUNCHPAD_TOKENS_PER_TICKET, LAUNCHPAD_TOKEN_ID,
+    MAX_TIER_TICKETS, NR_WINNING_TICKETS, TICKET_COST,
+};
+use multiversx_sc_scenario::{managed_address, managed_biguint, rust_biguint};
+
+#[test]
+fn claim_state_finalized_before_transfers_test() {
+    let mut lp_setup = LaunchpadSetup::new(NR_WINNING_TICKETS);
+    let participants = lp_setup.participants.clone();
+    let user_balance = TICKET_COST * MAX_TIER_TICKETS as u64;
+
+    lp_setup.confirm_all();
+    lp_setup.filter_tickets().assert_ok();
+    lp_setup.select_winners_mock(&[1, 2, 4]).assert_ok();
+
+    // 1 of the 3 confirmed tickets won
+    lp_setup.b_mock.set_block_round(CLAIM_START_ROUND);
+    lp_setup.claim_user(&participants[2]).assert_ok();
+    lp_setup
+        .b_mock
+        .check_egld_balance(&participants[2], &rust_biguint!(user_balance - TICKET_COST));
+    lp_setup
+        .query(|sc| {
+            let address = managed_address!(&participants[2]);
+            assert!(sc.has_user_claimed(&address));
+            assert_eq!(sc.nr_confirmed_tickets(&address).get(), 0);
+            assert_eq!(sc.user_ticket_payment(&address).get(), managed_biguint!(0));
+        })
+        .assert_ok();
+
+    // claiming again sends nothing
+    lp_setup
+        .claim_user(&participants[2])
+        .assert_user_error("Already claimed");
+    lp_setup
+        .b_mock
+        .check_egld_balance(&participants[2], &rust_biguint!(user_balance - TICKET_COST));
+    lp_setup.b_mock.check_esdt_balance(
+        &participants[2],
+        LAUNCHPAD_TOKEN_ID,
+        &rust_biguint!(LAUNCHPAD_TOKENS_PER_TICKET),
+    );
+}
@@ -0,0 +1,81 @@
+mod launchpad_setup;
+
+use launchpad_common::{
+    commit_reveal::CommitRevealModule, winner_selection::WinnerSelectionModule,
+};
+use launchpad_setup::{LaunchpadSetup, NR_WINNING_TICKETS};
+use multiversx_sc::contract_base::ContractBase;
+use multiversx_sc_scenario::managed_buffer;
+
+#[test]
+fn commit_reveal_entropy_test() {
+    let mut lp_setup = LaunchpadSetup::new(NR_WINNING_TICKETS);
+    let participants = lp_setup.participants.clone();
+
+    lp_setup
+        .call_owner(|sc| {
+            let entropy_hash = sc.crypto().sha256(&managed_buffer!(b"owner entropy"));
+            sc.commit_entropy(entropy_hash);
+        })
+        .assert_ok();
+
+    lp_setup
+        .call(&participants[0], |sc| {
+            let entropy_hash = sc.crypto().sha256(&managed_buffer!(b"user entropy"));
+            sc.commit_entropy(entropy_hash);
+        })
+        .assert_error(4, "User entropy commits are not enabled");
+
+    lp_setup
+        .call(&participants[0], |sc| {
+            sc.set_user_entropy_commits_enabled(true);
+        })
+        .assert_user_error("Permission denied");
+
+    lp_setup
+        .call_owner(|sc| {
+            sc.set_user_entropy_commits_enabled(true);
+        })
+        .assert_ok();
+
+    // never revealed, so it is ignored
+    lp_setup
+        .call(&participants[0], |sc| {
+            let entropy_hash = sc.crypto().sha256(&managed_buffer!(b"user entropy"));
+            sc.commit_entropy(entropy_hash);
+        })
+        .assert_ok();
+
+    lp_setup.confirm_all();
+
+    lp_setup.filter_tickets().assert_ok();
+    lp_setup
+        .select_winners()
+        .assert_error(4, "Owner entropy not revealed");
+
+    lp_setup
+        .call_owner(|sc| {
+            sc.reveal_entropy(managed_buffer!(b"other entropy"));
+        })
+        .assert_error(4, "Entropy does not match commitment");
+
+    lp_setup
+        .call_owner(|sc| {
+            sc.reveal_entropy(managed_buffer!(b"owner entropy"));
+        })
+        .assert_ok();
+
+    lp_setup.select_winners().assert_ok();
+
+    lp_setup
+        .query(|sc| {
+            assert!(!sc.revealed_entropy().is_empty());
+
+            let proof = sc.winner_selection_proof().get();
+            assert_eq!(proof.seed, sc.winner_selection_seed().get());
+            assert_eq!(proof.seed_index, 0);
+            assert_eq!(proof.last_ticket_position, 6);
+            assert_eq!(proof.nr_winning_tickets, NR_WINNING_TICKETS);
+        })
+        .assert_ok();
+}
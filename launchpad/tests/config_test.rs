@@ -0,0 +1,77 @@
+mod launchpad_setup;
+
+use launchpad_common::{
+    config::ConfigModule, setup::SetupModule, user_interactions::UserInteractionsModule,
+};
+use launchpad_setup::{
+    LaunchpadSetup, CONFIRM_START_ROUND, LAUNCHPAD_TOKENS_PER_TICKET, LAUNCHPAD_TOKEN_ID,
+    NR_WINNING_TICKETS, TICKET_COST, WINNER_SELECTION_START_ROUND,
+};
+use multiversx_sc_scenario::{managed_biguint, rust_biguint};
+
+#[test]
+fn dynamic_winning_tickets_test() {
+    let mut lp_setup = LaunchpadSetup::new(NR_WINNING_TICKETS);
+    let participants = lp_setup.participants.clone();
+    let owner_address = lp_setup.owner_address.clone();
+
+    lp_setup
+        .call_owner(|sc| {
+            sc.set_dynamic_winning_tickets(true);
+        })
+        .assert_ok();
+
+    // only one ticket is confirmed, out of the three winning ones
+    lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND);
+    lp_setup.confirm(&participants[0], 1).assert_ok();
+
+    lp_setup
+        .b_mock
+        .set_block_round(WINNER_SELECTION_START_ROUND);
+    lp_setup.filter_tickets().assert_ok();
+
+    lp_setup.b_mock.check_esdt_balance(
+        &owner_address,
+        LAUNCHPAD_TOKEN_ID,
+        &rust_biguint!(LAUNCHPAD_TOKENS_PER_TICKET * 2),
+    );
+    lp_setup
+        .query(|sc| {
+            assert_eq!(sc.nr_winning_tickets().get(), 1);
+            assert_eq!(
+                sc.total_launchpad_tokens_deposited().get(),
+                managed_biguint!(LAUNCHPAD_TOKENS_PER_TICKET)
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn hard_cap_test() {
+    let mut lp_setup = LaunchpadSetup::new(NR_WINNING_TICKETS);
+    let participants = lp_setup.participants.clone();
+
+    lp_setup
+        .call_owner(|sc| {
+            assert!(sc.get_remaining_raise_capacity().into_option().is_none());
+
+            sc.set_hard_cap(managed_biguint!(TICKET_COST * 3));
+        })
+        .assert_ok();
+
+    lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND);
+    lp_setup.confirm(&participants[1], 2).assert_ok();
+    lp_setup
+        .confirm(&participants[2], 2)
+        .assert_user_error("Hard cap exceeded");
+    lp_setup.confirm(&participants[2], 1).assert_ok();
+
+    lp_setup
+        .query(|sc| {
+            assert_eq!(
+                sc.get_remaining_raise_capacity().into_option(),
+                Some(managed_biguint!(0))
+            );
+        })
+        .assert_ok();
+}
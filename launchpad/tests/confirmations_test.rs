@@ -0,0 +1,358 @@
+mod launchpad_setup;
+
+use launchpad_common::{
+    config::ConfigModule, setup::SetupModule, tickets::TicketsModule,
+    user_interactions::UserInteractionsModule,
+};
+use launchpad_setup::{
+    LaunchpadSetup, CONFIRM_START_ROUND, MAX_TIER_TICKETS, NR_WINNING_TICKETS, TICKET_COST,
+    WINNER_SELECTION_START_ROUND,
+};
+use multiversx_sc::codec::Empty;
+use multiversx_sc::types::{MultiValueEncoded, OptionalValue};
+use multiversx_sc_scenario::{
+    managed_address, managed_biguint, rust_biguint, testing_framework::TxTokenTransfer,
+};
+
+#[test]
+fn unconfirm_tickets_test() {
+    let mut lp_setup = LaunchpadSetup::new(NR_WINNING_TICKETS);
+    lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND);
+    let participants = lp_setup.participants.clone();
+    let initial_balance = lp_setup.b_mock.get_egld_balance(&participants[2]);
+
+    lp_setup.confirm(&participants[2], 3).assert_ok();
+
+    lp_setup
+        .call(&participants[2], |sc| {
+            sc.unconfirm_tickets(4);
+        })
+        .assert_error(4, "Trying to unconfirm too many tickets");
+
+    lp_setup
+        .call(&participants[2], |sc| {
+            sc.unconfirm_tickets(2);
+
+            assert_eq!(
+                sc.nr_confirmed_tickets(&managed_address!(&participants[2]))
+                    .get(),
+                1
+            );
+            assert_eq!(sc.total_confirmed_tickets().get(), 1);
+            assert_eq!(sc.total_participants().get(), 1);
+            assert_eq!(
+                sc.total_payment_raised().get(),
+                managed_biguint!(TICKET_COST)
+            );
+        })
+        .assert_ok();
+
+    lp_setup.b_mock.check_egld_balance(
+        &participants[2],
+        &(initial_balance - rust_biguint!(TICKET_COST)),
+    );
+
+    lp_setup
+        .b_mock
+        .set_block_round(WINNER_SELECTION_START_ROUND);
+    lp_setup
+        .call(&participants[2], |sc| {
+            sc.unconfirm_tickets(1);
+        })
+        .assert_error(4, "Not in confirmation period");
+}
+
+#[test]
+fn deny_smart_contract_callers_test() {
+    let mut lp_setup = LaunchpadSetup::new(NR_WINNING_TICKETS);
+    let integrator_sc = lp_setup.b_mock.create_sc_account(
+        &rust_biguint!(TICKET_COST),
+        None,
+        launchpad::contract_obj,
+        "integrator wasm",
+    );
+    let integrator_address = integrator_sc.address_ref().clone();
+
+    lp_setup
+        .call_owner(|sc| {
+            sc.set_deny_smart_contract_callers(true);
+        })
+        .assert_ok();
+
+    lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND);
+    lp_setup
+        .confirm(&integrator_address, 1)
+        .assert_user_error("Smart contract callers are not allowed");
+    lp_setup
+        .call(&integrator_address, |sc| {
+            sc.unconfirm_tickets(1);
+        })
+        .assert_user_error("Smart contract callers are not allowed");
+
+    // user accounts may not be allowlisted
+    let user_address = lp_setup.participants[0].clone();
+    lp_setup
+        .call_owner(|sc| {
+            let mut addresses = MultiValueEncoded::new();
+            addresses.push(managed_address!(&user_address));
+            sc.add_allowed_smart_contract_callers(addresses);
+        })
+        .assert_user_error("Address is not a smart contract");
+
+    lp_setup
+        .call_owner(|sc| {
+            let mut addresses = MultiValueEncoded::new();
+            addresses.push(managed_address!(&integrator_address));
+            sc.add_allowed_smart_contract_callers(addresses);
+        })
+        .assert_ok();
+
+    // allowlisted contract passes the check, but has no tickets
+    lp_setup
+        .confirm(&integrator_address, 1)
+        .assert_user_error("Trying to confirm too many tickets");
+
+    lp_setup.confirm(&user_address, 1).assert_ok();
+}
+
+#[test]
+fn max_confirmed_tickets_per_address_test() {
+    let mut lp_setup = LaunchpadSetup::new(NR_WINNING_TICKETS);
+    let participants = lp_setup.participants.clone();
+
+    lp_setup
+        .call_owner(|sc| {
+            sc.set_max_confirmed_tickets_per_address(2);
+        })
+        .assert_ok();
+
+    lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND);
+    lp_setup
+        .confirm(&participants[2], 3)
+        .assert_user_error("Confirmed tickets cap per address exceeded");
+    lp_setup.confirm(&participants[2], 2).assert_ok();
+    lp_setup
+        .confirm(&participants[2], 1)
+        .assert_user_error("Confirmed tickets cap per address exceeded");
+    lp_setup.confirm(&participants[1], 2).assert_ok();
+
+    lp_setup
+        .query(|sc| {
+            assert_eq!(
+                sc.nr_confirmed_tickets(&managed_address!(&participants[2]))
+                    .get(),
+                2
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn confirm_all_tickets_endpoint_test() {
+    let mut lp_setup = LaunchpadSetup::new(NR_WINNING_TICKETS);
+    let participants = lp_setup.participants.clone();
+
+    // user[2] has 3 tickets
+    lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND);
+    lp_setup.confirm(&participants[2], 1).assert_ok();
+
+    let nr_remaining_tickets = (MAX_TIER_TICKETS - 1) as u64;
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &participants[2],
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(TICKET_COST * (nr_remaining_tickets - 1)),
+            |sc| {
+                sc.confirm_all_tickets();
+            },
+        )
+        .assert_user_error("Wrong amount sent");
+
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &participants[2],
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(TICKET_COST * nr_remaining_tickets),
+            |sc| {
+                sc.confirm_all_tickets();
+
+                assert_eq!(
+                    sc.nr_confirmed_tickets(&managed_address!(&participants[2]))
+                        .get(),
+                    MAX_TIER_TICKETS
+                );
+            },
+        )
+        .assert_ok();
+
+    lp_setup
+        .call(&participants[2], |sc| {
+            sc.confirm_all_tickets();
+        })
+        .assert_user_error("No tickets left to confirm");
+}
+
+#[test]
+fn confirm_tickets_derived_from_payment_test() {
+    let mut lp_setup = LaunchpadSetup::new(NR_WINNING_TICKETS);
+    let participants = lp_setup.participants.clone();
+
+    lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND);
+    for payment_amount in [0, TICKET_COST + TICKET_COST / 2] {
+        lp_setup
+            .b_mock
+            .execute_tx(
+                &participants[2],
+                &lp_setup.lp_wrapper,
+                &rust_biguint!(payment_amount),
+                |sc| {
+                    sc.confirm_tickets(OptionalValue::None, OptionalValue::None);
+                },
+            )
+            .assert_user_error("Payment is not a multiple of the ticket price");
+    }
+
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &participants[2],
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(TICKET_COST * 2),
+            |sc| {
+                sc.confirm_tickets(OptionalValue::None, OptionalValue::None);
+
+                assert_eq!(
+                    sc.nr_confirmed_tickets(&managed_address!(&participants[2]))
+                        .get(),
+                    2
+                );
+            },
+        )
+        .assert_ok();
+}
+
+#[test]
+fn confirm_tickets_for_user_test() {
+    let mut lp_setup = LaunchpadSetup::new(NR_WINNING_TICKETS);
+    let participants = lp_setup.participants.clone();
+    let payer_balance = TICKET_COST * MAX_TIER_TICKETS as u64;
+
+    lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND);
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &participants[0],
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(TICKET_COST),
+            |sc| {
+                sc.confirm_tickets_for_user(managed_address!(&participants[0]), 1);
+            },
+        )
+        .assert_user_error("Use confirmTickets instead");
+
+    // user[0] pays for user[2]'s tickets
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &participants[0],
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(TICKET_COST * 2),
+            |sc| {
+                sc.confirm_tickets_for_user(managed_address!(&participants[2]), 2);
+
+                assert_eq!(
+                    sc.nr_confirmed_tickets(&managed_address!(&participants[0]))
+                        .get(),
+                    0
+                );
+                assert_eq!(
+                    sc.nr_confirmed_tickets(&managed_address!(&participants[2]))
+                        .get(),
+                    2
+                );
+            },
+        )
+        .assert_ok();
+
+    lp_setup.b_mock.check_egld_balance(
+        &participants[0],
+        &rust_biguint!(payer_balance - TICKET_COST * 2),
+    );
+    lp_setup
+        .b_mock
+        .check_egld_balance(&participants[2], &rust_biguint!(payer_balance));
+}
+
+#[test]
+fn confirm_tickets_payment_validation_test() {
+    let mut lp_setup = LaunchpadSetup::new(NR_WINNING_TICKETS);
+    let participants = lp_setup.participants.clone();
+    let other_token_id = b"OTHER-123456";
+    let sft_token_id = b"SFT-123456";
+    lp_setup.b_mock.set_esdt_balance(
+        &participants[2],
+        other_token_id,
+        &rust_biguint!(TICKET_COST),
+    );
+    lp_setup.b_mock.set_nft_balance(
+        &participants[2],
+        sft_token_id,
+        1,
+        &rust_biguint!(TICKET_COST),
+        &Empty,
+    );
+
+    lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND);
+
+    // extra transfers are rejected, instead of ignored
+    let transfers = [
+        TxTokenTransfer {
+            token_identifier: other_token_id.to_vec(),
+            nonce: 0,
+            value: rust_biguint!(TICKET_COST),
+        },
+        TxTokenTransfer {
+            token_identifier: sft_token_id.to_vec(),
+            nonce: 1,
+            value: rust_biguint!(TICKET_COST),
+        },
+    ];
+    lp_setup
+        .b_mock
+        .execute_esdt_multi_transfer(&participants[2], &lp_setup.lp_wrapper, &transfers, |sc| {
+            sc.confirm_tickets(OptionalValue::Some(1), OptionalValue::None);
+        })
+        .assert_user_error("Only one payment may be sent");
+
+    lp_setup
+        .b_mock
+        .execute_esdt_transfer(
+            &participants[2],
+            &lp_setup.lp_wrapper,
+            sft_token_id,
+            1,
+            &rust_biguint!(TICKET_COST),
+            |sc| {
+                sc.confirm_tickets(OptionalValue::None, OptionalValue::None);
+            },
+        )
+        .assert_user_error("Ticket payment must be a fungible token");
+
+    lp_setup
+        .b_mock
+        .execute_esdt_transfer(
+            &participants[2],
+            &lp_setup.lp_wrapper,
+            other_token_id,
+            0,
+            &rust_biguint!(TICKET_COST),
+            |sc| {
+                sc.confirm_tickets(OptionalValue::Some(1), OptionalValue::None);
+            },
+        )
+        .assert_user_error("Wrong payment token used");
+
+    lp_setup.confirm(&participants[2], 1).assert_ok();
+}
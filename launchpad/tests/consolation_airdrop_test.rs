@@ -0,0 +1,81 @@
+mod launchpad_setup;
+
+use launchpad_common::consolation_airdrop::ConsolationAirdropModule;
+use launchpad_setup::{
+    LaunchpadSetup, CLAIM_START_ROUND, CONFIRM_START_ROUND, NR_WINNING_TICKETS,
+    WINNER_SELECTION_START_ROUND,
+};
+use multiversx_sc_scenario::{managed_biguint, rust_biguint};
+
+#[test]
+fn consolation_airdrop_test() {
+    let mut lp_setup = LaunchpadSetup::new(NR_WINNING_TICKETS);
+    let participants = lp_setup.participants.clone();
+    let consolation_token_id = b"CONSOL-123456";
+    let consolation_amount = rust_biguint!(300);
+    lp_setup.b_mock.set_esdt_balance(
+        &lp_setup.owner_address,
+        consolation_token_id,
+        &consolation_amount,
+    );
+
+    lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND);
+    for (i, p) in participants.iter().enumerate() {
+        lp_setup.confirm(p, i + 1).assert_ok();
+    }
+
+    lp_setup
+        .b_mock
+        .execute_esdt_transfer(
+            &lp_setup.owner_address,
+            &lp_setup.lp_wrapper,
+            consolation_token_id,
+            0,
+            &consolation_amount,
+            |sc| {
+                sc.deposit_consolation_tokens();
+            },
+        )
+        .assert_user_error("Winners not selected yet");
+
+    // tickets 1, 2 and 4 are winning, out of 6 confirmed tickets
+    lp_setup
+        .b_mock
+        .set_block_round(WINNER_SELECTION_START_ROUND);
+    lp_setup.filter_tickets().assert_ok();
+    lp_setup.select_winners_mock(&[1, 2, 4]).assert_ok();
+
+    lp_setup
+        .b_mock
+        .execute_esdt_transfer(
+            &lp_setup.owner_address,
+            &lp_setup.lp_wrapper,
+            consolation_token_id,
+            0,
+            &consolation_amount,
+            |sc| {
+                sc.deposit_consolation_tokens();
+
+                assert_eq!(
+                    sc.consolation_tokens_per_ticket().get(),
+                    managed_biguint!(100)
+                );
+            },
+        )
+        .assert_ok();
+
+    lp_setup.b_mock.set_block_round(CLAIM_START_ROUND);
+    for p in participants.iter() {
+        lp_setup.claim_user(p).assert_ok();
+    }
+
+    lp_setup
+        .b_mock
+        .check_esdt_balance(&participants[0], consolation_token_id, &rust_biguint!(0));
+    lp_setup
+        .b_mock
+        .check_esdt_balance(&participants[1], consolation_token_id, &rust_biguint!(100));
+    lp_setup
+        .b_mock
+        .check_esdt_balance(&participants[2], consolation_token_id, &rust_biguint!(200));
+}
@@ -0,0 +1,130 @@
+mod launchpad_setup;
+
+use launchpad::Launchpad;
+use launchpad_common::fcfs_round::FcfsRoundModule;
+use launchpad_setup::{
+    LaunchpadSetup, CLAIM_START_ROUND, CONFIRM_START_ROUND, LAUNCHPAD_TOKENS_PER_TICKET,
+    LAUNCHPAD_TOKEN_ID, NR_WINNING_TICKETS, TICKET_COST, WINNER_SELECTION_START_ROUND,
+};
+use multiversx_sc_scenario::rust_biguint;
+
+const FCFS_END_ROUND: u64 = CLAIM_START_ROUND + 5;
+
+#[test]
+fn fcfs_round_test() {
+    let mut lp_setup = LaunchpadSetup::new(NR_WINNING_TICKETS);
+    let participants = lp_setup.participants.clone();
+    let owner_address = lp_setup.owner_address.clone();
+
+    lp_setup
+        .call(&participants[0], |sc| {
+            sc.set_fcfs_round(CLAIM_START_ROUND, FCFS_END_ROUND, 1);
+        })
+        .assert_error(4, "Permission denied");
+
+    lp_setup
+        .call_owner(|sc| {
+            sc.set_fcfs_round(WINNER_SELECTION_START_ROUND, FCFS_END_ROUND, 1);
+        })
+        .assert_user_error("FCFS round must start in the claim period");
+
+    lp_setup
+        .call_owner(|sc| {
+            sc.set_fcfs_round(CLAIM_START_ROUND, FCFS_END_ROUND, 1);
+        })
+        .assert_ok();
+
+    // only 2 of the 3 winning tickets are sold in the lottery round
+    lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND);
+    lp_setup.confirm(&participants[0], 1).assert_ok();
+    lp_setup.confirm(&participants[1], 1).assert_ok();
+
+    lp_setup
+        .b_mock
+        .set_block_round(WINNER_SELECTION_START_ROUND);
+    lp_setup.filter_tickets().assert_ok();
+    lp_setup.select_winners().assert_ok();
+
+    lp_setup.b_mock.set_block_round(CLAIM_START_ROUND);
+    lp_setup
+        .query(|sc| {
+            assert_eq!(sc.get_fcfs_tickets_available(), 1);
+        })
+        .assert_ok();
+
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &participants[2],
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(TICKET_COST),
+            |sc| {
+                sc.buy_fcfs_tickets_endpoint(1);
+            },
+        )
+        .assert_user_error("Only lottery round participants may buy");
+
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &participants[0],
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(TICKET_COST * 2),
+            |sc| {
+                sc.buy_fcfs_tickets_endpoint(2);
+            },
+        )
+        .assert_user_error("Max FCFS tickets per address exceeded");
+
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &participants[0],
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(TICKET_COST),
+            |sc| {
+                sc.buy_fcfs_tickets_endpoint(1);
+            },
+        )
+        .assert_ok();
+    lp_setup.b_mock.check_esdt_balance(
+        &participants[0],
+        LAUNCHPAD_TOKEN_ID,
+        &rust_biguint!(LAUNCHPAD_TOKENS_PER_TICKET),
+    );
+
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &participants[1],
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(TICKET_COST),
+            |sc| {
+                sc.buy_fcfs_tickets_endpoint(1);
+            },
+        )
+        .assert_user_error("Not enough FCFS tickets left");
+
+    // the FCFS payment is claimable with the lottery one,
+    // while the launchpad tokens are kept until the round ends
+    lp_setup.claim_owner().assert_ok();
+    lp_setup
+        .b_mock
+        .check_egld_balance(&owner_address, &rust_biguint!(TICKET_COST * 3));
+    lp_setup
+        .b_mock
+        .check_esdt_balance(&owner_address, LAUNCHPAD_TOKEN_ID, &rust_biguint!(0));
+
+    lp_setup.b_mock.set_block_round(FCFS_END_ROUND);
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &participants[1],
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(TICKET_COST),
+            |sc| {
+                sc.buy_fcfs_tickets_endpoint(1);
+            },
+        )
+        .assert_user_error("Not in FCFS round");
+}
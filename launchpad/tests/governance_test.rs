@@ -0,0 +1,99 @@
+mod launchpad_setup;
+
+use launchpad_common::{
+    governance::GovernanceModule,
+    tickets::{TicketsModule, WINNING_TICKET},
+};
+use launchpad_setup::{
+    governance_mock::GovernanceMock, LaunchpadSetup, NR_WINNING_TICKETS,
+    WINNER_SELECTION_START_ROUND,
+};
+use multiversx_sc::types::Address;
+use multiversx_sc_scenario::{managed_address, rust_biguint};
+
+fn set_governance_contract(
+    lp_setup: &mut LaunchpadSetup,
+    governance_builder: fn() -> GovernanceMock,
+) {
+    let governance_wrapper = lp_setup.b_mock.create_sc_account(
+        &rust_biguint!(0),
+        None,
+        governance_builder,
+        "governance mock",
+    );
+    let governance_address: Address = governance_wrapper.address_ref().clone();
+
+    lp_setup
+        .call_owner(|sc| {
+            sc.set_governance_contract(managed_address!(&governance_address));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn set_governance_contract_test() {
+    let mut lp_setup = LaunchpadSetup::new(NR_WINNING_TICKETS);
+    let participants = lp_setup.participants.clone();
+
+    lp_setup
+        .call(&participants[0], |sc| {
+            sc.set_governance_contract(managed_address!(&participants[0]));
+        })
+        .assert_error(4, "Permission denied");
+
+    lp_setup
+        .call_owner(|sc| {
+            sc.set_governance_contract(managed_address!(&participants[0]));
+        })
+        .assert_user_error("Invalid SC address");
+
+    set_governance_contract(&mut lp_setup, GovernanceMock::approving);
+
+    lp_setup
+        .b_mock
+        .set_block_round(WINNER_SELECTION_START_ROUND);
+    lp_setup
+        .call_owner(|sc| {
+            sc.remove_governance_contract();
+        })
+        .assert_user_error("Cannot change after winner selection started");
+}
+
+#[test]
+fn select_winners_rejected_by_governance_test() {
+    let mut lp_setup = LaunchpadSetup::new(NR_WINNING_TICKETS);
+    set_governance_contract(&mut lp_setup, GovernanceMock::rejecting);
+
+    lp_setup.confirm_all();
+    lp_setup.filter_tickets().assert_ok();
+    lp_setup
+        .select_winners()
+        .assert_user_error("Winner selection not approved by governance");
+
+    lp_setup
+        .query(|sc| {
+            for ticket_id in 1..=6 {
+                assert_ne!(sc.get_ticket_status(ticket_id), WINNING_TICKET);
+            }
+        })
+        .assert_ok();
+}
+
+#[test]
+fn select_winners_approved_by_governance_test() {
+    let mut lp_setup = LaunchpadSetup::new(NR_WINNING_TICKETS);
+    set_governance_contract(&mut lp_setup, GovernanceMock::approving);
+
+    lp_setup.confirm_all();
+    lp_setup.filter_tickets().assert_ok();
+    lp_setup.select_winners().assert_ok();
+
+    lp_setup
+        .query(|sc| {
+            let nr_winning_tickets = (1..=6)
+                .filter(|ticket_id| sc.get_ticket_status(*ticket_id) == WINNING_TICKET)
+                .count();
+            assert_eq!(nr_winning_tickets, NR_WINNING_TICKETS);
+        })
+        .assert_ok();
+}
@@ -0,0 +1,88 @@
+mod launchpad_setup;
+
+use launchpad::Launchpad;
+use launchpad_common::{
+    blacklist::{BlacklistModule, BlacklistReason},
+    guardian::{GuardedOperationType, GuardianModule},
+};
+use launchpad_setup::{LaunchpadSetup, NR_WINNING_TICKETS};
+use multiversx_sc::codec::TopEncode;
+use multiversx_sc::types::{ManagedBuffer, ManagedVec, MultiValueEncoded};
+use multiversx_sc_scenario::{managed_address, rust_biguint};
+
+#[test]
+fn guardian_approval_test() {
+    let mut lp_setup = LaunchpadSetup::new(NR_WINNING_TICKETS);
+    let participants = lp_setup.participants.clone();
+    let guardian_address = lp_setup.b_mock.create_user_account(&rust_biguint!(0));
+    let new_guardian_address = lp_setup.b_mock.create_user_account(&rust_biguint!(0));
+
+    lp_setup
+        .call(&participants[0], |sc| {
+            sc.set_guardian(managed_address!(&participants[0]), 1);
+        })
+        .assert_user_error("Permission denied");
+
+    lp_setup
+        .call_owner(|sc| {
+            sc.set_guardian(managed_address!(&guardian_address), 1);
+        })
+        .assert_ok();
+
+    // the guardian may only be changed with its approval
+    lp_setup
+        .call_owner(|sc| {
+            sc.set_guardian(managed_address!(&new_guardian_address), 1);
+        })
+        .assert_user_error("Guardian approval required");
+
+    // single addresses do not need approval
+    lp_setup
+        .call_owner(|sc| {
+            let mut blacklist = MultiValueEncoded::new();
+            blacklist.push(managed_address!(&participants[0]));
+            sc.add_users_to_blacklist_endpoint(BlacklistReason::Unspecified, blacklist);
+        })
+        .assert_ok();
+
+    lp_setup
+        .call_owner(|sc| {
+            let mut blacklist = MultiValueEncoded::new();
+            blacklist.push(managed_address!(&participants[1]));
+            blacklist.push(managed_address!(&participants[2]));
+            sc.add_users_to_blacklist_endpoint(BlacklistReason::Unspecified, blacklist);
+        })
+        .assert_user_error("Guardian approval required");
+
+    lp_setup
+        .call_owner(|sc| {
+            sc.approve_operation(GuardedOperationType::BlacklistBatch, ManagedBuffer::new());
+        })
+        .assert_user_error("Only the guardian may approve operations");
+
+    lp_setup
+        .call(&guardian_address, |sc| {
+            let mut users = ManagedVec::new();
+            users.push(managed_address!(&participants[1]));
+            users.push(managed_address!(&participants[2]));
+            let mut encoded_data = ManagedBuffer::new();
+            let _ = users.top_encode(&mut encoded_data);
+            sc.approve_operation(GuardedOperationType::BlacklistBatch, encoded_data);
+        })
+        .assert_ok();
+
+    lp_setup
+        .call_owner(|sc| {
+            let mut blacklist = MultiValueEncoded::new();
+            blacklist.push(managed_address!(&participants[1]));
+            blacklist.push(managed_address!(&participants[2]));
+            sc.add_users_to_blacklist_endpoint(BlacklistReason::Unspecified, blacklist);
+        })
+        .assert_ok();
+
+    lp_setup
+        .query(|sc| {
+            assert_eq!(sc.get_nr_blacklisted_users(), 3);
+        })
+        .assert_ok();
+}
@@ -0,0 +1,44 @@
+mod launchpad_setup;
+
+use launchpad_common::{config::ConfigModule, invariants::InvariantsModule};
+use launchpad_setup::{LaunchpadSetup, CLAIM_START_ROUND, NR_WINNING_TICKETS};
+
+#[test]
+fn verify_invariants_test() {
+    let mut lp_setup = LaunchpadSetup::new(NR_WINNING_TICKETS);
+    let participants = lp_setup.participants.clone();
+
+    lp_setup.confirm_all();
+    lp_setup.filter_tickets().assert_ok();
+    lp_setup.select_winners_mock(&[1, 2, 4]).assert_ok();
+
+    lp_setup
+        .query(|sc| {
+            let report = sc.verify_invariants();
+            assert_eq!(report.nr_winning_tickets, NR_WINNING_TICKETS);
+            assert_eq!(report.nr_unclaimed_winning_tickets, NR_WINNING_TICKETS);
+            assert!(report.all_hold());
+        })
+        .assert_ok();
+
+    lp_setup.b_mock.set_block_round(CLAIM_START_ROUND);
+    lp_setup.claim_user(&participants[0]).assert_ok();
+
+    lp_setup
+        .query(|sc| {
+            assert!(sc.verify_invariants().all_hold());
+        })
+        .assert_ok();
+
+    // corrupted state is reported
+    lp_setup
+        .call_owner(|sc| {
+            sc.nr_winning_tickets()
+                .update(|nr_winning| *nr_winning += 1);
+
+            let report = sc.verify_invariants();
+            assert!(!report.winning_tickets_consistent);
+            assert!(!report.all_hold());
+        })
+        .assert_ok();
+}
@@ -0,0 +1,69 @@
+mod launchpad_setup;
+
+use launchpad_common::{
+    keeper_incentives::KeeperIncentivesModule, winner_selection::WinnerSelectionModule,
+};
+use launchpad_setup::{LaunchpadSetup, NR_WINNING_TICKETS};
+use multiversx_sc::types::{EgldOrEsdtTokenIdentifier, OperationCompletionStatus};
+use multiversx_sc_scenario::{managed_address, managed_biguint, rust_biguint};
+
+#[test]
+fn keeper_bounty_test() {
+    let mut lp_setup = LaunchpadSetup::new(NR_WINNING_TICKETS);
+    let keeper = lp_setup.b_mock.create_user_account(&rust_biguint!(0));
+    let owner_address = lp_setup.owner_address.clone();
+    lp_setup
+        .b_mock
+        .set_egld_balance(&owner_address, &rust_biguint!(10));
+
+    lp_setup
+        .call(&keeper, |sc| {
+            sc.set_keeper_bounty(EgldOrEsdtTokenIdentifier::egld(), managed_biguint!(7), 0);
+        })
+        .assert_user_error("Permission denied");
+
+    lp_setup
+        .call_owner(|sc| {
+            sc.set_keeper_bounty(EgldOrEsdtTokenIdentifier::egld(), managed_biguint!(7), 0);
+        })
+        .assert_ok();
+
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &owner_address,
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(10),
+            |sc| {
+                sc.deposit_keeper_bounty_pool();
+            },
+        )
+        .assert_ok();
+
+    lp_setup.confirm_all();
+    lp_setup
+        .call(&keeper, |sc| {
+            let result = sc.filter_tickets();
+            assert_eq!(result, OperationCompletionStatus::Completed);
+        })
+        .assert_ok();
+    lp_setup
+        .b_mock
+        .check_egld_balance(&keeper, &rust_biguint!(7));
+
+    // the owner is not paid, and the remaining pool is not enough for another bounty
+    lp_setup.select_winners().assert_ok();
+    lp_setup
+        .b_mock
+        .check_egld_balance(&owner_address, &rust_biguint!(0));
+
+    lp_setup
+        .query(|sc| {
+            assert_eq!(sc.keeper_bounty_pool().get(), managed_biguint!(3));
+            assert_eq!(
+                sc.keeper_bounties_paid(&managed_address!(&keeper)).get(),
+                managed_biguint!(7)
+            );
+        })
+        .assert_ok();
+}
@@ -0,0 +1,55 @@
+mod launchpad_setup;
+
+use launchpad_common::{kyc::KycModule, permissions::Role};
+use launchpad_setup::{LaunchpadSetup, CONFIRM_START_ROUND, NR_WINNING_TICKETS};
+use multiversx_sc::types::MultiValueEncoded;
+use multiversx_sc_scenario::{managed_address, rust_biguint};
+
+#[test]
+fn kyc_gate_test() {
+    let mut lp_setup = LaunchpadSetup::new(NR_WINNING_TICKETS);
+    let participants = lp_setup.participants.clone();
+    let attestor = lp_setup.b_mock.create_user_account(&rust_biguint!(0));
+    let config_manager = lp_setup.b_mock.create_user_account(&rust_biguint!(0));
+    lp_setup.add_role(Role::KycAttestor, &attestor);
+    lp_setup.add_role(Role::ConfigManager, &config_manager);
+
+    // attestors may not change the KYC requirement
+    lp_setup
+        .call(&attestor, |sc| {
+            sc.set_kyc_required(true);
+        })
+        .assert_user_error("Permission denied");
+
+    lp_setup
+        .call(&config_manager, |sc| {
+            sc.set_kyc_required(true);
+        })
+        .assert_ok();
+
+    lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND);
+    lp_setup
+        .confirm(&participants[0], 1)
+        .assert_user_error("KYC not passed");
+
+    lp_setup
+        .call(&participants[1], |sc| {
+            let mut addresses = MultiValueEncoded::new();
+            addresses.push(managed_address!(&participants[0]));
+            sc.mark_kyc_passed(addresses);
+        })
+        .assert_user_error("Permission denied");
+
+    lp_setup
+        .call(&attestor, |sc| {
+            let mut addresses = MultiValueEncoded::new();
+            addresses.push(managed_address!(&participants[0]));
+            sc.mark_kyc_passed(addresses);
+        })
+        .assert_ok();
+
+    lp_setup.confirm(&participants[0], 1).assert_ok();
+    lp_setup
+        .confirm(&participants[1], 2)
+        .assert_user_error("KYC not passed");
+}
@@ -0,0 +1,130 @@
+mod launchpad_setup;
+
+use launchpad_common::{
+    launch_stage::{CurrentStage, LaunchPhase, LaunchStageModule, PhaseType},
+    setup::SetupModule,
+    tickets::TicketsModule,
+    time_provider::{TimeProviderModule, TimeUnit},
+};
+use launchpad_setup::{
+    LaunchpadSetup, CLAIM_START_ROUND, CONFIRM_START_ROUND, NR_WINNING_TICKETS,
+    WINNER_SELECTION_START_ROUND,
+};
+use multiversx_sc::types::MultiValueEncoded;
+use multiversx_sc_scenario::managed_address;
+
+#[test]
+fn launch_phases_test() {
+    let mut lp_setup = LaunchpadSetup::new(NR_WINNING_TICKETS);
+    let participants = lp_setup.participants.clone();
+    let public_round_start_round = CONFIRM_START_ROUND + 2;
+
+    lp_setup
+        .call_owner(|sc| {
+            let mut launch_phases = MultiValueEncoded::new();
+            launch_phases.push(LaunchPhase {
+                phase_type: PhaseType::GuaranteedRound,
+                start_round: CONFIRM_START_ROUND,
+                max_confirmed_tickets_per_address: 1,
+            });
+            launch_phases.push(LaunchPhase::new(
+                PhaseType::PublicRound,
+                public_round_start_round,
+            ));
+            launch_phases.push(LaunchPhase::new(
+                PhaseType::WinnerSelection,
+                WINNER_SELECTION_START_ROUND,
+            ));
+            launch_phases.push(LaunchPhase::new(PhaseType::Claim, CLAIM_START_ROUND));
+            sc.set_launch_phases(launch_phases);
+
+            let launch_phases = sc.get_launch_phases().to_vec();
+            assert_eq!(launch_phases.len(), 5);
+            assert_eq!(launch_phases.get(0).phase_type, PhaseType::Registration);
+            assert_eq!(launch_phases.get(1).phase_type, PhaseType::GuaranteedRound);
+            assert_eq!(launch_phases.get(2).start_round, public_round_start_round);
+        })
+        .assert_ok();
+
+    // phases out of order
+    lp_setup
+        .call_owner(|sc| {
+            let mut launch_phases = MultiValueEncoded::new();
+            launch_phases.push(LaunchPhase::new(
+                PhaseType::WinnerSelection,
+                WINNER_SELECTION_START_ROUND,
+            ));
+            launch_phases.push(LaunchPhase::new(
+                PhaseType::PublicRound,
+                CONFIRM_START_ROUND,
+            ));
+            launch_phases.push(LaunchPhase::new(PhaseType::Claim, CLAIM_START_ROUND));
+            sc.set_launch_phases(launch_phases);
+        })
+        .assert_user_error("Launch phases must be in order");
+
+    // guaranteed round, capped to one ticket per address
+    lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND);
+    lp_setup
+        .query(|sc| {
+            let (stage, rounds_remaining) = sc.get_current_stage().into_tuple();
+            assert_eq!(stage, CurrentStage::GuaranteedRound);
+            assert_eq!(rounds_remaining, 2);
+        })
+        .assert_ok();
+
+    lp_setup
+        .confirm(&participants[2], 2)
+        .assert_user_error("Confirmed tickets cap per address exceeded");
+    lp_setup.confirm(&participants[2], 1).assert_ok();
+
+    // public round
+    lp_setup.b_mock.set_block_round(public_round_start_round);
+    lp_setup.confirm(&participants[2], 2).assert_ok();
+
+    lp_setup
+        .query(|sc| {
+            assert_eq!(
+                sc.nr_confirmed_tickets(&managed_address!(&participants[2]))
+                    .get(),
+                3
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn time_unit_test() {
+    let mut lp_setup = LaunchpadSetup::new(NR_WINNING_TICKETS);
+    let participants = lp_setup.participants.clone();
+
+    // schedule already started in the new unit
+    lp_setup.b_mock.set_block_timestamp(CONFIRM_START_ROUND);
+    lp_setup
+        .call_owner(|sc| {
+            sc.set_time_unit(TimeUnit::Timestamp);
+        })
+        .assert_user_error("Launch already started in the new time unit");
+
+    lp_setup
+        .call_owner(|sc| {
+            sc.set_time_unit(TimeUnit::BlockNonce);
+            assert_eq!(sc.time_unit().get(), TimeUnit::BlockNonce);
+        })
+        .assert_ok();
+
+    // rounds are ignored
+    lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND);
+    lp_setup
+        .confirm(&participants[0], 1)
+        .assert_user_error("Not in confirmation period");
+
+    lp_setup.b_mock.set_block_nonce(CONFIRM_START_ROUND);
+    lp_setup
+        .query(|sc| {
+            let (stage, _) = sc.get_current_stage().into_tuple();
+            assert_eq!(stage, CurrentStage::Confirm);
+        })
+        .assert_ok();
+    lp_setup.confirm(&participants[0], 1).assert_ok();
+}
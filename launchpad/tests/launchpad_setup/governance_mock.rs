@@ -0,0 +1,54 @@
+use multiversx_sc::{
+    codec::TopEncode,
+    contract_base::{CallableContract, ContractBase},
+};
+use multiversx_sc_scenario::{testing_framework::TxContextStack, DebugApi};
+
+pub static IS_WINNER_SELECTION_APPROVED_FN_NAME: &str = "isWinnerSelectionApproved";
+
+#[derive(Clone)]
+pub struct GovernanceMock {
+    approved: bool,
+}
+
+impl ContractBase for GovernanceMock {
+    type Api = DebugApi;
+}
+
+impl CallableContract for GovernanceMock {
+    fn call(&self, fn_name: &str) -> bool {
+        if fn_name != IS_WINNER_SELECTION_APPROVED_FN_NAME {
+            return false;
+        }
+
+        self.call_is_winner_selection_approved();
+
+        true
+    }
+}
+
+impl GovernanceMock {
+    pub fn approving() -> Self {
+        GovernanceMock { approved: true }
+    }
+
+    pub fn rejecting() -> Self {
+        GovernanceMock { approved: false }
+    }
+
+    /// Gives the same answer for every launchpad
+    fn call_is_winner_selection_approved(&self) {
+        let api = TxContextStack::static_peek();
+        if api.input_ref().args.len() != 1 {
+            panic!("Invalid args");
+        }
+
+        let mut result = Vec::new();
+        self.approved.top_encode(&mut result).unwrap();
+        api.tx_result_cell
+            .try_lock()
+            .unwrap()
+            .result_values
+            .push(result);
+    }
+}
@@ -0,0 +1,226 @@
+#![allow(dead_code)]
+
+use multiversx_sc::types::{
+    Address, EgldOrEsdtTokenIdentifier, MultiValueEncoded, OperationCompletionStatus, OptionalValue,
+};
+
+use launchpad::Launchpad;
+use launchpad_common::{
+    config::ConfigModule,
+    launch_stage::{Flags, LaunchStageModule},
+    permissions::{PermissionsModule, Role},
+    tickets::{TicketId, TicketsModule, WINNING_TICKET},
+    user_interactions::UserInteractionsModule,
+    winner_selection::WinnerSelectionModule,
+    winners_hash::WinnersHashModule,
+};
+use multiversx_sc_scenario::{
+    managed_address, managed_biguint, managed_token_id, rust_biguint,
+    testing_framework::{BlockchainStateWrapper, ContractObjWrapper, TxResult},
+    DebugApi,
+};
+
+pub mod governance_mock;
+pub mod price_aggregator_mock;
+
+pub static LAUNCHPAD_TOKEN_ID: &[u8] = b"LAUNCH-123456";
+pub const LAUNCHPAD_TOKENS_PER_TICKET: u64 = 100;
+pub const CONFIRM_START_ROUND: u64 = 5;
+pub const WINNER_SELECTION_START_ROUND: u64 = 10;
+pub const CLAIM_START_ROUND: u64 = 15;
+
+pub const NR_LAUNCHPAD_PARTICIPANTS: usize = 3;
+pub const NR_WINNING_TICKETS: usize = 3;
+pub const MAX_TIER_TICKETS: usize = 3;
+pub const TICKET_COST: u64 = 10;
+
+pub type LaunchpadContract = launchpad::ContractObj<DebugApi>;
+pub type LaunchpadBuilder = fn() -> LaunchpadContract;
+
+pub struct LaunchpadSetup {
+    pub b_mock: BlockchainStateWrapper,
+    pub owner_address: Address,
+    pub participants: Vec<Address>,
+    pub lp_wrapper: ContractObjWrapper<LaunchpadContract, LaunchpadBuilder>,
+}
+
+impl LaunchpadSetup {
+    pub fn new(nr_winning_tickets: usize) -> Self {
+        let rust_zero = rust_biguint!(0u64);
+        let user_balance = rust_biguint!(TICKET_COST * MAX_TIER_TICKETS as u64);
+        let total_launchpad_tokens =
+            rust_biguint!(LAUNCHPAD_TOKENS_PER_TICKET * nr_winning_tickets as u64);
+
+        let mut b_mock = BlockchainStateWrapper::new();
+        let owner_address = b_mock.create_user_account(&rust_zero);
+        let mut participants = Vec::new();
+
+        for _ in 0..NR_LAUNCHPAD_PARTICIPANTS {
+            let addr = b_mock.create_user_account(&user_balance);
+            participants.push(addr);
+        }
+
+        b_mock.set_esdt_balance(&owner_address, LAUNCHPAD_TOKEN_ID, &total_launchpad_tokens);
+
+        let lp_wrapper = b_mock.create_sc_account(
+            &rust_zero,
+            Some(&owner_address),
+            launchpad::contract_obj as LaunchpadBuilder,
+            "launchpad.wasm",
+        );
+
+        // init launchpad
+        b_mock
+            .execute_tx(&owner_address, &lp_wrapper, &rust_zero, |sc| {
+                sc.init(
+                    managed_token_id!(LAUNCHPAD_TOKEN_ID),
+                    managed_biguint!(LAUNCHPAD_TOKENS_PER_TICKET),
+                    EgldOrEsdtTokenIdentifier::egld(),
+                    managed_biguint!(TICKET_COST),
+                    nr_winning_tickets,
+                    CONFIRM_START_ROUND,
+                    WINNER_SELECTION_START_ROUND,
+                    CLAIM_START_ROUND,
+                );
+            })
+            .assert_ok();
+
+        // add tickets
+        // first user - 1 ticket, second user - 2 tickets, 3rd user - 3 tickets
+        b_mock
+            .execute_tx(&owner_address, &lp_wrapper, &rust_zero, |sc| {
+                let mut args = MultiValueEncoded::new();
+                for (i, participant) in participants.iter().enumerate() {
+                    args.push((managed_address!(participant), i + 1).into());
+                }
+                sc.add_tickets_endpoint(args);
+            })
+            .assert_ok();
+
+        // deposit launchpad tokens
+        b_mock
+            .execute_esdt_transfer(
+                &owner_address,
+                &lp_wrapper,
+                LAUNCHPAD_TOKEN_ID,
+                0,
+                &total_launchpad_tokens,
+                |sc| {
+                    sc.deposit_launchpad_tokens_endpoint();
+                },
+            )
+            .assert_ok();
+
+        Self {
+            b_mock,
+            owner_address,
+            participants,
+            lp_wrapper,
+        }
+    }
+
+    pub fn call<TxFn>(&mut self, caller: &Address, tx_fn: TxFn) -> TxResult
+    where
+        TxFn: FnOnce(LaunchpadContract),
+    {
+        self.b_mock
+            .execute_tx(caller, &self.lp_wrapper, &rust_biguint!(0), tx_fn)
+    }
+
+    pub fn call_owner<TxFn>(&mut self, tx_fn: TxFn) -> TxResult
+    where
+        TxFn: FnOnce(LaunchpadContract),
+    {
+        let owner_address = self.owner_address.clone();
+        self.call(&owner_address, tx_fn)
+    }
+
+    pub fn query<QueryFn>(&mut self, query_fn: QueryFn) -> TxResult
+    where
+        QueryFn: FnOnce(LaunchpadContract),
+    {
+        self.b_mock.execute_query(&self.lp_wrapper, query_fn)
+    }
+
+    pub fn add_role(&mut self, role: Role, address: &Address) {
+        self.call_owner(|sc| {
+            let mut addresses = MultiValueEncoded::new();
+            addresses.push(managed_address!(address));
+            sc.add_role(role, addresses);
+        })
+        .assert_ok();
+    }
+
+    pub fn confirm(&mut self, caller: &Address, nr_tickets: usize) -> TxResult {
+        self.b_mock.execute_tx(
+            caller,
+            &self.lp_wrapper,
+            &rust_biguint!(TICKET_COST * nr_tickets as u64),
+            |sc| {
+                sc.confirm_tickets(OptionalValue::Some(nr_tickets), OptionalValue::None);
+            },
+        )
+    }
+
+    /// Confirms all the tickets of every participant and moves to the winner selection
+    pub fn confirm_all(&mut self) {
+        self.b_mock.set_block_round(CONFIRM_START_ROUND);
+        for (i, participant) in self.participants.clone().iter().enumerate() {
+            self.confirm(participant, i + 1).assert_ok();
+        }
+        self.b_mock.set_block_round(WINNER_SELECTION_START_ROUND);
+    }
+
+    pub fn filter_tickets(&mut self) -> TxResult {
+        self.call_owner(|sc| {
+            let result = sc.filter_tickets();
+            assert_eq!(result, OperationCompletionStatus::Completed);
+        })
+    }
+
+    pub fn select_winners(&mut self) -> TxResult {
+        self.call_owner(|sc| {
+            let result = sc.select_winners();
+            assert_eq!(result, OperationCompletionStatus::Completed);
+        })
+    }
+
+    /// Marks the given tickets as winning instead of running the selection
+    pub fn select_winners_mock(&mut self, winning_ticket_ids: &[TicketId]) -> TxResult {
+        self.call_owner(|sc| {
+            for ticket_id in winning_ticket_ids {
+                sc.set_ticket_status(*ticket_id, WINNING_TICKET);
+            }
+
+            sc.claimable_ticket_payment().set(&managed_biguint!(
+                TICKET_COST * winning_ticket_ids.len() as u64
+            ));
+
+            sc.flags().set(&Flags {
+                were_tickets_filtered: true,
+                has_winner_selection_process_started: true,
+                were_winners_selected: true,
+                was_additional_step_completed: true,
+            })
+        })
+    }
+
+    pub fn compute_winners_hash(&mut self) -> TxResult {
+        self.call_owner(|sc| {
+            let result = sc.compute_winners_hash();
+            assert_eq!(result, OperationCompletionStatus::Completed);
+        })
+    }
+
+    pub fn claim_user(&mut self, user: &Address) -> TxResult {
+        self.call(user, |sc| {
+            sc.claim_launchpad_tokens_endpoint(OptionalValue::None);
+        })
+    }
+
+    pub fn claim_owner(&mut self) -> TxResult {
+        self.call_owner(|sc| {
+            sc.claim_ticket_payment_endpoint();
+        })
+    }
+}
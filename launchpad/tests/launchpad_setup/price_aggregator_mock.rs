@@ -0,0 +1,61 @@
+use launchpad_common::price_oracle::price_aggregator_proxy::PriceFeed;
+use multiversx_sc::{
+    codec::{TopDecode, TopEncode},
+    contract_base::{CallableContract, ContractBase},
+    types::ManagedBuffer,
+};
+use multiversx_sc_scenario::{managed_biguint, testing_framework::TxContextStack, DebugApi};
+
+pub static LATEST_PRICE_FEED_FN_NAME: &str = "latestPriceFeedOptional";
+pub const PRICE_FEED_TIMESTAMP: u64 = 1_000;
+pub const PRICE_FEED_PRICE: u64 = 4;
+
+#[derive(Clone)]
+pub struct PriceAggregatorMock {}
+
+impl ContractBase for PriceAggregatorMock {
+    type Api = DebugApi;
+}
+
+impl CallableContract for PriceAggregatorMock {
+    fn call(&self, fn_name: &str) -> bool {
+        if fn_name != LATEST_PRICE_FEED_FN_NAME {
+            return false;
+        }
+
+        self.call_latest_price_feed_optional();
+
+        true
+    }
+}
+
+impl PriceAggregatorMock {
+    pub fn new() -> Self {
+        PriceAggregatorMock {}
+    }
+
+    /// Always returns the same price, published at `PRICE_FEED_TIMESTAMP`
+    fn call_latest_price_feed_optional(&self) {
+        let api = TxContextStack::static_peek();
+        let args = api.input_ref().args.clone();
+        if args.len() != 2 {
+            panic!("Invalid args");
+        }
+
+        let price_feed = PriceFeed::<DebugApi> {
+            round_id: 1,
+            from: ManagedBuffer::top_decode(args[0].clone()).unwrap(),
+            to: ManagedBuffer::top_decode(args[1].clone()).unwrap(),
+            timestamp: PRICE_FEED_TIMESTAMP,
+            price: managed_biguint!(PRICE_FEED_PRICE),
+            decimals: 0,
+        };
+        let mut result = Vec::new();
+        price_feed.top_encode(&mut result).unwrap();
+        api.tx_result_cell
+            .try_lock()
+            .unwrap()
+            .result_values
+            .push(result);
+    }
+}
@@ -0,0 +1,125 @@
+mod launchpad_setup;
+
+use launchpad_common::{
+    config::ConfigModule, legacy_import::LegacyImportModule, tickets::TicketsModule,
+};
+use launchpad_setup::{LaunchpadSetup, CONFIRM_START_ROUND, NR_WINNING_TICKETS, TICKET_COST};
+use multiversx_sc::types::{Address, MultiValueEncoded};
+use multiversx_sc_scenario::{
+    managed_address, managed_biguint, rust_biguint, testing_framework::TxResult,
+};
+
+fn import_chunk(
+    lp_setup: &mut LaunchpadSetup,
+    caller: &Address,
+    chunk_index: usize,
+    entries: &[(&Address, usize, usize)],
+    payment_amount: u64,
+) -> TxResult {
+    lp_setup.b_mock.execute_tx(
+        caller,
+        &lp_setup.lp_wrapper,
+        &rust_biguint!(payment_amount),
+        |sc| {
+            let mut args = MultiValueEncoded::new();
+            for (address, nr_tickets, nr_confirmed) in entries {
+                args.push((managed_address!(*address), *nr_tickets, *nr_confirmed).into());
+            }
+            sc.import_legacy_state(chunk_index, args);
+        },
+    )
+}
+
+#[test]
+fn import_legacy_state_test() {
+    let mut lp_setup = LaunchpadSetup::new(NR_WINNING_TICKETS);
+    let participants = lp_setup.participants.clone();
+    let owner_address = lp_setup.owner_address.clone();
+    let first_user = lp_setup.b_mock.create_user_account(&rust_biguint!(0));
+    let second_user = lp_setup.b_mock.create_user_account(&rust_biguint!(0));
+    lp_setup
+        .b_mock
+        .set_egld_balance(&owner_address, &rust_biguint!(TICKET_COST * 3));
+
+    import_chunk(
+        &mut lp_setup,
+        &participants[0],
+        0,
+        &[(&first_user, 1, 1)],
+        TICKET_COST,
+    )
+    .assert_error(4, "Permission denied");
+
+    // chunks have to be sent in order
+    import_chunk(&mut lp_setup, &owner_address, 1, &[(&first_user, 1, 0)], 0)
+        .assert_user_error("Invalid chunk index");
+
+    // the payment has to cover exactly the confirmed tickets
+    import_chunk(
+        &mut lp_setup,
+        &owner_address,
+        0,
+        &[(&first_user, 1, 1), (&second_user, 2, 2)],
+        TICKET_COST * 2,
+    )
+    .assert_user_error("Wrong payment");
+
+    import_chunk(
+        &mut lp_setup,
+        &owner_address,
+        0,
+        &[(&first_user, 1, 1)],
+        TICKET_COST,
+    )
+    .assert_ok();
+
+    // an already imported chunk is not imported again
+    import_chunk(
+        &mut lp_setup,
+        &owner_address,
+        0,
+        &[(&first_user, 1, 1)],
+        TICKET_COST,
+    )
+    .assert_user_error("Invalid chunk index");
+
+    import_chunk(
+        &mut lp_setup,
+        &owner_address,
+        1,
+        &[(&second_user, 2, 2)],
+        TICKET_COST * 2,
+    )
+    .assert_ok();
+
+    // the second participant also has 2 tickets, confirmed normally
+    lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND);
+    lp_setup.confirm(&participants[1], 2).assert_ok();
+
+    lp_setup
+        .query(|sc| {
+            let imported_user = managed_address!(&second_user);
+            let confirming_user = managed_address!(&participants[1]);
+            assert_eq!(sc.nr_imported_legacy_chunks().get(), 2);
+            assert_eq!(
+                sc.nr_confirmed_tickets(&imported_user).get(),
+                sc.nr_confirmed_tickets(&confirming_user).get()
+            );
+            assert_eq!(
+                sc.user_ticket_payment(&imported_user).get(),
+                sc.user_ticket_payment(&confirming_user).get()
+            );
+
+            assert_eq!(sc.total_confirmed_tickets().get(), 5);
+            assert_eq!(sc.total_participants().get(), 3);
+            assert_eq!(
+                sc.total_payment_raised().get(),
+                managed_biguint!(TICKET_COST * 5)
+            );
+            assert_eq!(
+                sc.total_refundable_ticket_payment().get(),
+                managed_biguint!(TICKET_COST * 5)
+            );
+        })
+        .assert_ok();
+}
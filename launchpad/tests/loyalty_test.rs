@@ -0,0 +1,69 @@
+mod launchpad_setup;
+
+use launchpad_common::{loyalty::LoyaltyModule, permissions::Role};
+use launchpad_setup::{LaunchpadSetup, CLAIM_START_ROUND, NR_WINNING_TICKETS};
+use multiversx_sc::types::MultiValueEncoded;
+use multiversx_sc_scenario::{managed_address, rust_biguint};
+
+#[test]
+fn loyalty_points_test() {
+    let mut lp_setup = LaunchpadSetup::new(NR_WINNING_TICKETS);
+    let participants = lp_setup.participants.clone();
+    let config_manager = lp_setup.b_mock.create_user_account(&rust_biguint!(0));
+    lp_setup.add_role(Role::ConfigManager, &config_manager);
+
+    lp_setup
+        .call(&participants[0], |sc| {
+            sc.set_loyalty_points_config(10, 100);
+        })
+        .assert_user_error("Permission denied");
+
+    lp_setup
+        .call(&config_manager, |sc| {
+            sc.set_loyalty_points_config(10, 100);
+
+            let mut tiers = MultiValueEncoded::new();
+            tiers.push((100u64, 1usize).into());
+            tiers.push((125u64, 2usize).into());
+            sc.set_loyalty_bonus_tiers(tiers);
+        })
+        .assert_ok();
+
+    // no points accrued yet, so no bonus is granted
+    lp_setup
+        .call_owner(|sc| {
+            let mut users = MultiValueEncoded::new();
+            users.push(managed_address!(&participants[0]));
+            sc.grant_loyalty_bonus_tickets(users);
+
+            assert!(sc
+                .loyalty_bonus_tickets(&managed_address!(&participants[0]))
+                .is_empty());
+        })
+        .assert_ok();
+
+    // tickets 1, 2 and 4 are winning, out of 6 confirmed tickets
+    lp_setup.confirm_all();
+    lp_setup.filter_tickets().assert_ok();
+    lp_setup.select_winners_mock(&[1, 2, 4]).assert_ok();
+
+    lp_setup.b_mock.set_block_round(CLAIM_START_ROUND);
+    for p in participants.iter() {
+        lp_setup.claim_user(p).assert_ok();
+    }
+
+    lp_setup
+        .query(|sc| {
+            let expected_points = [110u64, 120, 130];
+            let expected_bonus_tickets = [1usize, 1, 2];
+            for (i, p) in participants.iter().enumerate() {
+                let points = sc.get_accumulated_loyalty_points(managed_address!(p));
+                assert_eq!(points, expected_points[i]);
+                assert_eq!(
+                    sc.get_loyalty_bonus_tickets(points),
+                    expected_bonus_tickets[i]
+                );
+            }
+        })
+        .assert_ok();
+}
@@ -0,0 +1,84 @@
+mod launchpad_setup;
+
+use launchpad_common::{
+    ongoing_operation::{OngoingOperationModule, OngoingOperationType},
+    tickets::TicketsModule,
+};
+use launchpad_setup::{LaunchpadSetup, NR_WINNING_TICKETS, WINNER_SELECTION_START_ROUND};
+use multiversx_sc_scenario::managed_buffer;
+
+#[test]
+fn ongoing_operation_status_test() {
+    let mut lp_setup = LaunchpadSetup::new(NR_WINNING_TICKETS);
+
+    lp_setup
+        .call_owner(|sc| {
+            let (name, processed, total, remaining_calls) =
+                sc.get_ongoing_operation_status().into_tuple();
+            assert_eq!(name, managed_buffer!(b"None"));
+            assert_eq!((processed, total, remaining_calls), (0, 0, 0));
+
+            // 2 out of the 6 tickets were filtered in one call
+            sc.save_progress(&OngoingOperationType::FilterTickets {
+                first_ticket_id_in_batch: 3,
+                nr_removed: 0,
+            });
+
+            let (name, processed, total, remaining_calls) =
+                sc.get_ongoing_operation_status().into_tuple();
+            assert_eq!(name, managed_buffer!(b"FilterTickets"));
+            assert_eq!((processed, total, remaining_calls), (2, 6, 2));
+
+            sc.clear_operation();
+            assert!(sc.operation_nr_calls().is_empty());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn unfinished_add_tickets_blocks_filtering_test() {
+    let mut lp_setup = LaunchpadSetup::new(NR_WINNING_TICKETS);
+
+    lp_setup
+        .call_owner(|sc| {
+            sc.save_progress(&OngoingOperationType::AddTickets {
+                nr_processed_chunks: 1,
+                nr_added_users: 2,
+            });
+        })
+        .assert_ok();
+
+    lp_setup
+        .b_mock
+        .set_block_round(WINNER_SELECTION_START_ROUND);
+    lp_setup
+        .filter_tickets()
+        .assert_user_error("Another ongoing operation is in progress");
+}
+
+#[test]
+fn min_gas_to_save_progress_test() {
+    let mut lp_setup = LaunchpadSetup::new(NR_WINNING_TICKETS);
+    let participants = lp_setup.participants.clone();
+
+    lp_setup
+        .call_owner(|sc| {
+            sc.set_min_gas_to_save_progress(500_000);
+        })
+        .assert_user_error("Invalid min gas value");
+
+    lp_setup
+        .call_owner(|sc| {
+            assert_eq!(sc.get_min_gas_to_save_progress(), 10_000_000);
+
+            sc.set_min_gas_to_save_progress(20_000_000);
+            assert_eq!(sc.get_min_gas_to_save_progress(), 20_000_000);
+        })
+        .assert_ok();
+
+    lp_setup
+        .call(&participants[0], |sc| {
+            sc.set_min_gas_to_save_progress(20_000_000);
+        })
+        .assert_user_error("Endpoint can only be called by owner");
+}
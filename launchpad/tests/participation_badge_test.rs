@@ -0,0 +1,67 @@
+mod launchpad_setup;
+
+use launchpad_common::participation_badge::ParticipationBadgeModule;
+use launchpad_setup::{
+    LaunchpadSetup, CLAIM_START_ROUND, CONFIRM_START_ROUND, NR_WINNING_TICKETS,
+    WINNER_SELECTION_START_ROUND,
+};
+use multiversx_sc::codec::Empty;
+use multiversx_sc::storage::mappers::StorageTokenWrapper;
+use multiversx_sc::types::EsdtLocalRole;
+use multiversx_sc_scenario::{managed_token_id, rust_biguint};
+
+#[test]
+fn participation_badge_test() {
+    let mut lp_setup = LaunchpadSetup::new(NR_WINNING_TICKETS);
+    let participants = lp_setup.participants.clone();
+    let badge_token_id = b"BADGE-123456";
+
+    lp_setup.b_mock.set_esdt_local_roles(
+        lp_setup.lp_wrapper.address_ref(),
+        badge_token_id,
+        &[EsdtLocalRole::NftCreate, EsdtLocalRole::NftAddQuantity],
+    );
+    lp_setup
+        .call_owner(|sc| {
+            sc.participation_badge()
+                .set_token_id(managed_token_id!(badge_token_id));
+            sc.participation_badge_transfer_role_set().set(true);
+        })
+        .assert_ok();
+
+    // the third user does not confirm
+    lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND);
+    lp_setup.confirm(&participants[0], 1).assert_ok();
+    lp_setup.confirm(&participants[1], 2).assert_ok();
+
+    lp_setup
+        .b_mock
+        .set_block_round(WINNER_SELECTION_START_ROUND);
+    lp_setup.filter_tickets().assert_ok();
+    lp_setup.select_winners().assert_ok();
+
+    lp_setup.b_mock.set_block_round(CLAIM_START_ROUND);
+    for p in participants.iter() {
+        lp_setup.claim_user(p).assert_ok();
+    }
+
+    // all badges share the first nonce
+    for p in &participants[..2] {
+        lp_setup
+            .b_mock
+            .check_nft_balance::<Empty>(p, badge_token_id, 1, &rust_biguint!(1), None);
+    }
+    lp_setup.b_mock.check_nft_balance::<Empty>(
+        &participants[2],
+        badge_token_id,
+        1,
+        &rust_biguint!(0),
+        None,
+    );
+
+    lp_setup
+        .query(|sc| {
+            assert_eq!(sc.participation_badge_nonce().get(), 1);
+        })
+        .assert_ok();
+}
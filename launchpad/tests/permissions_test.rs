@@ -0,0 +1,148 @@
+mod launchpad_setup;
+
+use launchpad::Launchpad;
+use launchpad_common::{
+    blacklist::{BlacklistModule, BlacklistReason},
+    permissions::{PermissionsModule, Role},
+    setup::SetupModule,
+    LaunchpadMain,
+};
+use launchpad_setup::{LaunchpadSetup, NR_WINNING_TICKETS, TICKET_COST};
+use multiversx_sc::types::{EgldOrEsdtTokenIdentifier, MultiValueEncoded};
+use multiversx_sc_scenario::{managed_address, managed_biguint, rust_biguint};
+
+#[test]
+fn role_based_permissions_test() {
+    let mut lp_setup = LaunchpadSetup::new(NR_WINNING_TICKETS);
+    let owner_address = lp_setup.owner_address.clone();
+    let participants = lp_setup.participants.clone();
+    let blacklist_manager = lp_setup.b_mock.create_user_account(&rust_biguint!(0));
+
+    // Only the owner or an admin may grant roles
+    lp_setup
+        .call(&participants[0], |sc| {
+            let mut addresses = MultiValueEncoded::new();
+            addresses.push(managed_address!(&participants[0]));
+            sc.add_role(Role::BlacklistManager, addresses);
+        })
+        .assert_error(4, "Permission denied");
+
+    lp_setup.add_role(Role::BlacklistManager, &blacklist_manager);
+
+    // Blacklist manager cannot change the ticket price
+    lp_setup
+        .call(&blacklist_manager, |sc| {
+            sc.set_ticket_price(
+                EgldOrEsdtTokenIdentifier::egld(),
+                managed_biguint!(TICKET_COST * 2),
+            );
+        })
+        .assert_error(4, "Permission denied");
+
+    // Blacklist manager can blacklist users
+    lp_setup
+        .call(&blacklist_manager, |sc| {
+            let mut blacklist = MultiValueEncoded::new();
+            blacklist.push(managed_address!(&participants[0]));
+            sc.add_users_to_blacklist_endpoint(BlacklistReason::Unspecified, blacklist);
+        })
+        .assert_ok();
+
+    lp_setup
+        .query(|sc| {
+            assert!(sc.is_user_blacklisted(&managed_address!(&participants[0])));
+            assert!(sc.has_role(
+                &managed_address!(&blacklist_manager),
+                Role::BlacklistManager
+            ));
+            assert!(!sc.has_role(&managed_address!(&blacklist_manager), Role::Finance));
+            assert!(sc.has_role(&managed_address!(&owner_address), Role::Finance));
+        })
+        .assert_ok();
+
+    lp_setup
+        .call_owner(|sc| {
+            let mut addresses = MultiValueEncoded::new();
+            addresses.push(managed_address!(&blacklist_manager));
+            sc.remove_role(Role::BlacklistManager, addresses);
+        })
+        .assert_ok();
+
+    lp_setup
+        .call(&blacklist_manager, |sc| {
+            let mut blacklist = MultiValueEncoded::new();
+            blacklist.push(managed_address!(&participants[1]));
+            sc.add_users_to_blacklist_endpoint(BlacklistReason::Unspecified, blacklist);
+        })
+        .assert_error(4, "Permission denied");
+}
+
+#[test]
+fn support_addresses_test() {
+    let mut lp_setup = LaunchpadSetup::new(NR_WINNING_TICKETS);
+    let first_support = lp_setup.b_mock.create_user_account(&rust_biguint!(0));
+    let second_support = lp_setup.b_mock.create_user_account(&rust_biguint!(0));
+
+    for support in [&first_support, &second_support] {
+        lp_setup
+            .call_owner(|sc| {
+                sc.propose_support_address(managed_address!(support));
+            })
+            .assert_ok();
+
+        lp_setup
+            .call(support, |sc| {
+                sc.accept_support_address();
+            })
+            .assert_ok();
+    }
+
+    // the deployer is the first support address
+    lp_setup
+        .query(|sc| {
+            let support_addresses: Vec<_> = sc.get_support_addresses(1, 5).into_iter().collect();
+            assert_eq!(
+                support_addresses,
+                vec![
+                    managed_address!(&first_support),
+                    managed_address!(&second_support)
+                ]
+            );
+            assert!(sc.has_role(&managed_address!(&first_support), Role::Finance));
+            assert!(sc.has_role(&managed_address!(&second_support), Role::Finance));
+        })
+        .assert_ok();
+
+    // revoking one support address does not affect the other
+    lp_setup
+        .call_owner(|sc| {
+            sc.remove_support_address(managed_address!(&first_support));
+        })
+        .assert_ok();
+
+    lp_setup
+        .call_owner(|sc| {
+            sc.remove_support_address(managed_address!(&first_support));
+        })
+        .assert_user_error("Not a support address");
+
+    lp_setup
+        .query(|sc| {
+            assert!(!sc.is_support_address(managed_address!(&first_support)));
+            assert!(!sc.has_role(&managed_address!(&first_support), Role::Finance));
+            assert!(sc.has_role(&managed_address!(&second_support), Role::Finance));
+        })
+        .assert_ok();
+
+    // the single support address of older contracts is kept on upgrade
+    lp_setup
+        .call_owner(|sc| {
+            sc.legacy_support_address()
+                .set(managed_address!(&first_support));
+            sc.storage_version().set(1);
+            sc.upgrade();
+            assert!(sc.legacy_support_address().is_empty());
+            assert!(sc.is_support_address(managed_address!(&first_support)));
+        })
+        .assert_ok();
+}
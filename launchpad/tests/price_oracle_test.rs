@@ -0,0 +1,134 @@
+mod launchpad_setup;
+
+use launchpad_common::{
+    config::ConfigModule, price_oracle::PriceOracleModule,
+    user_interactions::UserInteractionsModule,
+};
+use launchpad_setup::{
+    price_aggregator_mock::{PriceAggregatorMock, PRICE_FEED_TIMESTAMP},
+    LaunchpadSetup, CONFIRM_START_ROUND, NR_WINNING_TICKETS,
+};
+use multiversx_sc::types::{Address, ManagedBuffer, OptionalValue};
+use multiversx_sc_scenario::{
+    managed_address, managed_biguint, rust_biguint, testing_framework::TxResult,
+};
+
+const USD_TICKET_PRICE: u64 = 20;
+const MAX_PRICE_AGE_SECONDS: u64 = 100;
+// USD_TICKET_PRICE divided by the mock's price
+const QUOTED_TICKET_PRICE: u64 = 5;
+
+fn set_usd_ticket_pricing(lp_setup: &mut LaunchpadSetup) {
+    let oracle_wrapper = lp_setup.b_mock.create_sc_account(
+        &rust_biguint!(0),
+        None,
+        PriceAggregatorMock::new,
+        "price aggregator mock",
+    );
+    let oracle_address = oracle_wrapper.address_ref().clone();
+
+    lp_setup
+        .call_owner(|sc| {
+            sc.set_usd_ticket_pricing(
+                managed_address!(&oracle_address),
+                ManagedBuffer::from(b"EGLD"),
+                0,
+                managed_biguint!(USD_TICKET_PRICE),
+                MAX_PRICE_AGE_SECONDS,
+            );
+        })
+        .assert_ok();
+}
+
+fn confirm_at_quoted_price(
+    lp_setup: &mut LaunchpadSetup,
+    caller: &Address,
+    nr_tickets: usize,
+) -> TxResult {
+    lp_setup.b_mock.execute_tx(
+        caller,
+        &lp_setup.lp_wrapper,
+        &rust_biguint!(QUOTED_TICKET_PRICE * nr_tickets as u64),
+        |sc| {
+            sc.confirm_tickets(OptionalValue::Some(nr_tickets), OptionalValue::None);
+        },
+    )
+}
+
+#[test]
+fn usd_ticket_price_quote_test() {
+    let mut lp_setup = LaunchpadSetup::new(NR_WINNING_TICKETS);
+    let participants = lp_setup.participants.clone();
+    set_usd_ticket_pricing(&mut lp_setup);
+
+    lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND);
+    lp_setup
+        .b_mock
+        .set_block_timestamp(PRICE_FEED_TIMESTAMP + MAX_PRICE_AGE_SECONDS);
+
+    lp_setup
+        .query(|sc| {
+            assert_eq!(
+                sc.get_usd_ticket_price_quote().into_option(),
+                Some(managed_biguint!(QUOTED_TICKET_PRICE))
+            );
+            assert!(!sc.usd_ticket_price_fixed().get());
+        })
+        .assert_ok();
+
+    // the configured price is not used once USD pricing is set
+    lp_setup
+        .confirm(&participants[0], 1)
+        .assert_user_error("Wrong amount sent");
+
+    confirm_at_quoted_price(&mut lp_setup, &participants[1], 2).assert_ok();
+
+    lp_setup
+        .query(|sc| {
+            assert_eq!(
+                sc.ticket_price().get().amount,
+                managed_biguint!(QUOTED_TICKET_PRICE)
+            );
+            assert!(sc.usd_ticket_price_fixed().get());
+            assert_eq!(
+                sc.total_payment_raised().get(),
+                managed_biguint!(QUOTED_TICKET_PRICE * 2)
+            );
+        })
+        .assert_ok();
+
+    confirm_at_quoted_price(&mut lp_setup, &participants[0], 1).assert_ok();
+}
+
+#[test]
+fn usd_ticket_price_stale_feed_test() {
+    let mut lp_setup = LaunchpadSetup::new(NR_WINNING_TICKETS);
+    let participants = lp_setup.participants.clone();
+    set_usd_ticket_pricing(&mut lp_setup);
+
+    lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND);
+    lp_setup
+        .b_mock
+        .set_block_timestamp(PRICE_FEED_TIMESTAMP + MAX_PRICE_AGE_SECONDS + 1);
+
+    lp_setup
+        .query(|sc| {
+            assert_eq!(sc.get_usd_ticket_price_quote().into_option(), None);
+        })
+        .assert_ok();
+
+    // no silent fallback to the configured price
+    lp_setup
+        .confirm(&participants[0], 1)
+        .assert_user_error("USD price feed missing or stale");
+
+    lp_setup.b_mock.set_block_timestamp(PRICE_FEED_TIMESTAMP);
+    confirm_at_quoted_price(&mut lp_setup, &participants[1], 2).assert_ok();
+
+    // the feed is still checked once the price is set
+    lp_setup
+        .b_mock
+        .set_block_timestamp(PRICE_FEED_TIMESTAMP + MAX_PRICE_AGE_SECONDS + 1);
+    confirm_at_quoted_price(&mut lp_setup, &participants[0], 1)
+        .assert_user_error("USD price feed missing or stale");
+}
@@ -0,0 +1,119 @@
+mod launchpad_setup;
+
+use launchpad_common::{
+    permissions::Role, referral::ReferralModule, user_interactions::UserInteractionsModule,
+};
+use launchpad_setup::{
+    LaunchpadSetup, CLAIM_START_ROUND, CONFIRM_START_ROUND, NR_WINNING_TICKETS, TICKET_COST,
+    WINNER_SELECTION_START_ROUND,
+};
+use multiversx_sc::types::OptionalValue;
+use multiversx_sc_scenario::{managed_address, managed_biguint, rust_biguint};
+
+#[test]
+fn referral_test() {
+    let mut lp_setup = LaunchpadSetup::new(NR_WINNING_TICKETS);
+    let participants = lp_setup.participants.clone();
+    let finance = lp_setup.b_mock.create_user_account(&rust_biguint!(100));
+    lp_setup.add_role(Role::Finance, &finance);
+
+    lp_setup
+        .call_owner(|sc| {
+            sc.set_referral_reward_percentage(1_000);
+        })
+        .assert_ok();
+
+    lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND);
+    let confirm_with_referrer = |lp_setup: &mut LaunchpadSetup,
+                                 caller_index: usize,
+                                 nr_tickets: usize,
+                                 referrer_index: usize| {
+        let referrer = participants[referrer_index].clone();
+        lp_setup.b_mock.execute_tx(
+            &participants[caller_index],
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(TICKET_COST * nr_tickets as u64),
+            |sc| {
+                sc.confirm_tickets(
+                    OptionalValue::Some(nr_tickets),
+                    OptionalValue::Some(managed_address!(&referrer)),
+                );
+            },
+        )
+    };
+
+    confirm_with_referrer(&mut lp_setup, 0, 1, 0).assert_user_error("Cannot refer yourself");
+    confirm_with_referrer(&mut lp_setup, 0, 1, 2).assert_ok();
+    confirm_with_referrer(&mut lp_setup, 1, 2, 2).assert_ok();
+    confirm_with_referrer(&mut lp_setup, 2, 3, 0).assert_user_error("Circular referral");
+    lp_setup.confirm(&participants[2], 3).assert_ok();
+
+    lp_setup
+        .query(|sc| {
+            assert_eq!(
+                sc.user_referrer(&managed_address!(&participants[0])).get(),
+                managed_address!(&participants[2])
+            );
+            assert_eq!(
+                sc.nr_referred_users(&managed_address!(&participants[2]))
+                    .get(),
+                2
+            );
+        })
+        .assert_ok();
+
+    // tickets 1, 2 and 4 are winning, so both referred users have one winning ticket
+    lp_setup
+        .b_mock
+        .set_block_round(WINNER_SELECTION_START_ROUND);
+    lp_setup.filter_tickets().assert_ok();
+    lp_setup.select_winners_mock(&[1, 2, 4]).assert_ok();
+
+    lp_setup.b_mock.set_block_round(CLAIM_START_ROUND);
+    for p in participants.iter() {
+        lp_setup.claim_user(p).assert_ok();
+    }
+
+    lp_setup
+        .call(&participants[2], |sc| {
+            sc.claim_referral_rewards();
+        })
+        .assert_user_error("Not enough referral rewards deposited");
+
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &participants[0],
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(5),
+            |sc| {
+                sc.deposit_referral_rewards();
+            },
+        )
+        .assert_user_error("Permission denied");
+    lp_setup
+        .b_mock
+        .execute_tx(&finance, &lp_setup.lp_wrapper, &rust_biguint!(5), |sc| {
+            sc.deposit_referral_rewards();
+        })
+        .assert_ok();
+
+    // 10% of the 2 winning tickets payment
+    let referrer_balance_before = lp_setup.b_mock.get_egld_balance(&participants[2]);
+    lp_setup
+        .call(&participants[2], |sc| {
+            sc.claim_referral_rewards();
+
+            assert_eq!(sc.referral_reward_pool().get(), managed_biguint!(3));
+        })
+        .assert_ok();
+    lp_setup
+        .b_mock
+        .check_egld_balance(&participants[2], &(referrer_balance_before + 2u32));
+
+    lp_setup
+        .call(&participants[2], |sc| {
+            sc.claim_referral_rewards();
+        })
+        .assert_user_error("No referral rewards");
+}
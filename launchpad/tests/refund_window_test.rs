@@ -0,0 +1,123 @@
+mod launchpad_setup;
+
+use launchpad_common::{
+    action_pause::{ActionPauseModule, PausableAction},
+    config::ConfigModule,
+    launch_stage::{CurrentStage, LaunchStageModule},
+    setup::SetupModule,
+    tickets::TicketsModule,
+    user_interactions::UserInteractionsModule,
+};
+use launchpad_setup::{
+    LaunchpadSetup, CLAIM_START_ROUND, LAUNCHPAD_TOKENS_PER_TICKET, LAUNCHPAD_TOKEN_ID,
+    MAX_TIER_TICKETS, NR_WINNING_TICKETS, TICKET_COST, WINNER_SELECTION_START_ROUND,
+};
+use multiversx_sc_scenario::{managed_biguint, rust_biguint};
+
+#[test]
+fn refund_window_test() {
+    let mut lp_setup = LaunchpadSetup::new(NR_WINNING_TICKETS);
+    let participants = lp_setup.participants.clone();
+    let refund_window_start_round = WINNER_SELECTION_START_ROUND + 2;
+
+    lp_setup
+        .call_owner(|sc| {
+            sc.set_refund_window_start_round(CLAIM_START_ROUND);
+        })
+        .assert_user_error("Refund window must be between winner selection and claim start round");
+
+    lp_setup
+        .call_owner(|sc| {
+            sc.set_refund_window_start_round(refund_window_start_round);
+        })
+        .assert_ok();
+
+    lp_setup.confirm_all();
+    lp_setup.filter_tickets().assert_ok();
+    lp_setup.select_winners_mock(&[1, 2, 4]).assert_ok();
+
+    lp_setup
+        .call(&participants[2], |sc| {
+            sc.claim_refund();
+        })
+        .assert_user_error("Not in refund period");
+
+    lp_setup.b_mock.set_block_round(refund_window_start_round);
+    lp_setup
+        .query(|sc| {
+            let (stage, rounds_remaining) = sc.get_current_stage().into_tuple();
+            assert_eq!(stage, CurrentStage::Refund);
+            assert_eq!(
+                rounds_remaining,
+                CLAIM_START_ROUND - refund_window_start_round
+            );
+        })
+        .assert_ok();
+
+    // each user won 1 ticket, so the first user has nothing to refund
+    lp_setup
+        .call(&participants[0], |sc| {
+            sc.claim_refund();
+        })
+        .assert_user_error("No tickets to refund");
+
+    // pausing the claims also pauses the refunds
+    lp_setup
+        .call_owner(|sc| {
+            sc.pause_action(PausableAction::Claims);
+        })
+        .assert_ok();
+
+    lp_setup
+        .call(&participants[2], |sc| {
+            sc.claim_refund();
+        })
+        .assert_user_error("Claims are paused");
+
+    lp_setup
+        .call_owner(|sc| {
+            sc.unpause_action(PausableAction::Claims);
+        })
+        .assert_ok();
+
+    lp_setup
+        .call(&participants[2], |sc| {
+            sc.claim_refund();
+        })
+        .assert_ok();
+
+    // the two refunded tickets are no longer counted as confirmed
+    lp_setup
+        .query(|sc| {
+            assert_eq!(sc.total_confirmed_tickets().get(), 4);
+            assert_eq!(
+                sc.total_payment_raised().get(),
+                managed_biguint!(TICKET_COST * 4)
+            );
+        })
+        .assert_ok();
+
+    let base_user_balance = rust_biguint!(TICKET_COST * MAX_TIER_TICKETS as u64);
+    let remaining_balance = &base_user_balance - TICKET_COST;
+    lp_setup
+        .b_mock
+        .check_egld_balance(&participants[2], &remaining_balance);
+
+    lp_setup
+        .call(&participants[2], |sc| {
+            sc.claim_refund();
+        })
+        .assert_user_error("No tickets to refund");
+
+    // only the launchpad tokens are left to claim
+    lp_setup.b_mock.set_block_round(CLAIM_START_ROUND);
+    lp_setup.claim_user(&participants[2]).assert_ok();
+    lp_setup
+        .b_mock
+        .check_egld_balance(&participants[2], &remaining_balance);
+    lp_setup.b_mock.check_esdt_balance(
+        &participants[2],
+        LAUNCHPAD_TOKEN_ID,
+        &rust_biguint!(LAUNCHPAD_TOKENS_PER_TICKET),
+    );
+}
@@ -0,0 +1,30 @@
+mod launchpad_setup;
+
+use launchpad_common::user_interactions::UserInteractionsModule;
+use launchpad_setup::{LaunchpadSetup, CONFIRM_START_ROUND, NR_WINNING_TICKETS, TICKET_COST};
+use multiversx_sc::types::OptionalValue;
+use multiversx_sc_scenario::{managed_address, managed_buffer, rust_biguint};
+
+#[test]
+fn relayed_confirmation_permission_test() {
+    let mut lp_setup = LaunchpadSetup::new(NR_WINNING_TICKETS);
+    let participants = lp_setup.participants.clone();
+
+    lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND);
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &participants[1],
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(TICKET_COST),
+            |sc| {
+                sc.confirm_tickets_for(
+                    managed_address!(&participants[0]),
+                    1,
+                    managed_buffer!(&[0u8; 64]),
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_user_error("Permission denied");
+}
@@ -0,0 +1,90 @@
+mod launchpad_setup;
+
+use launchpad_common::{reward_bundle::RewardBundleModule, setup::SetupModule};
+use launchpad_setup::{
+    LaunchpadSetup, CLAIM_START_ROUND, CONFIRM_START_ROUND, LAUNCHPAD_TOKENS_PER_TICKET,
+    NR_WINNING_TICKETS, WINNER_SELECTION_START_ROUND,
+};
+use multiversx_sc::codec::Empty;
+use multiversx_sc::types::{EsdtTokenPayment, ManagedVec, MultiValueEncoded};
+use multiversx_sc_scenario::{managed_biguint, managed_token_id, rust_biguint};
+
+#[test]
+fn extra_rewards_test() {
+    let mut lp_setup = LaunchpadSetup::new(NR_WINNING_TICKETS);
+    let participants = lp_setup.participants.clone();
+    let owner_address = lp_setup.owner_address.clone();
+    let reward_token_id = b"REWARD-123456";
+    let reward_per_ticket = 5u64;
+
+    // the launchpad tokens were already deposited during setup
+    lp_setup
+        .call_owner(|sc| {
+            let mut rewards = MultiValueEncoded::new();
+            rewards.push(
+                (
+                    managed_token_id!(reward_token_id),
+                    1u64,
+                    managed_biguint!(reward_per_ticket),
+                )
+                    .into(),
+            );
+            sc.set_extra_rewards_per_winning_ticket(rewards);
+        })
+        .assert_user_error("Tokens already deposited");
+
+    // simulate a deposit which included the extra rewards
+    lp_setup
+        .call_owner(|sc| {
+            sc.extra_rewards_per_winning_ticket()
+                .set(ManagedVec::from_single_item(EsdtTokenPayment::new(
+                    managed_token_id!(reward_token_id),
+                    1,
+                    managed_biguint!(reward_per_ticket),
+                )));
+            sc.set_dynamic_winning_tickets(true);
+
+            let reward_bundle = sc.get_reward_bundle_per_winning_ticket();
+            assert_eq!(reward_bundle.len(), 2);
+            assert_eq!(
+                reward_bundle.get(0).amount,
+                managed_biguint!(LAUNCHPAD_TOKENS_PER_TICKET)
+            );
+        })
+        .assert_ok();
+    lp_setup.b_mock.set_nft_balance(
+        lp_setup.lp_wrapper.address_ref(),
+        reward_token_id,
+        1,
+        &rust_biguint!(reward_per_ticket * NR_WINNING_TICKETS as u64),
+        &Empty,
+    );
+
+    // only one ticket is confirmed, so the rewards of the other two are released
+    lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND);
+    lp_setup.confirm(&participants[0], 1).assert_ok();
+
+    lp_setup
+        .b_mock
+        .set_block_round(WINNER_SELECTION_START_ROUND);
+    lp_setup.filter_tickets().assert_ok();
+    lp_setup.b_mock.check_nft_balance::<Empty>(
+        &owner_address,
+        reward_token_id,
+        1,
+        &rust_biguint!(reward_per_ticket * 2),
+        None,
+    );
+
+    lp_setup.select_winners().assert_ok();
+
+    lp_setup.b_mock.set_block_round(CLAIM_START_ROUND);
+    lp_setup.claim_user(&participants[0]).assert_ok();
+    lp_setup.b_mock.check_nft_balance::<Empty>(
+        &participants[0],
+        reward_token_id,
+        1,
+        &rust_biguint!(reward_per_ticket),
+        None,
+    );
+}
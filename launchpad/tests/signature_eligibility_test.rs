@@ -0,0 +1,66 @@
+mod launchpad_setup;
+
+use launchpad_common::{
+    signature_eligibility::SignatureEligibilityModule, tickets::TicketsModule,
+    user_interactions::UserInteractionsModule,
+};
+use launchpad_setup::{LaunchpadSetup, CONFIRM_START_ROUND, NR_WINNING_TICKETS, TICKET_COST};
+use multiversx_sc::types::OptionalValue;
+use multiversx_sc_scenario::{managed_address, managed_buffer, rust_biguint};
+
+#[test]
+fn signature_eligibility_test() {
+    let mut lp_setup = LaunchpadSetup::new(NR_WINNING_TICKETS);
+    let participants = lp_setup.participants.clone();
+    let new_user = lp_setup
+        .b_mock
+        .create_user_account(&rust_biguint!(TICKET_COST));
+
+    lp_setup
+        .call_owner(|sc| {
+            sc.set_eligibility_signer(managed_buffer!(b"short key"));
+        })
+        .assert_user_error("Invalid public key");
+
+    lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND);
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &new_user,
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(TICKET_COST),
+            |sc| {
+                sc.confirm_tickets_with_signature(
+                    1,
+                    1,
+                    managed_buffer!(&[0u8; 64]),
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_user_error("Eligibility signer not set");
+
+    // whitelisted users are not checked against the signature
+    lp_setup
+        .b_mock
+        .execute_tx(
+            &participants[0],
+            &lp_setup.lp_wrapper,
+            &rust_biguint!(TICKET_COST),
+            |sc| {
+                sc.confirm_tickets_with_signature(
+                    1,
+                    1,
+                    managed_buffer!(&[0u8; 64]),
+                    OptionalValue::None,
+                );
+
+                assert_eq!(
+                    sc.nr_confirmed_tickets(&managed_address!(&participants[0]))
+                        .get(),
+                    1
+                );
+            },
+        )
+        .assert_ok();
+}
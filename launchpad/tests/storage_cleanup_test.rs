@@ -0,0 +1,63 @@
+mod launchpad_setup;
+
+use launchpad_common::{
+    setup::SetupModule, storage_cleanup::StorageCleanupModule, tickets::TicketsModule,
+    unclaimed_sweep::UnclaimedSweepModule,
+};
+use launchpad_setup::{LaunchpadSetup, CLAIM_START_ROUND, NR_WINNING_TICKETS};
+use multiversx_sc::types::OperationCompletionStatus;
+use multiversx_sc_scenario::{managed_address, rust_biguint};
+
+#[test]
+fn cleanup_storage_test() {
+    let mut lp_setup = LaunchpadSetup::new(NR_WINNING_TICKETS);
+    let participants = lp_setup.participants.clone();
+    let treasury = lp_setup.b_mock.create_user_account(&rust_biguint!(0));
+
+    lp_setup.confirm_all();
+    lp_setup.filter_tickets().assert_ok();
+    lp_setup.select_winners_mock(&[1, 2, 4]).assert_ok();
+
+    lp_setup.b_mock.set_block_round(CLAIM_START_ROUND);
+    let claim_deadline_round = CLAIM_START_ROUND + 5;
+    lp_setup
+        .call_owner(|sc| {
+            sc.set_claim_deadline_round(claim_deadline_round);
+            sc.set_treasury_address(managed_address!(&treasury));
+        })
+        .assert_ok();
+    lp_setup.claim_user(&participants[0]).assert_ok();
+
+    lp_setup
+        .call(&participants[0], |sc| {
+            let _ = sc.cleanup_storage();
+        })
+        .assert_error(4, "Claim deadline has not passed yet");
+
+    lp_setup.b_mock.set_block_round(claim_deadline_round);
+    lp_setup
+        .call(&participants[0], |sc| {
+            let _ = sc.cleanup_storage();
+        })
+        .assert_error(4, "Unclaimed tickets must be swept first");
+
+    lp_setup
+        .call_owner(|sc| {
+            let _ = sc.sweep_unclaimed();
+        })
+        .assert_ok();
+
+    lp_setup
+        .call(&participants[0], |sc| {
+            let result = sc.cleanup_storage();
+            assert_eq!(result, OperationCompletionStatus::Completed);
+            assert!(sc.storage_cleaned_up().get());
+
+            assert!(sc.ticket_status_chunk(0).is_empty());
+            for ticket_id in 1..=sc.last_ticket_id().get() {
+                assert!(sc.ticket_pos_to_id(ticket_id).is_empty());
+                assert!(sc.ticket_batch(ticket_id).is_empty());
+            }
+        })
+        .assert_ok();
+}
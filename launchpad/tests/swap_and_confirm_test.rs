@@ -0,0 +1,207 @@
+mod launchpad_setup;
+
+use launchpad_common::{
+    setup::SetupModule, swap_and_confirm::SwapAndConfirmModule, tickets::TicketsModule,
+};
+use launchpad_setup::{LaunchpadSetup, CONFIRM_START_ROUND, NR_WINNING_TICKETS, TICKET_COST};
+use multiversx_sc::types::{
+    EgldOrEsdtTokenIdentifier, EsdtTokenPayment, IgnoreValue, ManagedAsyncCallError,
+    ManagedAsyncCallResult, MultiValueEncoded,
+};
+use multiversx_sc_scenario::{
+    managed_address, managed_biguint, managed_buffer, managed_token_id, rust_biguint,
+};
+
+static TICKET_PAYMENT_TOKEN_ID: &[u8] = b"USDC-123456";
+static SWAP_TOKEN_ID: &[u8] = b"WEGLD-123456";
+const SWAP_PAYMENT_AMOUNT: u64 = 50;
+
+#[test]
+fn swap_and_confirm_test() {
+    let mut lp_setup = LaunchpadSetup::new(NR_WINNING_TICKETS);
+    let participants = lp_setup.participants.clone();
+    let lp_address = lp_setup.lp_wrapper.address_ref().clone();
+    let router = lp_setup.b_mock.create_sc_account(
+        &rust_biguint!(0),
+        None,
+        launchpad::contract_obj,
+        "router mock",
+    );
+    let router_address = router.address_ref().clone();
+    lp_setup.b_mock.set_esdt_balance(
+        &participants[1],
+        SWAP_TOKEN_ID,
+        &rust_biguint!(SWAP_PAYMENT_AMOUNT),
+    );
+    lp_setup.b_mock.set_esdt_balance(
+        &router_address,
+        TICKET_PAYMENT_TOKEN_ID,
+        &rust_biguint!(TICKET_COST * 10),
+    );
+
+    lp_setup
+        .call(&participants[0], |sc| {
+            sc.set_swap_router_address(managed_address!(&router_address));
+        })
+        .assert_error(4, "Permission denied");
+
+    lp_setup
+        .call(&participants[0], |sc| {
+            let mut tokens = MultiValueEncoded::new();
+            tokens.push(
+                (
+                    managed_token_id!(SWAP_TOKEN_ID),
+                    managed_address!(&router_address),
+                )
+                    .into(),
+            );
+            sc.add_swap_tokens(tokens);
+        })
+        .assert_error(4, "Permission denied");
+
+    lp_setup
+        .call_owner(|sc| {
+            sc.set_ticket_price(
+                EgldOrEsdtTokenIdentifier::esdt(managed_token_id!(TICKET_PAYMENT_TOKEN_ID)),
+                managed_biguint!(TICKET_COST),
+            );
+        })
+        .assert_ok();
+
+    lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND);
+    lp_setup
+        .b_mock
+        .execute_esdt_transfer(
+            &participants[1],
+            &lp_setup.lp_wrapper,
+            SWAP_TOKEN_ID,
+            0,
+            &rust_biguint!(SWAP_PAYMENT_AMOUNT),
+            |sc| {
+                sc.swap_and_confirm_tickets(2);
+            },
+        )
+        .assert_user_error("Swap router not set");
+
+    lp_setup
+        .call_owner(|sc| {
+            sc.set_swap_router_address(managed_address!(&router_address));
+        })
+        .assert_ok();
+
+    lp_setup
+        .b_mock
+        .execute_esdt_transfer(
+            &participants[1],
+            &lp_setup.lp_wrapper,
+            SWAP_TOKEN_ID,
+            0,
+            &rust_biguint!(SWAP_PAYMENT_AMOUNT),
+            |sc| {
+                sc.swap_and_confirm_tickets(2);
+            },
+        )
+        .assert_user_error("Token not accepted for swap");
+
+    // a failed swap returns the original payment, which is refunded to the user
+    lp_setup
+        .b_mock
+        .set_esdt_balance(&participants[1], SWAP_TOKEN_ID, &rust_biguint!(0));
+    lp_setup.b_mock.set_esdt_balance(
+        &lp_address,
+        SWAP_TOKEN_ID,
+        &rust_biguint!(SWAP_PAYMENT_AMOUNT),
+    );
+    lp_setup
+        .call(&router_address, |sc| {
+            sc.swap_and_confirm_callback(
+                managed_address!(&participants[1]),
+                2,
+                EsdtTokenPayment::new(
+                    managed_token_id!(SWAP_TOKEN_ID),
+                    0,
+                    managed_biguint!(SWAP_PAYMENT_AMOUNT),
+                ),
+                ManagedAsyncCallResult::Err(ManagedAsyncCallError {
+                    err_code: 4,
+                    err_msg: managed_buffer!(b"swap failed"),
+                }),
+            );
+        })
+        .assert_ok();
+    lp_setup.b_mock.check_esdt_balance(
+        &participants[1],
+        SWAP_TOKEN_ID,
+        &rust_biguint!(SWAP_PAYMENT_AMOUNT),
+    );
+
+    // the tickets are confirmed and the extra output is refunded
+    lp_setup
+        .b_mock
+        .execute_esdt_transfer(
+            &router_address,
+            &lp_setup.lp_wrapper,
+            TICKET_PAYMENT_TOKEN_ID,
+            0,
+            &rust_biguint!(TICKET_COST * 2 + 3),
+            |sc| {
+                sc.swap_and_confirm_callback(
+                    managed_address!(&participants[1]),
+                    2,
+                    EsdtTokenPayment::new(
+                        managed_token_id!(SWAP_TOKEN_ID),
+                        0,
+                        managed_biguint!(SWAP_PAYMENT_AMOUNT),
+                    ),
+                    ManagedAsyncCallResult::Ok(IgnoreValue),
+                );
+
+                assert_eq!(
+                    sc.nr_confirmed_tickets(&managed_address!(&participants[1]))
+                        .get(),
+                    2
+                );
+            },
+        )
+        .assert_ok();
+    lp_setup.b_mock.check_esdt_balance(
+        &participants[1],
+        TICKET_PAYMENT_TOKEN_ID,
+        &rust_biguint!(3),
+    );
+
+    // tickets which may no longer be confirmed get the whole swap output refunded
+    lp_setup
+        .b_mock
+        .execute_esdt_transfer(
+            &router_address,
+            &lp_setup.lp_wrapper,
+            TICKET_PAYMENT_TOKEN_ID,
+            0,
+            &rust_biguint!(TICKET_COST),
+            |sc| {
+                sc.swap_and_confirm_callback(
+                    managed_address!(&participants[1]),
+                    1,
+                    EsdtTokenPayment::new(
+                        managed_token_id!(SWAP_TOKEN_ID),
+                        0,
+                        managed_biguint!(SWAP_PAYMENT_AMOUNT),
+                    ),
+                    ManagedAsyncCallResult::Ok(IgnoreValue),
+                );
+
+                assert_eq!(
+                    sc.nr_confirmed_tickets(&managed_address!(&participants[1]))
+                        .get(),
+                    2
+                );
+            },
+        )
+        .assert_ok();
+    lp_setup.b_mock.check_esdt_balance(
+        &participants[1],
+        TICKET_PAYMENT_TOKEN_ID,
+        &rust_biguint!(TICKET_COST + 3),
+    );
+}
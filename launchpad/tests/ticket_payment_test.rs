@@ -0,0 +1,169 @@
+mod launchpad_setup;
+
+use launchpad_common::{
+    config::ConfigModule, locked_token_payment::LockedTokenPaymentModule,
+    user_interactions::UserInteractionsModule,
+};
+use launchpad_setup::{
+    LaunchpadSetup, CLAIM_START_ROUND, CONFIRM_START_ROUND, NR_WINNING_TICKETS, TICKET_COST,
+    WINNER_SELECTION_START_ROUND,
+};
+use multiversx_sc_scenario::{managed_address, managed_biguint, rust_biguint};
+
+#[test]
+fn ticket_payment_accounting_test() {
+    let mut lp_setup = LaunchpadSetup::new(NR_WINNING_TICKETS);
+    let participants = lp_setup.participants.clone();
+
+    lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND);
+    lp_setup.confirm(&participants[0], 1).assert_ok();
+    lp_setup.confirm(&participants[1], 3).assert_ok();
+
+    lp_setup
+        .call(&participants[1], |sc| {
+            sc.unconfirm_tickets(1);
+
+            assert_eq!(
+                sc.user_ticket_payment(&managed_address!(&participants[0]))
+                    .get(),
+                managed_biguint!(TICKET_COST)
+            );
+            assert_eq!(
+                sc.user_ticket_payment(&managed_address!(&participants[1]))
+                    .get(),
+                managed_biguint!(TICKET_COST * 2)
+            );
+            assert_eq!(
+                sc.total_refundable_ticket_payment().get(),
+                managed_biguint!(TICKET_COST * 3)
+            );
+        })
+        .assert_ok();
+
+    lp_setup
+        .b_mock
+        .set_block_round(WINNER_SELECTION_START_ROUND);
+    lp_setup.filter_tickets().assert_ok();
+    lp_setup.select_winners().assert_ok();
+
+    lp_setup.b_mock.set_block_round(CLAIM_START_ROUND);
+    lp_setup.claim_user(&participants[0]).assert_ok();
+    lp_setup.claim_user(&participants[1]).assert_ok();
+
+    lp_setup
+        .query(|sc| {
+            for p in participants.iter().take(2) {
+                assert!(sc.user_ticket_payment(&managed_address!(p)).is_empty());
+            }
+            assert_eq!(sc.total_refundable_ticket_payment().get(), 0);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn owner_ticket_payment_accounting_test() {
+    let mut lp_setup = LaunchpadSetup::new(NR_WINNING_TICKETS);
+    let participants = lp_setup.participants.clone();
+
+    lp_setup.confirm_all();
+    lp_setup.filter_tickets().assert_ok();
+    lp_setup.select_winners_mock(&[1, 2, 4]).assert_ok();
+
+    // the losing tickets' payment stays held for the refunds
+    let winning_tickets_payment = TICKET_COST * NR_WINNING_TICKETS as u64;
+    lp_setup
+        .query(|sc| {
+            assert_eq!(
+                sc.get_owner_claimable_ticket_payment(),
+                managed_biguint!(winning_tickets_payment)
+            );
+            assert_eq!(
+                sc.total_refundable_ticket_payment().get(),
+                managed_biguint!(TICKET_COST * 6)
+            );
+        })
+        .assert_ok();
+
+    lp_setup.b_mock.set_block_round(CLAIM_START_ROUND);
+    lp_setup.claim_owner().assert_ok();
+    lp_setup.b_mock.check_egld_balance(
+        &lp_setup.owner_address,
+        &rust_biguint!(winning_tickets_payment),
+    );
+
+    lp_setup
+        .query(|sc| {
+            assert_eq!(sc.get_owner_claimable_ticket_payment(), managed_biguint!(0));
+            assert_eq!(
+                sc.total_ticket_payment_claimed().get(),
+                managed_biguint!(winning_tickets_payment)
+            );
+        })
+        .assert_ok();
+
+    // the users are still refunded after the owner claimed
+    for p in participants.iter() {
+        lp_setup.claim_user(p).assert_ok();
+    }
+    lp_setup
+        .query(|sc| {
+            assert_eq!(
+                sc.total_refundable_ticket_payment().get(),
+                managed_biguint!(0)
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn owner_claim_only_pays_tracked_ticket_payment_test() {
+    let mut lp_setup = LaunchpadSetup::new(NR_WINNING_TICKETS);
+    let participants = lp_setup.participants.clone();
+
+    lp_setup.confirm_all();
+    lp_setup.filter_tickets().assert_ok();
+    lp_setup.select_winners_mock(&[1, 2, 4]).assert_ok();
+
+    // 3 of the 6 confirmed tickets lost
+    let outstanding_refunds = TICKET_COST * 3;
+    lp_setup
+        .query(|sc| {
+            assert_eq!(
+                sc.get_outstanding_refunds(),
+                managed_biguint!(outstanding_refunds)
+            );
+        })
+        .assert_ok();
+
+    // unrelated payment tokens are not ticket revenue
+    let unrelated_amount = TICKET_COST * 5;
+    let total_ticket_payment = TICKET_COST * 6;
+    lp_setup.b_mock.set_egld_balance(
+        lp_setup.lp_wrapper.address_ref(),
+        &rust_biguint!(total_ticket_payment + unrelated_amount),
+    );
+
+    let winning_tickets_payment = TICKET_COST * NR_WINNING_TICKETS as u64;
+    lp_setup.b_mock.set_block_round(CLAIM_START_ROUND);
+    lp_setup.claim_owner().assert_ok();
+    lp_setup.b_mock.check_egld_balance(
+        &lp_setup.owner_address,
+        &rust_biguint!(winning_tickets_payment),
+    );
+
+    // claiming again pays nothing
+    lp_setup.claim_owner().assert_ok();
+    lp_setup.b_mock.check_egld_balance(
+        &lp_setup.owner_address,
+        &rust_biguint!(winning_tickets_payment),
+    );
+
+    // the refunds are still covered
+    for p in participants.iter() {
+        lp_setup.claim_user(p).assert_ok();
+    }
+    lp_setup.b_mock.check_egld_balance(
+        lp_setup.lp_wrapper.address_ref(),
+        &rust_biguint!(unrelated_amount),
+    );
+}
@@ -0,0 +1,171 @@
+mod launchpad_setup;
+
+use launchpad_common::{
+    tickets::{TicketId, TicketsModule, TICKET_POS_SWAPS_FLUSH_SIZE, WINNING_TICKET},
+    winner_selection::WinnerSelectionModule,
+};
+use launchpad_setup::{
+    LaunchpadSetup, CONFIRM_START_ROUND, NR_WINNING_TICKETS, WINNER_SELECTION_START_ROUND,
+};
+use multiversx_sc::types::ManagedVec;
+use multiversx_sc_scenario::managed_address;
+
+#[test]
+fn add_tickets_to_existing_user_test() {
+    let mut lp_setup = LaunchpadSetup::new(NR_WINNING_TICKETS);
+    let participants = lp_setup.participants.clone();
+
+    // first user's batch is moved to the end, then extended in place
+    lp_setup
+        .call_owner(|sc| {
+            let user = managed_address!(&participants[0]);
+            sc.add_tickets_to_existing_user(user.clone(), 2);
+            assert_eq!(sc.get_total_number_of_tickets_for_address(&user), 3);
+            assert_eq!(sc.last_ticket_id().get(), 9);
+            assert_eq!(sc.get_ticket_owner_view(7), user);
+
+            sc.add_tickets_to_existing_user(user.clone(), 1);
+            assert_eq!(sc.get_total_number_of_tickets_for_address(&user), 4);
+            assert_eq!(sc.last_ticket_id().get(), 10);
+        })
+        .assert_ok();
+
+    lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND);
+    lp_setup.confirm(&participants[0], 3).assert_ok();
+    lp_setup.confirm(&participants[1], 2).assert_ok();
+    lp_setup.confirm(&participants[2], 3).assert_ok();
+
+    lp_setup
+        .b_mock
+        .set_block_round(WINNER_SELECTION_START_ROUND);
+    lp_setup.filter_tickets().assert_ok();
+
+    lp_setup
+        .query(|sc| {
+            let user = managed_address!(&participants[0]);
+            assert_eq!(sc.last_ticket_id().get(), 8);
+            assert_eq!(sc.get_total_number_of_tickets_for_address(&user), 3);
+            assert_eq!(
+                sc.get_ticket_owner_view(1),
+                managed_address!(&participants[1])
+            );
+            assert_eq!(sc.get_ticket_owner_view(6), user);
+            assert_eq!(sc.get_ticket_owner_view(8), user);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn remove_tickets_from_user_test() {
+    let mut lp_setup = LaunchpadSetup::new(NR_WINNING_TICKETS);
+    let participants = lp_setup.participants.clone();
+
+    lp_setup
+        .call_owner(|sc| {
+            // last batch is shrunk in place
+            let third_user = managed_address!(&participants[2]);
+            sc.remove_tickets_from_user(third_user.clone(), 1);
+            assert_eq!(sc.get_total_number_of_tickets_for_address(&third_user), 2);
+            assert_eq!(sc.last_ticket_id().get(), 5);
+
+            // first user's batch is voided
+            let first_user = managed_address!(&participants[0]);
+            sc.remove_tickets_from_user(first_user.clone(), 1);
+            assert_eq!(sc.get_total_number_of_tickets_for_address(&first_user), 0);
+            assert_eq!(sc.last_ticket_id().get(), 5);
+        })
+        .assert_ok();
+
+    lp_setup
+        .call_owner(|sc| {
+            sc.remove_tickets_from_user(managed_address!(&participants[1]), 3);
+        })
+        .assert_error(4, "Trying to remove too many tickets");
+
+    lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND);
+    lp_setup
+        .confirm(&participants[0], 1)
+        .assert_error(4, "Trying to confirm too many tickets");
+    lp_setup.confirm(&participants[1], 2).assert_ok();
+    lp_setup.confirm(&participants[2], 2).assert_ok();
+
+    lp_setup
+        .b_mock
+        .set_block_round(WINNER_SELECTION_START_ROUND);
+    lp_setup.filter_tickets().assert_ok();
+
+    lp_setup
+        .query(|sc| {
+            assert_eq!(sc.last_ticket_id().get(), 4);
+            assert_eq!(
+                sc.get_ticket_owner_view(1),
+                managed_address!(&participants[1])
+            );
+            assert_eq!(
+                sc.get_ticket_owner_view(4),
+                managed_address!(&participants[2])
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn buffered_ticket_pos_swaps_test() {
+    let mut lp_setup = LaunchpadSetup::new(NR_WINNING_TICKETS);
+
+    lp_setup
+        .call_owner(|sc| {
+            let mut pending_swaps = ManagedVec::new();
+
+            // a buffered read sees the pending swap before it is written
+            sc.buffer_ticket_pos_swap(2, 5, &mut pending_swaps);
+            assert_eq!(sc.get_buffered_ticket_id_from_pos(2, &pending_swaps), 5);
+            assert_eq!(sc.get_ticket_id_from_pos(2), 2);
+
+            // a new swap for the same position overwrites the pending one
+            sc.buffer_ticket_pos_swap(2, 6, &mut pending_swaps);
+            assert_eq!(pending_swaps.len(), 1);
+            assert_eq!(sc.get_buffered_ticket_id_from_pos(2, &pending_swaps), 6);
+
+            sc.flush_ticket_pos_swaps(&mut pending_swaps);
+            assert!(pending_swaps.is_empty());
+            assert_eq!(sc.get_ticket_id_from_pos(2), 6);
+
+            // the swaps are written once a full chunk is pending
+            for ticket_pos in 1..=TICKET_POS_SWAPS_FLUSH_SIZE as TicketId {
+                sc.buffer_ticket_pos_swap(ticket_pos, ticket_pos + 100, &mut pending_swaps);
+            }
+            assert!(pending_swaps.is_empty());
+            assert_eq!(sc.get_ticket_id_from_pos(1), 101);
+            assert_eq!(
+                sc.get_ticket_id_from_pos(TICKET_POS_SWAPS_FLUSH_SIZE as TicketId),
+                TICKET_POS_SWAPS_FLUSH_SIZE as TicketId + 100
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn select_winners_flushes_ticket_pos_swaps_test() {
+    let mut lp_setup = LaunchpadSetup::new(NR_WINNING_TICKETS);
+
+    lp_setup.confirm_all();
+    lp_setup.filter_tickets().assert_ok();
+    lp_setup.select_winners().assert_ok();
+
+    // the positions still hold a permutation of the tickets
+    lp_setup
+        .query(|sc| {
+            let mut ticket_ids: Vec<TicketId> = (1..=6)
+                .map(|ticket_pos| sc.get_ticket_id_from_pos(ticket_pos))
+                .collect();
+            ticket_ids.sort_unstable();
+            assert_eq!(ticket_ids, (1..=6).collect::<Vec<TicketId>>());
+
+            let nr_winning_tickets = (1..=6)
+                .filter(|ticket_id| sc.get_ticket_status(*ticket_id) == WINNING_TICKET)
+                .count();
+            assert_eq!(nr_winning_tickets, NR_WINNING_TICKETS);
+        })
+        .assert_ok();
+}
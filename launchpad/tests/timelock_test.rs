@@ -0,0 +1,101 @@
+mod launchpad_setup;
+
+use launchpad::Launchpad;
+use launchpad_common::{
+    config::ConfigModule, setup::SetupModule, time_provider::TimeUnit, timelock::TimelockModule,
+};
+use launchpad_setup::{LaunchpadSetup, NR_WINNING_TICKETS, TICKET_COST};
+use multiversx_sc::types::EgldOrEsdtTokenIdentifier;
+use multiversx_sc_scenario::managed_biguint;
+
+#[test]
+fn timelocked_ticket_price_change_test() {
+    let mut lp_setup = LaunchpadSetup::new(NR_WINNING_TICKETS);
+    let timelock_delay = 2;
+
+    lp_setup
+        .call_owner(|sc| {
+            sc.set_timelock_delay(timelock_delay);
+            sc.set_ticket_price(
+                EgldOrEsdtTokenIdentifier::egld(),
+                managed_biguint!(TICKET_COST * 2),
+            );
+        })
+        .assert_ok();
+
+    // price is unchanged until the change is executed
+    lp_setup
+        .query(|sc| {
+            assert_eq!(
+                sc.ticket_price().get().amount,
+                managed_biguint!(TICKET_COST)
+            );
+            assert_eq!(sc.get_pending_changes().len(), 1);
+            assert_eq!(sc.pending_change(1).get().executable_round, timelock_delay);
+        })
+        .assert_ok();
+
+    lp_setup
+        .call_owner(|sc| {
+            sc.execute_pending_change_endpoint(1);
+        })
+        .assert_error(4, "Timelock has not expired yet");
+
+    lp_setup.b_mock.set_block_round(timelock_delay);
+
+    lp_setup
+        .call_owner(|sc| {
+            sc.execute_pending_change_endpoint(1);
+        })
+        .assert_ok();
+
+    lp_setup
+        .query(|sc| {
+            assert_eq!(
+                sc.ticket_price().get().amount,
+                managed_biguint!(TICKET_COST * 2)
+            );
+            assert_eq!(sc.get_pending_changes().len(), 0);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn timelock_in_configured_time_unit_test() {
+    let mut lp_setup = LaunchpadSetup::new(NR_WINNING_TICKETS);
+    let participants = lp_setup.participants.clone();
+    let timelock_delay = 100;
+
+    lp_setup
+        .call(&participants[0], |sc| {
+            sc.set_timelock_delay(timelock_delay);
+        })
+        .assert_error(4, "Permission denied");
+
+    lp_setup
+        .call_owner(|sc| {
+            sc.set_time_unit(TimeUnit::Timestamp);
+            sc.set_timelock_delay(timelock_delay);
+
+            // lowering the delay is queued
+            sc.set_timelock_delay(timelock_delay / 2);
+            assert_eq!(sc.pending_change(1).get().executable_round, timelock_delay);
+        })
+        .assert_ok();
+
+    // the rounds passing do not count when the launch runs on timestamps
+    lp_setup.b_mock.set_block_round(timelock_delay);
+    lp_setup
+        .call_owner(|sc| {
+            sc.execute_pending_change_endpoint(1);
+        })
+        .assert_error(4, "Timelock has not expired yet");
+
+    lp_setup.b_mock.set_block_timestamp(timelock_delay);
+    lp_setup
+        .call_owner(|sc| {
+            sc.execute_pending_change_endpoint(1);
+            assert_eq!(sc.timelock_delay().get(), timelock_delay / 2);
+        })
+        .assert_ok();
+}
@@ -0,0 +1,90 @@
+mod launchpad_setup;
+
+use launchpad::Launchpad;
+use launchpad_common::{keeper_incentives::KeeperIncentivesModule, permissions::Role};
+use launchpad_setup::{LaunchpadSetup, LAUNCHPAD_TOKEN_ID, NR_WINNING_TICKETS};
+use multiversx_sc::types::EgldOrEsdtTokenIdentifier;
+use multiversx_sc_scenario::{managed_biguint, managed_token_id, rust_biguint};
+
+#[test]
+fn rescue_tokens_test() {
+    let mut lp_setup = LaunchpadSetup::new(NR_WINNING_TICKETS);
+    let owner_address = lp_setup.owner_address.clone();
+    let foreign_token_id = b"OTHER-123456";
+    lp_setup.b_mock.set_esdt_balance(
+        lp_setup.lp_wrapper.address_ref(),
+        foreign_token_id,
+        &rust_biguint!(500),
+    );
+
+    let launch_tokens = [
+        EgldOrEsdtTokenIdentifier::egld(),
+        EgldOrEsdtTokenIdentifier::esdt(managed_token_id!(LAUNCHPAD_TOKEN_ID)),
+    ];
+    for token_id in launch_tokens {
+        lp_setup
+            .call_owner(|sc| {
+                sc.rescue_tokens_endpoint(token_id, 0);
+            })
+            .assert_user_error("Cannot rescue the launch tokens");
+    }
+
+    let participants = lp_setup.participants.clone();
+    lp_setup
+        .call(&participants[0], |sc| {
+            sc.rescue_tokens_endpoint(
+                EgldOrEsdtTokenIdentifier::esdt(managed_token_id!(foreign_token_id)),
+                0,
+            );
+        })
+        .assert_user_error("Permission denied");
+
+    // the rescued tokens are sent to the owner, not to the finance member
+    let finance = lp_setup.b_mock.create_user_account(&rust_biguint!(0));
+    lp_setup.add_role(Role::Finance, &finance);
+    lp_setup
+        .call(&finance, |sc| {
+            sc.rescue_tokens_endpoint(
+                EgldOrEsdtTokenIdentifier::esdt(managed_token_id!(foreign_token_id)),
+                0,
+            );
+        })
+        .assert_ok();
+
+    lp_setup
+        .b_mock
+        .check_esdt_balance(&owner_address, foreign_token_id, &rust_biguint!(500));
+    lp_setup
+        .b_mock
+        .check_esdt_balance(&finance, foreign_token_id, &rust_biguint!(0));
+    lp_setup.b_mock.check_esdt_balance(
+        lp_setup.lp_wrapper.address_ref(),
+        foreign_token_id,
+        &rust_biguint!(0),
+    );
+
+    // the keeper bounty pool is kept for the permissionless batches
+    let bounty_token_id = b"BOUNTY-123456";
+    lp_setup.b_mock.set_esdt_balance(
+        lp_setup.lp_wrapper.address_ref(),
+        bounty_token_id,
+        &rust_biguint!(100),
+    );
+    lp_setup
+        .call_owner(|sc| {
+            sc.set_keeper_bounty(
+                EgldOrEsdtTokenIdentifier::esdt(managed_token_id!(bounty_token_id)),
+                managed_biguint!(10),
+                0,
+            );
+        })
+        .assert_ok();
+    lp_setup
+        .call_owner(|sc| {
+            sc.rescue_tokens_endpoint(
+                EgldOrEsdtTokenIdentifier::esdt(managed_token_id!(bounty_token_id)),
+                0,
+            );
+        })
+        .assert_user_error("Cannot rescue the launch tokens");
+}
@@ -0,0 +1,91 @@
+mod launchpad_setup;
+
+use launchpad_common::{
+    config::ConfigModule,
+    setup::SetupModule,
+    unclaimed_sweep::UnclaimedSweepModule,
+    user_interactions::{ClaimStatus, UserInteractionsModule},
+};
+use launchpad_setup::{
+    LaunchpadSetup, CLAIM_START_ROUND, LAUNCHPAD_TOKENS_PER_TICKET, LAUNCHPAD_TOKEN_ID,
+    NR_WINNING_TICKETS, TICKET_COST,
+};
+use multiversx_sc::types::OperationCompletionStatus;
+use multiversx_sc_scenario::{managed_address, rust_biguint};
+
+#[test]
+fn sweep_unclaimed_after_deadline_test() {
+    let mut lp_setup = LaunchpadSetup::new(NR_WINNING_TICKETS);
+    let participants = lp_setup.participants.clone();
+    let treasury = lp_setup.b_mock.create_user_account(&rust_biguint!(0));
+
+    lp_setup.confirm_all();
+    lp_setup.filter_tickets().assert_ok();
+    lp_setup.select_winners_mock(&[1, 2, 4]).assert_ok();
+
+    lp_setup.b_mock.set_block_round(CLAIM_START_ROUND);
+
+    lp_setup
+        .call(&treasury, |sc| {
+            sc.set_treasury_address(managed_address!(&treasury));
+        })
+        .assert_user_error("Permission denied");
+
+    let claim_deadline_round = CLAIM_START_ROUND + 5;
+    lp_setup
+        .call_owner(|sc| {
+            sc.set_claim_deadline_round(claim_deadline_round);
+            sc.set_treasury_address(managed_address!(&treasury));
+        })
+        .assert_ok();
+
+    lp_setup.claim_user(&participants[0]).assert_ok();
+
+    lp_setup
+        .call_owner(|sc| {
+            let _ = sc.sweep_unclaimed();
+        })
+        .assert_error(4, "Claim deadline has not passed yet");
+
+    lp_setup.b_mock.set_block_round(claim_deadline_round);
+
+    lp_setup
+        .claim_user(&participants[1])
+        .assert_error(4, "Claim deadline has passed");
+
+    lp_setup
+        .call(&participants[0], |sc| {
+            let _ = sc.sweep_unclaimed();
+        })
+        .assert_error(4, "Permission denied");
+
+    lp_setup
+        .call_owner(|sc| {
+            let result = sc.sweep_unclaimed();
+            assert_eq!(result, OperationCompletionStatus::Completed);
+
+            assert_eq!(sc.nr_winning_tickets().get(), 0);
+            assert!(sc.has_user_claimed(&managed_address!(&participants[1])));
+            assert!(sc.has_user_claimed(&managed_address!(&participants[2])));
+            assert_eq!(
+                sc.get_user_claim_status(managed_address!(&participants[1])),
+                ClaimStatus::Swept
+            );
+            assert_eq!(
+                sc.get_user_claim_status(managed_address!(&participants[2])),
+                ClaimStatus::Swept
+            );
+        })
+        .assert_ok();
+
+    // launchpad tokens of the two remaining winners go back to the owner,
+    // while the refunds for their 3 losing tickets go to the treasury
+    lp_setup.b_mock.check_esdt_balance(
+        &lp_setup.owner_address,
+        LAUNCHPAD_TOKEN_ID,
+        &rust_biguint!(LAUNCHPAD_TOKENS_PER_TICKET * 2),
+    );
+    lp_setup
+        .b_mock
+        .check_egld_balance(&treasury, &rust_biguint!(TICKET_COST * 3));
+}
@@ -0,0 +1,174 @@
+mod launchpad_setup;
+
+use launchpad::Launchpad;
+use launchpad_common::{
+    ongoing_operation::{OngoingOperationModule, OngoingOperationType},
+    tickets::{TicketsModule, TICKET_STATUS_CHUNK_BITS},
+    tickets_layout_migration::{
+        LegacyTicketId, LegacyTicketRange, LegacyWinnerSelectionProof, TicketsLayoutMigrationModule,
+    },
+    winner_selection::WinnerSelectionModule,
+    LaunchpadMain, CURRENT_STORAGE_VERSION, TICKETS_LAYOUT_STORAGE_VERSION,
+};
+use launchpad_setup::{
+    LaunchpadSetup, CLAIM_START_ROUND, CONFIRM_START_ROUND, NR_WINNING_TICKETS,
+    WINNER_SELECTION_START_ROUND,
+};
+use multiversx_sc_scenario::managed_address;
+
+#[test]
+fn upgrade_storage_version_test() {
+    let mut lp_setup = LaunchpadSetup::new(NR_WINNING_TICKETS);
+
+    // simulate a contract deployed before storage versioning
+    lp_setup
+        .call_owner(|sc| {
+            assert_eq!(sc.storage_version().get(), CURRENT_STORAGE_VERSION);
+            sc.storage_version().clear();
+            sc.upgrade();
+            assert_eq!(sc.storage_version().get(), CURRENT_STORAGE_VERSION);
+        })
+        .assert_ok();
+
+    lp_setup
+        .call_owner(|sc| {
+            sc.storage_version().set(CURRENT_STORAGE_VERSION + 1);
+            sc.upgrade();
+        })
+        .assert_user_error("Cannot downgrade storage version");
+}
+
+#[test]
+fn upgrade_legacy_tickets_layout_test() {
+    // contracts from the first release, without the batch lists, and later ones
+    for previous_version in [0, TICKETS_LAYOUT_STORAGE_VERSION - 1] {
+        let mut lp_setup = LaunchpadSetup::new(NR_WINNING_TICKETS);
+        let participants = lp_setup.participants.clone();
+
+        // the second user's unconfirmed ticket moves the third user's tickets when filtering
+        lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND);
+        lp_setup.confirm(&participants[0], 1).assert_ok();
+        lp_setup.confirm(&participants[1], 1).assert_ok();
+        lp_setup.confirm(&participants[2], 3).assert_ok();
+
+        lp_setup
+            .b_mock
+            .set_block_round(WINNER_SELECTION_START_ROUND);
+        lp_setup.filter_tickets().assert_ok();
+        lp_setup.select_winners().assert_ok();
+
+        lp_setup
+            .call_owner(|sc| {
+                let last_ticket_id = sc.last_ticket_id().get();
+                let mut ticket_ranges = Vec::new();
+                for participant in participants.iter() {
+                    let ticket_range = sc
+                        .ticket_range_for_address(&managed_address!(participant))
+                        .get();
+                    ticket_ranges.push((ticket_range.first_id, ticket_range.last_id));
+                }
+                let mut ticket_statuses = Vec::new();
+                let mut ticket_positions = Vec::new();
+                let mut ticket_owners = Vec::new();
+                for ticket_id in 1..=last_ticket_id {
+                    ticket_statuses.push(sc.get_ticket_status(ticket_id));
+                    ticket_positions.push(sc.ticket_pos_to_id(ticket_id).get());
+                    ticket_owners.push(sc.get_ticket_owner(ticket_id));
+                }
+                let proof = sc.winner_selection_proof().get();
+                let last_ticket_position = proof.last_ticket_position;
+
+                // store the tickets as they were before the u64 ticket IDs
+                for participant in participants.iter() {
+                    let address = managed_address!(participant);
+                    let ticket_range = sc.ticket_range_for_address(&address).get();
+                    sc.legacy_ticket_range_for_address(&address)
+                        .set(LegacyTicketRange {
+                            first_id: ticket_range.first_id as LegacyTicketId,
+                            last_id: ticket_range.last_id as LegacyTicketId,
+                        });
+                }
+                for ticket_id in 1..=last_ticket_id {
+                    let legacy_ticket_id = ticket_id as LegacyTicketId;
+                    sc.legacy_ticket_status(legacy_ticket_id)
+                        .set(sc.get_ticket_status(ticket_id));
+
+                    let ticket_pos_to_id = sc.ticket_pos_to_id(ticket_id).take();
+                    sc.legacy_ticket_pos_to_id(legacy_ticket_id)
+                        .set(ticket_pos_to_id as LegacyTicketId);
+
+                    let ticket_batch_mapper = sc.ticket_batch(ticket_id);
+                    if !ticket_batch_mapper.is_empty() {
+                        sc.legacy_ticket_batch(legacy_ticket_id)
+                            .set(ticket_batch_mapper.take());
+                    }
+                }
+                for chunk_index in 0..=last_ticket_id / TICKET_STATUS_CHUNK_BITS {
+                    sc.ticket_status_chunk(chunk_index).clear();
+                }
+                sc.legacy_winner_selection_proof()
+                    .set(LegacyWinnerSelectionProof {
+                        seed: proof.seed,
+                        seed_index: proof.seed_index,
+                        last_ticket_position: last_ticket_position as LegacyTicketId,
+                        nr_winning_tickets: proof.nr_winning_tickets,
+                        nr_guaranteed_tickets: proof.nr_guaranteed_tickets,
+                        max_winning_tickets_per_address: proof.max_winning_tickets_per_address,
+                    });
+                if previous_version == 0 {
+                    sc.batch_first_ticket_ids().clear();
+                    sc.confirmers_first_ticket_ids().clear();
+                }
+                sc.storage_version().set(previous_version);
+
+                sc.upgrade();
+                assert_eq!(sc.storage_version().get(), CURRENT_STORAGE_VERSION);
+
+                for (participant, (first_id, last_id)) in
+                    participants.iter().zip(ticket_ranges.iter())
+                {
+                    let ticket_range = sc
+                        .ticket_range_for_address(&managed_address!(participant))
+                        .get();
+                    assert_eq!(ticket_range.first_id, *first_id);
+                    assert_eq!(ticket_range.last_id, *last_id);
+                }
+                for ticket_id in 1..=last_ticket_id {
+                    let legacy_ticket_id = ticket_id as LegacyTicketId;
+                    let index = (ticket_id - 1) as usize;
+                    assert_eq!(sc.get_ticket_status(ticket_id), ticket_statuses[index]);
+                    assert_eq!(
+                        sc.ticket_pos_to_id(ticket_id).get(),
+                        ticket_positions[index]
+                    );
+                    assert_eq!(sc.get_ticket_owner(ticket_id), ticket_owners[index]);
+
+                    assert!(sc.legacy_ticket_status(legacy_ticket_id).is_empty());
+                    assert!(sc.legacy_ticket_pos_to_id(legacy_ticket_id).is_empty());
+                    assert!(sc.legacy_ticket_batch(legacy_ticket_id).is_empty());
+                }
+                assert_eq!(
+                    sc.winner_selection_proof().get().last_ticket_position,
+                    last_ticket_position
+                );
+            })
+            .assert_ok();
+
+        // the launch goes on with the migrated tickets
+        lp_setup.b_mock.set_block_round(CLAIM_START_ROUND);
+        for participant in participants.iter() {
+            lp_setup.claim_user(participant).assert_ok();
+        }
+    }
+
+    // the other operations were encoded with the legacy ticket IDs
+    let mut lp_setup = LaunchpadSetup::new(NR_WINNING_TICKETS);
+    lp_setup
+        .call_owner(|sc| {
+            sc.current_ongoing_operation()
+                .set(OngoingOperationType::DistributeResults { ticket_id: 1 });
+            sc.storage_version().set(TICKETS_LAYOUT_STORAGE_VERSION - 1);
+            sc.upgrade();
+        })
+        .assert_user_error("Cannot upgrade during an ongoing operation");
+}
@@ -0,0 +1,336 @@
+mod launchpad_setup;
+
+use launchpad::Launchpad;
+use launchpad_common::{
+    config::ConfigModule,
+    launch_stage::{CurrentStage, LaunchStageModule},
+    setup::SetupModule,
+    tickets::TicketsModule,
+    user_interactions::UserInteractionsModule,
+    winner_selection::WinnerSelectionModule,
+};
+use launchpad_setup::{
+    LaunchpadSetup, CLAIM_START_ROUND, CONFIRM_START_ROUND, LAUNCHPAD_TOKENS_PER_TICKET,
+    LAUNCHPAD_TOKEN_ID, MAX_TIER_TICKETS, NR_WINNING_TICKETS, TICKET_COST,
+    WINNER_SELECTION_START_ROUND,
+};
+use multiversx_sc_scenario::{
+    managed_address, managed_biguint, managed_buffer, managed_token_id, rust_biguint,
+};
+
+#[test]
+fn user_full_status_test() {
+    let mut lp_setup = LaunchpadSetup::new(NR_WINNING_TICKETS);
+    lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND);
+    let participants = lp_setup.participants.clone();
+
+    for (i, p) in participants.iter().enumerate() {
+        lp_setup.confirm(p, i + 1).assert_ok();
+    }
+
+    // refundable amount is not known before the winner selection
+    lp_setup
+        .query(|sc| {
+            let status = sc.user_full_status(managed_address!(&participants[2]));
+            assert_eq!(status.total_tickets, MAX_TIER_TICKETS);
+            assert_eq!(status.confirmed_tickets, 3);
+            assert_eq!(status.winning_tickets, 0);
+            assert_eq!(status.guaranteed_tickets, 0);
+            assert!(!status.has_claimed);
+            assert_eq!(status.refundable_amount, managed_biguint!(0));
+            assert!(!status.is_blacklisted);
+        })
+        .assert_ok();
+
+    lp_setup
+        .b_mock
+        .set_block_round(WINNER_SELECTION_START_ROUND);
+
+    lp_setup.filter_tickets().assert_ok();
+    lp_setup.select_winners_mock(&[1, 2, 4]).assert_ok();
+
+    lp_setup
+        .query(|sc| {
+            let status = sc.user_full_status(managed_address!(&participants[2]));
+            assert_eq!(status.winning_tickets, 1);
+            assert_eq!(status.refundable_amount, managed_biguint!(TICKET_COST * 2));
+        })
+        .assert_ok();
+
+    lp_setup.b_mock.set_block_round(CLAIM_START_ROUND);
+    lp_setup.claim_user(&participants[2]).assert_ok();
+
+    lp_setup
+        .query(|sc| {
+            let status = sc.user_full_status(managed_address!(&participants[2]));
+            assert!(status.has_claimed);
+            assert_eq!(status.refundable_amount, managed_biguint!(0));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn global_statistics_test() {
+    let mut lp_setup = LaunchpadSetup::new(NR_WINNING_TICKETS);
+    lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND);
+    let participants = lp_setup.participants.clone();
+
+    lp_setup.confirm(&participants[1], 1).assert_ok();
+    lp_setup.confirm(&participants[1], 1).assert_ok();
+    lp_setup.confirm(&participants[2], 3).assert_ok();
+
+    lp_setup
+        .query(|sc| {
+            assert_eq!(sc.total_participants().get(), 2);
+            assert_eq!(sc.total_confirmed_tickets().get(), 5);
+            assert_eq!(
+                sc.total_payment_raised().get(),
+                managed_biguint!(TICKET_COST * 5)
+            );
+            assert_eq!(sc.total_tokens_claimed().get(), managed_biguint!(0));
+        })
+        .assert_ok();
+
+    lp_setup
+        .b_mock
+        .set_block_round(WINNER_SELECTION_START_ROUND);
+
+    lp_setup.filter_tickets().assert_ok();
+    lp_setup.select_winners().assert_ok();
+
+    // the second user only has 2 tickets, so the third one wins at least once
+    let mut nr_user_winning_tickets = 0;
+    lp_setup
+        .query(|sc| {
+            nr_user_winning_tickets =
+                sc.get_number_of_winning_tickets_for_address(managed_address!(&participants[2]));
+        })
+        .assert_ok();
+    assert!(nr_user_winning_tickets >= 1);
+
+    lp_setup.b_mock.set_block_round(CLAIM_START_ROUND);
+    lp_setup.claim_user(&participants[2]).assert_ok();
+
+    let expected_tokens_claimed = LAUNCHPAD_TOKENS_PER_TICKET * nr_user_winning_tickets as u64;
+    lp_setup.b_mock.check_esdt_balance(
+        &participants[2],
+        LAUNCHPAD_TOKEN_ID,
+        &rust_biguint!(expected_tokens_claimed),
+    );
+    lp_setup
+        .query(|sc| {
+            assert_eq!(
+                sc.total_tokens_claimed().get(),
+                managed_biguint!(expected_tokens_claimed)
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn current_stage_view_test() {
+    let mut lp_setup = LaunchpadSetup::new(NR_WINNING_TICKETS);
+
+    lp_setup
+        .query(|sc| {
+            let (stage, rounds_remaining) = sc.get_current_stage().into_tuple();
+            assert_eq!(stage, CurrentStage::AddTickets);
+            assert_eq!(rounds_remaining, CONFIRM_START_ROUND);
+        })
+        .assert_ok();
+
+    lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND + 1);
+    lp_setup
+        .query(|sc| {
+            let (stage, rounds_remaining) = sc.get_current_stage().into_tuple();
+            assert_eq!(stage, CurrentStage::Confirm);
+            assert_eq!(
+                rounds_remaining,
+                WINNER_SELECTION_START_ROUND - CONFIRM_START_ROUND - 1
+            );
+        })
+        .assert_ok();
+
+    // claim stage only starts after the winners are selected
+    lp_setup.b_mock.set_block_round(CLAIM_START_ROUND);
+    lp_setup
+        .query(|sc| {
+            let (stage, rounds_remaining) = sc.get_current_stage().into_tuple();
+            assert_eq!(stage, CurrentStage::WinnerSelection);
+            assert_eq!(rounds_remaining, 0);
+        })
+        .assert_ok();
+
+    lp_setup.filter_tickets().assert_ok();
+    lp_setup.select_winners().assert_ok();
+
+    let claim_deadline_round = CLAIM_START_ROUND + 5;
+    lp_setup
+        .call_owner(|sc| {
+            sc.set_claim_deadline_round(claim_deadline_round);
+
+            let (stage, rounds_remaining) = sc.get_current_stage().into_tuple();
+            assert_eq!(stage, CurrentStage::Claim);
+            assert_eq!(rounds_remaining, 5);
+        })
+        .assert_ok();
+
+    lp_setup.b_mock.set_block_round(claim_deadline_round);
+    lp_setup
+        .query(|sc| {
+            let (stage, rounds_remaining) = sc.get_current_stage().into_tuple();
+            assert_eq!(stage, CurrentStage::Ended);
+            assert_eq!(rounds_remaining, 0);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn get_configuration_test() {
+    let mut lp_setup = LaunchpadSetup::new(NR_WINNING_TICKETS);
+
+    lp_setup
+        .query(|sc| {
+            let configuration = sc.get_configuration_view();
+            assert_eq!(
+                configuration.launchpad_token_id,
+                managed_token_id!(LAUNCHPAD_TOKEN_ID)
+            );
+            assert_eq!(
+                configuration.launchpad_tokens_per_winning_ticket,
+                managed_biguint!(LAUNCHPAD_TOKENS_PER_TICKET)
+            );
+            assert!(configuration.ticket_payment_token.is_egld());
+            assert_eq!(configuration.ticket_price, managed_biguint!(TICKET_COST));
+            assert_eq!(configuration.nr_winning_tickets, NR_WINNING_TICKETS);
+            assert_eq!(
+                configuration.confirmation_period_start_round,
+                CONFIRM_START_ROUND
+            );
+            assert_eq!(
+                configuration.winner_selection_start_round,
+                WINNER_SELECTION_START_ROUND
+            );
+            assert_eq!(configuration.claim_start_round, CLAIM_START_ROUND);
+            assert!(configuration.launchpad_tokens_deposited);
+            assert!(configuration.release_config.is_empty());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn user_refundable_amount_test() {
+    let mut lp_setup = LaunchpadSetup::new(NR_WINNING_TICKETS);
+    let participants = lp_setup.participants.clone();
+
+    lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND);
+    for (i, p) in participants.iter().enumerate() {
+        lp_setup.confirm(p, i + 1).assert_ok();
+    }
+
+    lp_setup
+        .query(|sc| {
+            assert_eq!(
+                sc.get_user_refundable_amount(managed_address!(&participants[2])),
+                0
+            );
+        })
+        .assert_ok();
+
+    lp_setup
+        .b_mock
+        .set_block_round(WINNER_SELECTION_START_ROUND);
+    lp_setup.filter_tickets().assert_ok();
+    lp_setup.select_winners_mock(&[1, 2, 4]).assert_ok();
+
+    // only one of the 3 tickets of the user wins
+    let expected_refund = TICKET_COST * 2;
+    lp_setup
+        .query(|sc| {
+            assert_eq!(
+                sc.get_user_refundable_amount(managed_address!(&participants[2])),
+                managed_biguint!(expected_refund)
+            );
+        })
+        .assert_ok();
+
+    lp_setup.b_mock.set_block_round(CLAIM_START_ROUND);
+    let balance_before = lp_setup.b_mock.get_egld_balance(&participants[2]);
+    lp_setup.claim_user(&participants[2]).assert_ok();
+    lp_setup.b_mock.check_egld_balance(
+        &participants[2],
+        &(balance_before + rust_biguint!(expected_refund)),
+    );
+
+    lp_setup
+        .query(|sc| {
+            assert_eq!(
+                sc.get_user_refundable_amount(managed_address!(&participants[2])),
+                0
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn ticket_status_batch_test() {
+    let mut lp_setup = LaunchpadSetup::new(NR_WINNING_TICKETS);
+    let participants = lp_setup.participants.clone();
+
+    lp_setup.confirm_all();
+    lp_setup.filter_tickets().assert_ok();
+    lp_setup.select_winners_mock(&[1, 2, 5]).assert_ok();
+
+    lp_setup
+        .query(|sc| {
+            assert_eq!(
+                sc.get_ticket_status_batch(1, 6),
+                managed_buffer!(&[0b0001_0011])
+            );
+            assert_eq!(
+                sc.get_ticket_status_batch(2, 6),
+                managed_buffer!(&[0b0000_1001])
+            );
+            assert_eq!(sc.get_ticket_status_batch(6, 6), managed_buffer!(&[0]));
+        })
+        .assert_ok();
+
+    lp_setup
+        .call_owner(|sc| {
+            let _ = sc.get_ticket_status_batch(3, 2);
+        })
+        .assert_user_error("Invalid ticket range");
+}
+
+#[test]
+fn confirmed_users_test() {
+    let mut lp_setup = LaunchpadSetup::new(NR_WINNING_TICKETS);
+    let participants = lp_setup.participants.clone();
+
+    lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND);
+    for (i, p) in participants.iter().enumerate() {
+        lp_setup.confirm(p, i + 1).assert_ok();
+    }
+
+    lp_setup
+        .call(&participants[0], |sc| {
+            sc.unconfirm_tickets(1);
+        })
+        .assert_ok();
+
+    lp_setup
+        .query(|sc| {
+            let confirmed_users: Vec<_> = sc
+                .get_confirmed_users(0, 10)
+                .into_iter()
+                .map(|entry| entry.into_tuple())
+                .collect();
+            assert_eq!(confirmed_users.len(), 2);
+            assert!(confirmed_users.contains(&(managed_address!(&participants[1]), 2)));
+            assert!(confirmed_users.contains(&(managed_address!(&participants[2]), 3)));
+
+            assert_eq!(sc.get_confirmed_users(1, 10).into_iter().count(), 1);
+            assert_eq!(sc.get_confirmed_users(2, 10).into_iter().count(), 0);
+        })
+        .assert_ok();
+}
@@ -0,0 +1,67 @@
+mod launchpad_setup;
+
+use launchpad::Launchpad;
+use launchpad_common::{
+    tickets::TicketsModule, wallet_migration::WalletMigrationModule,
+    winner_selection::WinnerSelectionModule,
+};
+use launchpad_setup::{LaunchpadSetup, CONFIRM_START_ROUND, NR_WINNING_TICKETS};
+use multiversx_sc_scenario::{managed_address, rust_biguint};
+
+#[test]
+fn wallet_migration_test() {
+    let mut lp_setup = LaunchpadSetup::new(NR_WINNING_TICKETS);
+    let participants = lp_setup.participants.clone();
+    let new_address = lp_setup.b_mock.create_user_account(&rust_biguint!(0));
+
+    lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND);
+    lp_setup.confirm(&participants[2], 3).assert_ok();
+
+    // not requested yet
+    lp_setup
+        .call_owner(|sc| {
+            sc.approve_wallet_migration_endpoint(managed_address!(&participants[2]));
+        })
+        .assert_user_error("No wallet migration requested");
+
+    // new address already has tickets
+    lp_setup
+        .call(&participants[2], |sc| {
+            sc.request_wallet_migration(managed_address!(&participants[1]));
+        })
+        .assert_user_error("New address already has tickets");
+
+    lp_setup
+        .call(&participants[2], |sc| {
+            sc.request_wallet_migration(managed_address!(&new_address));
+        })
+        .assert_ok();
+
+    // only admins may approve
+    lp_setup
+        .call(&participants[0], |sc| {
+            sc.approve_wallet_migration_endpoint(managed_address!(&participants[2]));
+        })
+        .assert_user_error("Permission denied");
+
+    lp_setup
+        .call_owner(|sc| {
+            sc.approve_wallet_migration_endpoint(managed_address!(&participants[2]));
+        })
+        .assert_ok();
+
+    lp_setup
+        .query(|sc| {
+            let old_address = managed_address!(&participants[2]);
+            let new_address = managed_address!(&new_address);
+
+            assert_eq!(sc.get_total_number_of_tickets_for_address(&old_address), 0);
+            assert_eq!(sc.nr_confirmed_tickets(&old_address).get(), 0);
+            assert!(sc.wallet_migration_request(&old_address).is_empty());
+
+            assert_eq!(sc.get_total_number_of_tickets_for_address(&new_address), 3);
+            assert_eq!(sc.nr_confirmed_tickets(&new_address).get(), 3);
+            assert_eq!(sc.get_ticket_owner_view(4), new_address);
+        })
+        .assert_ok();
+}
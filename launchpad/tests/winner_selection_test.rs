@@ -0,0 +1,261 @@
+mod launchpad_setup;
+
+use launchpad_common::{
+    config::ConfigModule,
+    setup::SetupModule,
+    tickets::{TicketsModule, WINNING_TICKET},
+    user_interactions::UserInteractionsModule,
+    winner_selection::WinnerSelectionModule,
+};
+use launchpad_setup::{
+    LaunchpadSetup, CONFIRM_START_ROUND, NR_WINNING_TICKETS, TICKET_COST,
+    WINNER_SELECTION_START_ROUND,
+};
+use multiversx_sc_scenario::{managed_address, managed_biguint};
+
+#[test]
+fn guaranteed_ticket_per_confirmer_test() {
+    let nr_winning_tickets = 4;
+    let mut lp_setup = LaunchpadSetup::new(nr_winning_tickets);
+    let participants = lp_setup.participants.clone();
+
+    lp_setup
+        .call_owner(|sc| {
+            sc.set_guaranteed_ticket_per_confirmer(true);
+        })
+        .assert_ok();
+
+    lp_setup.confirm_all();
+
+    lp_setup.filter_tickets().assert_ok();
+    lp_setup.select_winners().assert_ok();
+
+    // 4 winning tickets, one for each of the 3 confirmers, and one drawn randomly
+    lp_setup
+        .query(|sc| {
+            assert_eq!(sc.confirmers_first_ticket_ids().len(), 3);
+            assert_eq!(sc.get_ticket_status(1), WINNING_TICKET);
+            assert_eq!(sc.get_ticket_status(2), WINNING_TICKET);
+            assert_eq!(sc.get_ticket_status(4), WINNING_TICKET);
+
+            let mut total_winning_tickets = 0;
+            for p in participants.iter() {
+                let nr_user_winning_tickets =
+                    sc.get_number_of_winning_tickets_for_address(managed_address!(p));
+                assert!(nr_user_winning_tickets >= 1);
+
+                total_winning_tickets += nr_user_winning_tickets;
+            }
+            assert_eq!(total_winning_tickets, nr_winning_tickets);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn max_winning_tickets_per_address_test() {
+    let nr_winning_tickets = 4;
+    let mut lp_setup = LaunchpadSetup::new(nr_winning_tickets);
+    let participants = lp_setup.participants.clone();
+
+    lp_setup
+        .call_owner(|sc| {
+            sc.set_max_winning_tickets_per_address(1);
+        })
+        .assert_ok();
+
+    lp_setup.confirm_all();
+
+    lp_setup.filter_tickets().assert_ok();
+    lp_setup.select_winners().assert_ok();
+
+    // only 3 of the 4 winning tickets can be assigned, one per address
+    lp_setup
+        .query(|sc| {
+            for p in participants.iter() {
+                assert_eq!(
+                    sc.get_number_of_winning_tickets_for_address(managed_address!(p)),
+                    1
+                );
+            }
+
+            assert_eq!(sc.nr_winning_tickets().get(), 3);
+            assert_eq!(
+                sc.claimable_ticket_payment().get(),
+                managed_biguint!(TICKET_COST * 3)
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn paginated_winners_views_test() {
+    let mut lp_setup = LaunchpadSetup::new(NR_WINNING_TICKETS);
+    let participants = lp_setup.participants.clone();
+    lp_setup.confirm_all();
+
+    lp_setup.filter_tickets().assert_ok();
+    lp_setup.select_winners().assert_ok();
+
+    lp_setup
+        .query(|sc| {
+            let mut expected_winning_tickets = Vec::new();
+            let mut expected_winners = Vec::new();
+            for p in participants.iter() {
+                let user_ticket_ids = sc.get_winning_ticket_ids_for_address(managed_address!(p));
+                if !user_ticket_ids.is_empty() {
+                    expected_winners.push(managed_address!(p));
+                }
+                expected_winning_tickets.extend(user_ticket_ids.into_iter());
+            }
+
+            let mut winning_tickets: Vec<u64> = sc.get_winning_tickets(0, 3).into_iter().collect();
+            winning_tickets.extend(sc.get_winning_tickets(3, 3).into_iter());
+            assert_eq!(winning_tickets, expected_winning_tickets);
+
+            let mut winners: Vec<_> = sc.get_winners_addresses(0, 2).into_iter().collect();
+            winners.extend(sc.get_winners_addresses(2, 10).into_iter());
+            assert_eq!(winners, expected_winners);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn ticket_owner_view_test() {
+    let mut lp_setup = LaunchpadSetup::new(NR_WINNING_TICKETS);
+    let participants = lp_setup.participants.clone();
+
+    lp_setup
+        .query(|sc| {
+            assert_eq!(
+                sc.get_ticket_owner_view(1),
+                managed_address!(&participants[0])
+            );
+            assert_eq!(
+                sc.get_ticket_owner_view(3),
+                managed_address!(&participants[1])
+            );
+            assert_eq!(
+                sc.get_ticket_owner_view(5),
+                managed_address!(&participants[2])
+            );
+        })
+        .assert_ok();
+
+    // first user does not confirm, so the IDs of the other tickets are shifted
+    lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND);
+    lp_setup.confirm(&participants[1], 2).assert_ok();
+    lp_setup.confirm(&participants[2], 3).assert_ok();
+
+    lp_setup
+        .b_mock
+        .set_block_round(WINNER_SELECTION_START_ROUND);
+    lp_setup.filter_tickets().assert_ok();
+
+    lp_setup
+        .query(|sc| {
+            assert_eq!(
+                sc.get_ticket_owner_view(1),
+                managed_address!(&participants[1])
+            );
+            assert_eq!(
+                sc.get_ticket_owner_view(2),
+                managed_address!(&participants[1])
+            );
+            assert_eq!(
+                sc.get_ticket_owner_view(3),
+                managed_address!(&participants[2])
+            );
+            assert_eq!(
+                sc.get_ticket_owner_view(5),
+                managed_address!(&participants[2])
+            );
+        })
+        .assert_ok();
+
+    lp_setup
+        .query(|sc| {
+            let _ = sc.get_ticket_owner_view(6);
+        })
+        .assert_error(4, "Invalid ticket ID");
+}
+
+#[test]
+fn mock_random_reproducible_selection_test() {
+    let mut selections = Vec::new();
+    for block_seed in [1u8, 2u8] {
+        let mut lp_setup = LaunchpadSetup::new(NR_WINNING_TICKETS);
+        lp_setup.confirm_all();
+        lp_setup
+            .b_mock
+            .set_block_random_seed(Box::new([block_seed; 48]));
+        lp_setup.filter_tickets().assert_ok();
+        lp_setup.select_winners().assert_ok();
+
+        let mut winning_ticket_ids = Vec::new();
+        lp_setup
+            .query(|sc| {
+                for ticket_id in 1..=sc.last_ticket_id().get() {
+                    if sc.get_ticket_status(ticket_id) == WINNING_TICKET {
+                        winning_ticket_ids.push(ticket_id);
+                    }
+                }
+            })
+            .assert_ok();
+
+        selections.push(winning_ticket_ids);
+    }
+
+    assert_eq!(selections[0], selections[1]);
+}
+
+#[test]
+fn ordered_selection_mode_test() {
+    // enough winning tickets for all the allowances
+    let mut lp_setup = LaunchpadSetup::new(NR_WINNING_TICKETS + 3);
+    let participants = lp_setup.participants.clone();
+
+    lp_setup
+        .call_owner(|sc| {
+            sc.set_ordered_selection_mode(true);
+        })
+        .assert_ok();
+
+    lp_setup.b_mock.set_block_round(CONFIRM_START_ROUND);
+    for (i, p) in participants.iter().enumerate() {
+        lp_setup.confirm(p, i + 1).assert_ok();
+    }
+
+    lp_setup
+        .call_owner(|sc| {
+            sc.set_ordered_selection_mode(false);
+        })
+        .assert_user_error("Cannot change after confirmation period started");
+
+    lp_setup
+        .call(&participants[1], |sc| {
+            sc.unconfirm_tickets(1);
+        })
+        .assert_user_error("Confirmed tickets are final in ordered selection mode");
+
+    lp_setup
+        .b_mock
+        .set_block_round(WINNER_SELECTION_START_ROUND);
+    lp_setup.filter_tickets().assert_ok();
+    lp_setup.select_winners().assert_ok();
+
+    lp_setup
+        .query(|sc| {
+            for (i, p) in participants.iter().enumerate() {
+                assert_eq!(
+                    sc.get_number_of_winning_tickets_for_address(managed_address!(p)),
+                    i + 1
+                );
+            }
+            assert!(sc.winner_selection_proof().is_empty());
+            assert_eq!(
+                sc.claimable_ticket_payment().get(),
+                managed_biguint!(TICKET_COST * 6)
+            );
+        })
+        .assert_ok();
+}
@@ -0,0 +1,46 @@
+mod launchpad_setup;
+
+use launchpad_common::winners_hash::WinnersHashModule;
+use launchpad_setup::{LaunchpadSetup, CLAIM_START_ROUND, NR_WINNING_TICKETS};
+use multiversx_sc::contract_base::ContractBase;
+use multiversx_sc::types::ManagedByteArray;
+
+#[test]
+fn winners_hash_test() {
+    let mut lp_setup = LaunchpadSetup::new(NR_WINNING_TICKETS);
+
+    lp_setup.confirm_all();
+    lp_setup.filter_tickets().assert_ok();
+
+    lp_setup
+        .call_owner(|sc| {
+            let _ = sc.compute_winners_hash();
+        })
+        .assert_user_error("Winners not selected yet");
+
+    // tickets 1, 2 and 4 are winning
+    lp_setup.select_winners_mock(&[1, 2, 4]).assert_ok();
+    lp_setup.compute_winners_hash().assert_ok();
+
+    lp_setup
+        .query(|sc| {
+            let mut expected_hash = ManagedByteArray::new_from_bytes(&[0u8; 32]);
+            for ticket_id in [1u64, 2, 4] {
+                let mut hash_input = expected_hash.as_managed_buffer().clone();
+                hash_input.append_bytes(&ticket_id.to_be_bytes());
+                expected_hash = sc.crypto().sha256(&hash_input);
+            }
+
+            assert_eq!(sc.winners_hash().get(), expected_hash);
+        })
+        .assert_ok();
+
+    lp_setup
+        .compute_winners_hash()
+        .assert_user_error("Winners hash already computed");
+
+    lp_setup.b_mock.set_block_round(CLAIM_START_ROUND);
+    lp_setup
+        .compute_winners_hash()
+        .assert_user_error("Claim period has started");
+}